@@ -1,13 +1,126 @@
 use std::{net::SocketAddr, sync::Arc};
 use axum::{Router,serve};
 use tokio::net::TcpListener;
-use project_tracker_db::database::Database as ProdDatabase;
+use project_tracker_db::{database::Database as ProdDatabase, relations::DeletePolicy};
 use project_tracker_db_mock::database::Database as MockDatabase;
 use crate::{
+    config::Config,
     routes::create_router,
-    db::project_repository,
-    services::project_services
+    db::{
+        project_repository::{MockProjectRepository, ProdProjectRepository, ProjectRepository},
+        task_repository::{MockTaskRepository, ProdTaskRepository, TaskRepository},
+        tag_repository::{MockTagRepository, ProdTagRepository, TagRepository},
+        person_repository::{MockPersonRepository, PersonRepository, ProdPersonRepository},
+        milestone_repository::{MilestoneRepository, MockMilestoneRepository, ProdMilestoneRepository},
+        status_transition_repository::{
+            MockStatusTransitionRepository, ProdStatusTransitionRepository, StatusTransitionRepository,
+        },
+        time_entry_repository::{MockTimeEntryRepository, ProdTimeEntryRepository, TimeEntryRepository},
+        comment_repository::{CommentRepository, MockCommentRepository, ProdCommentRepository},
+        audit_repository::{AuditRepository, MockAuditRepository, ProdAuditRepository},
+        audited::Audited,
+    },
+    services::command_services::CommandHistories,
 };
+use project_tracker_core::models::{project::Project, task::Task};
+
+/// Shared handler state, holding the repositories the services operate on
+/// and the command histories of the items edited through commands
+#[derive(Clone)]
+pub struct AppState {
+    pub project_repository: Arc<dyn ProjectRepository>,
+    pub task_repository: Arc<dyn TaskRepository>,
+    pub tag_repository: Arc<dyn TagRepository>,
+    pub person_repository: Arc<dyn PersonRepository>,
+    pub transition_repository: Arc<dyn StatusTransitionRepository>,
+    pub milestone_repository: Arc<dyn MilestoneRepository>,
+    pub time_entry_repository: Arc<dyn TimeEntryRepository>,
+    pub comment_repository: Arc<dyn CommentRepository>,
+    pub audit_repository: Arc<dyn AuditRepository>,
+    pub project_commands: Arc<CommandHistories<Project>>,
+    pub task_commands: Arc<CommandHistories<Task>>,
+}
+
+impl AppState {
+    /// State backed by `db`, deleting projects and tasks under `delete_policy`
+    pub fn new(db: Arc<ProdDatabase>, delete_policy: DeletePolicy) -> Self {
+        let audit_repository: Arc<dyn AuditRepository> = Arc::new(ProdAuditRepository::new(db.clone()));
+        Self {
+            project_repository: Arc::new(Audited::<dyn ProjectRepository>::new(
+                Arc::new(ProdProjectRepository::new(db.clone()).with_delete_policy(delete_policy)),
+                audit_repository.clone(),
+            )),
+            task_repository: Arc::new(Audited::<dyn TaskRepository>::new(
+                Arc::new(ProdTaskRepository::new(db.clone()).with_delete_policy(delete_policy)),
+                audit_repository.clone(),
+            )),
+            tag_repository: Arc::new(Audited::<dyn TagRepository>::new(
+                Arc::new(ProdTagRepository::new(db.clone())),
+                audit_repository.clone(),
+            )),
+            person_repository: Arc::new(Audited::<dyn PersonRepository>::new(
+                Arc::new(ProdPersonRepository::new(db.clone())),
+                audit_repository.clone(),
+            )),
+            transition_repository: Arc::new(ProdStatusTransitionRepository::new(db.clone())),
+            milestone_repository: Arc::new(Audited::<dyn MilestoneRepository>::new(
+                Arc::new(ProdMilestoneRepository::new(db.clone())),
+                audit_repository.clone(),
+            )),
+            time_entry_repository: Arc::new(Audited::<dyn TimeEntryRepository>::new(
+                Arc::new(ProdTimeEntryRepository::new(db.clone())),
+                audit_repository.clone(),
+            )),
+            comment_repository: Arc::new(Audited::<dyn CommentRepository>::new(
+                Arc::new(ProdCommentRepository::new(db)),
+                audit_repository.clone(),
+            )),
+            audit_repository,
+            project_commands: Arc::default(),
+            task_commands: Arc::default(),
+        }
+    }
+
+    /// State backed by in-memory repositories, for tests
+    pub fn mock() -> Self {
+        let audit_repository: Arc<dyn AuditRepository> = Arc::new(MockAuditRepository::new());
+        let db = Arc::new(MockDatabase::new());
+        Self {
+            project_repository: Arc::new(Audited::<dyn ProjectRepository>::new(
+                Arc::new(MockProjectRepository::with_database(db.clone())),
+                audit_repository.clone(),
+            )),
+            task_repository: Arc::new(Audited::<dyn TaskRepository>::new(
+                Arc::new(MockTaskRepository::with_database(db.clone())),
+                audit_repository.clone(),
+            )),
+            tag_repository: Arc::new(Audited::<dyn TagRepository>::new(
+                Arc::new(MockTagRepository::with_database(db.clone())),
+                audit_repository.clone(),
+            )),
+            person_repository: Arc::new(Audited::<dyn PersonRepository>::new(
+                Arc::new(MockPersonRepository::with_database(db)),
+                audit_repository.clone(),
+            )),
+            transition_repository: Arc::new(MockStatusTransitionRepository::new()),
+            milestone_repository: Arc::new(Audited::<dyn MilestoneRepository>::new(
+                Arc::new(MockMilestoneRepository::new()),
+                audit_repository.clone(),
+            )),
+            time_entry_repository: Arc::new(Audited::<dyn TimeEntryRepository>::new(
+                Arc::new(MockTimeEntryRepository::new()),
+                audit_repository.clone(),
+            )),
+            comment_repository: Arc::new(Audited::<dyn CommentRepository>::new(
+                Arc::new(MockCommentRepository::new()),
+                audit_repository.clone(),
+            )),
+            audit_repository,
+            project_commands: Arc::default(),
+            task_commands: Arc::default(),
+        }
+    }
+}
 
 pub async fn run() {
     /* TODO: finalize back-end setup
@@ -17,32 +130,46 @@ pub async fn run() {
     4. create router
     5. launch back-end server
     */
-    
-    load_config(); // WIP
 
-    let db = MockDatabase::connect().await.expect("Failed to connect to db");
+    let config = load_config();
+
+    let db = ProdDatabase::connect_with(&config.database).await.expect("Failed to connect to db");
     let db = Arc::new(db);
 
-    let router = create_router();
-    prepare_services();
+    let state = prepare_services(db, config.database.delete_policy());
+    let router = create_router(state);
     launch_server(router).await;
 }
 
-fn load_config() {
-    // TODO: config definition for back-end
+/// Prints where each schema migration stands against the configured database, without applying any
+pub async fn print_migration_status() {
+    let config = load_config();
+
+    let db = ProdDatabase::open(&config.database).await.expect("Failed to connect to db");
+    let status = db.migration_status().await.expect("Failed to read migration status");
+
+    for migration in &status {
+        println!("{migration}");
+    }
+    let pending = status.iter().filter(|migration| !migration.is_applied()).count();
+    println!("{pending} of {} migrations pending", status.len());
 }
 
-fn prepare_services() {
+fn load_config() -> Config {
+    Config::from_env().expect("Invalid configuration")
+}
 
+fn prepare_services(db: Arc<ProdDatabase>, delete_policy: DeletePolicy) -> AppState {
+    AppState::new(db, delete_policy)
 }
 
 async fn launch_server(router: Router) {
     let addr = SocketAddr::from(([127,0,0,1],7878));
-    
+
     let listener = TcpListener::bind(addr)
         .await
         .expect("Failed to bind to address");
-    
+
     println!("Server listening on http://{}",listener.local_addr().unwrap());
     serve(listener,router).await.unwrap()
-}
\ No newline at end of file
+}
@@ -0,0 +1,17 @@
+use project_tracker_db::config::DatabaseConfig;
+
+use crate::Result;
+
+/// Back-end settings, read from the environment
+#[derive(Clone, Debug)]
+pub struct Config {
+    pub database: DatabaseConfig,
+}
+
+impl Config {
+    pub fn from_env() -> Result<Self> {
+        Ok(Self {
+            database: DatabaseConfig::from_env()?,
+        })
+    }
+}
@@ -0,0 +1,79 @@
+use std::sync::{Arc, RwLock};
+use project_tracker_core::models::audit::AuditEntry;
+use crate::{Result, Error};
+use project_tracker_db::database::Database as ProdDatabase;
+use async_trait::async_trait;
+
+// Traits
+#[async_trait]
+pub trait AuditRepository: Send + Sync {
+    /// Appends an entry; entries are never updated or deleted
+    async fn record(&self, entry: AuditEntry) -> Result<()>;
+    /// Changes made to one entity, oldest first
+    async fn get_for_entity(&self, entity_id: &str) -> Result<Vec<AuditEntry>>;
+}
+
+// region: Actual DB
+pub struct ProdAuditRepository {
+    db: Arc<ProdDatabase>
+}
+
+impl ProdAuditRepository {
+    pub fn new(db: Arc<ProdDatabase>) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl AuditRepository for ProdAuditRepository {
+    async fn record(&self, entry: AuditEntry) -> Result<()> {
+        use project_tracker_db::audit_repository::ProdAuditRepository as DbAuditRepository;
+        use project_tracker_db::audit_repository::AuditRepository as DbAuditRepositoryTrait;
+
+        let db_repo = DbAuditRepository::new(self.db.clone());
+        db_repo.record(entry).await.map_err(Error::DatabaseError)
+    }
+
+    async fn get_for_entity(&self, entity_id: &str) -> Result<Vec<AuditEntry>> {
+        use project_tracker_db::audit_repository::ProdAuditRepository as DbAuditRepository;
+        use project_tracker_db::audit_repository::AuditRepository as DbAuditRepositoryTrait;
+
+        let db_repo = DbAuditRepository::new(self.db.clone());
+        db_repo.get_for_entity(entity_id).await.map_err(Error::DatabaseError)
+    }
+}
+
+// endregion: Actual DB
+// region: Mock db for testing
+#[derive(Default)]
+pub struct MockAuditRepository {
+    entries: RwLock<Vec<AuditEntry>>
+}
+
+impl MockAuditRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl AuditRepository for MockAuditRepository {
+    async fn record(&self, entry: AuditEntry) -> Result<()> {
+        self.entries.write().unwrap().push(entry);
+        Ok(())
+    }
+
+    async fn get_for_entity(&self, entity_id: &str) -> Result<Vec<AuditEntry>> {
+        let mut history: Vec<AuditEntry> = self
+            .entries
+            .read()
+            .unwrap()
+            .iter()
+            .filter(|entry| entry.entity_id() == entity_id)
+            .cloned()
+            .collect();
+        history.sort_by(|a, b| a.at().cmp(&b.at()).then_with(|| a.field().cmp(b.field())));
+        Ok(history)
+    }
+}
+// endregion: Mock db for testing
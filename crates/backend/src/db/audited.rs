@@ -0,0 +1,308 @@
+use std::{future::Future, sync::Arc};
+use async_trait::async_trait;
+use chrono::Utc;
+use project_tracker_core::{
+    EntityType, HasId,
+    id::Id,
+    models::{
+        audit::{AuditEntry, Auditable},
+        comment::Comment,
+        milestone::Milestone,
+        person::Person,
+        project::Project,
+        schedulable::SchedulableItem,
+        tag::Tag,
+        task::Task,
+        time_entry::TimeEntry,
+    },
+};
+use project_tracker_db::relations::DeletedItems;
+use crate::{
+    Result,
+    db::{
+        audit_repository::AuditRepository, comment_repository::CommentRepository,
+        milestone_repository::MilestoneRepository, person_repository::PersonRepository,
+        project_repository::ProjectRepository, tag_repository::TagRepository, task_repository::TaskRepository,
+        time_entry_repository::TimeEntryRepository,
+    },
+};
+
+tokio::task_local! {
+    static ACTOR: Option<Id<Person>>;
+}
+
+/// Runs `future` with `actor` credited for the changes it makes through audited repositories
+pub async fn with_actor<F: Future>(actor: Option<Id<Person>>, future: F) -> F::Output {
+    ACTOR.scope(actor, future).await
+}
+
+/// Person the running request acts as, if it named one
+fn current_actor() -> Option<Id<Person>> {
+    ACTOR.try_with(Clone::clone).ok().flatten()
+}
+
+/// Wraps a repository so every create, update and delete going through it lands in the audit log,
+/// one entry per changed field.
+///
+/// Only the back-end writes through these wrappers: the CLI, or anything else using the db repositories
+/// directly, is not audited. Entries are recorded after the write succeeded, in queries of their own, so
+/// a failure in between leaves that change out of the log.
+pub struct Audited<R: ?Sized> {
+    inner: Arc<R>,
+    audit: Arc<dyn AuditRepository>,
+}
+
+impl<R: ?Sized> Audited<R> {
+    pub fn new(inner: Arc<R>, audit: Arc<dyn AuditRepository>) -> Self {
+        Self { inner, audit }
+    }
+
+    async fn log<T>(&self, before: Option<&T>, after: Option<&T>) -> Result<()>
+    where
+        T: Auditable + Sync,
+        T::Entity: EntityType,
+    {
+        for entry in AuditEntry::changes(before, after, current_actor(), Utc::now()) {
+            self.audit.record(entry).await?;
+        }
+        Ok(())
+    }
+
+    /// Logs every project and task a delete removed, and the change to every one it unlinked
+    async fn log_deleted(&self, deleted: &DeletedItems) -> Result<()> {
+        for project in &deleted.projects {
+            self.log(Some(project), None).await?;
+        }
+        for task in &deleted.tasks {
+            self.log(Some(task), None).await?;
+        }
+        for (before, after) in &deleted.detached_projects {
+            self.log(Some(before), Some(after)).await?;
+        }
+        for (before, after) in &deleted.detached_tasks {
+            self.log(Some(before), Some(after)).await?;
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ProjectRepository for Audited<dyn ProjectRepository> {
+    async fn create(&self, project: Project) -> Result<()> {
+        self.inner.create(project.clone()).await?;
+        self.log(None, Some(&project)).await
+    }
+
+    async fn get_by_id(&self, id: Id<Project>) -> Result<Option<Project>> {
+        self.inner.get_by_id(id).await
+    }
+
+    async fn get_all(&self) -> Result<Vec<Project>> {
+        self.inner.get_all().await
+    }
+
+    async fn update(&self, project: Project) -> Result<()> {
+        let before = self.inner.get_by_id(project.id()).await?;
+        self.inner.update(project.clone()).await?;
+        self.log(before.as_ref(), Some(&project)).await
+    }
+
+    async fn delete(&self, id: Id<Project>) -> Result<DeletedItems> {
+        let deleted = self.inner.delete(id).await?;
+        self.log_deleted(&deleted).await?;
+        Ok(deleted)
+    }
+}
+
+#[async_trait]
+impl TaskRepository for Audited<dyn TaskRepository> {
+    async fn create(&self, task: Task) -> Result<()> {
+        self.inner.create(task.clone()).await?;
+        self.log(None, Some(&task)).await
+    }
+
+    async fn get_by_id(&self, id: Id<Task>) -> Result<Option<Task>> {
+        self.inner.get_by_id(id).await
+    }
+
+    async fn get_all(&self) -> Result<Vec<Task>> {
+        self.inner.get_all().await
+    }
+
+    async fn update(&self, task: Task) -> Result<()> {
+        let before = self.inner.get_by_id(task.id()).await?;
+        self.inner.update(task.clone()).await?;
+        self.log(before.as_ref(), Some(&task)).await
+    }
+
+    async fn delete(&self, id: Id<Task>) -> Result<DeletedItems> {
+        let deleted = self.inner.delete(id).await?;
+        self.log_deleted(&deleted).await?;
+        Ok(deleted)
+    }
+}
+
+#[async_trait]
+impl TagRepository for Audited<dyn TagRepository> {
+    async fn create(&self, tag: Tag) -> Result<()> {
+        self.inner.create(tag.clone()).await?;
+        self.log(None, Some(&tag)).await
+    }
+
+    async fn get_by_id(&self, id: Id<Tag>) -> Result<Option<Tag>> {
+        self.inner.get_by_id(id).await
+    }
+
+    async fn get_by_name(&self, name: &str) -> Result<Option<Tag>> {
+        self.inner.get_by_name(name).await
+    }
+
+    async fn get_all(&self) -> Result<Vec<Tag>> {
+        self.inner.get_all().await
+    }
+
+    async fn update(&self, tag: Tag) -> Result<()> {
+        let before = self.inner.get_by_id(tag.id()).await?;
+        self.inner.update(tag.clone()).await?;
+        self.log(before.as_ref(), Some(&tag)).await
+    }
+
+    async fn delete(&self, id: Id<Tag>) -> Result<()> {
+        let before = self.inner.get_by_id(id.clone()).await?;
+        self.inner.delete(id).await?;
+        self.log(before.as_ref(), None).await
+    }
+
+    async fn get_or_create_by_name(&self, name: &str) -> Result<Tag> {
+        let existing = self.inner.get_by_name(name).await?;
+        let tag = self.inner.get_or_create_by_name(name).await?;
+        if existing.is_none() {
+            self.log(None, Some(&tag)).await?;
+        }
+        Ok(tag)
+    }
+}
+
+#[async_trait]
+impl PersonRepository for Audited<dyn PersonRepository> {
+    async fn create(&self, person: Person) -> Result<()> {
+        self.inner.create(person.clone()).await?;
+        self.log(None, Some(&person)).await
+    }
+
+    async fn get_by_id(&self, id: Id<Person>) -> Result<Option<Person>> {
+        self.inner.get_by_id(id).await
+    }
+
+    async fn get_all(&self) -> Result<Vec<Person>> {
+        self.inner.get_all().await
+    }
+
+    async fn update(&self, person: Person) -> Result<()> {
+        let before = self.inner.get_by_id(person.id()).await?;
+        self.inner.update(person.clone()).await?;
+        self.log(before.as_ref(), Some(&person)).await
+    }
+
+    async fn delete(&self, id: Id<Person>) -> Result<()> {
+        let before = self.inner.get_by_id(id.clone()).await?;
+        self.inner.delete(id).await?;
+        self.log(before.as_ref(), None).await
+    }
+}
+
+#[async_trait]
+impl MilestoneRepository for Audited<dyn MilestoneRepository> {
+    async fn create(&self, milestone: Milestone) -> Result<()> {
+        self.inner.create(milestone.clone()).await?;
+        self.log(None, Some(&milestone)).await
+    }
+
+    async fn get_by_id(&self, id: Id<Milestone>) -> Result<Option<Milestone>> {
+        self.inner.get_by_id(id).await
+    }
+
+    async fn get_all(&self) -> Result<Vec<Milestone>> {
+        self.inner.get_all().await
+    }
+
+    async fn update(&self, milestone: Milestone) -> Result<()> {
+        let before = self.inner.get_by_id(milestone.id()).await?;
+        self.inner.update(milestone.clone()).await?;
+        self.log(before.as_ref(), Some(&milestone)).await
+    }
+
+    async fn delete(&self, id: Id<Milestone>) -> Result<()> {
+        let before = self.inner.get_by_id(id.clone()).await?;
+        self.inner.delete(id).await?;
+        self.log(before.as_ref(), None).await
+    }
+}
+
+#[async_trait]
+impl TimeEntryRepository for Audited<dyn TimeEntryRepository> {
+    async fn create(&self, entry: TimeEntry) -> Result<()> {
+        self.inner.create(entry.clone()).await?;
+        self.log(None, Some(&entry)).await
+    }
+
+    async fn get_by_id(&self, id: Id<TimeEntry>) -> Result<Option<TimeEntry>> {
+        self.inner.get_by_id(id).await
+    }
+
+    async fn get_all(&self) -> Result<Vec<TimeEntry>> {
+        self.inner.get_all().await
+    }
+
+    async fn get_for_item(&self, item: &SchedulableItem) -> Result<Vec<TimeEntry>> {
+        self.inner.get_for_item(item).await
+    }
+
+    async fn get_for_person(&self, person_id: &Id<Person>) -> Result<Vec<TimeEntry>> {
+        self.inner.get_for_person(person_id).await
+    }
+
+    async fn update(&self, entry: TimeEntry) -> Result<()> {
+        let before = self.inner.get_by_id(entry.id()).await?;
+        self.inner.update(entry.clone()).await?;
+        self.log(before.as_ref(), Some(&entry)).await
+    }
+
+    async fn delete(&self, id: Id<TimeEntry>) -> Result<()> {
+        let before = self.inner.get_by_id(id.clone()).await?;
+        self.inner.delete(id).await?;
+        self.log(before.as_ref(), None).await
+    }
+}
+
+#[async_trait]
+impl CommentRepository for Audited<dyn CommentRepository> {
+    async fn create(&self, comment: Comment) -> Result<()> {
+        self.inner.create(comment.clone()).await?;
+        self.log(None, Some(&comment)).await
+    }
+
+    async fn get_by_id(&self, id: Id<Comment>) -> Result<Option<Comment>> {
+        self.inner.get_by_id(id).await
+    }
+
+    async fn get_all(&self) -> Result<Vec<Comment>> {
+        self.inner.get_all().await
+    }
+
+    async fn get_for_item(&self, item: &SchedulableItem) -> Result<Vec<Comment>> {
+        self.inner.get_for_item(item).await
+    }
+
+    async fn update(&self, comment: Comment) -> Result<()> {
+        let before = self.inner.get_by_id(comment.id()).await?;
+        self.inner.update(comment.clone()).await?;
+        self.log(before.as_ref(), Some(&comment)).await
+    }
+
+    async fn delete(&self, id: Id<Comment>) -> Result<()> {
+        let before = self.inner.get_by_id(id.clone()).await?;
+        self.inner.delete(id).await?;
+        self.log(before.as_ref(), None).await
+    }
+}
@@ -0,0 +1,152 @@
+use std::{collections::HashMap, sync::{Arc, RwLock}};
+use project_tracker_core::{
+    id::Id,
+    models::{comment::Comment, schedulable::SchedulableItem},
+    HasId,
+};
+use crate::{Result, Error};
+use project_tracker_db::database::Database as ProdDatabase;
+use async_trait::async_trait;
+
+// Traits
+#[async_trait]
+pub trait CommentRepository: Send + Sync {
+    async fn create(&self, comment: Comment) -> Result<()>;
+    async fn get_by_id(&self, id: Id<Comment>) -> Result<Option<Comment>>;
+    async fn get_all(&self) -> Result<Vec<Comment>>;
+    /// Comments left on one item, replies included, oldest first
+    async fn get_for_item(&self, item: &SchedulableItem) -> Result<Vec<Comment>>;
+    async fn update(&self, comment: Comment) -> Result<()>;
+    async fn delete(&self, id: Id<Comment>) -> Result<()>;
+}
+
+// region: Actual DB
+pub struct ProdCommentRepository {
+    db: Arc<ProdDatabase>
+}
+
+impl ProdCommentRepository {
+    pub fn new(db: Arc<ProdDatabase>) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl CommentRepository for ProdCommentRepository {
+    async fn create(&self, comment: Comment) -> Result<()> {
+        use project_tracker_db::comment_repository::ProdCommentRepository as DbCommentRepository;
+        use project_tracker_db::comment_repository::CommentRepository as DbCommentRepositoryTrait;
+
+        let db_repo = DbCommentRepository::new(self.db.clone());
+        db_repo.create(comment).await.map_err(Error::DatabaseError)
+    }
+
+    async fn get_by_id(&self, id: Id<Comment>) -> Result<Option<Comment>> {
+        use project_tracker_db::comment_repository::ProdCommentRepository as DbCommentRepository;
+        use project_tracker_db::comment_repository::CommentRepository as DbCommentRepositoryTrait;
+
+        let db_repo = DbCommentRepository::new(self.db.clone());
+        db_repo.get_by_id(id).await.map_err(Error::DatabaseError)
+    }
+
+    async fn get_all(&self) -> Result<Vec<Comment>> {
+        use project_tracker_db::comment_repository::ProdCommentRepository as DbCommentRepository;
+        use project_tracker_db::comment_repository::CommentRepository as DbCommentRepositoryTrait;
+
+        let db_repo = DbCommentRepository::new(self.db.clone());
+        db_repo.get_all().await.map_err(Error::DatabaseError)
+    }
+
+    async fn get_for_item(&self, item: &SchedulableItem) -> Result<Vec<Comment>> {
+        use project_tracker_db::comment_repository::ProdCommentRepository as DbCommentRepository;
+        use project_tracker_db::comment_repository::CommentRepository as DbCommentRepositoryTrait;
+
+        let db_repo = DbCommentRepository::new(self.db.clone());
+        db_repo.get_for_item(item).await.map_err(Error::DatabaseError)
+    }
+
+    async fn update(&self, comment: Comment) -> Result<()> {
+        use project_tracker_db::comment_repository::ProdCommentRepository as DbCommentRepository;
+        use project_tracker_db::comment_repository::CommentRepository as DbCommentRepositoryTrait;
+
+        let db_repo = DbCommentRepository::new(self.db.clone());
+        db_repo.update(comment).await.map_err(Error::DatabaseError)
+    }
+
+    async fn delete(&self, id: Id<Comment>) -> Result<()> {
+        use project_tracker_db::comment_repository::ProdCommentRepository as DbCommentRepository;
+        use project_tracker_db::comment_repository::CommentRepository as DbCommentRepositoryTrait;
+
+        let db_repo = DbCommentRepository::new(self.db.clone());
+        db_repo.delete(id).await.map_err(Error::DatabaseError)
+    }
+}
+
+// endregion: Actual DB
+// region: Mock db for testing
+#[derive(Default)]
+pub struct MockCommentRepository {
+    comments: RwLock<HashMap<Id<Comment>, Comment>>
+}
+
+impl MockCommentRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn filtered(&self, filter: impl Fn(&Comment) -> bool) -> Vec<Comment> {
+        let mut comments: Vec<Comment> = self
+            .comments
+            .read()
+            .unwrap()
+            .values()
+            .filter(|comment| filter(comment))
+            .cloned()
+            .collect();
+        comments.sort_by_key(Comment::created_at);
+        comments
+    }
+}
+
+#[async_trait]
+impl CommentRepository for MockCommentRepository {
+    async fn create(&self, comment: Comment) -> Result<()> {
+        let mut comments = self.comments.write().unwrap();
+        if comments.contains_key(&comment.id()) {
+            return Err(Error::ProjectError(format!("Comment {} already exists", comment.id())));
+        }
+        comments.insert(comment.id(), comment);
+        Ok(())
+    }
+
+    async fn get_by_id(&self, id: Id<Comment>) -> Result<Option<Comment>> {
+        Ok(self.comments.read().unwrap().get(&id).cloned())
+    }
+
+    async fn get_all(&self) -> Result<Vec<Comment>> {
+        Ok(self.comments.read().unwrap().values().cloned().collect())
+    }
+
+    async fn get_for_item(&self, item: &SchedulableItem) -> Result<Vec<Comment>> {
+        Ok(self.filtered(|comment| comment.item() == item))
+    }
+
+    async fn update(&self, comment: Comment) -> Result<()> {
+        let mut comments = self.comments.write().unwrap();
+        match comments.get_mut(&comment.id()) {
+            Some(stored) => {
+                *stored = comment;
+                Ok(())
+            }
+            None => Err(Error::NotFound(format!("Comment {} not found", comment.id()))),
+        }
+    }
+
+    async fn delete(&self, id: Id<Comment>) -> Result<()> {
+        match self.comments.write().unwrap().remove(&id) {
+            Some(_) => Ok(()),
+            None => Err(Error::NotFound(format!("Comment {id} not found"))),
+        }
+    }
+}
+// endregion: Mock db for testing
@@ -0,0 +1,121 @@
+use std::{collections::HashMap, sync::{Arc, RwLock}};
+use project_tracker_core::{id::Id, models::milestone::Milestone, HasId};
+use crate::{Result, Error};
+use project_tracker_db::database::Database as ProdDatabase;
+use async_trait::async_trait;
+
+// Traits
+#[async_trait]
+pub trait MilestoneRepository: Send + Sync {
+    async fn create(&self, milestone: Milestone) -> Result<()>;
+    async fn get_by_id(&self, id: Id<Milestone>) -> Result<Option<Milestone>>;
+    async fn get_all(&self) -> Result<Vec<Milestone>>;
+    async fn update(&self, milestone: Milestone) -> Result<()>;
+    async fn delete(&self, id: Id<Milestone>) -> Result<()>;
+}
+
+// region: Actual DB
+pub struct ProdMilestoneRepository {
+    db: Arc<ProdDatabase>
+}
+
+impl ProdMilestoneRepository {
+    pub fn new(db: Arc<ProdDatabase>) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl MilestoneRepository for ProdMilestoneRepository {
+    async fn create(&self, milestone: Milestone) -> Result<()> {
+        use project_tracker_db::milestone_repository::ProdMilestoneRepository as DbMilestoneRepository;
+        use project_tracker_db::milestone_repository::MilestoneRepository as DbMilestoneRepositoryTrait;
+
+        let db_repo = DbMilestoneRepository::new(self.db.clone());
+        db_repo.create(milestone).await.map_err(Error::DatabaseError)
+    }
+
+    async fn get_by_id(&self, id: Id<Milestone>) -> Result<Option<Milestone>> {
+        use project_tracker_db::milestone_repository::ProdMilestoneRepository as DbMilestoneRepository;
+        use project_tracker_db::milestone_repository::MilestoneRepository as DbMilestoneRepositoryTrait;
+
+        let db_repo = DbMilestoneRepository::new(self.db.clone());
+        db_repo.get_by_id(id).await.map_err(Error::DatabaseError)
+    }
+
+    async fn get_all(&self) -> Result<Vec<Milestone>> {
+        use project_tracker_db::milestone_repository::ProdMilestoneRepository as DbMilestoneRepository;
+        use project_tracker_db::milestone_repository::MilestoneRepository as DbMilestoneRepositoryTrait;
+
+        let db_repo = DbMilestoneRepository::new(self.db.clone());
+        db_repo.get_all().await.map_err(Error::DatabaseError)
+    }
+
+    async fn update(&self, milestone: Milestone) -> Result<()> {
+        use project_tracker_db::milestone_repository::ProdMilestoneRepository as DbMilestoneRepository;
+        use project_tracker_db::milestone_repository::MilestoneRepository as DbMilestoneRepositoryTrait;
+
+        let db_repo = DbMilestoneRepository::new(self.db.clone());
+        db_repo.update(milestone).await.map_err(Error::DatabaseError)
+    }
+
+    async fn delete(&self, id: Id<Milestone>) -> Result<()> {
+        use project_tracker_db::milestone_repository::ProdMilestoneRepository as DbMilestoneRepository;
+        use project_tracker_db::milestone_repository::MilestoneRepository as DbMilestoneRepositoryTrait;
+
+        let db_repo = DbMilestoneRepository::new(self.db.clone());
+        db_repo.delete(id).await.map_err(Error::DatabaseError)
+    }
+}
+
+// endregion: Actual DB
+// region: Mock db for testing
+#[derive(Default)]
+pub struct MockMilestoneRepository {
+    milestones: RwLock<HashMap<Id<Milestone>, Milestone>>
+}
+
+impl MockMilestoneRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl MilestoneRepository for MockMilestoneRepository {
+    async fn create(&self, milestone: Milestone) -> Result<()> {
+        let mut milestones = self.milestones.write().unwrap();
+        if milestones.contains_key(&milestone.id()) {
+            return Err(Error::ProjectError(format!("Milestone {} already exists", milestone.id())));
+        }
+        milestones.insert(milestone.id(), milestone);
+        Ok(())
+    }
+
+    async fn get_by_id(&self, id: Id<Milestone>) -> Result<Option<Milestone>> {
+        Ok(self.milestones.read().unwrap().get(&id).cloned())
+    }
+
+    async fn get_all(&self) -> Result<Vec<Milestone>> {
+        Ok(self.milestones.read().unwrap().values().cloned().collect())
+    }
+
+    async fn update(&self, milestone: Milestone) -> Result<()> {
+        let mut milestones = self.milestones.write().unwrap();
+        match milestones.get_mut(&milestone.id()) {
+            Some(stored) => {
+                *stored = milestone;
+                Ok(())
+            }
+            None => Err(Error::NotFound(format!("Milestone {} not found", milestone.id()))),
+        }
+    }
+
+    async fn delete(&self, id: Id<Milestone>) -> Result<()> {
+        match self.milestones.write().unwrap().remove(&id) {
+            Some(_) => Ok(()),
+            None => Err(Error::NotFound(format!("Milestone {id} not found"))),
+        }
+    }
+}
+// endregion: Mock db for testing
@@ -1,4 +1,10 @@
 pub mod project_repository;
 pub mod task_repository;
 pub mod person_repository;
-pub mod tag_repository;
\ No newline at end of file
+pub mod tag_repository;
+pub mod status_transition_repository;
+pub mod milestone_repository;
+pub mod time_entry_repository;
+pub mod comment_repository;
+pub mod audit_repository;
+pub mod audited;
@@ -0,0 +1,137 @@
+use std::sync::Arc;
+use project_tracker_core::{id::Id, models::person::Person};
+use crate::{Result, Error};
+use project_tracker_db::database::Database as ProdDatabase;
+use project_tracker_db_mock::database::Database as MockDatabase;
+use async_trait::async_trait;
+
+// Traits
+#[async_trait]
+pub trait PersonRepository: Send + Sync {
+    async fn create(&self, person: Person) -> Result<()>;
+    async fn get_by_id(&self, id: Id<Person>) -> Result<Option<Person>>;
+    async fn get_all(&self) -> Result<Vec<Person>>;
+    async fn update(&self, person: Person) -> Result<()>;
+    async fn delete(&self, id: Id<Person>) -> Result<()>;
+}
+
+// region: Actual DB
+pub struct ProdPersonRepository {
+    db: Arc<ProdDatabase>
+}
+
+impl ProdPersonRepository {
+    pub fn new(db: Arc<ProdDatabase>) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl PersonRepository for ProdPersonRepository {
+    async fn create(&self, person: Person) -> Result<()> {
+        use project_tracker_db::person_repository::ProdPersonRepository as DbPersonRepository;
+        use project_tracker_db::person_repository::PersonRepository as DbPersonRepositoryTrait;
+
+        let db_repo = DbPersonRepository::new(self.db.clone());
+        db_repo.create(person).await.map_err(Error::DatabaseError)
+    }
+
+    async fn get_by_id(&self, id: Id<Person>) -> Result<Option<Person>> {
+        use project_tracker_db::person_repository::ProdPersonRepository as DbPersonRepository;
+        use project_tracker_db::person_repository::PersonRepository as DbPersonRepositoryTrait;
+
+        let db_repo = DbPersonRepository::new(self.db.clone());
+        db_repo.get_by_id(id).await.map_err(Error::DatabaseError)
+    }
+
+    async fn get_all(&self) -> Result<Vec<Person>> {
+        use project_tracker_db::person_repository::ProdPersonRepository as DbPersonRepository;
+        use project_tracker_db::person_repository::PersonRepository as DbPersonRepositoryTrait;
+
+        let db_repo = DbPersonRepository::new(self.db.clone());
+        db_repo.get_all().await.map_err(Error::DatabaseError)
+    }
+
+    async fn update(&self, person: Person) -> Result<()> {
+        use project_tracker_db::person_repository::ProdPersonRepository as DbPersonRepository;
+        use project_tracker_db::person_repository::PersonRepository as DbPersonRepositoryTrait;
+
+        let db_repo = DbPersonRepository::new(self.db.clone());
+        db_repo.update(person).await.map_err(Error::DatabaseError)
+    }
+
+    async fn delete(&self, id: Id<Person>) -> Result<()> {
+        use project_tracker_db::person_repository::ProdPersonRepository as DbPersonRepository;
+        use project_tracker_db::person_repository::PersonRepository as DbPersonRepositoryTrait;
+
+        let db_repo = DbPersonRepository::new(self.db.clone());
+        db_repo.delete(id).await.map_err(Error::DatabaseError)
+    }
+}
+
+// endregion: Actual DB
+// region: Mock db for testing
+pub struct MockPersonRepository {
+    db: Arc<MockDatabase>
+}
+
+impl MockPersonRepository {
+    pub fn new() -> Self {
+        Self::with_database(Arc::new(MockDatabase::new()))
+    }
+
+    /// Repository on a store shared with other mock repositories, so references between them resolve
+    pub fn with_database(db: Arc<MockDatabase>) -> Self {
+        Self { db }
+    }
+}
+
+impl Default for MockPersonRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl PersonRepository for MockPersonRepository {
+    async fn create(&self, person: Person) -> Result<()> {
+        use project_tracker_db_mock::person_repository::MockPersonRepository as DbPersonRepository;
+        use project_tracker_db::person_repository::PersonRepository as DbPersonRepositoryTrait;
+
+        let db_repo = DbPersonRepository::new(self.db.clone());
+        db_repo.create(person).await.map_err(Error::DatabaseError)
+    }
+
+    async fn get_by_id(&self, id: Id<Person>) -> Result<Option<Person>> {
+        use project_tracker_db_mock::person_repository::MockPersonRepository as DbPersonRepository;
+        use project_tracker_db::person_repository::PersonRepository as DbPersonRepositoryTrait;
+
+        let db_repo = DbPersonRepository::new(self.db.clone());
+        db_repo.get_by_id(id).await.map_err(Error::DatabaseError)
+    }
+
+    async fn get_all(&self) -> Result<Vec<Person>> {
+        use project_tracker_db_mock::person_repository::MockPersonRepository as DbPersonRepository;
+        use project_tracker_db::person_repository::PersonRepository as DbPersonRepositoryTrait;
+
+        let db_repo = DbPersonRepository::new(self.db.clone());
+        db_repo.get_all().await.map_err(Error::DatabaseError)
+    }
+
+    async fn update(&self, person: Person) -> Result<()> {
+        use project_tracker_db_mock::person_repository::MockPersonRepository as DbPersonRepository;
+        use project_tracker_db::person_repository::PersonRepository as DbPersonRepositoryTrait;
+
+        let db_repo = DbPersonRepository::new(self.db.clone());
+        db_repo.update(person).await.map_err(Error::DatabaseError)
+    }
+
+    async fn delete(&self, id: Id<Person>) -> Result<()> {
+        use project_tracker_db_mock::person_repository::MockPersonRepository as DbPersonRepository;
+        use project_tracker_db::person_repository::PersonRepository as DbPersonRepositoryTrait;
+
+        let db_repo = DbPersonRepository::new(self.db.clone());
+        db_repo.delete(id).await.map_err(Error::DatabaseError)
+    }
+}
+// endregion: Mock db for testing
@@ -1,25 +1,37 @@
 use std::sync::Arc;
-use project_tracker_core::{id::Id, models::project::Project, HasId};
+use project_tracker_core::{id::Id, models::project::Project};
 use crate::{Result, Error};
 use project_tracker_db::database::Database as ProdDatabase;
+use project_tracker_db::relations::{DeletePolicy, DeletedItems};
 use project_tracker_db_mock::database::Database as MockDatabase;
 use async_trait::async_trait;
 
 // Traits
 #[async_trait]
-pub trait ProjectRepository { // can serve db and db-mock
+pub trait ProjectRepository: Send + Sync { // can serve db and db-mock
     async fn create(&self, project: Project) -> Result<()>;
     async fn get_by_id(&self, id: Id<Project>) -> Result<Option<Project>>;
+    async fn get_all(&self) -> Result<Vec<Project>>;
+    async fn update(&self, project: Project) -> Result<()>;
+    /// Returns every record the delete removed or unlinked
+    async fn delete(&self, id: Id<Project>) -> Result<DeletedItems>;
 }
 
 // region: Actual DB
 pub struct ProdProjectRepository {
-    db: Arc<ProdDatabase>
+    db: Arc<ProdDatabase>,
+    delete_policy: DeletePolicy,
 }
 
 impl ProdProjectRepository {
     pub fn new(db: Arc<ProdDatabase>) -> Self {
-        Self { db } 
+        Self { db, delete_policy: DeletePolicy::default() }
+    }
+
+    /// What `delete` does to linked items, `DeletePolicy::Detach` unless set
+    pub fn with_delete_policy(mut self, delete_policy: DeletePolicy) -> Self {
+        self.delete_policy = delete_policy;
+        self
     }
 }
 
@@ -28,7 +40,7 @@ impl ProjectRepository for ProdProjectRepository {
     async fn create(&self, project: Project) -> Result<()> {
         use project_tracker_db::project_repository::ProdProjectRepository as DbProjectRepository;
         use project_tracker_db::project_repository::ProjectRepository as DbProjectRepositoryTrait;
-        
+
         let db_repo = DbProjectRepository::new(self.db.clone());
         db_repo.create(project).await.map_err(Error::DatabaseError)
     }
@@ -36,10 +48,34 @@ impl ProjectRepository for ProdProjectRepository {
     async fn get_by_id(&self, id: Id<Project>) -> Result<Option<Project>> {
         use project_tracker_db::project_repository::ProdProjectRepository as DbProjectRepository;
         use project_tracker_db::project_repository::ProjectRepository as DbProjectRepositoryTrait;
-        
+
         let db_repo = DbProjectRepository::new(self.db.clone());
         db_repo.get_by_id(id).await.map_err(Error::DatabaseError)
     }
+
+    async fn get_all(&self) -> Result<Vec<Project>> {
+        use project_tracker_db::project_repository::ProdProjectRepository as DbProjectRepository;
+        use project_tracker_db::project_repository::ProjectRepository as DbProjectRepositoryTrait;
+
+        let db_repo = DbProjectRepository::new(self.db.clone());
+        db_repo.get_all().await.map_err(Error::DatabaseError)
+    }
+
+    async fn update(&self, project: Project) -> Result<()> {
+        use project_tracker_db::project_repository::ProdProjectRepository as DbProjectRepository;
+        use project_tracker_db::project_repository::ProjectRepository as DbProjectRepositoryTrait;
+
+        let db_repo = DbProjectRepository::new(self.db.clone());
+        db_repo.update(project).await.map_err(Error::DatabaseError)
+    }
+
+    async fn delete(&self, id: Id<Project>) -> Result<DeletedItems> {
+        use project_tracker_db::project_repository::ProdProjectRepository as DbProjectRepository;
+        use project_tracker_db::project_repository::ProjectRepository as DbProjectRepositoryTrait;
+
+        let db_repo = DbProjectRepository::new(self.db.clone()).with_delete_policy(self.delete_policy);
+        db_repo.delete(id).await.map_err(Error::DatabaseError)
+    }
 }
 
 // endregion: Actual DB
@@ -49,25 +85,62 @@ pub struct MockProjectRepository {
 }
 
 impl MockProjectRepository {
-    pub fn new(db: Arc<MockDatabase>) -> Self {
-        Self { db } 
+    pub fn new() -> Self {
+        Self::with_database(Arc::new(MockDatabase::new()))
+    }
+
+    /// Repository on a store shared with other mock repositories, so references between them resolve
+    pub fn with_database(db: Arc<MockDatabase>) -> Self {
+        Self { db }
+    }
+}
+
+impl Default for MockProjectRepository {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
 #[async_trait]
 impl ProjectRepository for MockProjectRepository {
     async fn create(&self, project: Project) -> Result<()> {
-        let id_str = project.id().to_string();
-        println!("Succesfully created project in database");
-        Ok(())
+        use project_tracker_db_mock::project_repository::MockProjectRepository as DbProjectRepository;
+        use project_tracker_db::project_repository::ProjectRepository as DbProjectRepositoryTrait;
+
+        let db_repo = DbProjectRepository::new(self.db.clone());
+        db_repo.create(project).await.map_err(Error::DatabaseError)
     }
 
     async fn get_by_id(&self, id: Id<Project>) -> Result<Option<Project>> {
-        // query db for the project by its id
-        Ok(None)
+        use project_tracker_db_mock::project_repository::MockProjectRepository as DbProjectRepository;
+        use project_tracker_db::project_repository::ProjectRepository as DbProjectRepositoryTrait;
+
+        let db_repo = DbProjectRepository::new(self.db.clone());
+        db_repo.get_by_id(id).await.map_err(Error::DatabaseError)
     }
-}
-// endregion: Mock db for testing
 
-// TODO: Hold a reference to the database client
+    async fn get_all(&self) -> Result<Vec<Project>> {
+        use project_tracker_db_mock::project_repository::MockProjectRepository as DbProjectRepository;
+        use project_tracker_db::project_repository::ProjectRepository as DbProjectRepositoryTrait;
 
+        let db_repo = DbProjectRepository::new(self.db.clone());
+        db_repo.get_all().await.map_err(Error::DatabaseError)
+    }
+
+    async fn update(&self, project: Project) -> Result<()> {
+        use project_tracker_db_mock::project_repository::MockProjectRepository as DbProjectRepository;
+        use project_tracker_db::project_repository::ProjectRepository as DbProjectRepositoryTrait;
+
+        let db_repo = DbProjectRepository::new(self.db.clone());
+        db_repo.update(project).await.map_err(Error::DatabaseError)
+    }
+
+    async fn delete(&self, id: Id<Project>) -> Result<DeletedItems> {
+        use project_tracker_db_mock::project_repository::MockProjectRepository as DbProjectRepository;
+        use project_tracker_db::project_repository::ProjectRepository as DbProjectRepositoryTrait;
+
+        let db_repo = DbProjectRepository::new(self.db.clone());
+        db_repo.delete(id).await.map_err(Error::DatabaseError)
+    }
+}
+// endregion: Mock db for testing
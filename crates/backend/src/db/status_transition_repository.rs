@@ -0,0 +1,92 @@
+use std::sync::{Arc, RwLock};
+use project_tracker_core::models::{schedulable::SchedulableItem, status_transition::StatusTransition};
+use crate::{Result, Error};
+use project_tracker_db::database::Database as ProdDatabase;
+use async_trait::async_trait;
+
+// Traits
+#[async_trait]
+pub trait StatusTransitionRepository: Send + Sync {
+    async fn record(&self, transition: StatusTransition) -> Result<()>;
+    /// Records every transition in a single write, so either all of them are kept or none is
+    async fn record_all(&self, transitions: Vec<StatusTransition>) -> Result<()>;
+    async fn get_for_item(&self, item: &SchedulableItem) -> Result<Vec<StatusTransition>>;
+}
+
+// region: Actual DB
+pub struct ProdStatusTransitionRepository {
+    db: Arc<ProdDatabase>
+}
+
+impl ProdStatusTransitionRepository {
+    pub fn new(db: Arc<ProdDatabase>) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl StatusTransitionRepository for ProdStatusTransitionRepository {
+    async fn record(&self, transition: StatusTransition) -> Result<()> {
+        use project_tracker_db::status_transition_repository::ProdStatusTransitionRepository as DbStatusTransitionRepository;
+        use project_tracker_db::status_transition_repository::StatusTransitionRepository as DbStatusTransitionRepositoryTrait;
+
+        let db_repo = DbStatusTransitionRepository::new(self.db.clone());
+        db_repo.record(transition).await.map_err(Error::DatabaseError)
+    }
+
+    async fn record_all(&self, transitions: Vec<StatusTransition>) -> Result<()> {
+        use project_tracker_db::status_transition_repository::ProdStatusTransitionRepository as DbStatusTransitionRepository;
+        use project_tracker_db::status_transition_repository::StatusTransitionRepository as DbStatusTransitionRepositoryTrait;
+
+        let db_repo = DbStatusTransitionRepository::new(self.db.clone());
+        db_repo.record_all(transitions).await.map_err(Error::DatabaseError)
+    }
+
+    async fn get_for_item(&self, item: &SchedulableItem) -> Result<Vec<StatusTransition>> {
+        use project_tracker_db::status_transition_repository::ProdStatusTransitionRepository as DbStatusTransitionRepository;
+        use project_tracker_db::status_transition_repository::StatusTransitionRepository as DbStatusTransitionRepositoryTrait;
+
+        let db_repo = DbStatusTransitionRepository::new(self.db.clone());
+        db_repo.get_for_item(item).await.map_err(Error::DatabaseError)
+    }
+}
+
+// endregion: Actual DB
+// region: Mock db for testing
+#[derive(Default)]
+pub struct MockStatusTransitionRepository {
+    transitions: RwLock<Vec<StatusTransition>>
+}
+
+impl MockStatusTransitionRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl StatusTransitionRepository for MockStatusTransitionRepository {
+    async fn record(&self, transition: StatusTransition) -> Result<()> {
+        self.transitions.write().unwrap().push(transition);
+        Ok(())
+    }
+
+    async fn record_all(&self, transitions: Vec<StatusTransition>) -> Result<()> {
+        self.transitions.write().unwrap().extend(transitions);
+        Ok(())
+    }
+
+    async fn get_for_item(&self, item: &SchedulableItem) -> Result<Vec<StatusTransition>> {
+        let mut history: Vec<StatusTransition> = self
+            .transitions
+            .read()
+            .unwrap()
+            .iter()
+            .filter(|transition| transition.item() == item)
+            .cloned()
+            .collect();
+        history.sort_by_key(|transition| transition.at());
+        Ok(history)
+    }
+}
+// endregion: Mock db for testing
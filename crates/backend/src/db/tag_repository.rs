@@ -0,0 +1,171 @@
+use std::sync::Arc;
+use project_tracker_core::{id::Id, models::tag::Tag};
+use crate::{Result, Error};
+use project_tracker_db::database::Database as ProdDatabase;
+use project_tracker_db_mock::database::Database as MockDatabase;
+use async_trait::async_trait;
+
+// Traits
+#[async_trait]
+pub trait TagRepository: Send + Sync {
+    async fn create(&self, tag: Tag) -> Result<()>;
+    async fn get_by_id(&self, id: Id<Tag>) -> Result<Option<Tag>>;
+    async fn get_by_name(&self, name: &str) -> Result<Option<Tag>>;
+    async fn get_all(&self) -> Result<Vec<Tag>>;
+    async fn update(&self, tag: Tag) -> Result<()>;
+    async fn delete(&self, id: Id<Tag>) -> Result<()>;
+    async fn get_or_create_by_name(&self, name: &str) -> Result<Tag>;
+}
+
+// region: Actual DB
+pub struct ProdTagRepository {
+    db: Arc<ProdDatabase>
+}
+
+impl ProdTagRepository {
+    pub fn new(db: Arc<ProdDatabase>) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl TagRepository for ProdTagRepository {
+    async fn create(&self, tag: Tag) -> Result<()> {
+        use project_tracker_db::tag_repository::ProdTagRepository as DbTagRepository;
+        use project_tracker_db::tag_repository::TagRepository as DbTagRepositoryTrait;
+
+        let db_repo = DbTagRepository::new(self.db.clone());
+        db_repo.create(tag).await.map_err(Error::DatabaseError)
+    }
+
+    async fn get_by_id(&self, id: Id<Tag>) -> Result<Option<Tag>> {
+        use project_tracker_db::tag_repository::ProdTagRepository as DbTagRepository;
+        use project_tracker_db::tag_repository::TagRepository as DbTagRepositoryTrait;
+
+        let db_repo = DbTagRepository::new(self.db.clone());
+        db_repo.get_by_id(id).await.map_err(Error::DatabaseError)
+    }
+
+    async fn get_by_name(&self, name: &str) -> Result<Option<Tag>> {
+        use project_tracker_db::tag_repository::ProdTagRepository as DbTagRepository;
+        use project_tracker_db::tag_repository::TagRepository as DbTagRepositoryTrait;
+
+        let db_repo = DbTagRepository::new(self.db.clone());
+        db_repo.get_by_name(name).await.map_err(Error::DatabaseError)
+    }
+
+    async fn get_all(&self) -> Result<Vec<Tag>> {
+        use project_tracker_db::tag_repository::ProdTagRepository as DbTagRepository;
+        use project_tracker_db::tag_repository::TagRepository as DbTagRepositoryTrait;
+
+        let db_repo = DbTagRepository::new(self.db.clone());
+        db_repo.get_all().await.map_err(Error::DatabaseError)
+    }
+
+    async fn update(&self, tag: Tag) -> Result<()> {
+        use project_tracker_db::tag_repository::ProdTagRepository as DbTagRepository;
+        use project_tracker_db::tag_repository::TagRepository as DbTagRepositoryTrait;
+
+        let db_repo = DbTagRepository::new(self.db.clone());
+        db_repo.update(tag).await.map_err(Error::DatabaseError)
+    }
+
+    async fn delete(&self, id: Id<Tag>) -> Result<()> {
+        use project_tracker_db::tag_repository::ProdTagRepository as DbTagRepository;
+        use project_tracker_db::tag_repository::TagRepository as DbTagRepositoryTrait;
+
+        let db_repo = DbTagRepository::new(self.db.clone());
+        db_repo.delete(id).await.map_err(Error::DatabaseError)
+    }
+
+    async fn get_or_create_by_name(&self, name: &str) -> Result<Tag> {
+        use project_tracker_db::tag_repository::ProdTagRepository as DbTagRepository;
+        use project_tracker_db::tag_repository::TagRepository as DbTagRepositoryTrait;
+
+        let db_repo = DbTagRepository::new(self.db.clone());
+        db_repo.get_or_create_by_name(name).await.map_err(Error::DatabaseError)
+    }
+}
+
+// endregion: Actual DB
+// region: Mock db for testing
+pub struct MockTagRepository {
+    db: Arc<MockDatabase>
+}
+
+impl MockTagRepository {
+    pub fn new() -> Self {
+        Self::with_database(Arc::new(MockDatabase::new()))
+    }
+
+    /// Repository on a store shared with other mock repositories, so references between them resolve
+    pub fn with_database(db: Arc<MockDatabase>) -> Self {
+        Self { db }
+    }
+}
+
+impl Default for MockTagRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl TagRepository for MockTagRepository {
+    async fn create(&self, tag: Tag) -> Result<()> {
+        use project_tracker_db_mock::tag_repository::MockTagRepository as DbTagRepository;
+        use project_tracker_db::tag_repository::TagRepository as DbTagRepositoryTrait;
+
+        let db_repo = DbTagRepository::new(self.db.clone());
+        db_repo.create(tag).await.map_err(Error::DatabaseError)
+    }
+
+    async fn get_by_id(&self, id: Id<Tag>) -> Result<Option<Tag>> {
+        use project_tracker_db_mock::tag_repository::MockTagRepository as DbTagRepository;
+        use project_tracker_db::tag_repository::TagRepository as DbTagRepositoryTrait;
+
+        let db_repo = DbTagRepository::new(self.db.clone());
+        db_repo.get_by_id(id).await.map_err(Error::DatabaseError)
+    }
+
+    async fn get_by_name(&self, name: &str) -> Result<Option<Tag>> {
+        use project_tracker_db_mock::tag_repository::MockTagRepository as DbTagRepository;
+        use project_tracker_db::tag_repository::TagRepository as DbTagRepositoryTrait;
+
+        let db_repo = DbTagRepository::new(self.db.clone());
+        db_repo.get_by_name(name).await.map_err(Error::DatabaseError)
+    }
+
+    async fn get_all(&self) -> Result<Vec<Tag>> {
+        use project_tracker_db_mock::tag_repository::MockTagRepository as DbTagRepository;
+        use project_tracker_db::tag_repository::TagRepository as DbTagRepositoryTrait;
+
+        let db_repo = DbTagRepository::new(self.db.clone());
+        db_repo.get_all().await.map_err(Error::DatabaseError)
+    }
+
+    async fn update(&self, tag: Tag) -> Result<()> {
+        use project_tracker_db_mock::tag_repository::MockTagRepository as DbTagRepository;
+        use project_tracker_db::tag_repository::TagRepository as DbTagRepositoryTrait;
+
+        let db_repo = DbTagRepository::new(self.db.clone());
+        db_repo.update(tag).await.map_err(Error::DatabaseError)
+    }
+
+    async fn delete(&self, id: Id<Tag>) -> Result<()> {
+        use project_tracker_db_mock::tag_repository::MockTagRepository as DbTagRepository;
+        use project_tracker_db::tag_repository::TagRepository as DbTagRepositoryTrait;
+
+        let db_repo = DbTagRepository::new(self.db.clone());
+        db_repo.delete(id).await.map_err(Error::DatabaseError)
+    }
+
+    async fn get_or_create_by_name(&self, name: &str) -> Result<Tag> {
+        use project_tracker_db_mock::tag_repository::MockTagRepository as DbTagRepository;
+        use project_tracker_db::tag_repository::TagRepository as DbTagRepositoryTrait;
+
+        let db_repo = DbTagRepository::new(self.db.clone());
+        db_repo.get_or_create_by_name(name).await.map_err(Error::DatabaseError)
+    }
+}
+// endregion: Mock db for testing
@@ -1,25 +1,37 @@
 use std::sync::Arc;
-use project_tracker_core::{id::Id, models::task::Task, HasId};
+use project_tracker_core::{id::Id, models::task::Task};
 use crate::{Result, Error};
 use project_tracker_db::database::Database as ProdDatabase;
+use project_tracker_db::relations::{DeletePolicy, DeletedItems};
 use project_tracker_db_mock::database::Database as MockDatabase;
 use async_trait::async_trait;
 
 // Traits
 #[async_trait]
-pub trait TaskRepository {
+pub trait TaskRepository: Send + Sync {
     async fn create(&self, task: Task) -> Result<()>;
     async fn get_by_id(&self, id: Id<Task>) -> Result<Option<Task>>;
+    async fn get_all(&self) -> Result<Vec<Task>>;
+    async fn update(&self, task: Task) -> Result<()>;
+    /// Returns every record the delete removed or unlinked
+    async fn delete(&self, id: Id<Task>) -> Result<DeletedItems>;
 }
 
 // region: Actual DB
 pub struct ProdTaskRepository {
-    db: Arc<ProdDatabase>
+    db: Arc<ProdDatabase>,
+    delete_policy: DeletePolicy,
 }
 
 impl ProdTaskRepository {
     pub fn new(db: Arc<ProdDatabase>) -> Self {
-        Self { db } 
+        Self { db, delete_policy: DeletePolicy::default() }
+    }
+
+    /// What `delete` does to linked items, `DeletePolicy::Detach` unless set
+    pub fn with_delete_policy(mut self, delete_policy: DeletePolicy) -> Self {
+        self.delete_policy = delete_policy;
+        self
     }
 }
 
@@ -28,7 +40,7 @@ impl TaskRepository for ProdTaskRepository {
     async fn create(&self, task: Task) -> Result<()> {
         use project_tracker_db::task_repository::ProdTaskRepository as DbTaskRepository;
         use project_tracker_db::task_repository::TaskRepository as DbTaskRepositoryTrait;
-        
+
         let db_repo = DbTaskRepository::new(self.db.clone());
         db_repo.create(task).await.map_err(Error::DatabaseError)
     }
@@ -36,10 +48,34 @@ impl TaskRepository for ProdTaskRepository {
     async fn get_by_id(&self, id: Id<Task>) -> Result<Option<Task>> {
         use project_tracker_db::task_repository::ProdTaskRepository as DbTaskRepository;
         use project_tracker_db::task_repository::TaskRepository as DbTaskRepositoryTrait;
-        
+
         let db_repo = DbTaskRepository::new(self.db.clone());
         db_repo.get_by_id(id).await.map_err(Error::DatabaseError)
     }
+
+    async fn get_all(&self) -> Result<Vec<Task>> {
+        use project_tracker_db::task_repository::ProdTaskRepository as DbTaskRepository;
+        use project_tracker_db::task_repository::TaskRepository as DbTaskRepositoryTrait;
+
+        let db_repo = DbTaskRepository::new(self.db.clone());
+        db_repo.get_all().await.map_err(Error::DatabaseError)
+    }
+
+    async fn update(&self, task: Task) -> Result<()> {
+        use project_tracker_db::task_repository::ProdTaskRepository as DbTaskRepository;
+        use project_tracker_db::task_repository::TaskRepository as DbTaskRepositoryTrait;
+
+        let db_repo = DbTaskRepository::new(self.db.clone());
+        db_repo.update(task).await.map_err(Error::DatabaseError)
+    }
+
+    async fn delete(&self, id: Id<Task>) -> Result<DeletedItems> {
+        use project_tracker_db::task_repository::ProdTaskRepository as DbTaskRepository;
+        use project_tracker_db::task_repository::TaskRepository as DbTaskRepositoryTrait;
+
+        let db_repo = DbTaskRepository::new(self.db.clone()).with_delete_policy(self.delete_policy);
+        db_repo.delete(id).await.map_err(Error::DatabaseError)
+    }
 }
 
 // endregion: Actual DB
@@ -49,22 +85,62 @@ pub struct MockTaskRepository {
 }
 
 impl MockTaskRepository {
-    pub fn new(db: Arc<MockDatabase>) -> Self {
-        Self { db } 
+    pub fn new() -> Self {
+        Self::with_database(Arc::new(MockDatabase::new()))
+    }
+
+    /// Repository on a store shared with other mock repositories, so references between them resolve
+    pub fn with_database(db: Arc<MockDatabase>) -> Self {
+        Self { db }
+    }
+}
+
+impl Default for MockTaskRepository {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
 #[async_trait]
 impl TaskRepository for MockTaskRepository {
     async fn create(&self, task: Task) -> Result<()> {
-        let id_str = task.id().to_string();
-        println!("Successfully created task in database");
-        Ok(())
+        use project_tracker_db_mock::task_repository::MockTaskRepository as DbTaskRepository;
+        use project_tracker_db::task_repository::TaskRepository as DbTaskRepositoryTrait;
+
+        let db_repo = DbTaskRepository::new(self.db.clone());
+        db_repo.create(task).await.map_err(Error::DatabaseError)
     }
 
     async fn get_by_id(&self, id: Id<Task>) -> Result<Option<Task>> {
-        // query db for the task by its id
-        Ok(None)
+        use project_tracker_db_mock::task_repository::MockTaskRepository as DbTaskRepository;
+        use project_tracker_db::task_repository::TaskRepository as DbTaskRepositoryTrait;
+
+        let db_repo = DbTaskRepository::new(self.db.clone());
+        db_repo.get_by_id(id).await.map_err(Error::DatabaseError)
+    }
+
+    async fn get_all(&self) -> Result<Vec<Task>> {
+        use project_tracker_db_mock::task_repository::MockTaskRepository as DbTaskRepository;
+        use project_tracker_db::task_repository::TaskRepository as DbTaskRepositoryTrait;
+
+        let db_repo = DbTaskRepository::new(self.db.clone());
+        db_repo.get_all().await.map_err(Error::DatabaseError)
+    }
+
+    async fn update(&self, task: Task) -> Result<()> {
+        use project_tracker_db_mock::task_repository::MockTaskRepository as DbTaskRepository;
+        use project_tracker_db::task_repository::TaskRepository as DbTaskRepositoryTrait;
+
+        let db_repo = DbTaskRepository::new(self.db.clone());
+        db_repo.update(task).await.map_err(Error::DatabaseError)
+    }
+
+    async fn delete(&self, id: Id<Task>) -> Result<DeletedItems> {
+        use project_tracker_db_mock::task_repository::MockTaskRepository as DbTaskRepository;
+        use project_tracker_db::task_repository::TaskRepository as DbTaskRepositoryTrait;
+
+        let db_repo = DbTaskRepository::new(self.db.clone());
+        db_repo.delete(id).await.map_err(Error::DatabaseError)
     }
 }
-// endregion: Mock db for testing
\ No newline at end of file
+// endregion: Mock db for testing
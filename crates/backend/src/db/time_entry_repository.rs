@@ -0,0 +1,166 @@
+use std::{collections::HashMap, sync::{Arc, RwLock}};
+use project_tracker_core::{
+    id::Id,
+    models::{person::Person, schedulable::SchedulableItem, time_entry::TimeEntry},
+    HasId,
+};
+use crate::{Result, Error};
+use project_tracker_db::database::Database as ProdDatabase;
+use async_trait::async_trait;
+
+// Traits
+#[async_trait]
+pub trait TimeEntryRepository: Send + Sync {
+    async fn create(&self, entry: TimeEntry) -> Result<()>;
+    async fn get_by_id(&self, id: Id<TimeEntry>) -> Result<Option<TimeEntry>>;
+    async fn get_all(&self) -> Result<Vec<TimeEntry>>;
+    /// Entries logged directly against one item, oldest first
+    async fn get_for_item(&self, item: &SchedulableItem) -> Result<Vec<TimeEntry>>;
+    /// Entries logged by one person, oldest first
+    async fn get_for_person(&self, person_id: &Id<Person>) -> Result<Vec<TimeEntry>>;
+    async fn update(&self, entry: TimeEntry) -> Result<()>;
+    async fn delete(&self, id: Id<TimeEntry>) -> Result<()>;
+}
+
+// region: Actual DB
+pub struct ProdTimeEntryRepository {
+    db: Arc<ProdDatabase>
+}
+
+impl ProdTimeEntryRepository {
+    pub fn new(db: Arc<ProdDatabase>) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl TimeEntryRepository for ProdTimeEntryRepository {
+    async fn create(&self, entry: TimeEntry) -> Result<()> {
+        use project_tracker_db::time_entry_repository::ProdTimeEntryRepository as DbTimeEntryRepository;
+        use project_tracker_db::time_entry_repository::TimeEntryRepository as DbTimeEntryRepositoryTrait;
+
+        let db_repo = DbTimeEntryRepository::new(self.db.clone());
+        db_repo.create(entry).await.map_err(Error::DatabaseError)
+    }
+
+    async fn get_by_id(&self, id: Id<TimeEntry>) -> Result<Option<TimeEntry>> {
+        use project_tracker_db::time_entry_repository::ProdTimeEntryRepository as DbTimeEntryRepository;
+        use project_tracker_db::time_entry_repository::TimeEntryRepository as DbTimeEntryRepositoryTrait;
+
+        let db_repo = DbTimeEntryRepository::new(self.db.clone());
+        db_repo.get_by_id(id).await.map_err(Error::DatabaseError)
+    }
+
+    async fn get_all(&self) -> Result<Vec<TimeEntry>> {
+        use project_tracker_db::time_entry_repository::ProdTimeEntryRepository as DbTimeEntryRepository;
+        use project_tracker_db::time_entry_repository::TimeEntryRepository as DbTimeEntryRepositoryTrait;
+
+        let db_repo = DbTimeEntryRepository::new(self.db.clone());
+        db_repo.get_all().await.map_err(Error::DatabaseError)
+    }
+
+    async fn get_for_item(&self, item: &SchedulableItem) -> Result<Vec<TimeEntry>> {
+        use project_tracker_db::time_entry_repository::ProdTimeEntryRepository as DbTimeEntryRepository;
+        use project_tracker_db::time_entry_repository::TimeEntryRepository as DbTimeEntryRepositoryTrait;
+
+        let db_repo = DbTimeEntryRepository::new(self.db.clone());
+        db_repo.get_for_item(item).await.map_err(Error::DatabaseError)
+    }
+
+    async fn get_for_person(&self, person_id: &Id<Person>) -> Result<Vec<TimeEntry>> {
+        use project_tracker_db::time_entry_repository::ProdTimeEntryRepository as DbTimeEntryRepository;
+        use project_tracker_db::time_entry_repository::TimeEntryRepository as DbTimeEntryRepositoryTrait;
+
+        let db_repo = DbTimeEntryRepository::new(self.db.clone());
+        db_repo.get_for_person(person_id).await.map_err(Error::DatabaseError)
+    }
+
+    async fn update(&self, entry: TimeEntry) -> Result<()> {
+        use project_tracker_db::time_entry_repository::ProdTimeEntryRepository as DbTimeEntryRepository;
+        use project_tracker_db::time_entry_repository::TimeEntryRepository as DbTimeEntryRepositoryTrait;
+
+        let db_repo = DbTimeEntryRepository::new(self.db.clone());
+        db_repo.update(entry).await.map_err(Error::DatabaseError)
+    }
+
+    async fn delete(&self, id: Id<TimeEntry>) -> Result<()> {
+        use project_tracker_db::time_entry_repository::ProdTimeEntryRepository as DbTimeEntryRepository;
+        use project_tracker_db::time_entry_repository::TimeEntryRepository as DbTimeEntryRepositoryTrait;
+
+        let db_repo = DbTimeEntryRepository::new(self.db.clone());
+        db_repo.delete(id).await.map_err(Error::DatabaseError)
+    }
+}
+
+// endregion: Actual DB
+// region: Mock db for testing
+#[derive(Default)]
+pub struct MockTimeEntryRepository {
+    entries: RwLock<HashMap<Id<TimeEntry>, TimeEntry>>
+}
+
+impl MockTimeEntryRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn filtered(&self, filter: impl Fn(&TimeEntry) -> bool) -> Vec<TimeEntry> {
+        let mut entries: Vec<TimeEntry> = self
+            .entries
+            .read()
+            .unwrap()
+            .values()
+            .filter(|entry| filter(entry))
+            .cloned()
+            .collect();
+        entries.sort_by_key(TimeEntry::start);
+        entries
+    }
+}
+
+#[async_trait]
+impl TimeEntryRepository for MockTimeEntryRepository {
+    async fn create(&self, entry: TimeEntry) -> Result<()> {
+        let mut entries = self.entries.write().unwrap();
+        if entries.contains_key(&entry.id()) {
+            return Err(Error::ProjectError(format!("Time entry {} already exists", entry.id())));
+        }
+        entries.insert(entry.id(), entry);
+        Ok(())
+    }
+
+    async fn get_by_id(&self, id: Id<TimeEntry>) -> Result<Option<TimeEntry>> {
+        Ok(self.entries.read().unwrap().get(&id).cloned())
+    }
+
+    async fn get_all(&self) -> Result<Vec<TimeEntry>> {
+        Ok(self.entries.read().unwrap().values().cloned().collect())
+    }
+
+    async fn get_for_item(&self, item: &SchedulableItem) -> Result<Vec<TimeEntry>> {
+        Ok(self.filtered(|entry| entry.item() == item))
+    }
+
+    async fn get_for_person(&self, person_id: &Id<Person>) -> Result<Vec<TimeEntry>> {
+        Ok(self.filtered(|entry| entry.person_id() == person_id))
+    }
+
+    async fn update(&self, entry: TimeEntry) -> Result<()> {
+        let mut entries = self.entries.write().unwrap();
+        match entries.get_mut(&entry.id()) {
+            Some(stored) => {
+                *stored = entry;
+                Ok(())
+            }
+            None => Err(Error::NotFound(format!("Time entry {} not found", entry.id()))),
+        }
+    }
+
+    async fn delete(&self, id: Id<TimeEntry>) -> Result<()> {
+        match self.entries.write().unwrap().remove(&id) {
+            Some(_) => Ok(()),
+            None => Err(Error::NotFound(format!("Time entry {id} not found"))),
+        }
+    }
+}
+// endregion: Mock db for testing
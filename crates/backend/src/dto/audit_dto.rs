@@ -0,0 +1,31 @@
+use serde::Serialize;
+
+use project_tracker_core::{HasId, models::audit::AuditEntry};
+
+/// One field change from an entity's history
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct AuditEntryDTO {
+    id: String,
+    entity_id: String,
+    field: String,
+    /// Missing when the entity was created
+    old_value: Option<String>,
+    /// Missing when the entity was deleted
+    new_value: Option<String>,
+    actor: Option<String>,
+    at: String,
+}
+
+impl From<AuditEntry> for AuditEntryDTO {
+    fn from(entry: AuditEntry) -> Self {
+        Self {
+            id: entry.id().to_string(),
+            entity_id: entry.entity_id().to_string(),
+            field: entry.field().to_string(),
+            old_value: entry.old_value().map(str::to_string),
+            new_value: entry.new_value().map(str::to_string),
+            actor: entry.actor().map(|actor| actor.to_string()),
+            at: entry.at().to_rfc3339(),
+        }
+    }
+}
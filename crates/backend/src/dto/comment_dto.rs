@@ -0,0 +1,86 @@
+use serde::{Deserialize, Serialize};
+
+use crate::dto::project_dto::SchedulableItemDTO;
+use project_tracker_core::{
+    HasId,
+    graph::comment_thread::CommentThread,
+    models::comment::{Comment, CommentEdit},
+};
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct CommentDTO {
+    id: String,
+    item: SchedulableItemDTO,
+    author_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parent_id: Option<String>,
+    body: String,
+    created_at: String,
+    /// Same as `created_at` until the comment gets edited
+    updated_at: String,
+    /// Earlier versions of the body, oldest first
+    edits: Vec<CommentEditDTO>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct CommentEditDTO {
+    body: String,
+    edited_at: String,
+}
+
+impl From<Comment> for CommentDTO {
+    fn from(comment: Comment) -> Self {
+        Self {
+            id: comment.id().to_string(),
+            item: SchedulableItemDTO::from(comment.item().clone()),
+            author_id: comment.author_id().to_string(),
+            parent_id: comment.parent_id().map(|id| id.to_string()),
+            body: comment.body().to_string(),
+            created_at: comment.created_at().to_rfc3339(),
+            updated_at: comment.updated_at().to_rfc3339(),
+            edits: comment.edits().iter().map(CommentEditDTO::from).collect(),
+        }
+    }
+}
+
+impl From<&CommentEdit> for CommentEditDTO {
+    fn from(edit: &CommentEdit) -> Self {
+        Self {
+            body: edit.body.clone(),
+            edited_at: edit.edited_at.to_rfc3339(),
+        }
+    }
+}
+
+/// A comment with its replies nested below it
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct CommentThreadDTO {
+    #[serde(flatten)]
+    comment: CommentDTO,
+    replies: Vec<CommentThreadDTO>,
+}
+
+impl From<CommentThread> for CommentThreadDTO {
+    fn from(thread: CommentThread) -> Self {
+        Self {
+            comment: CommentDTO::from(thread.comment),
+            replies: thread.replies.into_iter().map(CommentThreadDTO::from).collect(),
+        }
+    }
+}
+
+/// Body adding a comment, e.g. `{"author_id": "person-01...", "body": "...", "parent_id": "comment-01..."}`
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+pub struct CommentPostDTO {
+    pub author_id: String,
+    pub body: String,
+    /// Comment this one answers, on the same item
+    #[serde(default)]
+    pub parent_id: Option<String>,
+}
+
+/// Body replacing the text of a comment
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+pub struct CommentPatchDTO {
+    pub body: String,
+}
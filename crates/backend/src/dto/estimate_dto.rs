@@ -0,0 +1,33 @@
+use serde::Serialize;
+
+use project_tracker_core::{graph::estimate_report::EstimateReport, models::schedulable::SchedulableItem};
+
+/// Rolled-up estimate of an item next to the time it actually took, durations in seconds
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct EstimateReportDTO {
+    item: String,
+    estimated_hours: u32,
+    estimated_points: u32,
+    logged_seconds: i64,
+    elapsed_seconds: Option<i64>,
+    /// Logged time when there is any, the elapsed duration otherwise
+    actual_seconds: Option<i64>,
+    /// Actual time minus the hour estimate, negative when under it
+    variance_seconds: Option<i64>,
+    over_estimate: bool,
+}
+
+impl EstimateReportDTO {
+    pub fn new(item: &SchedulableItem, report: EstimateReport) -> Self {
+        Self {
+            item: item.to_string(),
+            estimated_hours: report.estimate.hours,
+            estimated_points: report.estimate.points,
+            logged_seconds: report.logged.num_seconds(),
+            elapsed_seconds: report.elapsed.map(|elapsed| elapsed.num_seconds()),
+            actual_seconds: report.actual().map(|actual| actual.num_seconds()),
+            variance_seconds: report.variance().map(|variance| variance.num_seconds()),
+            over_estimate: report.is_over_estimate(),
+        }
+    }
+}
@@ -0,0 +1,111 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{Error, Result, dto::project_dto::SchedulableItemDTO};
+use chrono::{DateTime, Utc};
+use project_tracker_core::{
+    HasId,
+    builders::milestone_builder::MilestoneBuilder,
+    id::Id,
+    models::{
+        milestone::{Milestone, MilestoneState},
+        schedulable::SchedulableItem,
+    },
+};
+
+#[derive(Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct MilestoneDTO {
+    id: String,
+    name: String,
+    description: Option<String>,
+    target_date: String,
+    #[serde(default)]
+    items: Vec<SchedulableItemDTO>,
+    #[serde(default)]
+    reached_at: Option<String>,
+}
+
+impl fmt::Debug for MilestoneDTO {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Milestone [[{}]]", self.name)?;
+        writeln!(f, "- Milestone Id:{:?}", self.id)?;
+        writeln!(f, "- Milestone targets: {}", self.target_date)?;
+        if let Some(reached_at) = &self.reached_at {
+            writeln!(f, "- Milestone reached on: {}", reached_at)?;
+        }
+        writeln!(f, "- Milestone links {} items", self.items.len())?;
+        Ok(())
+    }
+}
+
+impl From<Milestone> for MilestoneDTO {
+    fn from(milestone: Milestone) -> Self {
+        Self {
+            id: milestone.id().to_string(),
+            name: milestone.name().to_string(),
+            description: milestone.has_description().then(|| milestone.description().to_string()),
+            target_date: milestone.target_date().to_rfc3339(),
+            items: milestone.items().into_iter().map(SchedulableItemDTO::from).collect(),
+            reached_at: milestone.reached_at().map(|date| date.to_rfc3339()),
+        }
+    }
+}
+
+impl MilestoneDTO {
+    pub fn id(&self) -> String {
+        self.id.clone()
+    }
+}
+
+impl TryFrom<MilestoneDTO> for Milestone {
+    type Error = Error;
+
+    fn try_from(dto: MilestoneDTO) -> Result<Self> {
+        let id = Id::<Milestone>::from_str(&dto.id)?;
+        let target_date = dto.target_date.parse::<DateTime<Utc>>().map_err(|_| {
+            Error::ProjectError(format!("Invalid milestone target date: {:?}", dto.target_date))
+        })?;
+        let reached_at = match dto.reached_at {
+            Some(date_string) => Some(date_string.parse::<DateTime<Utc>>().map_err(|_| {
+                Error::ProjectError(format!("Invalid milestone reached date: {date_string:?}"))
+            })?),
+            None => None,
+        };
+        let items: Vec<SchedulableItem> = dto
+            .items
+            .into_iter()
+            .map(|item| item.try_into())
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut builder = MilestoneBuilder::new()
+            .with_id(id)
+            .with_name(&dto.name)
+            .with_target_date(target_date)
+            .with_items(items)
+            .with_reached_at(reached_at);
+        if let Some(description) = &dto.description {
+            builder = builder.with_description(description);
+        }
+        Ok(builder.build())
+    }
+}
+
+/// Milestone as returned by reads, with its state and the linked items still open
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct MilestoneReportDTO {
+    #[serde(flatten)]
+    milestone: MilestoneDTO,
+    state: MilestoneState,
+    open_items: Vec<SchedulableItemDTO>,
+}
+
+impl MilestoneReportDTO {
+    pub fn new(milestone: Milestone, open_items: Vec<SchedulableItem>) -> Self {
+        Self {
+            state: milestone.state(),
+            milestone: MilestoneDTO::from(milestone),
+            open_items: open_items.into_iter().map(SchedulableItemDTO::from).collect(),
+        }
+    }
+}
@@ -1,2 +1,47 @@
 pub mod project_dto;
-pub mod task_dto;
\ No newline at end of file
+pub mod task_dto;
+pub mod tag_dto;
+pub mod person_dto;
+pub mod schedule_dto;
+
+use serde::{Deserialize, Deserializer};
+
+/// Query string for reads that can inline related records, e.g. `?expand=owner`
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize)]
+pub struct ExpandQuery {
+    pub expand: Option<String>,
+}
+
+impl ExpandQuery {
+    pub fn owner(&self) -> bool {
+        self.expand
+            .as_deref()
+            .is_some_and(|expand| expand.split(',').any(|field| field.trim() == "owner"))
+    }
+}
+
+/// Query string for list reads narrowed to a tag and its descendants, e.g. `?tag=tag-01...`
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize)]
+pub struct TagFilterQuery {
+    pub tag: Option<String>,
+}
+
+/// Keeps an explicit `null` apart from a missing field in partial update payloads:
+/// a missing field stays `None`, while `null` becomes `Some(None)` and clears the value
+pub(crate) fn deserialize_nullable<'de, T, D>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    T: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Some)
+}
+
+pub mod status_transition_dto;
+pub mod progress_dto;
+pub mod milestone_dto;
+pub mod time_entry_dto;
+pub mod estimate_dto;
+pub mod work_queue_dto;
+pub mod comment_dto;
+
+pub mod audit_dto;
@@ -0,0 +1,90 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{Error, Result};
+use project_tracker_core::{
+    HasId,
+    builders::person_builder::PersonBuilder,
+    id::Id,
+    models::person::Person,
+};
+
+#[derive(Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct PersonDTO {
+    id: String,
+    first_name: String,
+    last_name: String,
+}
+
+impl fmt::Debug for PersonDTO {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{} {}]({})", self.first_name, self.last_name, self.id)
+    }
+}
+
+impl From<Person> for PersonDTO {
+    fn from(person: Person) -> Self {
+        Self {
+            id: person.id().to_string(),
+            first_name: person.first_name().to_string(),
+            last_name: person.last_name().to_string(),
+        }
+    }
+}
+
+impl PersonDTO {
+    pub fn id(&self) -> String {
+        self.id.clone()
+    }
+}
+
+impl TryFrom<PersonDTO> for Person {
+    type Error = Error;
+
+    fn try_from(dto: PersonDTO) -> Result<Self> {
+        let id = Id::<Person>::from_str(&dto.id)?;
+
+        Ok(PersonBuilder::new()
+            .with_id(id)
+            .with_first_name(&dto.first_name)
+            .with_last_name(&dto.last_name)
+            .build())
+    }
+}
+
+/// Implemented by DTOs carrying an `owner_id` that reads can expand
+pub trait OwnedDTO {
+    fn owner_id(&self) -> Option<String>;
+}
+
+/// Owner names returned next to `owner_id` when a read asks for `expand=owner`
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct OwnerDTO {
+    first_name: String,
+    last_name: String,
+}
+
+impl From<Person> for OwnerDTO {
+    fn from(person: Person) -> Self {
+        Self {
+            first_name: person.first_name().to_string(),
+            last_name: person.last_name().to_string(),
+        }
+    }
+}
+
+/// Wraps a project or task DTO, adding the resolved `owner` when it was requested
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct WithOwnerDTO<T> {
+    #[serde(flatten)]
+    item: T,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    owner: Option<OwnerDTO>,
+}
+
+impl<T> WithOwnerDTO<T> {
+    pub fn new(item: T, owner: Option<OwnerDTO>) -> Self {
+        Self { item, owner }
+    }
+}
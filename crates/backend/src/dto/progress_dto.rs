@@ -0,0 +1,29 @@
+use serde::Serialize;
+
+use project_tracker_core::{
+    graph::project_tree::Progress,
+    models::{project::Project, schedulable::SchedulableItem},
+    HasId,
+};
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct ProgressDTO {
+    project_id: String,
+    finished: usize,
+    total: usize,
+    percent: u8,
+    /// Direct children still keeping the project from being completed
+    open_children: Vec<String>,
+}
+
+impl ProgressDTO {
+    pub fn new(project: &Project, progress: Progress, open_children: &[SchedulableItem]) -> Self {
+        Self {
+            project_id: project.id().to_string(),
+            finished: progress.finished,
+            total: progress.total,
+            percent: progress.percent(),
+            open_children: open_children.iter().map(|child| child.to_string()).collect(),
+        }
+    }
+}
@@ -1,15 +1,16 @@
 use std::str::FromStr;
 
-use crate::{Error, Result};
+use crate::{Error, Result, dto::{deserialize_nullable, person_dto::OwnedDTO}};
 use chrono::{DateTime, Utc};
 use project_tracker_core::{
     HasId,
     builders::project_builder::ProjectBuilder,
     id::Id,
     models::{
+        estimate::Estimate,
         person::Person,
         project::Project,
-        schedulable::{Schedulable, SchedulableItem, SchedulableItemStatus},
+        schedulable::{Priority, Schedulable, SchedulableItem, SchedulableItemStatus},
         tag::Tag,
         task::Task,
     },
@@ -29,6 +30,10 @@ pub struct ProjectDTO {
     children: Vec<SchedulableItemDTO>,
     dependencies: Vec<String>,
     status: SchedulableItemStatus,
+    #[serde(default)]
+    priority: Priority,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    estimate: Option<String>,
 }
 
 impl ProjectDTO {
@@ -37,6 +42,12 @@ impl ProjectDTO {
     }
 }
 
+impl OwnedDTO for ProjectDTO {
+    fn owner_id(&self) -> Option<String> {
+        self.owner_id.clone()
+    }
+}
+
 impl From<Project> for ProjectDTO {
     fn from(project: Project) -> Self {
         Self {
@@ -62,6 +73,8 @@ impl From<Project> for ProjectDTO {
                 .map(|id| id.to_string())
                 .collect(),
             status: project.status(),
+            priority: project.priority(),
+            estimate: project.estimate().map(|estimate| estimate.to_string()),
         }
     }
 }
@@ -90,9 +103,13 @@ impl fmt::Debug for ProjectDTO {
         } else {
             writeln!(f, "! No due date defined")?;
         }
+        writeln!(f, "- Project priority: {}", self.priority)?;
         writeln!(f, "- Project has {} children", self.children.len())?;
         writeln!(f, "- Project has {} dependencies", self.dependencies.len())?;
         writeln!(f, "- Project has {} tags", self.tags.len())?;
+        if let Some(estimate) = &self.estimate {
+            writeln!(f, "- Project is estimated at: {}", estimate)?;
+        }
         Ok(())
     }
 }
@@ -113,9 +130,13 @@ impl fmt::Display for ProjectDTO {
         if let Some(due_date) = &self.due_date {
             writeln!(f, "- Project is due on: {}", due_date)?;
         }
+        writeln!(f, "- Project priority: {}", self.priority)?;
         writeln!(f, "- Project has {} children", self.children.len())?;
         writeln!(f, "- Project has {} dependencies", self.dependencies.len())?;
         writeln!(f, "- Project has {} tags", self.tags.len())?;
+        if let Some(estimate) = &self.estimate {
+            writeln!(f, "- Project is estimated at: {}", estimate)?;
+        }
         Ok(())
     }
 }
@@ -167,6 +188,12 @@ impl TryFrom<ProjectDTO> for Project {
                     .map_err(|_| Error::ProjectError(format!("Invalid project dependency: {id:?}")))
             })
             .collect::<Result<Vec<_>>>()?;
+        let estimate = match dto.estimate {
+            Some(estimate) => Some(Estimate::from_str(&estimate).map_err(|_| {
+                Error::ProjectError(format!("Invalid project estimate: {estimate:?}"))
+            })?),
+            None => None,
+        };
 
         Ok(ProjectBuilder::new()
             .with_id(id)
@@ -179,11 +206,82 @@ impl TryFrom<ProjectDTO> for Project {
             .with_children(children)
             .with_dependencies(dependencies)
             .with_status(dto.status)
+            .with_priority(dto.priority)
+            .with_estimate(estimate)
             .build())
     }
 }
 
-#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct ProjectPatchDTO {
+    #[serde(default)]
+    name: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_nullable")]
+    owner_id: Option<Option<String>>,
+    #[serde(default, deserialize_with = "deserialize_nullable")]
+    description: Option<Option<String>>,
+    #[serde(default)]
+    tags: Option<Vec<String>>,
+    #[serde(default, deserialize_with = "deserialize_nullable")]
+    start_date: Option<Option<String>>,
+    #[serde(default, deserialize_with = "deserialize_nullable")]
+    due_date: Option<Option<String>>,
+    #[serde(default)]
+    children: Option<Vec<SchedulableItemDTO>>,
+    #[serde(default)]
+    dependencies: Option<Vec<String>>,
+    #[serde(default)]
+    status: Option<SchedulableItemStatus>,
+    #[serde(default)]
+    priority: Option<Priority>,
+    #[serde(default, deserialize_with = "deserialize_nullable")]
+    estimate: Option<Option<String>>,
+}
+
+impl ProjectPatchDTO {
+    /// Merges the provided fields over an existing project, leaving the others untouched
+    pub fn apply_to(self, project: Project) -> Result<Project> {
+        let mut dto = ProjectDTO::from(project);
+
+        if let Some(name) = self.name {
+            dto.name = name;
+        }
+        if let Some(owner_id) = self.owner_id {
+            dto.owner_id = owner_id;
+        }
+        if let Some(description) = self.description {
+            dto.description = description;
+        }
+        if let Some(tags) = self.tags {
+            dto.tags = tags;
+        }
+        if let Some(start_date) = self.start_date {
+            dto.start_date = start_date;
+        }
+        if let Some(due_date) = self.due_date {
+            dto.due_date = due_date;
+        }
+        if let Some(children) = self.children {
+            dto.children = children;
+        }
+        if let Some(dependencies) = self.dependencies {
+            dto.dependencies = dependencies;
+        }
+        if let Some(status) = self.status {
+            dto.status = status;
+        }
+        if let Some(priority) = self.priority {
+            dto.priority = priority;
+        }
+        if let Some(estimate) = self.estimate {
+            dto.estimate = estimate;
+        }
+
+        Project::try_from(dto)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub enum SchedulableItemDTO {
     Project(String),
     Task(String),
@@ -0,0 +1,69 @@
+use serde::Serialize;
+
+use project_tracker_core::{
+    graph::schedule::{Schedule, ScheduledItem},
+    models::{project::Project, schedulable::Schedulable, task::Task},
+    HasId,
+};
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct ScheduleDTO {
+    project_id: String,
+    finish: String,
+    critical_path: Vec<String>,
+    impossible_due_dates: Vec<String>,
+    items: Vec<ScheduledItemDTO>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct ScheduledItemDTO {
+    id: String,
+    name: String,
+    earliest_start: String,
+    earliest_finish: String,
+    latest_start: String,
+    latest_finish: String,
+    slack_seconds: i64,
+    critical: bool,
+    impossible_due_date: bool,
+}
+
+impl ScheduleDTO {
+    /// Builds the response for a project's schedule, naming each item from the scheduled tasks
+    pub fn new(project: &Project, schedule: &Schedule<Task>, tasks: &[Task]) -> Self {
+        Self {
+            project_id: project.id().to_string(),
+            finish: schedule.finish().to_rfc3339(),
+            critical_path: schedule.critical_path().iter().map(|id| id.to_string()).collect(),
+            impossible_due_dates: schedule.impossible_due_dates().iter().map(|id| id.to_string()).collect(),
+            items: schedule
+                .items()
+                .iter()
+                .map(|item| {
+                    let name = tasks
+                        .iter()
+                        .find(|task| task.id() == item.id)
+                        .map(|task| task.name().to_string())
+                        .unwrap_or_default();
+                    ScheduledItemDTO::new(item, name)
+                })
+                .collect(),
+        }
+    }
+}
+
+impl ScheduledItemDTO {
+    fn new(item: &ScheduledItem<Task>, name: String) -> Self {
+        Self {
+            id: item.id.to_string(),
+            name,
+            earliest_start: item.earliest_start.to_rfc3339(),
+            earliest_finish: item.earliest_finish.to_rfc3339(),
+            latest_start: item.latest_start.to_rfc3339(),
+            latest_finish: item.latest_finish.to_rfc3339(),
+            slack_seconds: item.slack.num_seconds(),
+            critical: item.critical,
+            impossible_due_date: item.impossible_due_date,
+        }
+    }
+}
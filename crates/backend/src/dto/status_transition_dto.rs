@@ -0,0 +1,35 @@
+use serde::{Deserialize, Serialize};
+
+use project_tracker_core::models::status_transition::{StatusAction, StatusTransition};
+
+/// Body of a workflow move, e.g. `{"action": "promote", "by": "person-01..."}`
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+pub struct TransitionRequestDTO {
+    pub action: StatusAction,
+    pub by: Option<String>,
+    /// Also start the parent projects that have not started yet when the item goes `InProgress`
+    #[serde(default)]
+    pub start_parents: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct StatusTransitionDTO {
+    item: String,
+    from: String,
+    to: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    by: Option<String>,
+    at: String,
+}
+
+impl From<StatusTransition> for StatusTransitionDTO {
+    fn from(transition: StatusTransition) -> Self {
+        Self {
+            item: transition.item().to_string(),
+            from: transition.from().to_string(),
+            to: transition.to().to_string(),
+            by: transition.by().map(|id| id.to_string()),
+            at: transition.at().to_rfc3339(),
+        }
+    }
+}
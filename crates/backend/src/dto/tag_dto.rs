@@ -0,0 +1,97 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{Error, Result};
+use project_tracker_core::{
+    HasId,
+    builders::tag_builder::TagBuilder,
+    id::Id,
+    models::tag::Tag,
+};
+
+#[derive(Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct TagDTO {
+    id: String,
+    name: String,
+    description: Option<String>,
+    #[serde(default)]
+    parents: Vec<String>,
+}
+
+impl fmt::Debug for TagDTO {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Tag [[{}]]", self.name)?;
+        writeln!(f, "- Tag Id:{:?}", self.id)?;
+        if let Some(description) = &self.description {
+            writeln!(f, "- Tag Description: {}", description)?;
+        } else {
+            writeln!(f, "! No description provided")?;
+        }
+        writeln!(f, "- Tag has {} parents", self.parents.len())?;
+        Ok(())
+    }
+}
+
+impl From<Tag> for TagDTO {
+    fn from(tag: Tag) -> Self {
+        Self {
+            id: tag.id().to_string(),
+            name: tag.name().to_string(),
+            description: if tag.description().is_empty() {
+                None
+            } else {
+                Some(tag.description().to_string())
+            },
+            parents: tag.parents().into_iter().map(|id| id.to_string()).collect(),
+        }
+    }
+}
+
+impl TagDTO {
+    pub fn id(&self) -> String {
+        self.id.clone()
+    }
+
+    pub fn name(&self) -> &str {
+        self.name.as_str()
+    }
+}
+
+impl TryFrom<TagDTO> for Tag {
+    type Error = Error;
+
+    fn try_from(dto: TagDTO) -> Result<Self> {
+        let id = Id::<Tag>::from_str(&dto.id)?;
+        let parents: Vec<Id<Tag>> = dto
+            .parents
+            .into_iter()
+            .map(|id| {
+                Id::from_str(&id)
+                    .map_err(|_| Error::ProjectError(format!("Invalid parent tag: {id:?}")))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut builder = TagBuilder::new()
+            .with_id(id)
+            .with_name(&dto.name)
+            .with_parents(parents);
+        if let Some(description) = &dto.description {
+            builder = builder.with_description(description);
+        }
+
+        Ok(builder.build())
+    }
+}
+
+/// Payload for resolving tag names to stored tags, creating the missing ones
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+pub struct TagLookupDTO {
+    pub names: Vec<String>,
+}
+
+/// Query string for `GET /tags`, optionally narrowing the list down to one name
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize)]
+pub struct TagQuery {
+    pub name: Option<String>,
+}
@@ -3,14 +3,16 @@ use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::str::FromStr;
 
-use crate::{Error, Result};
+use crate::{Error, Result, dto::{deserialize_nullable, person_dto::OwnedDTO}};
 use project_tracker_core::{
     HasId,
     builders::task_builder::*,
     id::Id,
     models::{
+        estimate::Estimate,
         person::Person,
-        schedulable::{Schedulable, SchedulableItemStatus},
+        recurrence::RecurrenceRule,
+        schedulable::{Priority, Schedulable, SchedulableItemStatus},
         tag::Tag,
         task::Task,
     },
@@ -28,6 +30,20 @@ pub struct TaskDTO {
     children: Vec<String>,
     dependencies: Vec<String>,
     status: SchedulableItemStatus,
+    #[serde(default)]
+    priority: Priority,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    recurrence: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    series_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    occurrence: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    series_start_date: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    series_due_date: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    estimate: Option<String>,
 }
 
 impl fmt::Debug for TaskDTO {
@@ -54,9 +70,16 @@ impl fmt::Debug for TaskDTO {
         } else {
             writeln!(f, "! No due date defined")?;
         }
+        writeln!(f, "- Task priority: {}", self.priority)?;
         writeln!(f, "- Task has {} children", self.children.len())?;
         writeln!(f, "- Task has {} dependencies", self.dependencies.len())?;
         writeln!(f, "- Task has {} tags", self.tags.len())?;
+        if let Some(estimate) = &self.estimate {
+            writeln!(f, "- Task is estimated at: {}", estimate)?;
+        }
+        if let Some(recurrence) = &self.recurrence {
+            writeln!(f, "- Task repeats: {}", recurrence)?;
+        }
         Ok(())
     }
 }
@@ -77,9 +100,16 @@ impl fmt::Display for TaskDTO {
         if let Some(due_date) = &self.due_date {
             writeln!(f, "- Task is due on: {}", due_date)?;
         }
+        writeln!(f, "- Task priority: {}", self.priority)?;
         writeln!(f, "- Task has {} children", self.children.len())?;
         writeln!(f, "- Task has {} dependencies", self.dependencies.len())?;
         writeln!(f, "- Task has {} tags", self.tags.len())?;
+        if let Some(estimate) = &self.estimate {
+            writeln!(f, "- Task is estimated at: {}", estimate)?;
+        }
+        if let Some(recurrence) = &self.recurrence {
+            writeln!(f, "- Task repeats: {}", recurrence)?;
+        }
         Ok(())
     }
 }
@@ -105,6 +135,13 @@ impl From<Task> for TaskDTO {
                 .map(|id| id.to_string())
                 .collect(),
             status: task.status(),
+            priority: task.priority(),
+            recurrence: task.recurrence().map(|rule| rule.to_string()),
+            series_id: task.is_recurring().then(|| task.series_id().to_string()),
+            occurrence: task.is_recurring().then(|| task.occurrence()),
+            series_start_date: task.series_start_date().map(|date| date.to_rfc3339()),
+            series_due_date: task.series_due_date().map(|date| date.to_rfc3339()),
+            estimate: task.estimate().map(|estimate| estimate.to_string()),
         }
     }
 }
@@ -113,6 +150,16 @@ impl TaskDTO {
     pub fn id(&self) -> String {
         self.id.clone()
     }
+
+    pub fn occurrence(&self) -> Option<u32> {
+        self.occurrence
+    }
+}
+
+impl OwnedDTO for TaskDTO {
+    fn owner_id(&self) -> Option<String> {
+        self.owner_id.clone()
+    }
 }
 
 impl TryFrom<TaskDTO> for Task {
@@ -165,6 +212,34 @@ impl TryFrom<TaskDTO> for Task {
                     .map_err(|_| Error::ProjectError(format!("Invalid child dependency: {id:?}")))
             })
             .collect::<Result<Vec<_>>>()?;
+        let recurrence = match dto.recurrence {
+            Some(rule) => Some(RecurrenceRule::from_str(&rule).map_err(|_| {
+                Error::ProjectError(format!("Invalid task recurrence: {rule:?}"))
+            })?),
+            None => None,
+        };
+        let series_id = match dto.series_id {
+            Some(ref series_id_str) => Some(Id::<Task>::from_str(series_id_str)?),
+            None => None,
+        };
+        let series_start_date = match dto.series_start_date {
+            Some(date_string) => Some(date_string.parse::<DateTime<Utc>>().map_err(|_| {
+                Error::ProjectError(format!("Invalid task series start date: {date_string:?}"))
+            })?),
+            None => None,
+        };
+        let series_due_date = match dto.series_due_date {
+            Some(date_string) => Some(date_string.parse::<DateTime<Utc>>().map_err(|_| {
+                Error::ProjectError(format!("Invalid task series due date: {date_string:?}"))
+            })?),
+            None => None,
+        };
+        let estimate = match dto.estimate {
+            Some(estimate) => Some(Estimate::from_str(&estimate).map_err(|_| {
+                Error::ProjectError(format!("Invalid task estimate: {estimate:?}"))
+            })?),
+            None => None,
+        };
 
         Ok(TaskBuilder::new()
             .with_id(id)
@@ -177,6 +252,87 @@ impl TryFrom<TaskDTO> for Task {
             .with_children(children)
             .with_dependencies(dependencies)
             .with_status(dto.status)
+            .with_priority(dto.priority)
+            .with_recurrence(recurrence)
+            .with_series_id(series_id)
+            .with_occurrence(dto.occurrence.unwrap_or(1))
+            .with_series_start_date(series_start_date)
+            .with_series_due_date(series_due_date)
+            .with_estimate(estimate)
             .build())
     }
 }
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize)]
+pub struct TaskPatchDTO {
+    #[serde(default)]
+    name: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_nullable")]
+    owner_id: Option<Option<String>>,
+    #[serde(default, deserialize_with = "deserialize_nullable")]
+    description: Option<Option<String>>,
+    #[serde(default)]
+    tags: Option<Vec<String>>,
+    #[serde(default, deserialize_with = "deserialize_nullable")]
+    start_date: Option<Option<String>>,
+    #[serde(default, deserialize_with = "deserialize_nullable")]
+    due_date: Option<Option<String>>,
+    #[serde(default)]
+    children: Option<Vec<String>>,
+    #[serde(default)]
+    dependencies: Option<Vec<String>>,
+    #[serde(default)]
+    status: Option<SchedulableItemStatus>,
+    #[serde(default)]
+    priority: Option<Priority>,
+    #[serde(default, deserialize_with = "deserialize_nullable")]
+    recurrence: Option<Option<String>>,
+    #[serde(default, deserialize_with = "deserialize_nullable")]
+    estimate: Option<Option<String>>,
+}
+
+impl TaskPatchDTO {
+    /// Merges the provided fields over an existing task, leaving the others untouched
+    pub fn apply_to(self, task: Task) -> Result<Task> {
+        let mut dto = TaskDTO::from(task);
+
+        if let Some(name) = self.name {
+            dto.name = name;
+        }
+        if let Some(owner_id) = self.owner_id {
+            dto.owner_id = owner_id;
+        }
+        if let Some(description) = self.description {
+            dto.description = description;
+        }
+        if let Some(tags) = self.tags {
+            dto.tags = tags;
+        }
+        if let Some(start_date) = self.start_date {
+            dto.start_date = start_date;
+        }
+        if let Some(due_date) = self.due_date {
+            dto.due_date = due_date;
+        }
+        if let Some(children) = self.children {
+            dto.children = children;
+        }
+        if let Some(dependencies) = self.dependencies {
+            dto.dependencies = dependencies;
+        }
+        if let Some(status) = self.status {
+            dto.status = status;
+        }
+        if let Some(priority) = self.priority {
+            dto.priority = priority;
+        }
+        if let Some(estimate) = self.estimate {
+            dto.estimate = estimate;
+        }
+        if let Some(recurrence) = self.recurrence {
+            dto.recurrence = recurrence;
+        }
+
+        Task::try_from(dto)
+    }
+}
@@ -0,0 +1,170 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{Error, Result, dto::project_dto::SchedulableItemDTO};
+use chrono::{DateTime, Duration, Utc};
+use project_tracker_core::{
+    HasId,
+    builders::time_entry_builder::TimeEntryBuilder,
+    id::Id,
+    models::{person::Person, schedulable::SchedulableItem, time_entry::TimeEntry},
+};
+
+#[derive(Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct TimeEntryDTO {
+    id: String,
+    person_id: String,
+    item: SchedulableItemDTO,
+    start: String,
+    #[serde(default)]
+    end: Option<String>,
+    #[serde(default)]
+    note: Option<String>,
+}
+
+impl fmt::Debug for TimeEntryDTO {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Time Entry [[{:?}]]", self.item)?;
+        writeln!(f, "- Time Entry Id:{:?}", self.id)?;
+        writeln!(f, "- Logged by: {:?}", self.person_id)?;
+        writeln!(f, "- Started on: {}", self.start)?;
+        if let Some(end) = &self.end {
+            writeln!(f, "- Ended on: {}", end)?;
+        } else {
+            writeln!(f, "! Timer is running")?;
+        }
+        Ok(())
+    }
+}
+
+impl From<TimeEntry> for TimeEntryDTO {
+    fn from(entry: TimeEntry) -> Self {
+        Self {
+            id: entry.id().to_string(),
+            person_id: entry.person_id().to_string(),
+            item: SchedulableItemDTO::from(entry.item().clone()),
+            start: entry.start().to_rfc3339(),
+            end: entry.end().map(|date| date.to_rfc3339()),
+            note: entry.has_note().then(|| entry.note().to_string()),
+        }
+    }
+}
+
+impl TimeEntryDTO {
+    pub fn id(&self) -> String {
+        self.id.clone()
+    }
+}
+
+impl TryFrom<TimeEntryDTO> for TimeEntry {
+    type Error = Error;
+
+    fn try_from(dto: TimeEntryDTO) -> Result<Self> {
+        let id = Id::<TimeEntry>::from_str(&dto.id)?;
+        let person_id = Id::<Person>::from_str(&dto.person_id)?;
+        let item = SchedulableItem::try_from(dto.item)?;
+        let start = dto.start.parse::<DateTime<Utc>>().map_err(|_| {
+            Error::ProjectError(format!("Invalid time entry start: {:?}", dto.start))
+        })?;
+        let end = match dto.end {
+            Some(date_string) => Some(date_string.parse::<DateTime<Utc>>().map_err(|_| {
+                Error::ProjectError(format!("Invalid time entry end: {date_string:?}"))
+            })?),
+            None => None,
+        };
+
+        let mut builder = TimeEntryBuilder::new()
+            .with_id(id)
+            .with_person_id(person_id)
+            .with_item(item)
+            .with_start(start)
+            .with_end(end);
+        if let Some(note) = &dto.note {
+            builder = builder.with_note(note);
+        }
+        Ok(builder.build())
+    }
+}
+
+/// Body starting a timer, e.g. `{"person_id": "person-01...", "item": {"Task": "task-01..."}}`
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+pub struct TimerStartDTO {
+    pub person_id: String,
+    pub item: SchedulableItemDTO,
+    #[serde(default)]
+    pub note: Option<String>,
+}
+
+/// Body stopping the running timer of a person
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+pub struct TimerStopDTO {
+    pub person_id: String,
+}
+
+/// Time spent on one project or task, in seconds
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct TimeTotalDTO {
+    item: String,
+    /// Logged directly against the item
+    logged_seconds: i64,
+    /// Logged against the item and everything below it
+    total_seconds: i64,
+}
+
+impl TimeTotalDTO {
+    pub fn new(item: &SchedulableItem, logged: Duration, total: Duration) -> Self {
+        Self {
+            item: item.to_string(),
+            logged_seconds: logged.num_seconds(),
+            total_seconds: total.num_seconds(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct TimeBucketDTO {
+    key: String,
+    seconds: i64,
+}
+
+/// Logged time grouped per item, person, tag and week, each sorted by key
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct TimeReportDTO {
+    total_seconds: i64,
+    per_item: Vec<TimeBucketDTO>,
+    per_person: Vec<TimeBucketDTO>,
+    per_tag: Vec<TimeBucketDTO>,
+    per_week: Vec<TimeBucketDTO>,
+}
+
+impl TimeReportDTO {
+    pub fn new(
+        total: Duration,
+        per_item: HashMap<SchedulableItem, Duration>,
+        per_person: HashMap<Id<Person>, Duration>,
+        per_tag: HashMap<impl ToString, Duration>,
+        per_week: impl IntoIterator<Item = (impl ToString, Duration)>,
+    ) -> Self {
+        Self {
+            total_seconds: total.num_seconds(),
+            per_item: buckets(per_item),
+            per_person: buckets(per_person),
+            per_tag: buckets(per_tag),
+            per_week: buckets(per_week),
+        }
+    }
+}
+
+fn buckets(groups: impl IntoIterator<Item = (impl ToString, Duration)>) -> Vec<TimeBucketDTO> {
+    let mut buckets: Vec<TimeBucketDTO> = groups
+        .into_iter()
+        .map(|(key, duration)| TimeBucketDTO {
+            key: key.to_string(),
+            seconds: duration.num_seconds(),
+        })
+        .collect();
+    buckets.sort_by(|a, b| a.key.cmp(&b.key));
+    buckets
+}
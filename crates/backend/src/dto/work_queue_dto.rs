@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+
+use crate::dto::project_dto::SchedulableItemDTO;
+use project_tracker_core::{graph::work_queue::NextWork, models::schedulable::Priority};
+
+/// Open item ranked by what to work on next
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct NextWorkDTO {
+    item: SchedulableItemDTO,
+    name: String,
+    priority: Priority,
+    due_date: Option<String>,
+    /// All dependencies are finished
+    ready: bool,
+    blockers: Vec<SchedulableItemDTO>,
+    score: u32,
+}
+
+impl From<&NextWork> for NextWorkDTO {
+    fn from(entry: &NextWork) -> Self {
+        Self {
+            item: entry.item.clone().into(),
+            name: entry.name.clone(),
+            priority: entry.priority,
+            due_date: entry.due_date.map(|date| date.to_rfc3339()),
+            ready: entry.is_ready(),
+            blockers: entry.blockers.iter().cloned().map(SchedulableItemDTO::from).collect(),
+            score: entry.score,
+        }
+    }
+}
+
+/// Query string for `GET /work-queue`, e.g. `?owner_id=person-01...&ready=true&limit=5`
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize)]
+pub struct WorkQueueQuery {
+    pub owner_id: Option<String>,
+    /// Leaves out items still waiting on their dependencies
+    #[serde(default)]
+    pub ready: bool,
+    pub limit: Option<usize>,
+}
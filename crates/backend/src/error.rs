@@ -6,7 +6,13 @@ use axum::{
 use serde::Serialize;
 use serde_json::json;
 
-use project_tracker_core::id::ParseIdError;
+use project_tracker_core::{
+    id::ParseIdError,
+    models::{
+        command::CommandError, comment::CommentError, status_transition::TransitionError,
+        time_entry::TimeEntryError,
+    },
+};
 use project_tracker_db::DatabaseError;
 
 pub type Result<T> = core::result::Result<T, Error>;
@@ -17,7 +23,12 @@ pub enum Error { // To be improved later
     ProjectError(String),
     ParseError(ParseIdError),
     InvalidPayload(String),
+    NotFound(String),
     DatabaseError(DatabaseError),
+    TransitionError(TransitionError),
+    TimeEntryError(TimeEntryError),
+    CommentError(CommentError),
+    CommandError(CommandError),
     // etc.
     Multiple(Vec<Error>)
 }
@@ -42,6 +53,30 @@ impl From<DatabaseError> for Error {
     }
 }
 
+impl From<TransitionError> for Error {
+    fn from(err: TransitionError) -> Self {
+        Error::TransitionError(err)
+    }
+}
+
+impl From<TimeEntryError> for Error {
+    fn from(err: TimeEntryError) -> Self {
+        Error::TimeEntryError(err)
+    }
+}
+
+impl From<CommentError> for Error {
+    fn from(err: CommentError) -> Self {
+        Error::CommentError(err)
+    }
+}
+
+impl From<CommandError> for Error {
+    fn from(err: CommandError) -> Self {
+        Error::CommandError(err)
+    }
+}
+
 impl IntoResponse for Error {
     fn into_response(self) -> Response {
         let (status, error_message) = match self {
@@ -49,9 +84,16 @@ impl IntoResponse for Error {
             Error::ProjectError(error_string) => (StatusCode::BAD_REQUEST, error_string),
             Error::ParseError(_) => (StatusCode::BAD_REQUEST, "Parsing Error".into()),
             Error::InvalidPayload(error_string) => (StatusCode::UNAUTHORIZED, error_string),
+            Error::NotFound(error_string) => (StatusCode::NOT_FOUND, error_string),
+            Error::DatabaseError(DatabaseError::IntegrityError(error_string)) => (StatusCode::CONFLICT, error_string),
             Error::DatabaseError(_) => (StatusCode::INTERNAL_SERVER_ERROR, "Database Error".into()),
+            Error::TransitionError(err) => (StatusCode::CONFLICT, err.to_string()),
+            Error::TimeEntryError(err) => (StatusCode::CONFLICT, err.to_string()),
+            Error::CommentError(err) => (StatusCode::BAD_REQUEST, err.to_string()),
+            Error::CommandError(err) => (StatusCode::CONFLICT, err.to_string()),
             Error::Multiple(_) => (StatusCode::BAD_REQUEST, "Multiple validation Errors".into()),
             // fallback
+            #[allow(unreachable_patterns)]
             _ => (StatusCode::INTERNAL_SERVER_ERROR, "Unhandled Error".into()) // Kept for validation for when more error types are added;
         };
 
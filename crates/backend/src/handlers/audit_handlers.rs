@@ -0,0 +1,67 @@
+use axum::{
+    extract::{Path, Request, State},
+    middleware::Next,
+    response::{IntoResponse, Response},
+    Json
+};
+use project_tracker_core::{
+    EntityType,
+    models::{
+        comment::Comment, milestone::Milestone, person::Person, project::Project, tag::Tag, task::Task,
+        time_entry::TimeEntry,
+    },
+};
+
+use crate::{
+    app::AppState,
+    db::audited,
+    dto::audit_dto::AuditEntryDTO,
+    services::audit_services,
+    Result
+};
+
+/// Credits the changes a request makes to the person named in its `X-Actor-Id` header
+pub async fn record_actor(req: Request, next: Next) -> Response {
+    let header = req
+        .headers()
+        .get(audit_services::ACTOR_HEADER)
+        .map(|value| String::from_utf8_lossy(value.as_bytes()).into_owned());
+
+    match audit_services::parse_actor(header.as_deref()) {
+        Ok(actor) => audited::with_actor(actor, next.run(req)).await,
+        Err(e) => e.into_response(),
+    }
+}
+
+async fn history<T: EntityType>(state: AppState, id: String) -> Result<Json<Vec<AuditEntryDTO>>> {
+    let entries = audit_services::get_history::<T>(state.audit_repository.as_ref(), id).await?;
+    Ok(Json(entries))
+}
+
+pub async fn get_project_history(State(state): State<AppState>, Path(id): Path<String>) -> Result<Json<Vec<AuditEntryDTO>>> {
+    history::<Project>(state, id).await
+}
+
+pub async fn get_task_history(State(state): State<AppState>, Path(id): Path<String>) -> Result<Json<Vec<AuditEntryDTO>>> {
+    history::<Task>(state, id).await
+}
+
+pub async fn get_tag_history(State(state): State<AppState>, Path(id): Path<String>) -> Result<Json<Vec<AuditEntryDTO>>> {
+    history::<Tag>(state, id).await
+}
+
+pub async fn get_person_history(State(state): State<AppState>, Path(id): Path<String>) -> Result<Json<Vec<AuditEntryDTO>>> {
+    history::<Person>(state, id).await
+}
+
+pub async fn get_milestone_history(State(state): State<AppState>, Path(id): Path<String>) -> Result<Json<Vec<AuditEntryDTO>>> {
+    history::<Milestone>(state, id).await
+}
+
+pub async fn get_time_entry_history(State(state): State<AppState>, Path(id): Path<String>) -> Result<Json<Vec<AuditEntryDTO>>> {
+    history::<TimeEntry>(state, id).await
+}
+
+pub async fn get_comment_history(State(state): State<AppState>, Path(id): Path<String>) -> Result<Json<Vec<AuditEntryDTO>>> {
+    history::<Comment>(state, id).await
+}
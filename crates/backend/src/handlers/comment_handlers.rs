@@ -0,0 +1,47 @@
+use axum::{
+    extract::{Path, State},
+    Json
+};
+use project_tracker_core::HasId;
+use serde_json::{Value, json};
+
+use crate::{
+    app::AppState,
+    dto::comment_dto::{CommentDTO, CommentPatchDTO},
+    services::comment_services,
+    Result
+};
+
+pub async fn get_comment_from_path(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<Json<CommentDTO>> {
+    let comment = comment_services::get_comment_from_id(state.comment_repository.as_ref(), id).await?;
+    Ok(Json(comment))
+}
+
+pub async fn patch_comment(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    payload: Json<CommentPatchDTO>,
+) -> Result<Json<Value>> {
+    let comment = comment_services::edit_comment(state.comment_repository.as_ref(), id, payload.0).await?;
+
+    Ok(Json(json!({
+        "status": "success",
+        "message": "Comment edited",
+        "comment_id": comment.id().to_string(),
+        "edits": comment.edits().len(),
+    })))
+}
+
+pub async fn delete_comment(State(state): State<AppState>, Path(id): Path<String>) -> Result<Json<Value>> {
+    let ids = comment_services::delete_comment(state.comment_repository.as_ref(), id).await?;
+
+    Ok(Json(json!({
+        "status": "success",
+        "message": "Comment deleted",
+        "comment_id": ids[0].to_string(),
+        "deleted_replies": ids[1..].iter().map(|id| id.to_string()).collect::<Vec<_>>(),
+    })))
+}
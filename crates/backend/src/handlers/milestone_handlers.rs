@@ -0,0 +1,84 @@
+use axum::{
+    extract::{Path, State},
+    Json
+};
+use project_tracker_core::HasId;
+use serde_json::{Value, json};
+
+use crate::{
+    app::AppState,
+    dto::milestone_dto::{MilestoneDTO, MilestoneReportDTO},
+    services::milestone_services,
+    Result
+};
+
+pub async fn list_milestones(State(state): State<AppState>) -> Result<Json<Vec<MilestoneReportDTO>>> {
+    let milestones = milestone_services::get_all_milestones(
+        state.milestone_repository.as_ref(),
+        state.project_repository.as_ref(),
+        state.task_repository.as_ref(),
+    )
+    .await?;
+    Ok(Json(milestones))
+}
+
+pub async fn get_milestone_from_path(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<Json<MilestoneReportDTO>> {
+    let milestone = milestone_services::get_milestone_from_id(
+        state.milestone_repository.as_ref(),
+        state.project_repository.as_ref(),
+        state.task_repository.as_ref(),
+        id,
+    )
+    .await?;
+    Ok(Json(milestone))
+}
+
+pub async fn post_milestone(State(state): State<AppState>, payload: Json<MilestoneDTO>) -> Result<Json<Value>> {
+    let milestone = milestone_services::create_milestone(
+        state.milestone_repository.as_ref(),
+        state.project_repository.as_ref(),
+        state.task_repository.as_ref(),
+        payload.0,
+    )
+    .await?;
+
+    Ok(Json(json!({
+        "status": "success",
+        "message": "Milestone received",
+        "milestone_id": milestone.id().to_string(),
+    })))
+}
+
+pub async fn put_milestone(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    payload: Json<MilestoneDTO>,
+) -> Result<Json<Value>> {
+    let milestone = milestone_services::update_milestone(
+        state.milestone_repository.as_ref(),
+        state.project_repository.as_ref(),
+        state.task_repository.as_ref(),
+        id,
+        payload.0,
+    )
+    .await?;
+
+    Ok(Json(json!({
+        "status": "success",
+        "message": "Milestone replaced",
+        "milestone_id": milestone.id().to_string(),
+    })))
+}
+
+pub async fn delete_milestone(State(state): State<AppState>, Path(id): Path<String>) -> Result<Json<Value>> {
+    let milestone_id = milestone_services::delete_milestone(state.milestone_repository.as_ref(), id).await?;
+
+    Ok(Json(json!({
+        "status": "success",
+        "message": "Milestone deleted",
+        "milestone_id": milestone_id.to_string(),
+    })))
+}
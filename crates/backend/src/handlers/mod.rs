@@ -1,2 +1,10 @@
 pub mod project_handlers;
-pub mod task_handlers;
\ No newline at end of file
+pub mod task_handlers;
+pub mod tag_handlers;
+pub mod person_handlers;
+pub mod milestone_handlers;
+pub mod time_entry_handlers;
+pub mod work_queue_handlers;
+pub mod comment_handlers;
+
+pub mod audit_handlers;
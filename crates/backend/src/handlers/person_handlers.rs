@@ -0,0 +1,60 @@
+use axum::{
+    extract::{Path, State},
+    Json
+};
+use project_tracker_core::HasId;
+use serde_json::{Value, json};
+
+use crate::{
+    app::AppState,
+    dto::person_dto::PersonDTO,
+    services::person_services,
+    Result
+};
+
+pub async fn list_people(State(state): State<AppState>) -> Result<Json<Vec<PersonDTO>>> {
+    let people = person_services::get_all_people(state.person_repository.as_ref()).await?;
+    Ok(Json(people))
+}
+
+pub async fn get_person_from_path(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<Json<PersonDTO>> {
+    let person = person_services::get_person_from_id(state.person_repository.as_ref(), id).await?;
+    Ok(Json(person))
+}
+
+pub async fn post_person(State(state): State<AppState>, payload: Json<PersonDTO>) -> Result<Json<Value>> {
+    let person = person_services::create_person(state.person_repository.as_ref(), payload.0).await?;
+
+    Ok(Json(json!({
+        "status": "success",
+        "message": "Person received",
+        "person_id": person.id().to_string(),
+    })))
+}
+
+pub async fn put_person(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    payload: Json<PersonDTO>,
+) -> Result<Json<Value>> {
+    let person = person_services::update_person(state.person_repository.as_ref(), id, payload.0).await?;
+
+    Ok(Json(json!({
+        "status": "success",
+        "message": "Person replaced",
+        "person_id": person.id().to_string(),
+    })))
+}
+
+pub async fn delete_person(State(state): State<AppState>, Path(id): Path<String>) -> Result<Json<Value>> {
+    let person_id = person_services::delete_person(state.person_repository.as_ref(), id).await?;
+
+    Ok(Json(json!({
+        "status": "success",
+        "message": "Person deleted",
+        "person_id": person_id.to_string(),
+    })))
+}
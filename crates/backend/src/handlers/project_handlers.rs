@@ -1,51 +1,307 @@
 use axum::{
-    extract::{Path, Query}, 
-    response::IntoResponse, 
+    extract::{Path, Query, State},
     Json
 };
-use project_tracker_core::HasId;
+use project_tracker_core::{HasId, models::command::SchedulableCommand};
 use serde::Deserialize;
 use serde_json::{json, Value};
 
 use crate::{
-    services::project_services,
-    dto::project_dto::ProjectDTO,
+    app::AppState,
+    services::{
+        command_services::CommandStep, comment_services, estimate_services, person_services, progress_services,
+        project_services, schedule_services, time_entry_services,
+    },
+    dto::{
+        ExpandQuery, TagFilterQuery,
+        comment_dto::{CommentPostDTO, CommentThreadDTO},
+        estimate_dto::EstimateReportDTO,
+        person_dto::WithOwnerDTO,
+        progress_dto::ProgressDTO,
+        project_dto::{ProjectDTO, ProjectPatchDTO},
+        schedule_dto::ScheduleDTO,
+        status_transition_dto::{StatusTransitionDTO, TransitionRequestDTO},
+        time_entry_dto::TimeTotalDTO,
+    },
     Result
 };
 
 #[derive(Debug, Deserialize)]
 pub struct ProjectParameters {
-    id: String
+    id: String,
+    expand: Option<String>,
 }
 
-pub async fn list_projects() -> impl IntoResponse {
-    let projects = project_services::get_all_projects();
-    Json(projects)
+pub async fn list_projects(
+    State(state): State<AppState>,
+    Query(query): Query<ExpandQuery>,
+    Query(filter): Query<TagFilterQuery>,
+) -> Result<Json<Vec<WithOwnerDTO<ProjectDTO>>>> {
+    let projects = match filter.tag {
+        Some(tag) => {
+            project_services::get_projects_tagged_with(
+                state.project_repository.as_ref(),
+                state.tag_repository.as_ref(),
+                &tag,
+            )
+            .await?
+        }
+        None => project_services::get_all_projects(state.project_repository.as_ref()).await?,
+    };
+    let projects = person_services::with_owners(state.person_repository.as_ref(), projects, query.owner()).await?;
+    Ok(Json(projects))
 }
 
-pub async fn get_project_from_parameters(Query(params): Query<ProjectParameters>) -> impl IntoResponse {
-    let id = params.id;
-    match project_services::get_project_from_id(id) {
-        Ok(project) => Json(project).into_response(),
-        Err(error) => error.into_response(),
-    }
+pub async fn get_project_from_parameters(
+    State(state): State<AppState>,
+    Query(params): Query<ProjectParameters>,
+) -> Result<Json<WithOwnerDTO<ProjectDTO>>> {
+    let expand = ExpandQuery { expand: params.expand };
+    let project = project_services::get_project_from_id(state.project_repository.as_ref(), params.id).await?;
+    let project = person_services::with_owner(state.person_repository.as_ref(), project, expand.owner()).await?;
+    Ok(Json(project))
 }
 
-pub async fn get_project_from_path(Path(id): Path<String>) -> impl IntoResponse {
-    match project_services::get_project_from_id(id) {
-        Ok(project) => Json(project).into_response(),
-        Err(error) => error.into_response(),
-    }
+pub async fn get_project_from_path(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    Query(query): Query<ExpandQuery>,
+) -> Result<Json<WithOwnerDTO<ProjectDTO>>> {
+    let project = project_services::get_project_from_id(state.project_repository.as_ref(), id).await?;
+    let project = person_services::with_owner(state.person_repository.as_ref(), project, query.owner()).await?;
+    Ok(Json(project))
 }
 
-pub async fn post_project(payload: Json<ProjectDTO>) -> Result<Json<Value>> {
-    let project = project_services::create_project(payload.0)?;
+pub async fn get_project_schedule(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<Json<ScheduleDTO>> {
+    let schedule = schedule_services::get_project_schedule(
+        state.project_repository.as_ref(),
+        state.task_repository.as_ref(),
+        id,
+    )
+    .await?;
+    Ok(Json(schedule))
+}
+
+pub async fn get_project_progress(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<Json<ProgressDTO>> {
+    let progress = progress_services::get_project_progress(
+        state.project_repository.as_ref(),
+        state.task_repository.as_ref(),
+        id,
+    )
+    .await?;
+    Ok(Json(progress))
+}
+
+pub async fn post_project(State(state): State<AppState>, payload: Json<ProjectDTO>) -> Result<Json<Value>> {
+    let project = project_services::create_project(
+        state.project_repository.as_ref(),
+        state.task_repository.as_ref(),
+        payload.0,
+    )
+    .await?;
 
-    println!("Created Project from payload:{project:?}");
+    log::debug!("Created Project from payload:{project:?}");
 
     Ok(Json(json!({
         "status": "success",
         "message": "Project received",
         "project_id": project.id().to_string(),
     })))
-}
\ No newline at end of file
+}
+
+pub async fn put_project(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    payload: Json<ProjectDTO>,
+) -> Result<Json<Value>> {
+    let project = project_services::update_project(
+        state.project_repository.as_ref(),
+        state.task_repository.as_ref(),
+        id,
+        payload.0,
+    )
+    .await?;
+
+    Ok(Json(json!({
+        "status": "success",
+        "message": "Project replaced",
+        "project_id": project.id().to_string(),
+    })))
+}
+
+pub async fn patch_project(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    payload: Json<ProjectPatchDTO>,
+) -> Result<Json<Value>> {
+    let project = project_services::patch_project(
+        state.project_repository.as_ref(),
+        state.task_repository.as_ref(),
+        id,
+        payload.0,
+    )
+    .await?;
+
+    Ok(Json(json!({
+        "status": "success",
+        "message": "Project updated",
+        "project_id": project.id().to_string(),
+    })))
+}
+
+pub async fn post_project_command(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    payload: Json<SchedulableCommand>,
+) -> Result<Json<Value>> {
+    command_project(state, id, CommandStep::Execute(payload.0)).await
+}
+
+pub async fn post_project_undo(State(state): State<AppState>, Path(id): Path<String>) -> Result<Json<Value>> {
+    command_project(state, id, CommandStep::Undo).await
+}
+
+pub async fn post_project_redo(State(state): State<AppState>, Path(id): Path<String>) -> Result<Json<Value>> {
+    command_project(state, id, CommandStep::Redo).await
+}
+
+async fn command_project(state: AppState, id: String, step: CommandStep) -> Result<Json<Value>> {
+    let (project, command) = project_services::command_project(
+        state.project_repository.as_ref(),
+        state.task_repository.as_ref(),
+        state.project_commands.as_ref(),
+        id,
+        step.clone(),
+    )
+    .await?;
+
+    Ok(Json(json!({
+        "status": "success",
+        "message": step.describe(command.as_ref()),
+        "project_id": project.id().to_string(),
+    })))
+}
+
+pub async fn delete_project(State(state): State<AppState>, Path(id): Path<String>) -> Result<Json<Value>> {
+    let project_id = project_services::delete_project(
+        state.project_repository.as_ref(),
+        state.comment_repository.as_ref(),
+        state.milestone_repository.as_ref(),
+        state.time_entry_repository.as_ref(),
+        id,
+    )
+    .await?;
+
+    Ok(Json(json!({
+        "status": "success",
+        "message": "Project deleted",
+        "project_id": project_id.to_string(),
+    })))
+}
+
+pub async fn get_project_transitions(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<Json<Vec<StatusTransitionDTO>>> {
+    let history = project_services::get_project_history(
+        state.project_repository.as_ref(),
+        state.transition_repository.as_ref(),
+        id,
+    )
+    .await?;
+    Ok(Json(history))
+}
+
+pub async fn post_project_transition(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    payload: Json<TransitionRequestDTO>,
+) -> Result<Json<Value>> {
+    let transitions = project_services::transition_project(
+        state.project_repository.as_ref(),
+        state.task_repository.as_ref(),
+        state.transition_repository.as_ref(),
+        state.person_repository.as_ref(),
+        id,
+        payload.0,
+    )
+    .await?;
+    let transition = &transitions[0];
+
+    Ok(Json(json!({
+        "status": "success",
+        "message": "Project status changed",
+        "project_id": transition.item().to_string(),
+        "from": transition.from().to_string(),
+        "to": transition.to().to_string(),
+        "started_parents": transitions[1..].iter().map(|parent| parent.item().to_string()).collect::<Vec<_>>(),
+    })))
+}
+
+pub async fn get_project_time(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<Json<TimeTotalDTO>> {
+    let time = time_entry_services::get_project_time(
+        state.time_entry_repository.as_ref(),
+        state.project_repository.as_ref(),
+        state.task_repository.as_ref(),
+        id,
+    )
+    .await?;
+    Ok(Json(time))
+}
+
+pub async fn get_project_estimate(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<Json<EstimateReportDTO>> {
+    let report = estimate_services::get_project_estimate(
+        state.project_repository.as_ref(),
+        state.task_repository.as_ref(),
+        state.time_entry_repository.as_ref(),
+        id,
+    )
+    .await?;
+    Ok(Json(report))
+}
+
+pub async fn get_project_comments(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<Json<Vec<CommentThreadDTO>>> {
+    let comments = comment_services::get_project_comments(
+        state.comment_repository.as_ref(),
+        state.project_repository.as_ref(),
+        id,
+    )
+    .await?;
+    Ok(Json(comments))
+}
+
+pub async fn post_project_comment(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    payload: Json<CommentPostDTO>,
+) -> Result<Json<Value>> {
+    let comment = comment_services::create_project_comment(
+        state.comment_repository.as_ref(),
+        state.project_repository.as_ref(),
+        state.person_repository.as_ref(),
+        id,
+        payload.0,
+    )
+    .await?;
+
+    Ok(Json(json!({
+        "status": "success",
+        "message": "Comment received",
+        "comment_id": comment.id().to_string(),
+    })))
+}
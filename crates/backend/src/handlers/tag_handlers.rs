@@ -0,0 +1,90 @@
+use axum::{
+    extract::{Path, Query, State},
+    Json
+};
+use project_tracker_core::HasId;
+use serde_json::{Value, json};
+
+use crate::{
+    app::AppState,
+    dto::tag_dto::{TagDTO, TagLookupDTO, TagQuery},
+    services::tag_services,
+    Result
+};
+
+pub async fn list_tags(
+    State(state): State<AppState>,
+    Query(query): Query<TagQuery>,
+) -> Result<Json<Vec<TagDTO>>> {
+    let tags = match query.name {
+        Some(name) => tag_services::get_tags_by_name(state.tag_repository.as_ref(), &name).await?,
+        None => tag_services::get_all_tags(state.tag_repository.as_ref()).await?,
+    };
+    Ok(Json(tags))
+}
+
+pub async fn get_tag_from_path(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<Json<TagDTO>> {
+    let tag = tag_services::get_tag_from_id(state.tag_repository.as_ref(), id).await?;
+    Ok(Json(tag))
+}
+
+pub async fn get_tag_ancestors(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<Json<Vec<TagDTO>>> {
+    let tags = tag_services::get_tag_ancestors(state.tag_repository.as_ref(), id).await?;
+    Ok(Json(tags))
+}
+
+pub async fn get_tag_descendants(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<Json<Vec<TagDTO>>> {
+    let tags = tag_services::get_tag_descendants(state.tag_repository.as_ref(), id).await?;
+    Ok(Json(tags))
+}
+
+pub async fn post_tag(State(state): State<AppState>, payload: Json<TagDTO>) -> Result<Json<Value>> {
+    let tag = tag_services::create_tag(state.tag_repository.as_ref(), payload.0).await?;
+
+    Ok(Json(json!({
+        "status": "success",
+        "message": "Tag received",
+        "tag_id": tag.id().to_string(),
+    })))
+}
+
+pub async fn lookup_tags(
+    State(state): State<AppState>,
+    payload: Json<TagLookupDTO>,
+) -> Result<Json<Vec<TagDTO>>> {
+    let tags = tag_services::lookup_or_create_tags(state.tag_repository.as_ref(), payload.0.names).await?;
+    Ok(Json(tags))
+}
+
+pub async fn put_tag(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    payload: Json<TagDTO>,
+) -> Result<Json<Value>> {
+    let tag = tag_services::update_tag(state.tag_repository.as_ref(), id, payload.0).await?;
+
+    Ok(Json(json!({
+        "status": "success",
+        "message": "Tag replaced",
+        "tag_id": tag.id().to_string(),
+    })))
+}
+
+pub async fn delete_tag(State(state): State<AppState>, Path(id): Path<String>) -> Result<Json<Value>> {
+    let tag_id = tag_services::delete_tag(state.tag_repository.as_ref(), id).await?;
+
+    Ok(Json(json!({
+        "status": "success",
+        "message": "Tag deleted",
+        "tag_id": tag_id.to_string(),
+    })))
+}
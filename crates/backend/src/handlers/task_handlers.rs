@@ -1,23 +1,248 @@
-use axum::{Json, response::IntoResponse};
-use project_tracker_core::HasId;
+use axum::{
+    extract::{Path, Query, State},
+    Json
+};
+use project_tracker_core::{HasId, models::command::SchedulableCommand};
 use serde_json::{Value, json};
 
-use crate::{Result, dto::task_dto::TaskDTO, services::task_services};
+use crate::{
+    app::AppState,
+    dto::{
+        ExpandQuery, TagFilterQuery,
+        comment_dto::{CommentPostDTO, CommentThreadDTO},
+        estimate_dto::EstimateReportDTO,
+        person_dto::WithOwnerDTO,
+        status_transition_dto::{StatusTransitionDTO, TransitionRequestDTO},
+        task_dto::{TaskDTO, TaskPatchDTO},
+        time_entry_dto::TimeTotalDTO,
+    },
+    services::{
+        command_services::CommandStep, comment_services, estimate_services, person_services, task_services,
+        time_entry_services,
+    },
+    Result
+};
 
-pub async fn list_tasks() -> impl IntoResponse {
-    let projects = task_services::get_all_tasks();
-    Json(projects)
+pub async fn list_tasks(
+    State(state): State<AppState>,
+    Query(query): Query<ExpandQuery>,
+    Query(filter): Query<TagFilterQuery>,
+) -> Result<Json<Vec<WithOwnerDTO<TaskDTO>>>> {
+    let tasks = match filter.tag {
+        Some(tag) => {
+            task_services::get_tasks_tagged_with(
+                state.task_repository.as_ref(),
+                state.tag_repository.as_ref(),
+                &tag,
+            )
+            .await?
+        }
+        None => task_services::get_all_tasks(state.task_repository.as_ref()).await?,
+    };
+    let tasks = person_services::with_owners(state.person_repository.as_ref(), tasks, query.owner()).await?;
+    Ok(Json(tasks))
 }
 
-pub async fn post_task(payload: Json<TaskDTO>) -> Result<Json<Value>> {
-    let task = task_services::create_task(payload.0)?;
+pub async fn get_task_from_path(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    Query(query): Query<ExpandQuery>,
+) -> Result<Json<WithOwnerDTO<TaskDTO>>> {
+    let task = task_services::get_task_from_id(state.task_repository.as_ref(), id).await?;
+    let task = person_services::with_owner(state.person_repository.as_ref(), task, query.owner()).await?;
+    Ok(Json(task))
+}
+
+pub async fn post_task(State(state): State<AppState>, payload: Json<TaskDTO>) -> Result<Json<Value>> {
+    let task = task_services::create_task(state.task_repository.as_ref(), payload.0).await?;
 
-    println!("Created Project from payload:{task:?}");
+    log::debug!("Created Task from payload:{task:?}");
 
     Ok(Json(json!({
         "status": "success",
         "message": "Task received",
-        "project_id": task.id().to_string(),
+        "task_id": task.id().to_string(),
+    })))
+}
+
+pub async fn put_task(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    payload: Json<TaskDTO>,
+) -> Result<Json<Value>> {
+    let task = task_services::update_task(state.task_repository.as_ref(), id, payload.0).await?;
+
+    Ok(Json(json!({
+        "status": "success",
+        "message": "Task replaced",
+        "task_id": task.id().to_string(),
+    })))
+}
+
+pub async fn patch_task(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    payload: Json<TaskPatchDTO>,
+) -> Result<Json<Value>> {
+    let task = task_services::patch_task(state.task_repository.as_ref(), id, payload.0).await?;
+
+    Ok(Json(json!({
+        "status": "success",
+        "message": "Task updated",
+        "task_id": task.id().to_string(),
     })))
 }
 
+pub async fn post_task_command(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    payload: Json<SchedulableCommand>,
+) -> Result<Json<Value>> {
+    command_task(state, id, CommandStep::Execute(payload.0)).await
+}
+
+pub async fn post_task_undo(State(state): State<AppState>, Path(id): Path<String>) -> Result<Json<Value>> {
+    command_task(state, id, CommandStep::Undo).await
+}
+
+pub async fn post_task_redo(State(state): State<AppState>, Path(id): Path<String>) -> Result<Json<Value>> {
+    command_task(state, id, CommandStep::Redo).await
+}
+
+async fn command_task(state: AppState, id: String, step: CommandStep) -> Result<Json<Value>> {
+    let (task, command) =
+        task_services::command_task(state.task_repository.as_ref(), state.task_commands.as_ref(), id, step.clone())
+            .await?;
+
+    Ok(Json(json!({
+        "status": "success",
+        "message": step.describe(command.as_ref()),
+        "task_id": task.id().to_string(),
+    })))
+}
+
+pub async fn delete_task(State(state): State<AppState>, Path(id): Path<String>) -> Result<Json<Value>> {
+    let task_id = task_services::delete_task(
+        state.task_repository.as_ref(),
+        state.comment_repository.as_ref(),
+        state.milestone_repository.as_ref(),
+        state.time_entry_repository.as_ref(),
+        id,
+    )
+    .await?;
+
+    Ok(Json(json!({
+        "status": "success",
+        "message": "Task deleted",
+        "task_id": task_id.to_string(),
+    })))
+}
+
+pub async fn get_task_transitions(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<Json<Vec<StatusTransitionDTO>>> {
+    let history = task_services::get_task_history(
+        state.task_repository.as_ref(),
+        state.transition_repository.as_ref(),
+        id,
+    )
+    .await?;
+    Ok(Json(history))
+}
+
+pub async fn get_task_series(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<Json<Vec<TaskDTO>>> {
+    let series = task_services::get_task_series(state.task_repository.as_ref(), id).await?;
+    Ok(Json(series))
+}
+
+pub async fn post_task_transition(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    payload: Json<TransitionRequestDTO>,
+) -> Result<Json<Value>> {
+    let transitions = task_services::transition_task(
+        state.task_repository.as_ref(),
+        state.project_repository.as_ref(),
+        state.transition_repository.as_ref(),
+        state.person_repository.as_ref(),
+        id,
+        payload.0,
+    )
+    .await?;
+    let transition = &transitions[0];
+
+    Ok(Json(json!({
+        "status": "success",
+        "message": "Task status changed",
+        "task_id": transition.item().to_string(),
+        "from": transition.from().to_string(),
+        "to": transition.to().to_string(),
+        "started_parents": transitions[1..].iter().map(|parent| parent.item().to_string()).collect::<Vec<_>>(),
+    })))
+}
+
+pub async fn get_task_time(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<Json<TimeTotalDTO>> {
+    let time = time_entry_services::get_task_time(
+        state.time_entry_repository.as_ref(),
+        state.project_repository.as_ref(),
+        state.task_repository.as_ref(),
+        id,
+    )
+    .await?;
+    Ok(Json(time))
+}
+
+pub async fn get_task_estimate(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<Json<EstimateReportDTO>> {
+    let report = estimate_services::get_task_estimate(
+        state.task_repository.as_ref(),
+        state.project_repository.as_ref(),
+        state.time_entry_repository.as_ref(),
+        id,
+    )
+    .await?;
+    Ok(Json(report))
+}
+
+pub async fn get_task_comments(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<Json<Vec<CommentThreadDTO>>> {
+    let comments = comment_services::get_task_comments(
+        state.comment_repository.as_ref(),
+        state.task_repository.as_ref(),
+        id,
+    )
+    .await?;
+    Ok(Json(comments))
+}
+
+pub async fn post_task_comment(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    payload: Json<CommentPostDTO>,
+) -> Result<Json<Value>> {
+    let comment = comment_services::create_task_comment(
+        state.comment_repository.as_ref(),
+        state.task_repository.as_ref(),
+        state.person_repository.as_ref(),
+        id,
+        payload.0,
+    )
+    .await?;
+
+    Ok(Json(json!({
+        "status": "success",
+        "message": "Comment received",
+        "comment_id": comment.id().to_string(),
+    })))
+}
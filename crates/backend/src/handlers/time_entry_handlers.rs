@@ -0,0 +1,118 @@
+use axum::{
+    extract::{Path, State},
+    Json
+};
+use project_tracker_core::HasId;
+use serde_json::{Value, json};
+
+use crate::{
+    app::AppState,
+    dto::time_entry_dto::{TimeEntryDTO, TimeReportDTO, TimerStartDTO, TimerStopDTO},
+    services::time_entry_services,
+    Result
+};
+
+pub async fn list_time_entries(State(state): State<AppState>) -> Result<Json<Vec<TimeEntryDTO>>> {
+    let entries = time_entry_services::get_all_time_entries(state.time_entry_repository.as_ref()).await?;
+    Ok(Json(entries))
+}
+
+pub async fn get_time_entry_from_path(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<Json<TimeEntryDTO>> {
+    let entry = time_entry_services::get_time_entry_from_id(state.time_entry_repository.as_ref(), id).await?;
+    Ok(Json(entry))
+}
+
+pub async fn post_time_entry(State(state): State<AppState>, payload: Json<TimeEntryDTO>) -> Result<Json<Value>> {
+    let entry = time_entry_services::create_time_entry(
+        state.time_entry_repository.as_ref(),
+        state.person_repository.as_ref(),
+        state.project_repository.as_ref(),
+        state.task_repository.as_ref(),
+        payload.0,
+    )
+    .await?;
+
+    Ok(Json(json!({
+        "status": "success",
+        "message": "Time entry received",
+        "time_entry_id": entry.id().to_string(),
+    })))
+}
+
+pub async fn put_time_entry(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    payload: Json<TimeEntryDTO>,
+) -> Result<Json<Value>> {
+    let entry = time_entry_services::update_time_entry(
+        state.time_entry_repository.as_ref(),
+        state.person_repository.as_ref(),
+        state.project_repository.as_ref(),
+        state.task_repository.as_ref(),
+        id,
+        payload.0,
+    )
+    .await?;
+
+    Ok(Json(json!({
+        "status": "success",
+        "message": "Time entry replaced",
+        "time_entry_id": entry.id().to_string(),
+    })))
+}
+
+pub async fn delete_time_entry(State(state): State<AppState>, Path(id): Path<String>) -> Result<Json<Value>> {
+    let entry_id = time_entry_services::delete_time_entry(state.time_entry_repository.as_ref(), id).await?;
+
+    Ok(Json(json!({
+        "status": "success",
+        "message": "Time entry deleted",
+        "time_entry_id": entry_id.to_string(),
+    })))
+}
+
+pub async fn post_timer_start(State(state): State<AppState>, payload: Json<TimerStartDTO>) -> Result<Json<Value>> {
+    let entry = time_entry_services::start_timer(
+        state.time_entry_repository.as_ref(),
+        state.person_repository.as_ref(),
+        state.project_repository.as_ref(),
+        state.task_repository.as_ref(),
+        payload.0,
+    )
+    .await?;
+
+    Ok(Json(json!({
+        "status": "success",
+        "message": "Timer started",
+        "time_entry_id": entry.id().to_string(),
+    })))
+}
+
+pub async fn post_timer_stop(State(state): State<AppState>, payload: Json<TimerStopDTO>) -> Result<Json<Value>> {
+    let entry = time_entry_services::stop_timer(
+        state.time_entry_repository.as_ref(),
+        state.person_repository.as_ref(),
+        payload.0,
+    )
+    .await?;
+
+    Ok(Json(json!({
+        "status": "success",
+        "message": "Timer stopped",
+        "time_entry_id": entry.id().to_string(),
+        "seconds": entry.duration().map(|duration| duration.num_seconds()),
+    })))
+}
+
+pub async fn get_time_report(State(state): State<AppState>) -> Result<Json<TimeReportDTO>> {
+    let report = time_entry_services::get_time_report(
+        state.time_entry_repository.as_ref(),
+        state.project_repository.as_ref(),
+        state.task_repository.as_ref(),
+    )
+    .await?;
+    Ok(Json(report))
+}
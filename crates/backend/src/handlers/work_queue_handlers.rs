@@ -0,0 +1,24 @@
+use axum::{
+    extract::{Query, State},
+    Json
+};
+
+use crate::{
+    app::AppState,
+    dto::work_queue_dto::{NextWorkDTO, WorkQueueQuery},
+    services::work_queue_services,
+    Result
+};
+
+pub async fn get_work_queue(
+    State(state): State<AppState>,
+    Query(query): Query<WorkQueueQuery>,
+) -> Result<Json<Vec<NextWorkDTO>>> {
+    let queue = work_queue_services::get_work_queue(
+        state.project_repository.as_ref(),
+        state.task_repository.as_ref(),
+        query,
+    )
+    .await?;
+    Ok(Json(queue))
+}
@@ -2,5 +2,8 @@ use project_tracker_backend::app;
 
 #[tokio::main]
 async fn main() {
-    app::run().await;
+    match std::env::args().nth(1).as_deref() {
+        Some("migrations") => app::print_migration_status().await,
+        _ => app::run().await,
+    }
 }
\ No newline at end of file
@@ -0,0 +1,14 @@
+use axum::{routing::get,Router};
+
+use crate::{app::AppState, handlers::audit_handlers};
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/project/{id}/history",get(audit_handlers::get_project_history))
+        .route("/task/{id}/history",get(audit_handlers::get_task_history))
+        .route("/tag/{id}/history",get(audit_handlers::get_tag_history))
+        .route("/person/{id}/history",get(audit_handlers::get_person_history))
+        .route("/milestone/{id}/history",get(audit_handlers::get_milestone_history))
+        .route("/time-entry/{id}/history",get(audit_handlers::get_time_entry_history))
+        .route("/comment/{id}/history",get(audit_handlers::get_comment_history))
+}
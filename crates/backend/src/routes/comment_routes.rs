@@ -0,0 +1,10 @@
+use axum::{routing::{delete,get,patch},Router};
+
+use crate::{app::AppState, handlers::comment_handlers};
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/comment/{id}",get(comment_handlers::get_comment_from_path))
+        .route("/comment/{id}",patch(comment_handlers::patch_comment))
+        .route("/comment/{id}",delete(comment_handlers::delete_comment))
+}
@@ -0,0 +1,12 @@
+use axum::{routing::{delete,get,post,put},Router};
+
+use crate::{app::AppState, handlers::milestone_handlers};
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/milestones",get(milestone_handlers::list_milestones))
+        .route("/milestones",post(milestone_handlers::post_milestone))
+        .route("/milestone/{id}",get(milestone_handlers::get_milestone_from_path))
+        .route("/milestone/{id}",put(milestone_handlers::put_milestone))
+        .route("/milestone/{id}",delete(milestone_handlers::delete_milestone))
+}
@@ -3,15 +3,31 @@ pub mod project_routes;
 pub mod task_routes;
 pub mod tag_routes;
 pub mod people_routes;
+pub mod milestone_routes;
+pub mod time_entry_routes;
+pub mod work_queue_routes;
+pub mod comment_routes;
+pub mod audit_routes;
 
-use axum::Router;
+use axum::{middleware,Router};
 
-pub fn create_router() -> Router {
+use crate::{app::AppState, handlers::audit_handlers};
+
+pub fn create_router(state: AppState) -> Router {
     Router::new()
         .merge(health_routes::routes())
         .nest("/api",
-            Router::new() 
+            Router::new()
                 .merge(project_routes::routes())
                 .merge(task_routes::routes())
+                .merge(tag_routes::routes())
+                .merge(people_routes::routes())
+                .merge(milestone_routes::routes())
+                .merge(time_entry_routes::routes())
+                .merge(work_queue_routes::routes())
+                .merge(comment_routes::routes())
+                .merge(audit_routes::routes())
+                .layer(middleware::from_fn(audit_handlers::record_actor))
+                .with_state(state)
         )
 }
\ No newline at end of file
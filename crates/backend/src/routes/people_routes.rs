@@ -0,0 +1,12 @@
+use axum::{routing::{delete,get,post,put},Router};
+
+use crate::{app::AppState, handlers::person_handlers};
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/people",get(person_handlers::list_people))
+        .route("/people",post(person_handlers::post_person))
+        .route("/person/{id}",get(person_handlers::get_person_from_path))
+        .route("/person/{id}",put(person_handlers::put_person))
+        .route("/person/{id}",delete(person_handlers::delete_person))
+}
\ No newline at end of file
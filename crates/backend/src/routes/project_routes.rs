@@ -1,11 +1,25 @@
-use axum::{routing::{get,post},Router};
+use axum::{routing::{delete,get,patch,post,put},Router};
 
-use crate::handlers::project_handlers;
+use crate::{app::AppState, handlers::project_handlers};
 
-pub fn routes() -> Router {
+pub fn routes() -> Router<AppState> {
     Router::new()
         .route("/projects",get(project_handlers::list_projects))
         .route("/project",get(project_handlers::get_project_from_parameters))
         .route("/project/{id}",get(project_handlers::get_project_from_path))
         .route("/project",post(project_handlers::post_project))
+        .route("/project/{id}",put(project_handlers::put_project))
+        .route("/project/{id}",patch(project_handlers::patch_project))
+        .route("/project/{id}",delete(project_handlers::delete_project))
+        .route("/project/{id}/commands",post(project_handlers::post_project_command))
+        .route("/project/{id}/undo",post(project_handlers::post_project_undo))
+        .route("/project/{id}/redo",post(project_handlers::post_project_redo))
+        .route("/project/{id}/schedule",get(project_handlers::get_project_schedule))
+        .route("/project/{id}/progress",get(project_handlers::get_project_progress))
+        .route("/project/{id}/time",get(project_handlers::get_project_time))
+        .route("/project/{id}/estimate",get(project_handlers::get_project_estimate))
+        .route("/project/{id}/comments",get(project_handlers::get_project_comments))
+        .route("/project/{id}/comments",post(project_handlers::post_project_comment))
+        .route("/project/{id}/transitions",get(project_handlers::get_project_transitions))
+        .route("/project/{id}/transitions",post(project_handlers::post_project_transition))
 }
\ No newline at end of file
@@ -0,0 +1,15 @@
+use axum::{routing::{delete,get,post,put},Router};
+
+use crate::{app::AppState, handlers::tag_handlers};
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/tags",get(tag_handlers::list_tags))
+        .route("/tags",post(tag_handlers::post_tag))
+        .route("/tags/lookup",post(tag_handlers::lookup_tags))
+        .route("/tag/{id}",get(tag_handlers::get_tag_from_path))
+        .route("/tag/{id}",put(tag_handlers::put_tag))
+        .route("/tag/{id}",delete(tag_handlers::delete_tag))
+        .route("/tag/{id}/ancestors",get(tag_handlers::get_tag_ancestors))
+        .route("/tag/{id}/descendants",get(tag_handlers::get_tag_descendants))
+}
\ No newline at end of file
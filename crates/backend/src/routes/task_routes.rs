@@ -1,9 +1,23 @@
-use axum::{routing::{get,post},Router};
+use axum::{routing::{delete,get,patch,post,put},Router};
 
-use crate::handlers::task_handlers;
+use crate::{app::AppState, handlers::task_handlers};
 
-pub fn routes() -> Router {
+pub fn routes() -> Router<AppState> {
     Router::new()
         .route("/tasks",get(task_handlers::list_tasks))
         .route("/tasks",post(task_handlers::post_task))
+        .route("/task/{id}",get(task_handlers::get_task_from_path))
+        .route("/task/{id}",put(task_handlers::put_task))
+        .route("/task/{id}",patch(task_handlers::patch_task))
+        .route("/task/{id}",delete(task_handlers::delete_task))
+        .route("/task/{id}/commands",post(task_handlers::post_task_command))
+        .route("/task/{id}/undo",post(task_handlers::post_task_undo))
+        .route("/task/{id}/redo",post(task_handlers::post_task_redo))
+        .route("/task/{id}/time",get(task_handlers::get_task_time))
+        .route("/task/{id}/estimate",get(task_handlers::get_task_estimate))
+        .route("/task/{id}/comments",get(task_handlers::get_task_comments))
+        .route("/task/{id}/comments",post(task_handlers::post_task_comment))
+        .route("/task/{id}/transitions",get(task_handlers::get_task_transitions))
+        .route("/task/{id}/transitions",post(task_handlers::post_task_transition))
+        .route("/task/{id}/series",get(task_handlers::get_task_series))
 }
\ No newline at end of file
@@ -0,0 +1,15 @@
+use axum::{routing::{delete,get,post,put},Router};
+
+use crate::{app::AppState, handlers::time_entry_handlers};
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/time-entries",get(time_entry_handlers::list_time_entries))
+        .route("/time-entries",post(time_entry_handlers::post_time_entry))
+        .route("/time-entry/{id}",get(time_entry_handlers::get_time_entry_from_path))
+        .route("/time-entry/{id}",put(time_entry_handlers::put_time_entry))
+        .route("/time-entry/{id}",delete(time_entry_handlers::delete_time_entry))
+        .route("/timer/start",post(time_entry_handlers::post_timer_start))
+        .route("/timer/stop",post(time_entry_handlers::post_timer_stop))
+        .route("/time-report",get(time_entry_handlers::get_time_report))
+}
@@ -0,0 +1,8 @@
+use axum::{routing::get,Router};
+
+use crate::{app::AppState, handlers::work_queue_handlers};
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/work-queue",get(work_queue_handlers::get_work_queue))
+}
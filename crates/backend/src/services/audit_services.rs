@@ -0,0 +1,23 @@
+use std::str::FromStr;
+
+use crate::{Result, db::audit_repository::AuditRepository, dto::audit_dto::AuditEntryDTO};
+use project_tracker_core::{EntityType, id::Id, models::person::Person};
+
+/// Header naming the person a request acts as, credited in the audit log
+pub const ACTOR_HEADER: &str = "x-actor-id";
+
+/// Change history of one entity, as made through the back-end, oldest first.
+/// Deleted entities keep their history, so the id only has to be well formed.
+pub async fn get_history<T: EntityType>(repository: &dyn AuditRepository, id: String) -> Result<Vec<AuditEntryDTO>> {
+    let entity_id = Id::<T>::from_str(&id)?;
+    let entries = repository.get_for_entity(&entity_id.to_string()).await?;
+    Ok(entries.into_iter().map(AuditEntryDTO::from).collect())
+}
+
+/// Reads the acting person from the request header, if one was sent
+pub fn parse_actor(header: Option<&str>) -> Result<Option<Id<Person>>> {
+    match header {
+        Some(value) => Ok(Some(Id::<Person>::from_str(value.trim())?)),
+        None => Ok(None),
+    }
+}
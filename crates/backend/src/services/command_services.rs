@@ -0,0 +1,64 @@
+use std::collections::HashMap;
+
+use tokio::sync::Mutex;
+
+use project_tracker_core::{
+    id::Id,
+    models::{
+        command::{CommandHistory, SchedulableCommand},
+        schedulable::Schedulable,
+    },
+};
+
+use crate::Result;
+
+/// Undo and redo history of every item edited through commands, kept in memory only,
+/// so it starts over when the back-end restarts
+pub type CommandHistories<T> = Mutex<HashMap<Id<T>, CommandHistory<T>>>;
+
+/// What to do with an item's command history
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum CommandStep {
+    Execute(SchedulableCommand),
+    Undo,
+    Redo,
+}
+
+impl CommandStep {
+    /// Takes the step against `item`, returning the command that was run, undone or redone,
+    /// or `None` when an executed command changed nothing
+    pub(crate) fn take<T: Schedulable + Clone + PartialEq>(
+        self,
+        history: &mut CommandHistory<T>,
+        item: &mut T,
+    ) -> Result<Option<SchedulableCommand>> {
+        match self {
+            CommandStep::Execute(command) => Ok(history.execute(item, command.clone()).then_some(command)),
+            CommandStep::Undo => Ok(Some(history.undo(item)?)),
+            CommandStep::Redo => Ok(Some(history.redo(item)?)),
+        }
+    }
+
+    /// Success message for the command the step went through
+    pub fn describe(&self, command: Option<&SchedulableCommand>) -> String {
+        match (self, command) {
+            (_, None) => "Nothing changed".into(),
+            (CommandStep::Execute(_), Some(command)) => format!("Ran: {command}"),
+            (CommandStep::Undo, Some(command)) => format!("Undid: {command}"),
+            (CommandStep::Redo, Some(command)) => format!("Redid: {command}"),
+        }
+    }
+}
+
+/// Takes `step` against a copy of the item's history, returning the edited item, the command
+/// and the updated history. The caller stores the item and only then keeps the history,
+/// so an edit that fails validation or storage is not recorded.
+pub(crate) fn take_step<T: Schedulable + Clone + PartialEq>(
+    history: Option<&CommandHistory<T>>,
+    mut item: T,
+    step: CommandStep,
+) -> Result<(T, Option<SchedulableCommand>, CommandHistory<T>)> {
+    let mut history = history.cloned().unwrap_or_default();
+    let command = step.take(&mut history, &mut item)?;
+    Ok((item, command, history))
+}
@@ -0,0 +1,163 @@
+use std::str::FromStr;
+
+use chrono::Utc;
+
+use crate::{
+    Error, Result,
+    db::{
+        comment_repository::CommentRepository, person_repository::PersonRepository,
+        project_repository::ProjectRepository, task_repository::TaskRepository,
+    },
+    dto::comment_dto::{CommentDTO, CommentPatchDTO, CommentPostDTO, CommentThreadDTO},
+    services::{person_services, project_services, task_services},
+};
+use project_tracker_core::{
+    HasId,
+    builders::comment_builder::CommentBuilder,
+    graph::comment_thread::CommentThread,
+    id::Id,
+    models::{
+        comment::{Comment, CommentError},
+        schedulable::SchedulableItem,
+    },
+};
+
+/// Comment threads on a project, oldest first
+pub async fn get_project_comments(
+    repository: &dyn CommentRepository,
+    project_repository: &dyn ProjectRepository,
+    id: String,
+) -> Result<Vec<CommentThreadDTO>> {
+    let project = project_services::find_project(project_repository, &id).await?;
+    item_comments(repository, SchedulableItem::Project(project.id())).await
+}
+
+/// Comment threads on a task, oldest first
+pub async fn get_task_comments(
+    repository: &dyn CommentRepository,
+    task_repository: &dyn TaskRepository,
+    id: String,
+) -> Result<Vec<CommentThreadDTO>> {
+    let task = task_services::find_task(task_repository, &id).await?;
+    item_comments(repository, SchedulableItem::Task(task.id())).await
+}
+
+pub async fn create_project_comment(
+    repository: &dyn CommentRepository,
+    project_repository: &dyn ProjectRepository,
+    person_repository: &dyn PersonRepository,
+    id: String,
+    payload: CommentPostDTO,
+) -> Result<Comment> {
+    let project = project_services::find_project(project_repository, &id).await?;
+    add_comment(repository, person_repository, SchedulableItem::Project(project.id()), payload).await
+}
+
+pub async fn create_task_comment(
+    repository: &dyn CommentRepository,
+    task_repository: &dyn TaskRepository,
+    person_repository: &dyn PersonRepository,
+    id: String,
+    payload: CommentPostDTO,
+) -> Result<Comment> {
+    let task = task_services::find_task(task_repository, &id).await?;
+    add_comment(repository, person_repository, SchedulableItem::Task(task.id()), payload).await
+}
+
+pub async fn get_comment_from_id(repository: &dyn CommentRepository, id: String) -> Result<CommentDTO> {
+    let comment = find_comment(repository, &id).await?;
+    Ok(CommentDTO::from(comment))
+}
+
+/// Replaces the body of a comment, keeping the previous one in its edit history
+pub async fn edit_comment(repository: &dyn CommentRepository, id: String, payload: CommentPatchDTO) -> Result<Comment> {
+    let mut comment = find_comment(repository, &id).await?;
+
+    comment.edit(&payload.body, Utc::now())?;
+
+    repository.update(comment.clone()).await?;
+
+    Ok(comment)
+}
+
+/// Deletes a comment together with the replies below it, returning every deleted id
+pub async fn delete_comment(repository: &dyn CommentRepository, id: String) -> Result<Vec<Id<Comment>>> {
+    let comment = find_comment(repository, &id).await?;
+
+    let comments = repository.get_for_item(comment.item()).await?;
+    let ids = CommentThread::build(comments)
+        .into_iter()
+        .flat_map(|thread| subthread_ids(&thread, &comment.id()))
+        .collect::<Vec<_>>();
+
+    for id in &ids {
+        repository.delete(id.clone()).await?;
+    }
+
+    Ok(ids)
+}
+
+pub(crate) async fn find_comment(repository: &dyn CommentRepository, id: &str) -> Result<Comment> {
+    let comment_id = Id::<Comment>::from_str(id)?;
+
+    match repository.get_by_id(comment_id).await? {
+        Some(comment) => Ok(comment),
+        None => Err(Error::NotFound(format!("Comment {id} not found"))),
+    }
+}
+
+/// Deletes every comment left on `items`, replies included, once the items themselves are gone
+pub(crate) async fn delete_item_comments(repository: &dyn CommentRepository, items: &[SchedulableItem]) -> Result<()> {
+    for item in items {
+        for comment in repository.get_for_item(item).await? {
+            repository.delete(comment.id()).await?;
+        }
+    }
+    Ok(())
+}
+
+async fn item_comments(repository: &dyn CommentRepository, item: SchedulableItem) -> Result<Vec<CommentThreadDTO>> {
+    let comments = repository.get_for_item(&item).await?;
+    Ok(CommentThread::build(comments).into_iter().map(CommentThreadDTO::from).collect())
+}
+
+async fn add_comment(
+    repository: &dyn CommentRepository,
+    person_repository: &dyn PersonRepository,
+    item: SchedulableItem,
+    payload: CommentPostDTO,
+) -> Result<Comment> {
+    let author = person_services::find_person(person_repository, &payload.author_id).await?;
+    let parent_id = match &payload.parent_id {
+        Some(parent_id) => {
+            let parent = find_comment(repository, parent_id).await?;
+            if parent.item() != &item {
+                return Err(CommentError::ParentOnOtherItem { parent_id: parent.id(), item }.into());
+            }
+            Some(parent.id())
+        }
+        None => None,
+    };
+
+    let comment = CommentBuilder::new()
+        .with_item(item)
+        .with_author_id(author.id())
+        .with_parent_id(parent_id)
+        .with_body(&payload.body)
+        .with_created_at(Utc::now())
+        .build();
+    if !comment.is_valid() {
+        return Err(CommentError::EmptyBody.into());
+    }
+
+    repository.create(comment.clone()).await?;
+
+    Ok(comment)
+}
+
+fn subthread_ids(thread: &CommentThread, id: &Id<Comment>) -> Vec<Id<Comment>> {
+    if &thread.comment.id() == id {
+        return thread.ids();
+    }
+    thread.replies.iter().flat_map(|reply| subthread_ids(reply, id)).collect()
+}
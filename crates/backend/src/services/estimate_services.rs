@@ -0,0 +1,54 @@
+use chrono::Utc;
+
+use crate::{
+    Error, Result,
+    db::{
+        project_repository::ProjectRepository, task_repository::TaskRepository,
+        time_entry_repository::TimeEntryRepository,
+    },
+    dto::estimate_dto::EstimateReportDTO,
+    services::{progress_services, project_services, task_services},
+};
+use project_tracker_core::{
+    HasId,
+    graph::{estimate_report::EstimateReport, time_report::TimeReport},
+    models::schedulable::SchedulableItem,
+};
+
+/// Estimate of a project rolled up through its child projects, against the time spent on it
+pub async fn get_project_estimate(
+    repository: &dyn ProjectRepository,
+    task_repository: &dyn TaskRepository,
+    time_entry_repository: &dyn TimeEntryRepository,
+    id: String,
+) -> Result<EstimateReportDTO> {
+    let project = project_services::find_project(repository, &id).await?;
+    report(repository, task_repository, time_entry_repository, SchedulableItem::Project(project.id())).await
+}
+
+/// Estimate of a task rolled up through its subtasks, against the time spent on it
+pub async fn get_task_estimate(
+    repository: &dyn TaskRepository,
+    project_repository: &dyn ProjectRepository,
+    time_entry_repository: &dyn TimeEntryRepository,
+    id: String,
+) -> Result<EstimateReportDTO> {
+    let task = task_services::find_task(repository, &id).await?;
+    report(project_repository, repository, time_entry_repository, SchedulableItem::Task(task.id())).await
+}
+
+async fn report(
+    project_repository: &dyn ProjectRepository,
+    task_repository: &dyn TaskRepository,
+    time_entry_repository: &dyn TimeEntryRepository,
+    item: SchedulableItem,
+) -> Result<EstimateReportDTO> {
+    let now = Utc::now();
+    let tree = progress_services::load_project_tree(project_repository, task_repository).await?;
+    let time = TimeReport::new(time_entry_repository.get_all().await?, now);
+
+    match EstimateReport::for_item(&tree, &time, &item, now) {
+        Some(report) => Ok(EstimateReportDTO::new(&item, report)),
+        None => Err(Error::NotFound(format!("{item} not found"))),
+    }
+}
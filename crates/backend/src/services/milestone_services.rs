@@ -0,0 +1,142 @@
+use std::str::FromStr;
+
+use crate::{
+    Error, Result,
+    db::{
+        milestone_repository::MilestoneRepository, project_repository::ProjectRepository,
+        task_repository::TaskRepository,
+    },
+    dto::milestone_dto::{MilestoneDTO, MilestoneReportDTO},
+    services::progress_services,
+};
+use project_tracker_core::{
+    HasId,
+    graph::project_tree::ProjectTree,
+    id::Id,
+    models::{milestone::Milestone, schedulable::SchedulableItem},
+};
+
+pub async fn get_all_milestones(
+    repository: &dyn MilestoneRepository,
+    project_repository: &dyn ProjectRepository,
+    task_repository: &dyn TaskRepository,
+) -> Result<Vec<MilestoneReportDTO>> {
+    let milestones = repository.get_all().await?;
+    let tree = progress_services::load_project_tree(project_repository, task_repository).await?;
+    Ok(milestones.into_iter().map(|milestone| report(milestone, &tree)).collect())
+}
+
+pub async fn get_milestone_from_id(
+    repository: &dyn MilestoneRepository,
+    project_repository: &dyn ProjectRepository,
+    task_repository: &dyn TaskRepository,
+    id: String,
+) -> Result<MilestoneReportDTO> {
+    let milestone = find_milestone(repository, &id).await?;
+    let tree = progress_services::load_project_tree(project_repository, task_repository).await?;
+    Ok(report(milestone, &tree))
+}
+
+pub async fn create_milestone(
+    repository: &dyn MilestoneRepository,
+    project_repository: &dyn ProjectRepository,
+    task_repository: &dyn TaskRepository,
+    payload: MilestoneDTO,
+) -> Result<Milestone> {
+    let tree = progress_services::load_project_tree(project_repository, task_repository).await?;
+    let milestone = validate(Milestone::try_from(payload)?, &tree)?;
+
+    repository.create(milestone.clone()).await?;
+
+    Ok(milestone)
+}
+
+pub async fn update_milestone(
+    repository: &dyn MilestoneRepository,
+    project_repository: &dyn ProjectRepository,
+    task_repository: &dyn TaskRepository,
+    id: String,
+    payload: MilestoneDTO,
+) -> Result<Milestone> {
+    if payload.id() != id {
+        return Err(Error::ProjectError(format!(
+            "Payload id {} does not match milestone {id}",
+            payload.id()
+        )));
+    }
+    find_milestone(repository, &id).await?;
+
+    let tree = progress_services::load_project_tree(project_repository, task_repository).await?;
+    let milestone = validate(Milestone::try_from(payload)?, &tree)?;
+
+    repository.update(milestone.clone()).await?;
+
+    Ok(milestone)
+}
+
+pub async fn delete_milestone(repository: &dyn MilestoneRepository, id: String) -> Result<Id<Milestone>> {
+    let milestone = find_milestone(repository, &id).await?;
+
+    repository.delete(milestone.id()).await?;
+
+    Ok(milestone.id())
+}
+
+/// Drops `items` from every milestone linking them, once the items themselves are gone
+pub(crate) async fn unlink_items(repository: &dyn MilestoneRepository, items: &[SchedulableItem]) -> Result<()> {
+    for mut milestone in repository.get_all().await? {
+        if items.iter().any(|item| milestone.has_item(item)) {
+            for item in items {
+                milestone.unlink(item);
+            }
+            repository.update(milestone).await?;
+        }
+    }
+    Ok(())
+}
+
+async fn find_milestone(repository: &dyn MilestoneRepository, id: &str) -> Result<Milestone> {
+    let milestone_id = Id::<Milestone>::from_str(id)?;
+
+    match repository.get_by_id(milestone_id).await? {
+        Some(milestone) => Ok(milestone),
+        None => Err(Error::NotFound(format!("Milestone {id} not found"))),
+    }
+}
+
+fn report(milestone: Milestone, tree: &ProjectTree) -> MilestoneReportDTO {
+    let open_items = tree.open_items(&milestone.items());
+    MilestoneReportDTO::new(milestone, open_items)
+}
+
+fn validate(milestone: Milestone, tree: &ProjectTree) -> Result<Milestone> {
+    let mut errors: Vec<Error> = Vec::new();
+
+    if milestone.name().trim().is_empty() {
+        errors.push(Error::InvalidPayload(
+            "Provided milestone must have a name".into(),
+        ));
+    }
+    for item in milestone.items() {
+        if tree.status(&item).is_none() {
+            errors.push(Error::InvalidPayload(format!(
+                "Provided milestone links unknown item {item}"
+            )));
+        }
+    }
+    if milestone.is_reached() {
+        let open_items = tree.open_items(&milestone.items());
+        if !open_items.is_empty() {
+            errors.push(Error::InvalidPayload(format!(
+                "Provided milestone cannot be reached while items are open: {}",
+                open_items.iter().map(|item| item.to_string()).collect::<Vec<_>>().join(", ")
+            )));
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(milestone)
+    } else {
+        Err(Error::Multiple(errors))
+    }
+}
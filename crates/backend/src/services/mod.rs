@@ -1,2 +1,16 @@
 pub mod project_services;
-pub mod task_services;
\ No newline at end of file
+pub mod task_services;
+pub mod tag_services;
+pub mod person_services;
+pub mod schedule_services;
+pub mod progress_services;
+pub mod milestone_services;
+pub mod status_transition_services;
+pub mod validation;
+pub mod time_entry_services;
+pub mod estimate_services;
+pub mod work_queue_services;
+pub mod comment_services;
+pub mod command_services;
+
+pub mod audit_services;
@@ -0,0 +1,98 @@
+use std::str::FromStr;
+
+use crate::{
+    Error, Result,
+    db::person_repository::PersonRepository,
+    dto::person_dto::{OwnedDTO, OwnerDTO, PersonDTO, WithOwnerDTO},
+};
+use project_tracker_core::{HasId, id::Id, models::person::Person};
+
+pub async fn get_all_people(repository: &dyn PersonRepository) -> Result<Vec<PersonDTO>> {
+    let people = repository.get_all().await?;
+    Ok(people.into_iter().map(PersonDTO::from).collect())
+}
+
+pub async fn get_person_from_id(repository: &dyn PersonRepository, id: String) -> Result<PersonDTO> {
+    let person = find_person(repository, &id).await?;
+    Ok(PersonDTO::from(person))
+}
+
+pub async fn create_person(repository: &dyn PersonRepository, payload: PersonDTO) -> Result<Person> {
+    let person = validate(Person::try_from(payload)?)?;
+
+    repository.create(person.clone()).await?;
+
+    Ok(person)
+}
+
+pub async fn update_person(repository: &dyn PersonRepository, id: String, payload: PersonDTO) -> Result<Person> {
+    if payload.id() != id {
+        return Err(Error::ProjectError(format!(
+            "Payload id {} does not match person {id}",
+            payload.id()
+        )));
+    }
+    find_person(repository, &id).await?;
+
+    let person = validate(Person::try_from(payload)?)?;
+
+    repository.update(person.clone()).await?;
+
+    Ok(person)
+}
+
+pub async fn delete_person(repository: &dyn PersonRepository, id: String) -> Result<Id<Person>> {
+    let person = find_person(repository, &id).await?;
+
+    repository.delete(person.id()).await?;
+
+    Ok(person.id())
+}
+
+/// Resolves the item's `owner_id` to the owner's names when `expand` is set; unknown owners are left out
+pub async fn with_owner<T: OwnedDTO>(
+    repository: &dyn PersonRepository,
+    item: T,
+    expand: bool,
+) -> Result<WithOwnerDTO<T>> {
+    let owner = match item.owner_id() {
+        Some(owner_id) if expand => {
+            let person_id = Id::<Person>::from_str(&owner_id)?;
+            repository.get_by_id(person_id).await?.map(OwnerDTO::from)
+        }
+        _ => None,
+    };
+
+    Ok(WithOwnerDTO::new(item, owner))
+}
+
+pub async fn with_owners<T: OwnedDTO>(
+    repository: &dyn PersonRepository,
+    items: Vec<T>,
+    expand: bool,
+) -> Result<Vec<WithOwnerDTO<T>>> {
+    let mut expanded = Vec::with_capacity(items.len());
+    for item in items {
+        expanded.push(with_owner(repository, item, expand).await?);
+    }
+    Ok(expanded)
+}
+
+pub(crate) async fn find_person(repository: &dyn PersonRepository, id: &str) -> Result<Person> {
+    let person_id = Id::<Person>::from_str(id)?;
+
+    match repository.get_by_id(person_id).await? {
+        Some(person) => Ok(person),
+        None => Err(Error::NotFound(format!("Person {id} not found"))),
+    }
+}
+
+fn validate(person: Person) -> Result<Person> {
+    if person.first_name().trim().is_empty() && person.last_name().trim().is_empty() {
+        return Err(Error::InvalidPayload(
+            "Provided person must have a first or last name".into(),
+        ));
+    }
+
+    Ok(person)
+}
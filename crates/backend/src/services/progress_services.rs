@@ -0,0 +1,36 @@
+use crate::{
+    Result,
+    db::{project_repository::ProjectRepository, task_repository::TaskRepository},
+    dto::progress_dto::ProgressDTO,
+    services::project_services::find_project,
+};
+use project_tracker_core::{
+    HasId,
+    graph::project_tree::ProjectTree,
+    models::schedulable::SchedulableItem,
+};
+
+/// Percent complete of a project, rolled up from its whole descendant tree
+pub async fn get_project_progress(
+    repository: &dyn ProjectRepository,
+    task_repository: &dyn TaskRepository,
+    id: String,
+) -> Result<ProgressDTO> {
+    let project = find_project(repository, &id).await?;
+    let tree = load_project_tree(repository, task_repository).await?;
+
+    let item = SchedulableItem::Project(project.id());
+    let progress = tree.progress(&item).unwrap_or_default();
+
+    Ok(ProgressDTO::new(&project, progress, &tree.open_children(&item)))
+}
+
+pub(crate) async fn load_project_tree(
+    repository: &dyn ProjectRepository,
+    task_repository: &dyn TaskRepository,
+) -> Result<ProjectTree> {
+    Ok(ProjectTree::from_items(
+        repository.get_all().await?,
+        task_repository.get_all().await?,
+    ))
+}
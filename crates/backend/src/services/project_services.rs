@@ -1,52 +1,271 @@
+use std::str::FromStr;
+
 use crate::{
-    Error, Result, db::project_repository::ProjectRepository, dto::project_dto::ProjectDTO,
+    Error, Result,
+    db::{
+        comment_repository::CommentRepository, milestone_repository::MilestoneRepository,
+        person_repository::PersonRepository, project_repository::ProjectRepository,
+        status_transition_repository::StatusTransitionRepository, tag_repository::TagRepository,
+        task_repository::TaskRepository, time_entry_repository::TimeEntryRepository,
+    },
+    dto::{
+        project_dto::{ProjectDTO, ProjectPatchDTO},
+        status_transition_dto::{StatusTransitionDTO, TransitionRequestDTO},
+    },
+    services::{
+        command_services::{self, CommandHistories, CommandStep},
+        comment_services, milestone_services, progress_services, status_transition_services, tag_services,
+        time_entry_services,
+        validation::{find_dependency_cycle, find_repeated, has_inconsistent_status, has_incorrect_schedule},
+    },
 };
-use chrono::Utc;
 use project_tracker_core::{
     HasId,
-    factories::project_factory::*,
+    id::Id,
     models::{
+        command::SchedulableCommand,
+        person::Person,
         project::Project,
         schedulable::{Schedulable, SchedulableItem, SchedulableItemStatus},
+        status_transition::{StatusAction, StatusTransition},
     },
 };
 
-pub fn get_all_projects() -> Vec<ProjectDTO> {
-    // TODO
-    vec![
-        ProjectDTO::from(sample_project_for_dto()),
-        ProjectDTO::from(sample_project_for_dto()),
-        ProjectDTO::from(sample_project_for_dto()),
-        ProjectDTO::from(sample_project_for_dto()),
-        ProjectDTO::from(sample_project_for_dto()),
-        ProjectDTO::from(sample_project_for_dto()),
-    ]
-}
-
-pub fn get_project_from_id(id: String) -> Result<ProjectDTO> {
-    /* TODO:
-    1. [ ] make call to DB
-    2. [ ] return success/failure
-    */
-
-    let project = ProjectDTO::from(basic_project()); //placeholder
+pub async fn get_all_projects(repository: &dyn ProjectRepository) -> Result<Vec<ProjectDTO>> {
+    let projects = repository.get_all().await?;
+    Ok(projects.into_iter().map(ProjectDTO::from).collect())
+}
+
+pub async fn get_projects_tagged_with(
+    repository: &dyn ProjectRepository,
+    tag_repository: &dyn TagRepository,
+    tag_id: &str,
+) -> Result<Vec<ProjectDTO>> {
+    let projects = tag_services::filter_tagged(tag_repository, repository.get_all().await?, tag_id).await?;
+    Ok(projects.into_iter().map(ProjectDTO::from).collect())
+}
+
+pub async fn get_project_from_id(repository: &dyn ProjectRepository, id: String) -> Result<ProjectDTO> {
+    let project = find_project(repository, &id).await?;
+    Ok(ProjectDTO::from(project))
+}
+
+pub async fn create_project(
+    repository: &dyn ProjectRepository,
+    task_repository: &dyn TaskRepository,
+    payload: ProjectDTO,
+) -> Result<Project> {
+    let project = validate(repository, task_repository, Project::try_from(payload)?).await?;
+
+    repository.create(project.clone()).await?;
+
+    Ok(project)
+}
+
+pub async fn update_project(
+    repository: &dyn ProjectRepository,
+    task_repository: &dyn TaskRepository,
+    id: String,
+    payload: ProjectDTO,
+) -> Result<Project> {
+    if payload.id() != id {
+        return Err(Error::ProjectError(format!(
+            "Payload id {} does not match project {id}",
+            payload.id()
+        )));
+    }
+    let existing = find_project(repository, &id).await?;
+
+    let project = Project::try_from(payload)?;
+    status_transition_services::ensure_status_unchanged(
+        &SchedulableItem::Project(project.id()),
+        existing.status(),
+        project.status(),
+    )?;
+    let project = validate(repository, task_repository, project).await?;
+
+    repository.update(project.clone()).await?;
+
     Ok(project)
 }
 
-pub fn create_project(payload: ProjectDTO) -> Result<Project> {
-    /* TODO:
-    1. [x] convert DTO to Project
-    2. [ ] validate
-    3. [ ] push to DB
-    4. [ ] return success/failure
-    */
+pub async fn patch_project(
+    repository: &dyn ProjectRepository,
+    task_repository: &dyn TaskRepository,
+    id: String,
+    payload: ProjectPatchDTO,
+) -> Result<Project> {
+    let existing = find_project(repository, &id).await?;
+    let stored_status = existing.status();
+
+    let project = payload.apply_to(existing)?;
+    status_transition_services::ensure_status_unchanged(
+        &SchedulableItem::Project(project.id()),
+        stored_status,
+        project.status(),
+    )?;
+    let project = validate(repository, task_repository, project).await?;
 
-    let project = validate(Project::try_from(payload)?)?;
+    repository.update(project.clone()).await?;
 
     Ok(project)
 }
 
-fn validate(project: Project) -> Result<Project> {
+/// Runs, undoes or redoes a command against the project and stores the result.
+/// Returns the project with the command the step went through, or `None` when nothing changed.
+pub async fn command_project(
+    repository: &dyn ProjectRepository,
+    task_repository: &dyn TaskRepository,
+    histories: &CommandHistories<Project>,
+    id: String,
+    step: CommandStep,
+) -> Result<(Project, Option<SchedulableCommand>)> {
+    let mut histories = histories.lock().await;
+    let project = find_project(repository, &id).await?;
+
+    let (project, command, history) = command_services::take_step(histories.get(&project.id()), project, step)?;
+    if command.is_none() {
+        return Ok((project, None));
+    }
+    let project = validate(repository, task_repository, project).await?;
+
+    repository.update(project.clone()).await?;
+    histories.insert(project.id(), history);
+
+    Ok((project, command))
+}
+
+/// Deletes the project, along with the comments and time entries on it and on every item removed with it,
+/// and drops the removed items from the milestones linking them
+pub async fn delete_project(
+    repository: &dyn ProjectRepository,
+    comment_repository: &dyn CommentRepository,
+    milestone_repository: &dyn MilestoneRepository,
+    time_entry_repository: &dyn TimeEntryRepository,
+    id: String,
+) -> Result<Id<Project>> {
+    let project = find_project(repository, &id).await?;
+
+    let deleted = repository.delete(project.id()).await?;
+    comment_services::delete_item_comments(comment_repository, &deleted.items()).await?;
+    milestone_services::unlink_items(milestone_repository, &deleted.items()).await?;
+    time_entry_services::delete_item_entries(time_entry_repository, &deleted.items()).await?;
+
+    Ok(project.id())
+}
+
+/// Moves the project through its status workflow and records the move in its history.
+/// Returns the project's own transition first, followed by any parent project started along with it.
+pub async fn transition_project(
+    repository: &dyn ProjectRepository,
+    task_repository: &dyn TaskRepository,
+    transition_repository: &dyn StatusTransitionRepository,
+    person_repository: &dyn PersonRepository,
+    id: String,
+    payload: TransitionRequestDTO,
+) -> Result<Vec<StatusTransition>> {
+    let mut project = find_project(repository, &id).await?;
+    let by = status_transition_services::find_actor(person_repository, payload.by).await?;
+
+    let transition = project.transition(payload.action, by.clone())?;
+    status_transition_services::stamp_start_date(&mut project, &transition);
+    let project = validate(repository, task_repository, project).await?;
+
+    let started_parents = if payload.start_parents && transition.to() == SchedulableItemStatus::InProgress {
+        start_parent_projects(repository, task_repository, &SchedulableItem::Project(project.id()), by).await?
+    } else {
+        Vec::new()
+    };
+
+    let mut projects = vec![project];
+    let mut transitions = vec![transition];
+    for (parent, parent_transition) in started_parents {
+        projects.push(parent);
+        transitions.push(parent_transition);
+    }
+    store_transitioned(repository, transition_repository, projects, &transitions).await?;
+
+    Ok(transitions)
+}
+
+/// Stores `projects` and then records `transitions` in one write. When any of it fails, the projects
+/// already stored are put back as they were, so a transition is never left half made.
+pub(crate) async fn store_transitioned(
+    repository: &dyn ProjectRepository,
+    transition_repository: &dyn StatusTransitionRepository,
+    projects: Vec<Project>,
+    transitions: &[StatusTransition],
+) -> Result<()> {
+    let mut originals = Vec::new();
+    let stored = async {
+        for project in projects {
+            let original = find_project(repository, &project.id().to_string()).await?;
+            repository.update(project).await?;
+            originals.push(original);
+        }
+        transition_repository.record_all(transitions.to_vec()).await
+    }
+    .await;
+
+    if stored.is_err() {
+        for original in originals.into_iter().rev() {
+            if let Err(err) = repository.update(original).await {
+                log::error!("Failed to roll back project after a failed transition: {err:?}");
+            }
+        }
+    }
+    stored
+}
+
+pub async fn get_project_history(
+    repository: &dyn ProjectRepository,
+    transition_repository: &dyn StatusTransitionRepository,
+    id: String,
+) -> Result<Vec<StatusTransitionDTO>> {
+    let project = find_project(repository, &id).await?;
+    status_transition_services::get_history(transition_repository, SchedulableItem::Project(project.id())).await
+}
+
+/// Starts every ancestor project of `item` that has not started yet, without storing them.
+/// Projects without a start date in the past are started now.
+pub(crate) async fn start_parent_projects(
+    repository: &dyn ProjectRepository,
+    task_repository: &dyn TaskRepository,
+    item: &SchedulableItem,
+    by: Option<Id<Person>>,
+) -> Result<Vec<(Project, StatusTransition)>> {
+    let tree = progress_services::load_project_tree(repository, task_repository).await?;
+    let mut started = Vec::new();
+
+    for parent_id in tree.ancestor_projects(item) {
+        let Some(mut parent) = tree.get_project(&parent_id).cloned() else {
+            continue;
+        };
+        if !matches!(parent.status(), SchedulableItemStatus::NotStarted | SchedulableItemStatus::Planned) {
+            continue;
+        }
+        let transition = parent.transition(StatusAction::Start, by.clone())?;
+        status_transition_services::stamp_start_date(&mut parent, &transition);
+        started.push((validate(repository, task_repository, parent).await?, transition));
+    }
+
+    Ok(started)
+}
+
+pub(crate) async fn find_project(repository: &dyn ProjectRepository, id: &str) -> Result<Project> {
+    let project_id = Id::<Project>::from_str(id)?;
+
+    match repository.get_by_id(project_id).await? {
+        Some(project) => Ok(project),
+        None => Err(Error::NotFound(format!("Project {id} not found"))),
+    }
+}
+
+async fn validate(
+    repository: &dyn ProjectRepository,
+    task_repository: &dyn TaskRepository,
+    project: Project,
+) -> Result<Project> {
     let mut errors: Vec<Error> = Vec::new();
 
     if has_incorrect_schedule(&project) {
@@ -69,11 +288,40 @@ fn validate(project: Project) -> Result<Project> {
             "Provided project cannot be its own dependency".into(),
         ));
     }
+    for (links, repeated) in [
+        ("children", find_repeated(&project.children())),
+        ("dependencies", find_repeated(&project.dependencies())),
+    ] {
+        if !repeated.is_empty() {
+            errors.push(Error::InvalidPayload(format!(
+                "Provided project lists {links} more than once: {}",
+                repeated.join(", ")
+            )));
+        }
+    }
+    if let Some(cycle) = find_dependency_cycle(repository.get_all().await?, &project) {
+        errors.push(Error::InvalidPayload(format!(
+            "Provided project dependencies form a cycle: {}",
+            cycle.join(" -> ")
+        )));
+    }
+    if project.status() == SchedulableItemStatus::Completed {
+        let mut tree = progress_services::load_project_tree(repository, task_repository).await?;
+        let item = SchedulableItem::Project(project.id());
+        tree.insert_project(project.clone());
+
+        let open_children = tree.open_children(&item);
+        if !open_children.is_empty() {
+            errors.push(Error::InvalidPayload(format!(
+                "Provided project cannot be completed while children are open: {}",
+                open_children.iter().map(|child| child.to_string()).collect::<Vec<_>>().join(", ")
+            )));
+        }
+    }
     // validate provided tags
     // validate provided tasks
-    // validate dependencies
 
-    println!("errors: {errors:?}");
+    log::debug!("errors: {errors:?}");
 
     if errors.is_empty() {
         Ok(project)
@@ -82,65 +330,6 @@ fn validate(project: Project) -> Result<Project> {
     }
 }
 
-fn has_incorrect_schedule(project: &Project) -> bool {
-    match (project.start_date(), project.due_date()) {
-        (Some(start_date), Some(due_date)) => due_date < start_date,
-        _ => false,
-    }
-}
-
-fn has_inconsistent_status(project: &Project) -> bool {
-    match project.status() {
-        SchedulableItemStatus::NotStarted => is_invalid_not_started_project(project),
-        SchedulableItemStatus::Planned => is_invalid_planned_project(project),
-        SchedulableItemStatus::InProgress => is_invalid_in_progress_project(project),
-        SchedulableItemStatus::InReview => is_invalid_in_review_project(project),
-        SchedulableItemStatus::Completed => is_invalid_completed_project(project),
-        _ => false, //No restrictions on canceled and archived projects
-    }
-}
-
-fn is_invalid_not_started_project(project: &Project) -> bool {
-    // not started project should not have a start date in the past
-    match project.start_date() {
-        Some(start_date) => start_date <= Utc::now(),
-        _ => false,
-    }
-}
-
-fn is_invalid_planned_project(project: &Project) -> bool {
-    // planned project should have a start date for the future
-    match project.start_date() {
-        Some(start_date) => start_date <= Utc::now(),
-        _ => true,
-    }
-}
-
-fn is_invalid_in_progress_project(project: &Project) -> bool {
-    // in progress project should have a start date in the past (and an optional due date in the future)
-    match (project.start_date(), project.due_date()) {
-        (Some(start_date), Some(due_date)) => !(start_date <= Utc::now() && due_date >= Utc::now()),
-        (Some(start_date), None) => !(start_date <= Utc::now()),
-        _ => true,
-    }
-}
-
-fn is_invalid_in_review_project(project: &Project) -> bool {
-    // in review project should have a start date in the past and a fixed due date in the future
-    match (project.start_date(), project.due_date()) {
-        (Some(start_date), Some(due_date)) => !(start_date <= Utc::now() && due_date >= Utc::now()),
-        _ => true,
-    }
-}
-
-fn is_invalid_completed_project(project: &Project) -> bool {
-    // completed project should have both a start date and a due date in the past
-    match (project.start_date(), project.due_date()) {
-        (Some(start_date), Some(due_date)) => !(start_date <= Utc::now() && due_date <= Utc::now()),
-        _ => true,
-    }
-}
-
 fn is_own_parent(project: &Project) -> bool {
     project
         .children()
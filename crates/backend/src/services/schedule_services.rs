@@ -0,0 +1,57 @@
+use std::collections::HashSet;
+
+use chrono::Utc;
+
+use crate::{
+    Error, Result,
+    db::{project_repository::ProjectRepository, task_repository::TaskRepository},
+    dto::schedule_dto::ScheduleDTO,
+    services::project_services::find_project,
+};
+use project_tracker_core::{
+    HasId,
+    graph::schedule::Schedule,
+    models::schedulable::{Schedulable, SchedulableItem},
+};
+
+/// Forecasts the tasks of a project, including the ones of its child projects at any depth
+pub async fn get_project_schedule(
+    repository: &dyn ProjectRepository,
+    task_repository: &dyn TaskRepository,
+    id: String,
+) -> Result<ScheduleDTO> {
+    let project = find_project(repository, &id).await?;
+
+    let mut tasks = Vec::new();
+    let mut visited = HashSet::from([project.id()]);
+    let mut current = vec![project.clone()];
+
+    while let Some(parent) = current.pop() {
+        for child in parent.children() {
+            match child {
+                SchedulableItem::Task(task_id) => {
+                    if let Some(task) = task_repository.get_by_id(task_id).await? {
+                        tasks.push(task);
+                    }
+                }
+                SchedulableItem::Project(project_id) => {
+                    if visited.insert(project_id.clone())
+                        && let Some(sub_project) = repository.get_by_id(project_id).await?
+                    {
+                        current.push(sub_project);
+                    }
+                }
+            }
+        }
+    }
+
+    // Undated tasks start with the project, or now when the project has no start date either
+    let origin = project
+        .start_date()
+        .or_else(|| tasks.iter().filter_map(|task| task.start_date()).min())
+        .unwrap_or_else(Utc::now);
+
+    let schedule = Schedule::compute(tasks.clone(), origin).map_err(|e| Error::ProjectError(e.to_string()))?;
+
+    Ok(ScheduleDTO::new(&project, &schedule, &tasks))
+}
@@ -0,0 +1,62 @@
+use crate::{
+    Error, Result,
+    db::{person_repository::PersonRepository, status_transition_repository::StatusTransitionRepository},
+    dto::status_transition_dto::StatusTransitionDTO,
+    services::person_services,
+};
+use chrono::Utc;
+use project_tracker_core::{
+    HasId,
+    id::Id,
+    models::{
+        person::Person,
+        schedulable::{Schedulable, SchedulableItem, SchedulableItemStatus},
+        status_transition::StatusTransition,
+    },
+};
+
+/// Status history of an item, oldest transition first
+pub async fn get_history(
+    repository: &dyn StatusTransitionRepository,
+    item: SchedulableItem,
+) -> Result<Vec<StatusTransitionDTO>> {
+    let transitions = repository.get_for_item(&item).await?;
+    Ok(transitions.into_iter().map(StatusTransitionDTO::from).collect())
+}
+
+/// Resolves the person a transition is attributed to, when the request names one
+pub(crate) async fn find_actor(
+    person_repository: &dyn PersonRepository,
+    by: Option<String>,
+) -> Result<Option<Id<Person>>> {
+    match by {
+        Some(id) => Ok(Some(person_services::find_person(person_repository, &id).await?.id())),
+        None => Ok(None),
+    }
+}
+
+/// An item moved into progress without a start date in the past starts now,
+/// so it passes the in-progress checks of validation
+pub(crate) fn stamp_start_date<T: Schedulable>(item: &mut T, transition: &StatusTransition) {
+    if transition.to() == SchedulableItemStatus::InProgress
+        && item.start_date().is_none_or(|start_date| start_date > Utc::now())
+    {
+        item.start();
+    }
+}
+
+/// Status only moves through the transitions endpoints, where the workflow is checked and the
+/// move recorded, so plain updates have to keep the stored one
+pub(crate) fn ensure_status_unchanged(
+    item: &SchedulableItem,
+    stored: SchedulableItemStatus,
+    requested: SchedulableItemStatus,
+) -> Result<()> {
+    if requested == stored {
+        Ok(())
+    } else {
+        Err(Error::ProjectError(format!(
+            "Status of {item} cannot change from {stored} to {requested} through an update, post a transition instead"
+        )))
+    }
+}
@@ -0,0 +1,159 @@
+use std::str::FromStr;
+
+use crate::{
+    Error, Result,
+    db::tag_repository::TagRepository,
+    dto::tag_dto::TagDTO,
+};
+use project_tracker_core::{
+    HasId,
+    graph::tag_graph::TagGraph,
+    id::Id,
+    models::{schedulable::Schedulable, tag::Tag},
+};
+
+pub async fn get_all_tags(repository: &dyn TagRepository) -> Result<Vec<TagDTO>> {
+    let tags = repository.get_all().await?;
+    Ok(tags.into_iter().map(TagDTO::from).collect())
+}
+
+pub async fn get_tags_by_name(repository: &dyn TagRepository, name: &str) -> Result<Vec<TagDTO>> {
+    let tag = repository.get_by_name(name).await?;
+    Ok(tag.into_iter().map(TagDTO::from).collect())
+}
+
+pub async fn get_tag_from_id(repository: &dyn TagRepository, id: String) -> Result<TagDTO> {
+    let tag = find_tag(repository, &id).await?;
+    Ok(TagDTO::from(tag))
+}
+
+pub async fn create_tag(repository: &dyn TagRepository, payload: TagDTO) -> Result<Tag> {
+    let tag = validate(Tag::try_from(payload)?)?;
+    ensure_unique_name(repository, &tag).await?;
+    ensure_acyclic(repository, &tag).await?;
+
+    repository.create(tag.clone()).await?;
+
+    Ok(tag)
+}
+
+pub async fn update_tag(repository: &dyn TagRepository, id: String, payload: TagDTO) -> Result<Tag> {
+    if payload.id() != id {
+        return Err(Error::ProjectError(format!(
+            "Payload id {} does not match tag {id}",
+            payload.id()
+        )));
+    }
+    find_tag(repository, &id).await?;
+
+    let tag = validate(Tag::try_from(payload)?)?;
+    ensure_unique_name(repository, &tag).await?;
+    ensure_acyclic(repository, &tag).await?;
+
+    repository.update(tag.clone()).await?;
+
+    Ok(tag)
+}
+
+pub async fn delete_tag(repository: &dyn TagRepository, id: String) -> Result<Id<Tag>> {
+    let tag = find_tag(repository, &id).await?;
+
+    repository.delete(tag.id()).await?;
+
+    Ok(tag.id())
+}
+
+pub async fn get_tag_ancestors(repository: &dyn TagRepository, id: String) -> Result<Vec<TagDTO>> {
+    let tag = find_tag(repository, &id).await?;
+    let graph = load_tag_graph(repository).await?;
+    Ok(collect_tags(&graph, graph.ancestors(&tag.id())))
+}
+
+pub async fn get_tag_descendants(repository: &dyn TagRepository, id: String) -> Result<Vec<TagDTO>> {
+    let tag = find_tag(repository, &id).await?;
+    let graph = load_tag_graph(repository).await?;
+    Ok(collect_tags(&graph, graph.descendants(&tag.id())))
+}
+
+/// Keeps the items tagged with the given tag or any of its descendants
+pub async fn filter_tagged<T: Schedulable>(repository: &dyn TagRepository, items: Vec<T>, tag_id: &str) -> Result<Vec<T>> {
+    let tag = find_tag(repository, tag_id).await?;
+    let graph = load_tag_graph(repository).await?;
+    Ok(graph.filter_tagged(items, &tag.id()))
+}
+
+/// Resolves each name to its stored tag, creating tags for names not seen before
+pub async fn lookup_or_create_tags(repository: &dyn TagRepository, names: Vec<String>) -> Result<Vec<TagDTO>> {
+    let mut tags = Vec::with_capacity(names.len());
+
+    for name in names {
+        if !is_valid_tag_name(&name) {
+            return Err(Error::InvalidPayload(format!("Invalid tag name: {name:?}")));
+        }
+        let tag = repository.get_or_create_by_name(&name).await?;
+        tags.push(TagDTO::from(tag));
+    }
+
+    Ok(tags)
+}
+
+async fn find_tag(repository: &dyn TagRepository, id: &str) -> Result<Tag> {
+    let tag_id = Id::<Tag>::from_str(id)?;
+
+    match repository.get_by_id(tag_id).await? {
+        Some(tag) => Ok(tag),
+        None => Err(Error::NotFound(format!("Tag {id} not found"))),
+    }
+}
+
+async fn load_tag_graph(repository: &dyn TagRepository) -> Result<TagGraph> {
+    TagGraph::from_tags(repository.get_all().await?).map_err(|e| Error::ProjectError(e.to_string()))
+}
+
+async fn ensure_acyclic(repository: &dyn TagRepository, tag: &Tag) -> Result<()> {
+    let mut graph = load_tag_graph(repository).await?;
+    graph.insert(tag.clone()).map_err(|e| Error::ProjectError(e.to_string()))
+}
+
+fn collect_tags(graph: &TagGraph, ids: Vec<Id<Tag>>) -> Vec<TagDTO> {
+    ids.iter()
+        .filter_map(|id| graph.get(id).cloned())
+        .map(TagDTO::from)
+        .collect()
+}
+
+async fn ensure_unique_name(repository: &dyn TagRepository, tag: &Tag) -> Result<()> {
+    match repository.get_by_name(tag.name()).await? {
+        Some(existing) if existing.id() != tag.id() => Err(Error::ProjectError(format!(
+            "Tag name {} is already used by tag {}",
+            tag.name(),
+            existing.id()
+        ))),
+        _ => Ok(()),
+    }
+}
+
+fn validate(tag: Tag) -> Result<Tag> {
+    let mut errors: Vec<Error> = Vec::new();
+
+    if !is_valid_tag_name(tag.name()) {
+        errors.push(Error::InvalidPayload(
+            "Provided tag name must be non-empty and contain no whitespace".into(),
+        ));
+    }
+    if tag.parents().contains(&tag.id()) {
+        errors.push(Error::InvalidPayload(
+            "Provided tag cannot be its own parent".into(),
+        ));
+    }
+
+    if errors.is_empty() {
+        Ok(tag)
+    } else {
+        Err(Error::Multiple(errors))
+    }
+}
+
+fn is_valid_tag_name(name: &str) -> bool {
+    !name.is_empty() && !name.contains(char::is_whitespace)
+}
@@ -1,33 +1,333 @@
+use std::str::FromStr;
+
+use chrono::Utc;
+
 use crate::{
-    dto::task_dto::TaskDTO,
-    Result
+    Error, Result,
+    db::{
+        comment_repository::CommentRepository, milestone_repository::MilestoneRepository,
+        person_repository::PersonRepository, project_repository::ProjectRepository,
+        status_transition_repository::StatusTransitionRepository, tag_repository::TagRepository,
+        task_repository::TaskRepository, time_entry_repository::TimeEntryRepository,
+    },
+    dto::{
+        status_transition_dto::{StatusTransitionDTO, TransitionRequestDTO},
+        task_dto::{TaskDTO, TaskPatchDTO},
+    },
+    services::{
+        command_services::{self, CommandHistories, CommandStep},
+        comment_services, milestone_services, project_services, status_transition_services, tag_services,
+        time_entry_services,
+        validation::{find_dependency_cycle, find_repeated, has_inconsistent_status, has_incorrect_schedule},
+    },
 };
 use project_tracker_core::{
-    factories::task_factory::*,
-    models::task::Task
+    HasId,
+    graph::project_tree::ProjectTree,
+    id::Id,
+    models::{
+        command::SchedulableCommand,
+        schedulable::{Schedulable, SchedulableItem, SchedulableItemStatus},
+        status_transition::StatusTransition,
+        task::Task,
+    },
 };
 
-pub fn get_all_tasks() -> Vec<TaskDTO> {
-    // TODO
-    vec![
-        TaskDTO::from(sample_task_for_dto()),
-        TaskDTO::from(sample_task_for_dto()),
-        TaskDTO::from(sample_task_for_dto()),
-        TaskDTO::from(sample_task_for_dto()),
-        TaskDTO::from(sample_task_for_dto()),
-        TaskDTO::from(sample_task_for_dto()),
-    ]
-}
-
-pub fn create_task(payload: TaskDTO) -> Result<Task> {
-    /* TODO:
-    1. [x] convert DTO to Task
-    2. [ ] validate
-    3. [ ] push to DB
-    4. [ ] return success.failure
-    */
+pub async fn get_all_tasks(repository: &dyn TaskRepository) -> Result<Vec<TaskDTO>> {
+    let tasks = repository.get_all().await?;
+    Ok(tasks.into_iter().map(TaskDTO::from).collect())
+}
+
+pub async fn get_tasks_tagged_with(
+    repository: &dyn TaskRepository,
+    tag_repository: &dyn TagRepository,
+    tag_id: &str,
+) -> Result<Vec<TaskDTO>> {
+    let tasks = tag_services::filter_tagged(tag_repository, repository.get_all().await?, tag_id).await?;
+    Ok(tasks.into_iter().map(TaskDTO::from).collect())
+}
+
+pub async fn get_task_from_id(repository: &dyn TaskRepository, id: String) -> Result<TaskDTO> {
+    let task = find_task(repository, &id).await?;
+    Ok(TaskDTO::from(task))
+}
+
+pub async fn create_task(repository: &dyn TaskRepository, payload: TaskDTO) -> Result<Task> {
+    let task = validate(repository, Task::try_from(payload)?).await?;
+
+    repository.create(task.clone()).await?;
+
+    Ok(task)
+}
+
+pub async fn update_task(repository: &dyn TaskRepository, id: String, payload: TaskDTO) -> Result<Task> {
+    if payload.id() != id {
+        return Err(Error::ProjectError(format!(
+            "Payload id {} does not match task {id}",
+            payload.id()
+        )));
+    }
+    let existing = find_task(repository, &id).await?;
 
     let task = Task::try_from(payload)?;
+    status_transition_services::ensure_status_unchanged(
+        &SchedulableItem::Task(task.id()),
+        existing.status(),
+        task.status(),
+    )?;
+    let task = validate(repository, task).await?;
+
+    repository.update(task.clone()).await?;
 
     Ok(task)
-}
\ No newline at end of file
+}
+
+pub async fn patch_task(repository: &dyn TaskRepository, id: String, payload: TaskPatchDTO) -> Result<Task> {
+    let existing = find_task(repository, &id).await?;
+    let stored_status = existing.status();
+
+    let task = payload.apply_to(existing)?;
+    status_transition_services::ensure_status_unchanged(
+        &SchedulableItem::Task(task.id()),
+        stored_status,
+        task.status(),
+    )?;
+    let task = validate(repository, task).await?;
+
+    repository.update(task.clone()).await?;
+
+    Ok(task)
+}
+
+/// Runs, undoes or redoes a command against the task and stores the result.
+/// Returns the task with the command the step went through, or `None` when nothing changed.
+pub async fn command_task(
+    repository: &dyn TaskRepository,
+    histories: &CommandHistories<Task>,
+    id: String,
+    step: CommandStep,
+) -> Result<(Task, Option<SchedulableCommand>)> {
+    let mut histories = histories.lock().await;
+    let task = find_task(repository, &id).await?;
+
+    let (task, command, history) = command_services::take_step(histories.get(&task.id()), task, step)?;
+    if command.is_none() {
+        return Ok((task, None));
+    }
+    let task = validate(repository, task).await?;
+
+    repository.update(task.clone()).await?;
+    histories.insert(task.id(), history);
+
+    Ok((task, command))
+}
+
+/// Deletes the task, along with the comments and time entries on it and on every item removed with it,
+/// and drops the removed items from the milestones linking them
+pub async fn delete_task(
+    repository: &dyn TaskRepository,
+    comment_repository: &dyn CommentRepository,
+    milestone_repository: &dyn MilestoneRepository,
+    time_entry_repository: &dyn TimeEntryRepository,
+    id: String,
+) -> Result<Id<Task>> {
+    let task = find_task(repository, &id).await?;
+
+    let deleted = repository.delete(task.id()).await?;
+    comment_services::delete_item_comments(comment_repository, &deleted.items()).await?;
+    milestone_services::unlink_items(milestone_repository, &deleted.items()).await?;
+    time_entry_services::delete_item_entries(time_entry_repository, &deleted.items()).await?;
+
+    Ok(task.id())
+}
+
+/// Moves the task through its status workflow and records the move in its history.
+/// Returns the task's own transition first, followed by any parent project started along with it.
+pub async fn transition_task(
+    repository: &dyn TaskRepository,
+    project_repository: &dyn ProjectRepository,
+    transition_repository: &dyn StatusTransitionRepository,
+    person_repository: &dyn PersonRepository,
+    id: String,
+    payload: TransitionRequestDTO,
+) -> Result<Vec<StatusTransition>> {
+    let original = find_task(repository, &id).await?;
+    let by = status_transition_services::find_actor(person_repository, payload.by).await?;
+
+    let mut task = original.clone();
+    let transition = task.transition(payload.action, by.clone())?;
+    status_transition_services::stamp_start_date(&mut task, &transition);
+    let task = validate(repository, task).await?;
+
+    let started_parents = if payload.start_parents && transition.to() == SchedulableItemStatus::InProgress {
+        project_services::start_parent_projects(project_repository, repository, &SchedulableItem::Task(task.id()), by)
+            .await?
+    } else {
+        Vec::new()
+    };
+
+    let mut parents = Vec::new();
+    let mut transitions = vec![transition];
+    for (parent, parent_transition) in started_parents {
+        parents.push(parent);
+        transitions.push(parent_transition);
+    }
+
+    // Every write is undone when a later one fails, so a transition is never left half made
+    repository.update(task.clone()).await?;
+    let stored = async {
+        let spawned = spawn_next_occurrence(repository, &task).await?;
+        let stored =
+            project_services::store_transitioned(project_repository, transition_repository, parents, &transitions).await;
+        if let (Err(_), Some(spawned)) = (&stored, spawned)
+            && let Err(err) = repository.delete(spawned).await
+        {
+            log::error!("Failed to roll back next occurrence after a failed transition: {err:?}");
+        }
+        stored
+    }
+    .await;
+
+    if let Err(err) = stored {
+        if let Err(rollback_err) = repository.update(original).await {
+            log::error!("Failed to roll back task after a failed transition: {rollback_err:?}");
+        }
+        return Err(err);
+    }
+
+    Ok(transitions)
+}
+
+pub async fn get_task_history(
+    repository: &dyn TaskRepository,
+    transition_repository: &dyn StatusTransitionRepository,
+    id: String,
+) -> Result<Vec<StatusTransitionDTO>> {
+    let task = find_task(repository, &id).await?;
+    status_transition_services::get_history(transition_repository, SchedulableItem::Task(task.id())).await
+}
+
+/// Lists every occurrence of the series the task belongs to, oldest first
+pub async fn get_task_series(repository: &dyn TaskRepository, id: String) -> Result<Vec<TaskDTO>> {
+    let task = find_task(repository, &id).await?;
+    let series_id = task.series_id();
+
+    let mut series: Vec<Task> = repository
+        .get_all()
+        .await?
+        .into_iter()
+        .filter(|stored| stored.series_id() == series_id)
+        .collect();
+    series.sort_by_key(|stored| stored.occurrence());
+
+    Ok(series.into_iter().map(TaskDTO::from).collect())
+}
+
+/// Creates the next occurrence of a completed recurring task, unless the rule has run out
+/// or that occurrence was already spawned by an earlier completion. Returns the id of the one it created.
+async fn spawn_next_occurrence(repository: &dyn TaskRepository, task: &Task) -> Result<Option<Id<Task>>> {
+    if task.status() != SchedulableItemStatus::Completed {
+        return Ok(None);
+    }
+    let Some(next) = task.next_occurrence_after(Utc::now()) else {
+        return Ok(None);
+    };
+
+    let series_id = task.series_id();
+    let already_spawned = repository
+        .get_all()
+        .await?
+        .iter()
+        .any(|stored| stored.series_id() == series_id && stored.occurrence() >= next.occurrence());
+    if already_spawned {
+        return Ok(None);
+    }
+
+    let next_id = next.id();
+    repository.create(next).await?;
+
+    Ok(Some(next_id))
+}
+
+pub(crate) async fn find_task(repository: &dyn TaskRepository, id: &str) -> Result<Task> {
+    let task_id = Id::<Task>::from_str(id)?;
+
+    match repository.get_by_id(task_id).await? {
+        Some(task) => Ok(task),
+        None => Err(Error::NotFound(format!("Task {id} not found"))),
+    }
+}
+
+async fn validate(repository: &dyn TaskRepository, task: Task) -> Result<Task> {
+    let mut errors: Vec<Error> = Vec::new();
+
+    if has_incorrect_schedule(&task) {
+        errors.push(Error::InvalidPayload(
+            "Provided task has incorrect schedule".into(),
+        ));
+    }
+    if has_inconsistent_status(&task) {
+        errors.push(Error::InvalidPayload(
+            "Provided task has status inconsistent with provided data".into(),
+        ));
+    }
+    if task.is_recurring() && !task.has_start_date() && !task.has_due_date() {
+        errors.push(Error::InvalidPayload(
+            "Provided recurring task needs a start or due date".into(),
+        ));
+    }
+    if is_own_parent(&task) {
+        errors.push(Error::InvalidPayload(
+            "Provided task cannot be its own parent".into(),
+        ));
+    }
+    if depends_on_self(&task) {
+        errors.push(Error::InvalidPayload(
+            "Provided task cannot be its own dependency".into(),
+        ));
+    }
+    for (links, repeated) in [
+        ("children", find_repeated(&task.children())),
+        ("dependencies", find_repeated(&task.dependencies())),
+    ] {
+        if !repeated.is_empty() {
+            errors.push(Error::InvalidPayload(format!(
+                "Provided task lists {links} more than once: {}",
+                repeated.join(", ")
+            )));
+        }
+    }
+    let stored = repository.get_all().await?;
+    if task.status() == SchedulableItemStatus::Completed {
+        let mut tree = ProjectTree::from_items(Vec::new(), stored.clone());
+        tree.insert_task(task.clone());
+
+        let open_children = tree.open_children(&SchedulableItem::Task(task.id()));
+        if !open_children.is_empty() {
+            errors.push(Error::InvalidPayload(format!(
+                "Provided task cannot be completed while subtasks are open: {}",
+                open_children.iter().map(|child| child.to_string()).collect::<Vec<_>>().join(", ")
+            )));
+        }
+    }
+    if let Some(cycle) = find_dependency_cycle(stored, &task) {
+        errors.push(Error::InvalidPayload(format!(
+            "Provided task dependencies form a cycle: {}",
+            cycle.join(" -> ")
+        )));
+    }
+
+    if errors.is_empty() {
+        Ok(task)
+    } else {
+        Err(Error::Multiple(errors))
+    }
+}
+
+fn is_own_parent(task: &Task) -> bool {
+    task.children().contains(&task.id())
+}
+
+fn depends_on_self(task: &Task) -> bool {
+    task.dependencies().contains(&task.id())
+}
@@ -0,0 +1,249 @@
+use std::str::FromStr;
+
+use chrono::Utc;
+
+use crate::{
+    Error, Result,
+    db::{
+        person_repository::PersonRepository, project_repository::ProjectRepository,
+        task_repository::TaskRepository, time_entry_repository::TimeEntryRepository,
+    },
+    dto::time_entry_dto::{TimeEntryDTO, TimeReportDTO, TimeTotalDTO, TimerStartDTO, TimerStopDTO},
+    services::{person_services, progress_services, project_services, task_services},
+};
+use project_tracker_core::{
+    HasId,
+    graph::{project_tree::ProjectTree, time_report::TimeReport},
+    id::Id,
+    models::{
+        person::Person,
+        schedulable::SchedulableItem,
+        time_entry::{TimeEntry, TimeEntryError},
+    },
+};
+
+pub async fn get_all_time_entries(repository: &dyn TimeEntryRepository) -> Result<Vec<TimeEntryDTO>> {
+    let mut entries = repository.get_all().await?;
+    entries.sort_by_key(TimeEntry::start);
+    Ok(entries.into_iter().map(TimeEntryDTO::from).collect())
+}
+
+pub async fn get_time_entry_from_id(repository: &dyn TimeEntryRepository, id: String) -> Result<TimeEntryDTO> {
+    let entry = find_time_entry(repository, &id).await?;
+    Ok(TimeEntryDTO::from(entry))
+}
+
+pub async fn create_time_entry(
+    repository: &dyn TimeEntryRepository,
+    person_repository: &dyn PersonRepository,
+    project_repository: &dyn ProjectRepository,
+    task_repository: &dyn TaskRepository,
+    payload: TimeEntryDTO,
+) -> Result<TimeEntry> {
+    let tree = progress_services::load_project_tree(project_repository, task_repository).await?;
+    let entry = validate(repository, person_repository, &tree, TimeEntry::try_from(payload)?).await?;
+
+    repository.create(entry.clone()).await?;
+
+    Ok(entry)
+}
+
+pub async fn update_time_entry(
+    repository: &dyn TimeEntryRepository,
+    person_repository: &dyn PersonRepository,
+    project_repository: &dyn ProjectRepository,
+    task_repository: &dyn TaskRepository,
+    id: String,
+    payload: TimeEntryDTO,
+) -> Result<TimeEntry> {
+    if payload.id() != id {
+        return Err(Error::ProjectError(format!(
+            "Payload id {} does not match time entry {id}",
+            payload.id()
+        )));
+    }
+    find_time_entry(repository, &id).await?;
+
+    let tree = progress_services::load_project_tree(project_repository, task_repository).await?;
+    let entry = validate(repository, person_repository, &tree, TimeEntry::try_from(payload)?).await?;
+
+    repository.update(entry.clone()).await?;
+
+    Ok(entry)
+}
+
+pub async fn delete_time_entry(repository: &dyn TimeEntryRepository, id: String) -> Result<Id<TimeEntry>> {
+    let entry = find_time_entry(repository, &id).await?;
+
+    repository.delete(entry.id()).await?;
+
+    Ok(entry.id())
+}
+
+/// Deletes the time logged on `items`, once the items themselves are gone
+pub(crate) async fn delete_item_entries(repository: &dyn TimeEntryRepository, items: &[SchedulableItem]) -> Result<()> {
+    for item in items {
+        for entry in repository.get_for_item(item).await? {
+            repository.delete(entry.id()).await?;
+        }
+    }
+    Ok(())
+}
+
+/// Starts a timer on a project or task; a person can only have one timer running at a time
+pub async fn start_timer(
+    repository: &dyn TimeEntryRepository,
+    person_repository: &dyn PersonRepository,
+    project_repository: &dyn ProjectRepository,
+    task_repository: &dyn TaskRepository,
+    payload: TimerStartDTO,
+) -> Result<TimeEntry> {
+    let person = person_services::find_person(person_repository, &payload.person_id).await?;
+
+    let mut entry = TimeEntry::start_timer(person.id(), SchedulableItem::try_from(payload.item)?, Utc::now());
+    if let Some(note) = payload.note {
+        entry.set_note(note);
+    }
+    let tree = progress_services::load_project_tree(project_repository, task_repository).await?;
+    let entry = validate(repository, person_repository, &tree, entry).await?;
+
+    repository.create(entry.clone()).await?;
+
+    Ok(entry)
+}
+
+/// Stops the timer the person has running
+pub async fn stop_timer(
+    repository: &dyn TimeEntryRepository,
+    person_repository: &dyn PersonRepository,
+    payload: TimerStopDTO,
+) -> Result<TimeEntry> {
+    let person = person_services::find_person(person_repository, &payload.person_id).await?;
+    let Some(mut entry) = find_running(repository, &person.id()).await? else {
+        return Err(Error::NotFound(format!("No timer running for person {}", person.id())));
+    };
+
+    entry.stop_timer(Utc::now())?;
+
+    repository.update(entry.clone()).await?;
+
+    Ok(entry)
+}
+
+/// Time logged on the project itself, and together with its whole descendant tree
+pub async fn get_project_time(
+    repository: &dyn TimeEntryRepository,
+    project_repository: &dyn ProjectRepository,
+    task_repository: &dyn TaskRepository,
+    id: String,
+) -> Result<TimeTotalDTO> {
+    let project = project_services::find_project(project_repository, &id).await?;
+    item_time(repository, project_repository, task_repository, SchedulableItem::Project(project.id())).await
+}
+
+/// Time logged on the task itself, and together with its subtasks
+pub async fn get_task_time(
+    repository: &dyn TimeEntryRepository,
+    project_repository: &dyn ProjectRepository,
+    task_repository: &dyn TaskRepository,
+    id: String,
+) -> Result<TimeTotalDTO> {
+    let task = task_services::find_task(task_repository, &id).await?;
+    item_time(repository, project_repository, task_repository, SchedulableItem::Task(task.id())).await
+}
+
+pub async fn get_time_report(
+    repository: &dyn TimeEntryRepository,
+    project_repository: &dyn ProjectRepository,
+    task_repository: &dyn TaskRepository,
+) -> Result<TimeReportDTO> {
+    let tree = progress_services::load_project_tree(project_repository, task_repository).await?;
+    let report = TimeReport::new(repository.get_all().await?, Utc::now());
+
+    Ok(TimeReportDTO::new(
+        report.total(),
+        report.per_item(),
+        report.per_person(),
+        report.per_tag(&tree),
+        report.per_week(),
+    ))
+}
+
+async fn item_time(
+    repository: &dyn TimeEntryRepository,
+    project_repository: &dyn ProjectRepository,
+    task_repository: &dyn TaskRepository,
+    item: SchedulableItem,
+) -> Result<TimeTotalDTO> {
+    let tree = progress_services::load_project_tree(project_repository, task_repository).await?;
+    let report = TimeReport::new(repository.get_all().await?, Utc::now());
+
+    Ok(TimeTotalDTO::new(
+        &item,
+        report.total_for_item(&item),
+        report.total_with_descendants(&tree, &item),
+    ))
+}
+
+async fn find_time_entry(repository: &dyn TimeEntryRepository, id: &str) -> Result<TimeEntry> {
+    let entry_id = Id::<TimeEntry>::from_str(id)?;
+
+    match repository.get_by_id(entry_id).await? {
+        Some(entry) => Ok(entry),
+        None => Err(Error::NotFound(format!("Time entry {id} not found"))),
+    }
+}
+
+async fn find_running(repository: &dyn TimeEntryRepository, person_id: &Id<Person>) -> Result<Option<TimeEntry>> {
+    Ok(repository
+        .get_for_person(person_id)
+        .await?
+        .into_iter()
+        .find(TimeEntry::is_running))
+}
+
+/// Checks every entry before it is stored. A running entry is refused while its person has
+/// another timer running, whichever route it comes through.
+async fn validate(
+    repository: &dyn TimeEntryRepository,
+    person_repository: &dyn PersonRepository,
+    tree: &ProjectTree,
+    entry: TimeEntry,
+) -> Result<TimeEntry> {
+    if entry.is_running() {
+        let other_running = repository
+            .get_for_person(entry.person_id())
+            .await?
+            .into_iter()
+            .find(|stored| stored.is_running() && stored.id() != entry.id());
+        if let Some(running) = other_running {
+            return Err(TimeEntryError::AlreadyRunning(running.id()).into());
+        }
+    }
+
+    let mut errors: Vec<Error> = Vec::new();
+
+    if person_repository.get_by_id(entry.person_id().clone()).await?.is_none() {
+        errors.push(Error::InvalidPayload(format!(
+            "Provided time entry is logged by unknown person {}",
+            entry.person_id()
+        )));
+    }
+    if tree.status(entry.item()).is_none() {
+        errors.push(Error::InvalidPayload(format!(
+            "Provided time entry is logged against unknown item {}",
+            entry.item()
+        )));
+    }
+    if !entry.is_valid() {
+        errors.push(Error::InvalidPayload(
+            "Provided time entry ends before it starts".into(),
+        ));
+    }
+
+    if errors.is_empty() {
+        Ok(entry)
+    } else {
+        Err(Error::Multiple(errors))
+    }
+}
@@ -0,0 +1,96 @@
+use chrono::Utc;
+use project_tracker_core::{
+    EntityType, HasId,
+    graph::dependency_graph::{DependencyGraph, DependencyGraphError},
+    id::Id,
+    models::schedulable::{Schedulable, SchedulableItemStatus},
+};
+
+// Checks shared by every schedulable item, whatever its concrete type
+
+pub fn has_incorrect_schedule<T: Schedulable>(item: &T) -> bool {
+    match (item.start_date(), item.due_date()) {
+        (Some(start_date), Some(due_date)) => due_date < start_date,
+        _ => false,
+    }
+}
+
+pub fn has_inconsistent_status<T: Schedulable>(item: &T) -> bool {
+    match item.status() {
+        SchedulableItemStatus::NotStarted => is_invalid_not_started_item(item),
+        SchedulableItemStatus::Planned => is_invalid_planned_item(item),
+        SchedulableItemStatus::InProgress => is_invalid_in_progress_item(item),
+        SchedulableItemStatus::InReview => is_invalid_in_review_item(item),
+        SchedulableItemStatus::Completed => is_invalid_completed_item(item),
+        _ => false, //No restrictions on canceled and archived items
+    }
+}
+
+/// Entries of `items` listed more than once, each named once, in the order they repeat
+pub fn find_repeated<T: PartialEq + std::fmt::Display>(items: &[T]) -> Vec<String> {
+    let mut repeated: Vec<&T> = Vec::new();
+    for (index, item) in items.iter().enumerate() {
+        if items[..index].contains(item) && !repeated.contains(&item) {
+            repeated.push(item);
+        }
+    }
+    repeated.iter().map(|item| item.to_string()).collect()
+}
+
+/// Looks for a dependency loop running through `item` once it replaces its stored version.
+/// Loops elsewhere in the stored data are left alone so they do not block unrelated writes.
+pub fn find_dependency_cycle<T>(stored: Vec<T>, item: &T) -> Option<Vec<String>>
+where
+    T: Schedulable<DependencyType = Id<T>> + HasId<Entity = T> + EntityType + Clone + Eq,
+{
+    let mut graph = DependencyGraph::from_items(stored);
+    graph.insert(item.clone());
+
+    item.dependencies()
+        .iter()
+        .find_map(|dependency| match graph.check_dependency(&item.id(), dependency) {
+            Err(DependencyGraphError::Cycle(cycle)) => Some(cycle),
+            _ => None,
+        })
+}
+
+fn is_invalid_not_started_item<T: Schedulable>(item: &T) -> bool {
+    // not started item should not have a start date in the past
+    match item.start_date() {
+        Some(start_date) => start_date <= Utc::now(),
+        _ => false,
+    }
+}
+
+fn is_invalid_planned_item<T: Schedulable>(item: &T) -> bool {
+    // planned item should have a start date for the future
+    match item.start_date() {
+        Some(start_date) => start_date <= Utc::now(),
+        _ => true,
+    }
+}
+
+fn is_invalid_in_progress_item<T: Schedulable>(item: &T) -> bool {
+    // in progress item should have a start date in the past (and an optional due date in the future)
+    match (item.start_date(), item.due_date()) {
+        (Some(start_date), Some(due_date)) => !(start_date <= Utc::now() && due_date >= Utc::now()),
+        (Some(start_date), None) => start_date > Utc::now(),
+        _ => true,
+    }
+}
+
+fn is_invalid_in_review_item<T: Schedulable>(item: &T) -> bool {
+    // in review item should have a start date in the past and a fixed due date in the future
+    match (item.start_date(), item.due_date()) {
+        (Some(start_date), Some(due_date)) => !(start_date <= Utc::now() && due_date >= Utc::now()),
+        _ => true,
+    }
+}
+
+fn is_invalid_completed_item<T: Schedulable>(item: &T) -> bool {
+    // completed item should have both a start date and a due date in the past
+    match (item.start_date(), item.due_date()) {
+        (Some(start_date), Some(due_date)) => !(start_date <= Utc::now() && due_date <= Utc::now()),
+        _ => true,
+    }
+}
@@ -0,0 +1,56 @@
+use std::{collections::HashSet, str::FromStr};
+
+use chrono::Utc;
+
+use crate::{
+    Result,
+    db::{project_repository::ProjectRepository, task_repository::TaskRepository},
+    dto::work_queue_dto::{NextWorkDTO, WorkQueueQuery},
+};
+use project_tracker_core::{
+    HasId,
+    graph::work_queue::WorkQueue,
+    id::Id,
+    models::{
+        person::Person,
+        schedulable::{Schedulable, SchedulableItem},
+    },
+};
+
+/// Open projects and tasks, best pick first.
+///
+/// The queue is ranked over everything stored, so dependencies owned by someone else
+/// still count as blockers when narrowing it down to one owner.
+pub async fn get_work_queue(
+    project_repository: &dyn ProjectRepository,
+    task_repository: &dyn TaskRepository,
+    query: WorkQueueQuery,
+) -> Result<Vec<NextWorkDTO>> {
+    let owner_id = query.owner_id.as_deref().map(Id::<Person>::from_str).transpose()?;
+
+    let projects = project_repository.get_all().await?;
+    let tasks = task_repository.get_all().await?;
+
+    let owned: Option<HashSet<SchedulableItem>> = owner_id.map(|owner_id| {
+        let owned_projects = projects
+            .iter()
+            .filter(|project| project.owner_id() == Some(&owner_id))
+            .map(|project| SchedulableItem::Project(project.id()));
+        let owned_tasks = tasks
+            .iter()
+            .filter(|task| task.owner_id() == Some(&owner_id))
+            .map(|task| SchedulableItem::Task(task.id()));
+        owned_projects.chain(owned_tasks).collect()
+    });
+
+    let queue = WorkQueue::new(projects, tasks, Utc::now());
+
+    Ok(queue
+        .entries()
+        .iter()
+        .filter(|entry| owned.as_ref().is_none_or(|owned| owned.contains(&entry.item)))
+        .filter(|entry| !query.ready || entry.is_ready())
+        .take(query.limit.unwrap_or(usize::MAX))
+        .map(NextWorkDTO::from)
+        .collect())
+}
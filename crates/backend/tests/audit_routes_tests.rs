@@ -0,0 +1,137 @@
+mod common;
+
+use common::{send, send_as};
+use axum::http::StatusCode;
+use project_tracker_backend::{app::AppState, dto::person_dto::PersonDTO, routes::create_router};
+use project_tracker_core::{
+    HasId,
+    builders::project_builder::ProjectBuilder,
+    factories::{
+        person_factory::{basic_person, sample_person},
+        project_factory::basic_project,
+        task_factory::basic_task,
+    },
+    models::schedulable::{Schedulable, SchedulableItem},
+};
+use serde_json::{Value, json};
+
+fn changes_to<'a>(history: &'a [Value], field: &str) -> Vec<&'a Value> {
+    history.iter().filter(|entry| entry["field"] == field).collect()
+}
+
+#[tokio::test]
+async fn test_project_history_records_each_change_with_actor() {
+    let state = AppState::mock();
+    let person = basic_person();
+    let actor = person.id().to_string();
+    let project = basic_project();
+    let project_id = project.id();
+    state.person_repository.create(person).await.unwrap();
+    state.project_repository.create(project.clone()).await.unwrap();
+    let router = create_router(state);
+
+    let payload = json!({ "name": "Renamed project" }).to_string();
+    let (status, _) = send_as(&router, &actor, "PATCH", &format!("/api/project/{project_id}"), Some(payload)).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, body) = send(&router, "GET", &format!("/api/project/{project_id}/history"), None).await;
+    assert_eq!(status, StatusCode::OK);
+    let history = body.as_array().unwrap();
+    let names = changes_to(history, "name");
+    assert_eq!(names.len(), 2);
+
+    assert_eq!(names[0]["old_value"], Value::Null);
+    assert_eq!(names[0]["new_value"], project.name());
+    assert_eq!(names[0]["actor"], Value::Null);
+
+    assert_eq!(names[1]["entity_id"], project_id.to_string());
+    assert_eq!(names[1]["old_value"], project.name());
+    assert_eq!(names[1]["new_value"], "Renamed project");
+    assert_eq!(names[1]["actor"], actor);
+}
+
+#[tokio::test]
+async fn test_history_survives_delete() {
+    let state = AppState::mock();
+    let person = basic_person();
+    let actor = person.id().to_string();
+    let project = basic_project();
+    let project_id = project.id();
+    state.person_repository.create(person).await.unwrap();
+    state.project_repository.create(project).await.unwrap();
+    let router = create_router(state);
+
+    let (status, _) = send_as(&router, &actor, "DELETE", &format!("/api/project/{project_id}"), None).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, body) = send(&router, "GET", &format!("/api/project/{project_id}/history"), None).await;
+    assert_eq!(status, StatusCode::OK);
+    let deletions: Vec<&Value> = body
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter(|entry| entry["new_value"].is_null())
+        .collect();
+    assert!(!deletions.is_empty());
+    assert!(deletions.iter().all(|entry| entry["actor"] == actor));
+    assert_eq!(changes_to(&deletions.into_iter().cloned().collect::<Vec<_>>(), "name").len(), 1);
+}
+
+#[tokio::test]
+async fn test_person_changes_are_audited() {
+    let state = AppState::mock();
+    let router = create_router(state);
+
+    let person = sample_person();
+    let payload = serde_json::to_string(&PersonDTO::from(person.clone())).unwrap();
+    let (status, body) = send(&router, "POST", "/api/people", Some(payload)).await;
+    assert_eq!(status, StatusCode::OK);
+    let person_id = body["person_id"].as_str().unwrap().to_string();
+
+    let (status, body) = send(&router, "GET", &format!("/api/person/{person_id}/history"), None).await;
+    assert_eq!(status, StatusCode::OK);
+    let history = body.as_array().unwrap();
+    assert_eq!(changes_to(history, "first_name")[0]["new_value"], person.first_name());
+    assert_eq!(changes_to(history, "last_name")[0]["new_value"], person.last_name());
+}
+
+#[tokio::test]
+async fn test_invalid_ids_are_rejected() {
+    let state = AppState::mock();
+    let project = basic_project();
+    let project_id = project.id();
+    state.project_repository.create(project).await.unwrap();
+    let router = create_router(state);
+
+    let (status, _) = send(&router, "GET", &format!("/api/task/{project_id}/history"), None).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let payload = json!({ "name": "Renamed project" }).to_string();
+    let (status, _) = send_as(&router, "not-a-person", "PATCH", &format!("/api/project/{project_id}"), Some(payload)).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_delete_logs_the_parent_it_detaches_from() {
+    let state = AppState::mock();
+    let task = basic_task();
+    let task_id = task.id();
+    let project = ProjectBuilder::new()
+        .with_name("Parent")
+        .with_children(vec![SchedulableItem::Task(task_id.clone())])
+        .build();
+    let project_id = project.id();
+    state.task_repository.create(task).await.unwrap();
+    state.project_repository.create(project).await.unwrap();
+    let router = create_router(state);
+
+    let (status, _) = send(&router, "DELETE", &format!("/api/task/{task_id}"), None).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, body) = send(&router, "GET", &format!("/api/project/{project_id}/history"), None).await;
+    assert_eq!(status, StatusCode::OK);
+    let children = changes_to(body.as_array().unwrap(), "children");
+    assert_eq!(children.len(), 2);
+    assert_eq!(children[1]["old_value"], SchedulableItem::Task(task_id).to_string());
+    assert_eq!(children[1]["new_value"], Value::Null);
+}
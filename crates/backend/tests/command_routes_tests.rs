@@ -0,0 +1,131 @@
+mod common;
+
+use common::send;
+use axum::http::StatusCode;
+use project_tracker_backend::{app::AppState, routes::create_router};
+use project_tracker_core::{
+    HasId,
+    builders::{project_builder::ProjectBuilder, tag_builder::TagBuilder, task_builder::TaskBuilder},
+    id::Id,
+    models::{command::SchedulableCommand, schedulable::SchedulableItem},
+};
+use serde_json::json;
+
+fn command_payload(command: SchedulableCommand) -> Option<String> {
+    Some(serde_json::to_string(&command).unwrap())
+}
+
+#[tokio::test]
+async fn test_project_commands_can_be_undone_and_redone() {
+    let state = AppState::mock();
+    let tag = TagBuilder::new().with_name("keep").build();
+    let task = TaskBuilder::new().with_name("Child").build();
+    state.tag_repository.create(tag.clone()).await.unwrap();
+    state.task_repository.create(task.clone()).await.unwrap();
+    let project = ProjectBuilder::new()
+        .with_name("Edited")
+        .with_tags(vec![tag.id()])
+        .with_children(vec![SchedulableItem::Task(task.id())])
+        .build();
+    let project_id = project.id();
+    state.project_repository.create(project).await.unwrap();
+    let router = create_router(state);
+    let uri = format!("/api/project/{project_id}");
+
+    let command = command_payload(SchedulableCommand::RemoveAllTags);
+    let (status, body) = send(&router, "POST", &format!("{uri}/commands"), command).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["message"], "Ran: Remove all tags");
+    let command = command_payload(SchedulableCommand::RemoveAllChildren);
+    let (status, _) = send(&router, "POST", &format!("{uri}/commands"), command).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (_, body) = send(&router, "GET", &uri, None).await;
+    assert_eq!(body["tags"], json!([]));
+    assert_eq!(body["children"], json!([]));
+
+    let (status, body) = send(&router, "POST", &format!("{uri}/undo"), None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["message"], "Undid: Remove all children");
+    let (status, _) = send(&router, "POST", &format!("{uri}/undo"), None).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (_, body) = send(&router, "GET", &uri, None).await;
+    assert_eq!(body["tags"], json!([tag.id().to_string()]));
+    assert_eq!(body["children"].as_array().unwrap().len(), 1);
+
+    let (status, _) = send(&router, "POST", &format!("{uri}/undo"), None).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    let (status, body) = send(&router, "POST", &format!("{uri}/redo"), None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["message"], "Redid: Remove all tags");
+    let (_, body) = send(&router, "GET", &uri, None).await;
+    assert_eq!(body["tags"], json!([]));
+    assert_eq!(body["children"].as_array().unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_commands_that_change_nothing_are_not_recorded() {
+    let state = AppState::mock();
+    let project = ProjectBuilder::new().with_name("Untagged").build();
+    let project_id = project.id();
+    state.project_repository.create(project).await.unwrap();
+    let router = create_router(state);
+    let uri = format!("/api/project/{project_id}");
+
+    let command = command_payload(SchedulableCommand::RemoveAllTags);
+    let (status, body) = send(&router, "POST", &format!("{uri}/commands"), command).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["message"], "Nothing changed");
+
+    let (status, _) = send(&router, "POST", &format!("{uri}/undo"), None).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn test_undo_refuses_to_drop_later_edits() {
+    let state = AppState::mock();
+    let project = ProjectBuilder::new().with_name("Original").build();
+    let project_id = project.id();
+    state.project_repository.create(project).await.unwrap();
+    let router = create_router(state);
+    let uri = format!("/api/project/{project_id}");
+
+    let command = command_payload(SchedulableCommand::Rename("Renamed".into()));
+    let (status, _) = send(&router, "POST", &format!("{uri}/commands"), command).await;
+    assert_eq!(status, StatusCode::OK);
+    let (status, _) = send(&router, "PATCH", &uri, Some(json!({ "name": "Patched" }).to_string())).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, _) = send(&router, "POST", &format!("{uri}/undo"), None).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    let (_, body) = send(&router, "GET", &uri, None).await;
+    assert_eq!(body["name"], "Patched");
+}
+
+#[tokio::test]
+async fn test_invalid_commands_are_not_recorded() {
+    let state = AppState::mock();
+    let task = TaskBuilder::new().with_name("Scheduled").build();
+    let task_id = task.id();
+    state.task_repository.create(task).await.unwrap();
+    let router = create_router(state);
+    let uri = format!("/api/task/{task_id}");
+
+    let command = command_payload(SchedulableCommand::Rename("Renamed".into()));
+    let (status, _) = send(&router, "POST", &format!("{uri}/commands"), command).await;
+    assert_eq!(status, StatusCode::OK);
+
+    // The tag does not exist, so the task is not stored and only the rename stays in the history
+    let command = command_payload(SchedulableCommand::AddTag(Id::new()));
+    let (status, _) = send(&router, "POST", &format!("{uri}/commands"), command).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    let (status, body) = send(&router, "POST", &format!("{uri}/undo"), None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["message"], "Undid: Rename to Renamed");
+    let (_, body) = send(&router, "GET", &uri, None).await;
+    assert_eq!(body["name"], "Scheduled");
+    assert_eq!(body["tags"], json!([]));
+}
@@ -0,0 +1,169 @@
+mod common;
+
+use common::send;
+use axum::http::StatusCode;
+use project_tracker_backend::{app::AppState, routes::create_router};
+use project_tracker_core::{
+    HasId,
+    factories::{person_factory::basic_person, project_factory::basic_project, task_factory::basic_task},
+};
+use serde_json::json;
+
+#[tokio::test]
+async fn test_threaded_comments_on_project() {
+    let state = AppState::mock();
+    let person = basic_person();
+    let project = basic_project();
+    let project_id = project.id();
+    state.person_repository.create(person.clone()).await.unwrap();
+    state.project_repository.create(project).await.unwrap();
+    let router = create_router(state);
+    let uri = format!("/api/project/{project_id}/comments");
+
+    let payload = json!({ "author_id": person.id().to_string(), "body": "Started on the parser" }).to_string();
+    let (status, body) = send(&router, "POST", &uri, Some(payload)).await;
+    assert_eq!(status, StatusCode::OK);
+    let first_id = body["comment_id"].as_str().unwrap().to_string();
+
+    let payload = json!({
+        "author_id": person.id().to_string(),
+        "body": "Lists work now",
+        "parent_id": first_id,
+    })
+    .to_string();
+    let (status, _) = send(&router, "POST", &uri, Some(payload)).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, body) = send(&router, "GET", &uri, None).await;
+    assert_eq!(status, StatusCode::OK);
+    let threads = body.as_array().unwrap();
+    assert_eq!(threads.len(), 1);
+    assert_eq!(threads[0]["id"], first_id);
+    assert_eq!(threads[0]["author_id"], person.id().to_string());
+    assert_eq!(threads[0]["item"], json!({ "Project": project_id.to_string() }));
+    assert_eq!(threads[0]["replies"][0]["body"], "Lists work now");
+    assert_eq!(threads[0]["replies"][0]["parent_id"], first_id);
+}
+
+#[tokio::test]
+async fn test_editing_comment_keeps_history() {
+    let state = AppState::mock();
+    let person = basic_person();
+    let task = basic_task();
+    let task_id = task.id();
+    state.person_repository.create(person.clone()).await.unwrap();
+    state.task_repository.create(task).await.unwrap();
+    let router = create_router(state);
+
+    let payload = json!({ "author_id": person.id().to_string(), "body": "Frist" }).to_string();
+    let (_, body) = send(&router, "POST", &format!("/api/task/{task_id}/comments"), Some(payload)).await;
+    let comment_id = body["comment_id"].as_str().unwrap().to_string();
+
+    let payload = json!({ "body": "First" }).to_string();
+    let (status, body) = send(&router, "PATCH", &format!("/api/comment/{comment_id}"), Some(payload.clone())).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["edits"], 1);
+
+    let (status, _) = send(&router, "PATCH", &format!("/api/comment/{comment_id}"), Some(payload)).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let (status, body) = send(&router, "GET", &format!("/api/comment/{comment_id}"), None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["body"], "First");
+    assert_eq!(body["edits"][0]["body"], "Frist");
+    assert_ne!(body["updated_at"], body["created_at"]);
+}
+
+#[tokio::test]
+async fn test_deleting_comment_removes_its_replies() {
+    let state = AppState::mock();
+    let person = basic_person();
+    let task = basic_task();
+    let task_id = task.id();
+    state.person_repository.create(person.clone()).await.unwrap();
+    state.task_repository.create(task).await.unwrap();
+    let router = create_router(state.clone());
+    let uri = format!("/api/task/{task_id}/comments");
+
+    let post = |body: &str, parent_id: Option<&str>| {
+        json!({ "author_id": person.id().to_string(), "body": body, "parent_id": parent_id }).to_string()
+    };
+    let (_, body) = send(&router, "POST", &uri, Some(post("Question", None))).await;
+    let question = body["comment_id"].as_str().unwrap().to_string();
+    let (_, body) = send(&router, "POST", &uri, Some(post("Answer", Some(&question)))).await;
+    let answer = body["comment_id"].as_str().unwrap().to_string();
+    send(&router, "POST", &uri, Some(post("Follow-up", Some(&answer)))).await;
+    send(&router, "POST", &uri, Some(post("Unrelated", None))).await;
+
+    let (status, body) = send(&router, "DELETE", &format!("/api/comment/{answer}"), None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["deleted_replies"].as_array().unwrap().len(), 1);
+
+    let (_, body) = send(&router, "GET", &uri, None).await;
+    let threads = body.as_array().unwrap();
+    assert_eq!(threads.len(), 2);
+    assert!(threads[0]["replies"].as_array().unwrap().is_empty());
+    assert_eq!(state.comment_repository.get_all().await.unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn test_invalid_comments_are_rejected() {
+    let state = AppState::mock();
+    let person = basic_person();
+    let task = basic_task();
+    let other = basic_task();
+    state.person_repository.create(person.clone()).await.unwrap();
+    state.task_repository.create(task.clone()).await.unwrap();
+    state.task_repository.create(other.clone()).await.unwrap();
+    let router = create_router(state);
+
+    let payload = json!({ "author_id": person.id().to_string(), "body": "  " }).to_string();
+    let (status, _) = send(&router, "POST", &format!("/api/task/{}/comments", task.id()), Some(payload)).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let payload = json!({ "author_id": basic_person().id().to_string(), "body": "Who am I?" }).to_string();
+    let (status, _) = send(&router, "POST", &format!("/api/task/{}/comments", task.id()), Some(payload)).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let payload = json!({ "author_id": person.id().to_string(), "body": "Here" }).to_string();
+    let (_, body) = send(&router, "POST", &format!("/api/task/{}/comments", task.id()), Some(payload)).await;
+    let comment_id = body["comment_id"].as_str().unwrap();
+    let payload = json!({ "author_id": person.id().to_string(), "body": "There", "parent_id": comment_id }).to_string();
+    let (status, body) = send(&router, "POST", &format!("/api/task/{}/comments", other.id()), Some(payload)).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["error"], format!("Comment {comment_id} is not on {}", other.id()));
+
+    let (status, _) = send(&router, "GET", &format!("/api/project/{}/comments", task.id()), None).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_deleting_an_item_deletes_its_comments() {
+    let state = AppState::mock();
+    let person = basic_person();
+    let task = basic_task();
+    let project = basic_project();
+    state.person_repository.create(person.clone()).await.unwrap();
+    state.task_repository.create(task.clone()).await.unwrap();
+    state.project_repository.create(project.clone()).await.unwrap();
+    let router = create_router(state.clone());
+
+    let payload = json!({ "author_id": person.id().to_string(), "body": "On the task" }).to_string();
+    let (_, body) = send(&router, "POST", &format!("/api/task/{}/comments", task.id()), Some(payload)).await;
+    let comment_id = body["comment_id"].as_str().unwrap();
+    let payload = json!({ "author_id": person.id().to_string(), "body": "Reply", "parent_id": comment_id }).to_string();
+    send(&router, "POST", &format!("/api/task/{}/comments", task.id()), Some(payload)).await;
+    let payload = json!({ "author_id": person.id().to_string(), "body": "On the project" }).to_string();
+    send(&router, "POST", &format!("/api/project/{}/comments", project.id()), Some(payload)).await;
+    assert_eq!(state.comment_repository.get_all().await.unwrap().len(), 3);
+
+    let (status, _) = send(&router, "DELETE", &format!("/api/task/{}", task.id()), None).await;
+    assert_eq!(status, StatusCode::OK);
+    let remaining = state.comment_repository.get_all().await.unwrap();
+    assert_eq!(remaining.len(), 1);
+    assert_eq!(remaining[0].body(), "On the project");
+
+    let (status, _) = send(&router, "DELETE", &format!("/api/project/{}", project.id()), None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(state.comment_repository.get_all().await.unwrap().is_empty());
+}
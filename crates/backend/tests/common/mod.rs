@@ -0,0 +1,39 @@
+use axum::{
+    Router,
+    body::{Body, to_bytes},
+    http::{Request, StatusCode},
+};
+use serde_json::Value;
+use tower::ServiceExt;
+
+/// Sends a JSON request through the router and returns the status with the decoded body
+pub async fn send(router: &Router, method: &str, uri: &str, body: Option<String>) -> (StatusCode, Value) {
+    let request = Request::builder()
+        .method(method)
+        .uri(uri)
+        .header("content-type", "application/json")
+        .body(body.map(Body::from).unwrap_or_else(Body::empty))
+        .unwrap();
+    dispatch(router, request).await
+}
+
+/// Same as `send`, with the request acting as `actor` through the `X-Actor-Id` header
+#[allow(dead_code)]
+pub async fn send_as(router: &Router, actor: &str, method: &str, uri: &str, body: Option<String>) -> (StatusCode, Value) {
+    let request = Request::builder()
+        .method(method)
+        .uri(uri)
+        .header("content-type", "application/json")
+        .header("x-actor-id", actor)
+        .body(body.map(Body::from).unwrap_or_else(Body::empty))
+        .unwrap();
+    dispatch(router, request).await
+}
+
+async fn dispatch(router: &Router, request: Request<Body>) -> (StatusCode, Value) {
+    let response = router.clone().oneshot(request).await.unwrap();
+    let status = response.status();
+    let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let value = serde_json::from_slice(&bytes).unwrap_or(Value::Null);
+    (status, value)
+}
@@ -0,0 +1,81 @@
+mod common;
+
+use common::send;
+use axum::http::StatusCode;
+use chrono::{Duration, Utc};
+use project_tracker_backend::{app::AppState, routes::create_router};
+use project_tracker_core::{
+    HasId,
+    builders::{project_builder::ProjectBuilder, task_builder::TaskBuilder, time_entry_builder::TimeEntryBuilder},
+    models::{estimate::Estimate, schedulable::SchedulableItem},
+};
+use serde_json::json;
+
+#[tokio::test]
+async fn test_estimate_round_trips_through_api() {
+    let state = AppState::mock();
+    let task = TaskBuilder::new().with_name("Sized").build();
+    let task_id = task.id();
+    state.task_repository.create(task).await.unwrap();
+    let router = create_router(state.clone());
+
+    let payload = json!({ "estimate": "5pt" }).to_string();
+    let (status, _) = send(&router, "PATCH", &format!("/api/task/{task_id}"), Some(payload)).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (_, body) = send(&router, "GET", &format!("/api/task/{task_id}"), None).await;
+    assert_eq!(body["estimate"], "5pt");
+
+    let payload = json!({ "estimate": "a while" }).to_string();
+    let (status, _) = send(&router, "PATCH", &format!("/api/task/{task_id}"), Some(payload)).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let payload = json!({ "estimate": null }).to_string();
+    let (status, _) = send(&router, "PATCH", &format!("/api/task/{task_id}"), Some(payload)).await;
+    assert_eq!(status, StatusCode::OK);
+    let stored = state.task_repository.get_by_id(task_id).await.unwrap().unwrap();
+    assert_eq!(stored.estimate(), None);
+}
+
+#[tokio::test]
+async fn test_project_estimate_report_rolls_up_and_compares_logged_time() {
+    let state = AppState::mock();
+    let task_a = TaskBuilder::new().with_estimate(Some(Estimate::Hours(2))).build();
+    let task_b = TaskBuilder::new().with_estimate(Some(Estimate::Hours(3))).build();
+    let sub = ProjectBuilder::new()
+        .with_children(vec![SchedulableItem::Task(task_a.id()), SchedulableItem::Task(task_b.id())])
+        .build();
+    let project = ProjectBuilder::new()
+        .with_name("Roll up")
+        .with_estimate(Some(Estimate::Hours(1)))
+        .with_children(vec![SchedulableItem::Project(sub.id())])
+        .build();
+    state.task_repository.create(task_a.clone()).await.unwrap();
+    state.task_repository.create(task_b).await.unwrap();
+    state.project_repository.create(sub).await.unwrap();
+    state.project_repository.create(project.clone()).await.unwrap();
+
+    let start = Utc::now() - Duration::hours(8);
+    let entry = TimeEntryBuilder::new()
+        .with_item(SchedulableItem::Task(task_a.id()))
+        .with_start(start)
+        .with_end(Some(start + Duration::hours(6)))
+        .build();
+    state.time_entry_repository.create(entry).await.unwrap();
+    let router = create_router(state.clone());
+
+    let (status, body) = send(&router, "GET", &format!("/api/project/{}/estimate", project.id()), None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["estimated_hours"], 5);
+    assert_eq!(body["logged_seconds"], 6 * 3600);
+    assert_eq!(body["variance_seconds"], 3600);
+    assert_eq!(body["over_estimate"], true);
+
+    let (status, body) = send(&router, "GET", &format!("/api/task/{}/estimate", task_a.id()), None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["estimated_hours"], 2);
+    assert_eq!(body["variance_seconds"], 4 * 3600);
+
+    let (status, _) = send(&router, "GET", &format!("/api/task/{}/estimate", TaskBuilder::new().build().id()), None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
@@ -0,0 +1,143 @@
+mod common;
+
+use common::send;
+use axum::http::StatusCode;
+use chrono::{Duration, Utc};
+use project_tracker_backend::{app::AppState, dto::milestone_dto::MilestoneDTO, routes::create_router};
+use project_tracker_core::{
+    HasId,
+    builders::{milestone_builder::MilestoneBuilder, project_builder::ProjectBuilder, task_builder::TaskBuilder},
+    id::Id,
+    models::{
+        milestone::Milestone,
+        schedulable::{SchedulableItem, SchedulableItemStatus},
+    },
+};
+use serde_json::json;
+
+fn milestone_payload(milestone: Milestone) -> String {
+    serde_json::to_string(&MilestoneDTO::from(milestone)).unwrap()
+}
+
+#[tokio::test]
+async fn test_post_then_get_milestone_reports_open_items() {
+    let state = AppState::mock();
+    let open = TaskBuilder::new().with_name("Open").build();
+    let done = ProjectBuilder::new().with_name("Done").with_status(SchedulableItemStatus::Completed).build();
+    let open_id = open.id();
+    let milestone = MilestoneBuilder::new()
+        .with_name("Beta")
+        .with_target_date(Utc::now() + Duration::days(7))
+        .with_items(vec![SchedulableItem::Task(open.id()), SchedulableItem::Project(done.id())])
+        .build();
+    let milestone_id = milestone.id().to_string();
+    state.task_repository.create(open).await.unwrap();
+    state.project_repository.create(done).await.unwrap();
+    let router = create_router(state);
+
+    let (status, body) = send(&router, "POST", "/api/milestones", Some(milestone_payload(milestone))).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["milestone_id"], milestone_id);
+
+    let (status, body) = send(&router, "GET", &format!("/api/milestone/{milestone_id}"), None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["name"], "Beta");
+    assert_eq!(body["state"], "Pending");
+    assert_eq!(body["items"].as_array().unwrap().len(), 2);
+    assert_eq!(body["open_items"], json!([{ "Task": open_id.to_string() }]));
+
+    let (status, body) = send(&router, "GET", "/api/milestones", None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body.as_array().unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_missed_milestone_state() {
+    let state = AppState::mock();
+    let milestone = MilestoneBuilder::new()
+        .with_name("Late")
+        .with_target_date(Utc::now() - Duration::days(1))
+        .build();
+    let milestone_id = milestone.id();
+    state.milestone_repository.create(milestone).await.unwrap();
+    let router = create_router(state);
+
+    let (_, body) = send(&router, "GET", &format!("/api/milestone/{milestone_id}"), None).await;
+    assert_eq!(body["state"], "Missed");
+}
+
+#[tokio::test]
+async fn test_milestone_validation() {
+    let state = AppState::mock();
+    let open = TaskBuilder::new().with_name("Open").build();
+    state.task_repository.create(open.clone()).await.unwrap();
+    let router = create_router(state);
+
+    let unknown = MilestoneBuilder::new()
+        .with_name("Unknown items")
+        .with_items(vec![SchedulableItem::Task(TaskBuilder::new().build().id())])
+        .build();
+    let (status, _) = send(&router, "POST", "/api/milestones", Some(milestone_payload(unknown))).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let reached_early = MilestoneBuilder::new()
+        .with_name("Reached early")
+        .with_items(vec![SchedulableItem::Task(open.id())])
+        .with_reached_at(Some(Utc::now()))
+        .build();
+    let (status, _) = send(&router, "POST", "/api/milestones", Some(milestone_payload(reached_early))).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_put_and_delete_milestone() {
+    let state = AppState::mock();
+    let milestone = MilestoneBuilder::new()
+        .with_name("Release")
+        .with_target_date(Utc::now() + Duration::days(3))
+        .build();
+    let milestone_id = milestone.id();
+    state.milestone_repository.create(milestone.clone()).await.unwrap();
+    let router = create_router(state);
+
+    let mut reached = milestone;
+    reached.mark_reached(Utc::now());
+    let (status, _) = send(&router, "PUT", &format!("/api/milestone/{milestone_id}"), Some(milestone_payload(reached))).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (_, body) = send(&router, "GET", &format!("/api/milestone/{milestone_id}"), None).await;
+    assert_eq!(body["state"], "Reached");
+
+    let (status, _) = send(&router, "DELETE", &format!("/api/milestone/{milestone_id}"), None).await;
+    assert_eq!(status, StatusCode::OK);
+    let (status, _) = send(&router, "GET", &format!("/api/milestone/{milestone_id}"), None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let missing = Id::<Milestone>::new();
+    let (status, _) = send(&router, "DELETE", &format!("/api/milestone/{missing}"), None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_deleting_an_item_unlinks_it_from_milestones() {
+    let state = AppState::mock();
+    let removed = TaskBuilder::new().with_name("Removed").build();
+    let kept = TaskBuilder::new().with_name("Kept").build();
+    let milestone = MilestoneBuilder::new()
+        .with_name("Beta")
+        .with_target_date(Utc::now() + Duration::days(7))
+        .with_items(vec![SchedulableItem::Task(removed.id()), SchedulableItem::Task(kept.id())])
+        .build();
+    let milestone_id = milestone.id();
+    state.task_repository.create(removed.clone()).await.unwrap();
+    state.task_repository.create(kept.clone()).await.unwrap();
+    state.milestone_repository.create(milestone).await.unwrap();
+    let router = create_router(state);
+
+    let (status, _) = send(&router, "DELETE", &format!("/api/task/{}", removed.id()), None).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (_, body) = send(&router, "GET", &format!("/api/milestone/{milestone_id}"), None).await;
+    assert_eq!(body["items"], json!([{ "Task": kept.id().to_string() }]));
+    assert_eq!(body["open_items"], json!([{ "Task": kept.id().to_string() }]));
+}
@@ -0,0 +1,130 @@
+mod common;
+
+use common::send;
+use axum::{Router, http::StatusCode};
+use project_tracker_backend::{
+    app::AppState,
+    db::person_repository::ProdPersonRepository,
+    dto::person_dto::PersonDTO,
+    routes::create_router,
+};
+use project_tracker_core::{
+    HasId,
+    builders::{person_builder::PersonBuilder, project_builder::ProjectBuilder, task_builder::TaskBuilder},
+    models::person::Person,
+};
+use project_tracker_db::database::Database;
+use std::sync::Arc;
+
+fn person_payload(person: Person) -> String {
+    serde_json::to_string(&PersonDTO::from(person)).unwrap()
+}
+
+fn setup_router(state: AppState) -> Router {
+    create_router(state)
+}
+
+#[tokio::test]
+async fn test_person_crud() {
+    let router = setup_router(AppState::mock());
+    let person = PersonBuilder::new().with_first_name("Ada").with_last_name("Lovelace").build();
+    let person_id = person.id();
+
+    let (status, body) = send(&router, "POST", "/api/people", Some(person_payload(person))).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["person_id"], person_id.to_string());
+
+    let (status, body) = send(&router, "GET", &format!("/api/person/{person_id}"), None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["first_name"], "Ada");
+
+    let replacement = PersonBuilder::new()
+        .with_id(person_id.clone())
+        .with_first_name("Augusta Ada")
+        .with_last_name("King")
+        .build();
+    let (status, _) = send(&router, "PUT", &format!("/api/person/{person_id}"), Some(person_payload(replacement))).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (_, body) = send(&router, "GET", "/api/people", None).await;
+    assert_eq!(body[0]["last_name"], "King");
+
+    let (status, _) = send(&router, "DELETE", &format!("/api/person/{person_id}"), None).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, _) = send(&router, "GET", &format!("/api/person/{person_id}"), None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_post_nameless_person_is_rejected() {
+    let router = setup_router(AppState::mock());
+    let nameless = PersonBuilder::new().build();
+
+    let (status, _) = send(&router, "POST", "/api/people", Some(person_payload(nameless))).await;
+    assert_ne!(status, StatusCode::OK);
+
+    let (_, body) = send(&router, "GET", "/api/people", None).await;
+    assert!(body.as_array().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_reads_expand_owner_names() {
+    let state = AppState::mock();
+    let owner = PersonBuilder::new().with_first_name("Grace").with_last_name("Hopper").build();
+    state.person_repository.create(owner.clone()).await.unwrap();
+
+    let project = ProjectBuilder::new().with_name("Owned").with_owner_id(Some(owner.id())).build();
+    let project_id = project.id();
+    state.project_repository.create(project).await.unwrap();
+
+    // Deleting a person leaves the tasks they owned pointing at them
+    let former = PersonBuilder::new().with_first_name("Former").with_last_name("Owner").build();
+    state.person_repository.create(former.clone()).await.unwrap();
+    let orphan = TaskBuilder::new().with_name("Orphan").with_owner_id(Some(former.id())).build();
+    let orphan_id = orphan.id();
+    state.task_repository.create(orphan).await.unwrap();
+    state.person_repository.delete(former.id()).await.unwrap();
+
+    let router = setup_router(state);
+
+    let (_, body) = send(&router, "GET", &format!("/api/project/{project_id}"), None).await;
+    assert_eq!(body["owner_id"], owner.id().to_string());
+    assert!(body.get("owner").is_none());
+
+    let (_, body) = send(&router, "GET", &format!("/api/project/{project_id}?expand=owner"), None).await;
+    assert_eq!(body["owner_id"], owner.id().to_string());
+    assert_eq!(body["owner"]["first_name"], "Grace");
+    assert_eq!(body["owner"]["last_name"], "Hopper");
+
+    let (_, body) = send(&router, "GET", &format!("/api/project?id={project_id}&expand=owner"), None).await;
+    assert_eq!(body["owner"]["first_name"], "Grace");
+
+    let (_, body) = send(&router, "GET", "/api/projects?expand=owner", None).await;
+    assert_eq!(body[0]["owner"]["last_name"], "Hopper");
+
+    let (status, body) = send(&router, "GET", &format!("/api/task/{orphan_id}?expand=owner"), None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(body.get("owner").is_none());
+}
+
+#[tokio::test]
+async fn test_person_routes_with_database_repository() {
+    let db = Arc::new(Database::connect().await.expect("Failed to connect to test database"));
+    let mut state = AppState::mock();
+    state.person_repository = Arc::new(ProdPersonRepository::new(db));
+    let router = setup_router(state);
+
+    let person = PersonBuilder::new().with_first_name("Alan").with_last_name("Turing").build();
+    let person_id = person.id();
+
+    let (status, _) = send(&router, "POST", "/api/people", Some(person_payload(person))).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, body) = send(&router, "GET", &format!("/api/person/{person_id}"), None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["last_name"], "Turing");
+
+    let (status, _) = send(&router, "DELETE", &format!("/api/person/{person_id}"), None).await;
+    assert_eq!(status, StatusCode::OK);
+}
@@ -0,0 +1,165 @@
+mod common;
+
+use common::send;
+use axum::http::StatusCode;
+use chrono::{Duration, Utc};
+use project_tracker_backend::{
+    app::AppState,
+    dto::{project_dto::ProjectDTO, task_dto::TaskDTO},
+    routes::create_router,
+};
+use project_tracker_core::{
+    HasId,
+    builders::{project_builder::ProjectBuilder, task_builder::TaskBuilder},
+    models::schedulable::{Schedulable, SchedulableItem, SchedulableItemStatus},
+};
+use serde_json::json;
+
+#[tokio::test]
+async fn test_project_progress_rolls_up_child_projects() {
+    let state = AppState::mock();
+    let done = TaskBuilder::new().with_name("Done").with_status(SchedulableItemStatus::Completed).build();
+    let open = TaskBuilder::new().with_name("Open").build();
+    let nested_done = TaskBuilder::new().with_name("Nested").with_status(SchedulableItemStatus::Completed).build();
+    let dropped = TaskBuilder::new().with_name("Dropped").with_status(SchedulableItemStatus::Canceled).build();
+    let sub = ProjectBuilder::new()
+        .with_name("Sub")
+        .with_children(vec![SchedulableItem::Task(nested_done.id()), SchedulableItem::Task(dropped.id())])
+        .build();
+    let root = ProjectBuilder::new()
+        .with_name("Root")
+        .with_children(vec![
+            SchedulableItem::Task(done.id()),
+            SchedulableItem::Task(open.id()),
+            SchedulableItem::Project(sub.id()),
+        ])
+        .build();
+    let root_id = root.id();
+    let open_id = open.id();
+    for task in [done, open, nested_done, dropped] {
+        state.task_repository.create(task).await.unwrap();
+    }
+    state.project_repository.create(sub.clone()).await.unwrap();
+    state.project_repository.create(root).await.unwrap();
+    let router = create_router(state);
+
+    let (status, body) = send(&router, "GET", &format!("/api/project/{root_id}/progress"), None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["finished"], 2);
+    assert_eq!(body["total"], 3);
+    assert_eq!(body["percent"], 66);
+    assert_eq!(body["open_children"], json!([open_id.to_string(), sub.id().to_string()]));
+}
+
+#[tokio::test]
+async fn test_completing_project_with_open_children_is_rejected() {
+    let state = AppState::mock();
+    let open = TaskBuilder::new().with_name("Still open").with_status(SchedulableItemStatus::InProgress).build();
+    let canceled = TaskBuilder::new().with_name("Dropped").with_status(SchedulableItemStatus::Canceled).build();
+    let project = ProjectBuilder::new()
+        .with_name("Finished early")
+        .with_start_date(Some(Utc::now() - Duration::days(10)))
+        .with_due_date(Some(Utc::now() - Duration::days(1)))
+        .with_status(SchedulableItemStatus::Completed)
+        .with_children(vec![SchedulableItem::Task(open.id()), SchedulableItem::Task(canceled.id())])
+        .build();
+    let mut open = open;
+    state.task_repository.create(open.clone()).await.unwrap();
+    state.task_repository.create(canceled).await.unwrap();
+    let router = create_router(state.clone());
+
+    let payload = serde_json::to_string(&ProjectDTO::from(project.clone())).unwrap();
+    let (status, _) = send(&router, "POST", "/api/project", Some(payload.clone())).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    open.promote();
+    open.promote();
+    state.task_repository.update(open).await.unwrap();
+
+    let (status, _) = send(&router, "POST", "/api/project", Some(payload)).await;
+    assert_eq!(status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_completing_task_with_open_subtasks_is_rejected() {
+    let state = AppState::mock();
+    let subtask = TaskBuilder::new().with_name("Subtask").build();
+    let task = TaskBuilder::new()
+        .with_name("Parent")
+        .with_start_date(Some(Utc::now() - Duration::days(3)))
+        .with_due_date(Some(Utc::now() - Duration::days(1)))
+        .with_status(SchedulableItemStatus::Completed)
+        .with_children(vec![subtask.id()])
+        .build();
+    state.task_repository.create(subtask).await.unwrap();
+    let router = create_router(state);
+
+    let payload = serde_json::to_string(&TaskDTO::from(task)).unwrap();
+    let (status, _) = send(&router, "POST", "/api/tasks", Some(payload)).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_starting_task_can_start_parent_projects() {
+    let state = AppState::mock();
+    let task = TaskBuilder::new()
+        .with_name("Kickoff")
+        .with_start_date(Some(Utc::now() - Duration::hours(1)))
+        .build();
+    let parent = ProjectBuilder::new()
+        .with_name("Parent")
+        .with_children(vec![SchedulableItem::Task(task.id())])
+        .build();
+    let grandparent = ProjectBuilder::new()
+        .with_name("Grandparent")
+        .with_children(vec![SchedulableItem::Project(parent.id())])
+        .build();
+    let task_id = task.id();
+    state.task_repository.create(task).await.unwrap();
+    state.project_repository.create(parent.clone()).await.unwrap();
+    state.project_repository.create(grandparent.clone()).await.unwrap();
+    let router = create_router(state.clone());
+
+    let payload = json!({ "action": "start", "start_parents": true }).to_string();
+    let (status, body) = send(&router, "POST", &format!("/api/task/{task_id}/transitions"), Some(payload)).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["to"], "InProgress");
+    assert_eq!(
+        body["started_parents"],
+        json!([parent.id().to_string(), grandparent.id().to_string()])
+    );
+
+    for project_id in [parent.id(), grandparent.id()] {
+        let stored = state.project_repository.get_by_id(project_id.clone()).await.unwrap().unwrap();
+        assert_eq!(stored.status(), SchedulableItemStatus::InProgress);
+        assert!(stored.start_date().is_some());
+
+        let (_, history) = send(&router, "GET", &format!("/api/project/{project_id}/transitions"), None).await;
+        assert_eq!(history[0]["from"], "NotStarted");
+    }
+}
+
+#[tokio::test]
+async fn test_parents_stay_put_without_start_parents() {
+    let state = AppState::mock();
+    let task = TaskBuilder::new()
+        .with_name("Quiet start")
+        .with_start_date(Some(Utc::now() - Duration::hours(1)))
+        .build();
+    let parent = ProjectBuilder::new()
+        .with_name("Parent")
+        .with_children(vec![SchedulableItem::Task(task.id())])
+        .build();
+    let task_id = task.id();
+    state.task_repository.create(task).await.unwrap();
+    state.project_repository.create(parent.clone()).await.unwrap();
+    let router = create_router(state.clone());
+
+    let payload = json!({ "action": "start" }).to_string();
+    let (status, body) = send(&router, "POST", &format!("/api/task/{task_id}/transitions"), Some(payload)).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["started_parents"], json!([]));
+
+    let stored = state.project_repository.get_by_id(parent.id()).await.unwrap().unwrap();
+    assert_eq!(stored.status(), SchedulableItemStatus::NotStarted);
+}
@@ -0,0 +1,284 @@
+mod common;
+
+use common::send;
+use axum::{Router, http::StatusCode};
+use chrono::{Duration, Utc};
+use project_tracker_backend::{
+    app::AppState,
+    db::project_repository::{MockProjectRepository, ProdProjectRepository, ProjectRepository},
+    dto::project_dto::ProjectDTO,
+    routes::create_router,
+};
+use project_tracker_core::{
+    HasId,
+    builders::{project_builder::ProjectBuilder, tag_builder::TagBuilder},
+    id::Id,
+    models::{project::Project, schedulable::SchedulableItemStatus},
+};
+use project_tracker_db::database::Database;
+use serde_json::json;
+use std::sync::Arc;
+
+fn setup_router(repository: Arc<dyn ProjectRepository>) -> Router {
+    let mut state = AppState::mock();
+    state.project_repository = repository;
+    create_router(state)
+}
+
+fn project_payload(project: Project) -> String {
+    serde_json::to_string(&ProjectDTO::from(project)).unwrap()
+}
+
+#[tokio::test]
+async fn test_post_then_get_project() {
+    let router = setup_router(Arc::new(MockProjectRepository::new()));
+    let project = ProjectBuilder::new().with_name("Persisted Project").build();
+    let project_id = project.id().to_string();
+
+    let (status, body) = send(&router, "POST", "/api/project", Some(project_payload(project))).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["project_id"], project_id);
+
+    let (status, body) = send(&router, "GET", &format!("/api/project/{project_id}"), None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["id"], project_id);
+    assert_eq!(body["name"], "Persisted Project");
+
+    let (status, body) = send(&router, "GET", &format!("/api/project?id={project_id}"), None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["id"], project_id);
+}
+
+#[tokio::test]
+async fn test_get_missing_project_returns_not_found() {
+    let router = setup_router(Arc::new(MockProjectRepository::new()));
+    let missing_id = Id::<Project>::new();
+
+    let (status, _) = send(&router, "GET", &format!("/api/project/{missing_id}"), None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_get_project_with_malformed_id() {
+    let router = setup_router(Arc::new(MockProjectRepository::new()));
+
+    let (status, _) = send(&router, "GET", "/api/project/not-an-id", None).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let (status, _) = send(&router, "GET", "/api/project/garbage", None).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_list_projects_returns_stored_projects() {
+    let router = setup_router(Arc::new(MockProjectRepository::new()));
+
+    let (status, body) = send(&router, "GET", "/api/projects", None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body.as_array().unwrap().len(), 0);
+
+    for name in ["Project 1", "Project 2"] {
+        let project = ProjectBuilder::new().with_name(name).build();
+        let (status, _) = send(&router, "POST", "/api/project", Some(project_payload(project))).await;
+        assert_eq!(status, StatusCode::OK);
+    }
+
+    let (status, body) = send(&router, "GET", "/api/projects", None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body.as_array().unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn test_invalid_project_is_not_persisted() {
+    let repository = Arc::new(MockProjectRepository::new());
+    let router = setup_router(repository.clone());
+    let project = ProjectBuilder::new()
+        .with_name("Broken Project")
+        .with_status(SchedulableItemStatus::Completed)
+        .build();
+    let project_id = project.id();
+
+    let (status, _) = send(&router, "POST", "/api/project", Some(project_payload(project))).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert!(repository.get_by_id(project_id).await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn test_routes_with_database_repository() {
+    let db = Arc::new(Database::connect().await.expect("Failed to connect to test database"));
+    let router = setup_router(Arc::new(ProdProjectRepository::new(db)));
+    let project = ProjectBuilder::new().with_name("Stored Project").build();
+    let project_id = project.id().to_string();
+
+    let (status, _) = send(&router, "POST", "/api/project", Some(project_payload(project))).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, body) = send(&router, "GET", &format!("/api/project/{project_id}"), None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["name"], "Stored Project");
+
+    let (status, body) = send(&router, "GET", "/api/projects", None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body.as_array().unwrap().len(), 1);
+    assert_eq!(body[0]["id"], project_id);
+}
+
+#[tokio::test]
+async fn test_put_replaces_project() {
+    let router = setup_router(Arc::new(MockProjectRepository::new()));
+    let project = ProjectBuilder::new().with_name("Original Name").build();
+    let project_id = project.id();
+
+    send(&router, "POST", "/api/project", Some(project_payload(project))).await;
+
+    let replacement = ProjectBuilder::new()
+        .with_id(project_id.clone())
+        .with_name("Replaced Name")
+        .with_description("Replaced description")
+        .build();
+    let (status, _) = send(&router, "PUT", &format!("/api/project/{project_id}"), Some(project_payload(replacement))).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (_, body) = send(&router, "GET", &format!("/api/project/{project_id}"), None).await;
+    assert_eq!(body["name"], "Replaced Name");
+    assert_eq!(body["description"], "Replaced description");
+}
+
+#[tokio::test]
+async fn test_put_rejects_mismatched_id() {
+    let router = setup_router(Arc::new(MockProjectRepository::new()));
+    let project = ProjectBuilder::new().with_name("Project").build();
+    let project_id = project.id();
+    send(&router, "POST", "/api/project", Some(project_payload(project))).await;
+
+    let other = ProjectBuilder::new().with_name("Other").build();
+    let (status, _) = send(&router, "PUT", &format!("/api/project/{project_id}"), Some(project_payload(other))).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_put_missing_project_returns_not_found() {
+    let router = setup_router(Arc::new(MockProjectRepository::new()));
+    let project = ProjectBuilder::new().with_name("Never Stored").build();
+    let project_id = project.id();
+
+    let (status, _) = send(&router, "PUT", &format!("/api/project/{project_id}"), Some(project_payload(project))).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_patch_updates_only_provided_fields() {
+    let state = AppState::mock();
+    let tag = TagBuilder::new().with_name("patched").build();
+    state.tag_repository.create(tag.clone()).await.unwrap();
+    let router = create_router(state);
+    let project = ProjectBuilder::new()
+        .with_name("Patchable")
+        .with_description("Keep me")
+        .with_due_date(Some(Utc::now() + Duration::days(10)))
+        .build();
+    let project_id = project.id();
+    send(&router, "POST", "/api/project", Some(project_payload(project))).await;
+
+    let tag_id = tag.id().to_string();
+    let patch = json!({ "name": "Patched", "tags": [tag_id], "due_date": null }).to_string();
+    let (status, _) = send(&router, "PATCH", &format!("/api/project/{project_id}"), Some(patch)).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (_, body) = send(&router, "GET", &format!("/api/project/{project_id}"), None).await;
+    assert_eq!(body["name"], "Patched");
+    assert_eq!(body["description"], "Keep me");
+    assert_eq!(body["tags"][0], tag_id);
+    assert!(body["due_date"].is_null());
+}
+
+#[tokio::test]
+async fn test_patch_revalidates_merged_project() {
+    let router = setup_router(Arc::new(MockProjectRepository::new()));
+    let project = ProjectBuilder::new()
+        .with_name("Scheduled")
+        .with_start_date(Some(Utc::now() + Duration::days(5)))
+        .build();
+    let project_id = project.id();
+    send(&router, "POST", "/api/project", Some(project_payload(project))).await;
+
+    let due_date = (Utc::now() + Duration::days(1)).to_rfc3339();
+    let patch = json!({ "due_date": due_date }).to_string();
+    let (status, _) = send(&router, "PATCH", &format!("/api/project/{project_id}"), Some(patch)).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let (_, body) = send(&router, "GET", &format!("/api/project/{project_id}"), None).await;
+    assert!(body["due_date"].is_null());
+}
+
+#[tokio::test]
+async fn test_delete_project() {
+    let router = setup_router(Arc::new(MockProjectRepository::new()));
+    let project = ProjectBuilder::new().with_name("Disposable").build();
+    let project_id = project.id();
+    send(&router, "POST", "/api/project", Some(project_payload(project))).await;
+
+    let (status, _) = send(&router, "DELETE", &format!("/api/project/{project_id}"), None).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, _) = send(&router, "GET", &format!("/api/project/{project_id}"), None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let (status, _) = send(&router, "DELETE", &format!("/api/project/{project_id}"), None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_project_dependency_cycle_is_rejected() {
+    let router = setup_router(Arc::new(MockProjectRepository::new()));
+    let base = ProjectBuilder::new().with_name("Base").build();
+    let app = ProjectBuilder::new().with_name("App").with_dependencies(vec![base.id()]).build();
+    send(&router, "POST", "/api/project", Some(project_payload(base.clone()))).await;
+    send(&router, "POST", "/api/project", Some(project_payload(app.clone()))).await;
+
+    let looping = ProjectBuilder::new()
+        .with_id(base.id())
+        .with_name("Base")
+        .with_dependencies(vec![app.id()])
+        .build();
+    let (status, _) = send(&router, "PUT", &format!("/api/project/{}", base.id()), Some(project_payload(looping))).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_repeated_links_are_rejected() {
+    let repository = Arc::new(MockProjectRepository::new());
+    let router = setup_router(repository.clone());
+    let base = ProjectBuilder::new().with_name("Base").build();
+    send(&router, "POST", "/api/project", Some(project_payload(base.clone()))).await;
+
+    let repeating = ProjectBuilder::new()
+        .with_name("Repeating")
+        .with_dependencies(vec![base.id(), base.id()])
+        .build();
+    let (status, _) = send(&router, "POST", "/api/project", Some(project_payload(repeating.clone()))).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert!(repository.get_by_id(repeating.id()).await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn test_update_and_delete_with_database_repository() {
+    let db = Arc::new(Database::connect().await.expect("Failed to connect to test database"));
+    let router = setup_router(Arc::new(ProdProjectRepository::new(db)));
+    let project = ProjectBuilder::new().with_name("Stored Project").build();
+    let project_id = project.id();
+    send(&router, "POST", "/api/project", Some(project_payload(project))).await;
+
+    let patch = json!({ "name": "Renamed in database" }).to_string();
+    let (status, _) = send(&router, "PATCH", &format!("/api/project/{project_id}"), Some(patch)).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (_, body) = send(&router, "GET", &format!("/api/project/{project_id}"), None).await;
+    assert_eq!(body["name"], "Renamed in database");
+
+    let (status, _) = send(&router, "DELETE", &format!("/api/project/{project_id}"), None).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, _) = send(&router, "GET", &format!("/api/project/{project_id}"), None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
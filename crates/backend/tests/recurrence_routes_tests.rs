@@ -0,0 +1,119 @@
+mod common;
+
+use common::send;
+use axum::{Router, http::StatusCode};
+use chrono::{Duration, Utc};
+use project_tracker_backend::{app::AppState, routes::create_router};
+use project_tracker_core::{
+    HasId,
+    builders::task_builder::TaskBuilder,
+    models::{
+        recurrence::RecurrenceRule,
+        schedulable::{Schedulable, SchedulableItemStatus},
+        task::Task,
+    },
+};
+use serde_json::json;
+
+fn weekly_chore(rule: RecurrenceRule) -> Task {
+    TaskBuilder::new()
+        .with_name("Water the plants")
+        .with_start_date(Some(Utc::now() - Duration::days(2)))
+        .with_due_date(Some(Utc::now() + Duration::days(1)))
+        .with_status(SchedulableItemStatus::InReview)
+        .with_recurrence(Some(rule))
+        .build()
+}
+
+// Lets the due date pass, then completes the task through its workflow
+async fn complete(state: &AppState, router: &Router, task: &Task) {
+    let mut overdue = task.clone();
+    overdue.set_due_date(Utc::now() - Duration::hours(1));
+    state.task_repository.update(overdue).await.unwrap();
+
+    let payload = json!({ "action": "promote" }).to_string();
+    let (status, _) = send(router, "POST", &format!("/api/task/{}/transitions", task.id()), Some(payload)).await;
+    assert_eq!(status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_completing_recurring_task_spawns_next_occurrence() {
+    let state = AppState::mock();
+    let task = weekly_chore(RecurrenceRule::weekly());
+    let task_id = task.id();
+    state.task_repository.create(task.clone()).await.unwrap();
+    let router = create_router(state.clone());
+
+    complete(&state, &router, &task).await;
+
+    let (status, body) = send(&router, "GET", &format!("/api/task/{task_id}/series"), None).await;
+    assert_eq!(status, StatusCode::OK);
+    let series = body.as_array().unwrap();
+    assert_eq!(series.len(), 2);
+    assert_eq!(series[0]["id"], task_id.to_string());
+    assert_eq!(series[1]["series_id"], task_id.to_string());
+    assert_eq!(series[1]["occurrence"], 2);
+    assert_eq!(series[1]["status"], "NotStarted");
+    assert_eq!(series[1]["recurrence"], "FREQ=WEEKLY;INTERVAL=1");
+}
+
+#[tokio::test]
+async fn test_transition_to_completed_spawns_next_occurrence_once() {
+    let state = AppState::mock();
+    let task = TaskBuilder::new()
+        .with_name("Monthly review")
+        .with_start_date(Some(Utc::now() - Duration::days(3)))
+        .with_due_date(Some(Utc::now() - Duration::days(1)))
+        .with_status(SchedulableItemStatus::InReview)
+        .with_recurrence(Some(RecurrenceRule::monthly()))
+        .build();
+    let task_id = task.id();
+    state.task_repository.create(task).await.unwrap();
+    let router = create_router(state.clone());
+
+    let payload = json!({ "action": "promote" }).to_string();
+    let (status, _) = send(&router, "POST", &format!("/api/task/{task_id}/transitions"), Some(payload)).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let stored = state.task_repository.get_by_id(task_id.clone()).await.unwrap().unwrap();
+    assert_eq!(stored.status(), SchedulableItemStatus::Completed);
+
+    let payload = json!({ "description": "Done, again" }).to_string();
+    let (status, _) = send(&router, "PATCH", &format!("/api/task/{task_id}"), Some(payload)).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (_, body) = send(&router, "GET", &format!("/api/task/{task_id}/series"), None).await;
+    assert_eq!(body.as_array().unwrap().len(), 2);
+    let tasks = state.task_repository.get_all().await.unwrap();
+    assert_eq!(tasks.len(), 2);
+}
+
+#[tokio::test]
+async fn test_exhausted_rule_spawns_nothing() {
+    let state = AppState::mock();
+    let task = weekly_chore(RecurrenceRule::weekly().with_count(1));
+    state.task_repository.create(task.clone()).await.unwrap();
+    let router = create_router(state.clone());
+
+    complete(&state, &router, &task).await;
+
+    let tasks = state.task_repository.get_all().await.unwrap();
+    assert_eq!(tasks.len(), 1);
+}
+
+#[tokio::test]
+async fn test_invalid_recurrence_is_rejected() {
+    let state = AppState::mock();
+    let task = weekly_chore(RecurrenceRule::weekly());
+    let task_id = task.id();
+    state.task_repository.create(task).await.unwrap();
+    let router = create_router(state.clone());
+
+    let payload = json!({ "recurrence": "FREQ=HOURLY" }).to_string();
+    let (status, _) = send(&router, "PATCH", &format!("/api/task/{task_id}"), Some(payload)).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let payload = json!({ "start_date": null, "due_date": null }).to_string();
+    let (status, _) = send(&router, "PATCH", &format!("/api/task/{task_id}"), Some(payload)).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
@@ -0,0 +1,142 @@
+mod common;
+
+use common::send;
+use axum::http::StatusCode;
+use chrono::{Duration, Utc};
+use project_tracker_backend::{app::AppState, routes::create_router};
+use project_tracker_core::{
+    HasId,
+    builders::{project_builder::ProjectBuilder, task_builder::TaskBuilder},
+    id::Id,
+    models::{project::Project, schedulable::SchedulableItem},
+};
+
+#[tokio::test]
+async fn test_project_schedule_reports_critical_path() {
+    let state = AppState::mock();
+    let start = Utc::now() + Duration::days(1);
+
+    let design = TaskBuilder::new()
+        .with_name("Design")
+        .with_start_date(Some(start))
+        .with_due_date(Some(start + Duration::days(2)))
+        .build();
+    let build = TaskBuilder::new()
+        .with_name("Build")
+        .with_start_date(Some(start + Duration::days(2)))
+        .with_due_date(Some(start + Duration::days(6)))
+        .with_dependencies(vec![design.id()])
+        .build();
+    let docs = TaskBuilder::new()
+        .with_name("Docs")
+        .with_start_date(Some(start + Duration::days(2)))
+        .with_due_date(Some(start + Duration::days(3)))
+        .with_dependencies(vec![design.id()])
+        .build();
+    let rushed = TaskBuilder::new()
+        .with_name("Rushed")
+        .with_start_date(Some(start + Duration::days(3)))
+        .with_due_date(Some(start + Duration::days(4)))
+        .with_dependencies(vec![build.id()])
+        .build();
+    let project = ProjectBuilder::new()
+        .with_name("Scheduled")
+        .with_start_date(Some(start))
+        .with_children(
+            [&design, &build, &docs, &rushed]
+                .iter()
+                .map(|task| SchedulableItem::Task(task.id()))
+                .collect(),
+        )
+        .build();
+    let project_id = project.id();
+
+    for task in [design.clone(), build.clone(), docs.clone(), rushed.clone()] {
+        state.task_repository.create(task).await.unwrap();
+    }
+    state.project_repository.create(project).await.unwrap();
+    let router = create_router(state);
+
+    let (status, body) = send(&router, "GET", &format!("/api/project/{project_id}/schedule"), None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["project_id"], project_id.to_string());
+    assert_eq!(body["items"].as_array().unwrap().len(), 4);
+    assert_eq!(body["items"][0]["name"], "Design");
+    assert_eq!(
+        body["critical_path"],
+        serde_json::json!([design.id().to_string(), build.id().to_string(), rushed.id().to_string()])
+    );
+    assert_eq!(body["impossible_due_dates"], serde_json::json!([rushed.id().to_string()]));
+
+    let docs_item = body["items"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|item| item["name"] == "Docs")
+        .unwrap();
+    assert_eq!(docs_item["critical"], false);
+    assert_eq!(docs_item["slack_seconds"], Duration::days(4).num_seconds());
+}
+
+#[tokio::test]
+async fn test_schedule_of_missing_project_returns_not_found() {
+    let router = create_router(AppState::mock());
+    let missing_id = Id::<Project>::new();
+
+    let (status, _) = send(&router, "GET", &format!("/api/project/{missing_id}/schedule"), None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_project_schedule_includes_tasks_of_sub_projects() {
+    let state = AppState::mock();
+    let start = Utc::now() + Duration::days(1);
+
+    let design = TaskBuilder::new()
+        .with_name("Design")
+        .with_start_date(Some(start))
+        .with_due_date(Some(start + Duration::days(2)))
+        .build();
+    let build = TaskBuilder::new()
+        .with_name("Build")
+        .with_start_date(Some(start + Duration::days(2)))
+        .with_due_date(Some(start + Duration::days(5)))
+        .with_dependencies(vec![design.id()])
+        .build();
+    let release = TaskBuilder::new()
+        .with_name("Release")
+        .with_start_date(Some(start + Duration::days(5)))
+        .with_due_date(Some(start + Duration::days(6)))
+        .with_dependencies(vec![build.id()])
+        .build();
+    let nested = ProjectBuilder::new()
+        .with_name("Nested")
+        .with_children(vec![SchedulableItem::Task(build.id())])
+        .build();
+    let sub_project = ProjectBuilder::new()
+        .with_name("Sub-project")
+        .with_children(vec![SchedulableItem::Task(design.id()), SchedulableItem::Project(nested.id())])
+        .build();
+    let project = ProjectBuilder::new()
+        .with_name("Scheduled")
+        .with_start_date(Some(start))
+        .with_children(vec![SchedulableItem::Project(sub_project.id()), SchedulableItem::Task(release.id())])
+        .build();
+    let project_id = project.id();
+
+    for task in [design.clone(), build.clone(), release.clone()] {
+        state.task_repository.create(task).await.unwrap();
+    }
+    for project in [nested, sub_project, project] {
+        state.project_repository.create(project).await.unwrap();
+    }
+    let router = create_router(state);
+
+    let (status, body) = send(&router, "GET", &format!("/api/project/{project_id}/schedule"), None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["items"].as_array().unwrap().len(), 3);
+    assert_eq!(
+        body["critical_path"],
+        serde_json::json!([design.id().to_string(), build.id().to_string(), release.id().to_string()])
+    );
+}
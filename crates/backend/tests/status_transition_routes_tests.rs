@@ -0,0 +1,246 @@
+mod common;
+
+use std::sync::Arc;
+use async_trait::async_trait;
+use common::send;
+use axum::http::StatusCode;
+use chrono::{Duration, Utc};
+use project_tracker_backend::{
+    Error, Result,
+    app::AppState,
+    db::status_transition_repository::StatusTransitionRepository,
+    dto::{project_dto::ProjectDTO, task_dto::TaskDTO},
+    routes::create_router,
+};
+use project_tracker_core::{
+    HasId,
+    builders::{person_builder::PersonBuilder, project_builder::ProjectBuilder, task_builder::TaskBuilder},
+    id::Id,
+    models::{
+        person::Person,
+        schedulable::{Schedulable, SchedulableItem, SchedulableItemStatus},
+        status_transition::StatusTransition,
+    },
+};
+use project_tracker_db::DatabaseError;
+use serde_json::json;
+
+// Transition history that cannot be written to
+struct UnavailableTransitionRepository;
+
+#[async_trait]
+impl StatusTransitionRepository for UnavailableTransitionRepository {
+    async fn record(&self, _transition: StatusTransition) -> Result<()> {
+        Err(Error::DatabaseError(DatabaseError::QueryError("History unavailable".into())))
+    }
+
+    async fn record_all(&self, _transitions: Vec<StatusTransition>) -> Result<()> {
+        Err(Error::DatabaseError(DatabaseError::QueryError("History unavailable".into())))
+    }
+
+    async fn get_for_item(&self, _item: &SchedulableItem) -> Result<Vec<StatusTransition>> {
+        Ok(Vec::new())
+    }
+}
+
+#[tokio::test]
+async fn test_promote_project_records_history() {
+    let state = AppState::mock();
+    let person = PersonBuilder::new().with_first_name("Ada").build();
+    let project = ProjectBuilder::new()
+        .with_name("Review me")
+        .with_start_date(Some(Utc::now() - Duration::days(1)))
+        .with_due_date(Some(Utc::now() + Duration::days(5)))
+        .with_status(SchedulableItemStatus::InProgress)
+        .build();
+    let project_id = project.id();
+    state.person_repository.create(person.clone()).await.unwrap();
+    state.project_repository.create(project).await.unwrap();
+    let router = create_router(state.clone());
+
+    let payload = json!({ "action": "promote", "by": person.id().to_string() }).to_string();
+    let (status, body) = send(&router, "POST", &format!("/api/project/{project_id}/transitions"), Some(payload)).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["from"], "InProgress");
+    assert_eq!(body["to"], "InReview");
+
+    let stored = state.project_repository.get_by_id(project_id.clone()).await.unwrap().unwrap();
+    assert_eq!(stored.status(), SchedulableItemStatus::InReview);
+
+    let (status, body) = send(&router, "GET", &format!("/api/project/{project_id}/transitions"), None).await;
+    assert_eq!(status, StatusCode::OK);
+    let history = body.as_array().unwrap();
+    assert_eq!(history.len(), 1);
+    assert_eq!(history[0]["item"], project_id.to_string());
+    assert_eq!(history[0]["to"], "InReview");
+    assert_eq!(history[0]["by"], person.id().to_string());
+    assert!(history[0]["at"].is_string());
+}
+
+#[tokio::test]
+async fn test_illegal_transition_is_a_conflict() {
+    let state = AppState::mock();
+    let task = TaskBuilder::new()
+        .with_name("Old task")
+        .with_status(SchedulableItemStatus::Archived)
+        .build();
+    let task_id = task.id();
+    state.task_repository.create(task).await.unwrap();
+    let router = create_router(state.clone());
+
+    let payload = json!({ "action": "promote" }).to_string();
+    let (status, body) = send(&router, "POST", &format!("/api/task/{task_id}/transitions"), Some(payload)).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    assert_eq!(body["error"], "Cannot promote an item that is Archived");
+
+    let (_, body) = send(&router, "GET", &format!("/api/task/{task_id}/transitions"), None).await;
+    assert!(body.as_array().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_transition_leaving_inconsistent_data_is_rejected() {
+    let state = AppState::mock();
+    // Demoting to Planned requires a start date in the future
+    let task = TaskBuilder::new()
+        .with_name("Started")
+        .with_start_date(Some(Utc::now() - Duration::days(1)))
+        .with_status(SchedulableItemStatus::InProgress)
+        .build();
+    let task_id = task.id();
+    state.task_repository.create(task).await.unwrap();
+    let router = create_router(state.clone());
+
+    let payload = json!({ "action": "demote" }).to_string();
+    let (status, _) = send(&router, "POST", &format!("/api/task/{task_id}/transitions"), Some(payload)).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let stored = state.task_repository.get_by_id(task_id.clone()).await.unwrap().unwrap();
+    assert_eq!(stored.status(), SchedulableItemStatus::InProgress);
+    let (_, body) = send(&router, "GET", &format!("/api/task/{task_id}/transitions"), None).await;
+    assert!(body.as_array().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_transition_by_unknown_person_is_not_found() {
+    let state = AppState::mock();
+    let task = TaskBuilder::new().with_name("Cancel me").build();
+    let task_id = task.id();
+    state.task_repository.create(task).await.unwrap();
+    let router = create_router(state);
+
+    let payload = json!({ "action": "cancel", "by": Id::<Person>::new().to_string() }).to_string();
+    let (status, _) = send(&router, "POST", &format!("/api/task/{task_id}/transitions"), Some(payload)).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_status_is_not_written_by_put_or_patch() {
+    let state = AppState::mock();
+    let project = ProjectBuilder::new()
+        .with_name("Sneaky")
+        .with_start_date(Some(Utc::now() - Duration::days(2)))
+        .with_due_date(Some(Utc::now() + Duration::days(2)))
+        .with_status(SchedulableItemStatus::InReview)
+        .build();
+    let task = TaskBuilder::new()
+        .with_name("Sneaky task")
+        .with_start_date(Some(Utc::now() - Duration::days(2)))
+        .with_due_date(Some(Utc::now() + Duration::days(2)))
+        .with_status(SchedulableItemStatus::InReview)
+        .build();
+    let (project_id, task_id) = (project.id(), task.id());
+    state.project_repository.create(project.clone()).await.unwrap();
+    state.task_repository.create(task.clone()).await.unwrap();
+    let router = create_router(state.clone());
+
+    let mut payload = serde_json::to_value(ProjectDTO::from(project)).unwrap();
+    payload["status"] = json!("InProgress");
+    let (status, _) = send(&router, "PUT", &format!("/api/project/{project_id}"), Some(payload.to_string())).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    let payload = json!({ "status": "InProgress" }).to_string();
+    let (status, _) = send(&router, "PATCH", &format!("/api/project/{project_id}"), Some(payload)).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let mut payload = serde_json::to_value(TaskDTO::from(task)).unwrap();
+    payload["status"] = json!("InProgress");
+    let (status, _) = send(&router, "PUT", &format!("/api/task/{task_id}"), Some(payload.to_string())).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    let payload = json!({ "status": "InProgress" }).to_string();
+    let (status, _) = send(&router, "PATCH", &format!("/api/task/{task_id}"), Some(payload)).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    // Repeating the stored status is still fine
+    let payload = json!({ "status": "InReview", "description": "Same status" }).to_string();
+    let (status, _) = send(&router, "PATCH", &format!("/api/task/{task_id}"), Some(payload)).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let stored = state.project_repository.get_by_id(project_id.clone()).await.unwrap().unwrap();
+    assert_eq!(stored.status(), SchedulableItemStatus::InReview);
+    let stored = state.task_repository.get_by_id(task_id.clone()).await.unwrap().unwrap();
+    assert_eq!(stored.status(), SchedulableItemStatus::InReview);
+    let (_, body) = send(&router, "GET", &format!("/api/task/{task_id}/transitions"), None).await;
+    assert!(body.as_array().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_failed_transition_leaves_nothing_changed() {
+    let mut state = AppState::mock();
+    let task = TaskBuilder::new()
+        .with_name("Kickoff")
+        .with_start_date(Some(Utc::now() - Duration::hours(1)))
+        .build();
+    let parent = ProjectBuilder::new()
+        .with_name("Parent")
+        .with_start_date(Some(Utc::now() - Duration::hours(1)))
+        .with_children(vec![SchedulableItem::Task(task.id())])
+        .build();
+    let task_id = task.id();
+    state.task_repository.create(task).await.unwrap();
+    state.project_repository.create(parent.clone()).await.unwrap();
+    state.transition_repository = Arc::new(UnavailableTransitionRepository);
+    let router = create_router(state.clone());
+
+    let payload = json!({ "action": "start", "start_parents": true }).to_string();
+    let (status, _) = send(&router, "POST", &format!("/api/task/{task_id}/transitions"), Some(payload)).await;
+    assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+
+    let task = state.task_repository.get_by_id(task_id).await.unwrap().unwrap();
+    assert_eq!(task.status(), SchedulableItemStatus::NotStarted);
+    let parent = state.project_repository.get_by_id(parent.id()).await.unwrap().unwrap();
+    assert_eq!(parent.status(), SchedulableItemStatus::NotStarted);
+
+    let payload = json!({ "action": "start" }).to_string();
+    let (status, _) = send(&router, "POST", &format!("/api/project/{}/transitions", parent.id()), Some(payload)).await;
+    assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+    let parent = state.project_repository.get_by_id(parent.id()).await.unwrap().unwrap();
+    assert_eq!(parent.status(), SchedulableItemStatus::NotStarted);
+}
+
+#[tokio::test]
+async fn test_items_created_without_dates_can_be_started() {
+    let state = AppState::mock();
+    let router = create_router(state.clone());
+
+    let project = ProjectBuilder::new().with_name("Undated project").build();
+    let payload = serde_json::to_string(&ProjectDTO::from(project.clone())).unwrap();
+    let (status, _) = send(&router, "POST", "/api/project", Some(payload)).await;
+    assert_eq!(status, StatusCode::OK);
+    let task = TaskBuilder::new().with_name("Undated task").build();
+    let payload = serde_json::to_string(&TaskDTO::from(task.clone())).unwrap();
+    let (status, _) = send(&router, "POST", "/api/tasks", Some(payload)).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let payload = json!({ "action": "start" }).to_string();
+    let (status, body) = send(&router, "POST", &format!("/api/project/{}/transitions", project.id()), Some(payload)).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["to"], "InProgress");
+    let stored = state.project_repository.get_by_id(project.id()).await.unwrap().unwrap();
+    assert!(stored.start_date().is_some_and(|start_date| start_date <= Utc::now()));
+
+    let payload = json!({ "action": "start" }).to_string();
+    let (status, body) = send(&router, "POST", &format!("/api/task/{}/transitions", task.id()), Some(payload)).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["to"], "InProgress");
+    let stored = state.task_repository.get_by_id(task.id()).await.unwrap().unwrap();
+    assert!(stored.start_date().is_some());
+}
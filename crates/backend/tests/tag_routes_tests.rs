@@ -0,0 +1,185 @@
+mod common;
+
+use common::send;
+use axum::{Router, http::StatusCode};
+use project_tracker_backend::{
+    app::AppState,
+    db::tag_repository::{MockTagRepository, ProdTagRepository, TagRepository},
+    dto::tag_dto::TagDTO,
+    routes::create_router,
+};
+use project_tracker_core::{
+    HasId,
+    builders::{project_builder::ProjectBuilder, tag_builder::TagBuilder, task_builder::TaskBuilder},
+    id::Id,
+    models::tag::Tag,
+};
+use project_tracker_db::database::Database;
+use serde_json::json;
+use std::sync::Arc;
+
+fn setup_router(repository: Arc<dyn TagRepository>) -> Router {
+    let mut state = AppState::mock();
+    state.tag_repository = repository;
+    create_router(state)
+}
+
+fn tag_payload(tag: Tag) -> String {
+    serde_json::to_string(&TagDTO::from(tag)).unwrap()
+}
+
+#[tokio::test]
+async fn test_post_then_get_tag() {
+    let router = setup_router(Arc::new(MockTagRepository::new()));
+    let tag = TagBuilder::new().with_name("backend").build();
+    let tag_id = tag.id().to_string();
+
+    let (status, body) = send(&router, "POST", "/api/tags", Some(tag_payload(tag))).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["tag_id"], tag_id);
+
+    let (status, body) = send(&router, "GET", &format!("/api/tag/{tag_id}"), None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["name"], "backend");
+
+    let (status, body) = send(&router, "GET", "/api/tags?name=backend", None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body[0]["id"], tag_id);
+
+    let (status, body) = send(&router, "GET", "/api/tags?name=frontend", None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(body.as_array().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_post_tag_is_validated() {
+    let router = setup_router(Arc::new(MockTagRepository::new()));
+
+    let spaced = TagBuilder::new().with_name("two words").build();
+    let (status, _) = send(&router, "POST", "/api/tags", Some(tag_payload(spaced))).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let tag_id = Id::<Tag>::new();
+    let own_parent = TagBuilder::new()
+        .with_id(tag_id.clone())
+        .with_name("loop")
+        .with_parents(vec![tag_id])
+        .build();
+    let (status, _) = send(&router, "POST", "/api/tags", Some(tag_payload(own_parent))).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let first = TagBuilder::new().with_name("taken").build();
+    let clash = TagBuilder::new().with_name("taken").build();
+    send(&router, "POST", "/api/tags", Some(tag_payload(first))).await;
+    let (status, _) = send(&router, "POST", "/api/tags", Some(tag_payload(clash))).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_lookup_creates_missing_tags_once() {
+    let router = setup_router(Arc::new(MockTagRepository::new()));
+
+    let payload = json!({ "names": ["urgent", "later"] }).to_string();
+    let (status, first) = send(&router, "POST", "/api/tags/lookup", Some(payload.clone())).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(first.as_array().unwrap().len(), 2);
+
+    let (_, second) = send(&router, "POST", "/api/tags/lookup", Some(payload)).await;
+    assert_eq!(first, second);
+
+    let (_, body) = send(&router, "GET", "/api/tags", None).await;
+    assert_eq!(body.as_array().unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn test_tag_hierarchy_rejects_cycles() {
+    let router = setup_router(Arc::new(MockTagRepository::new()));
+    let hobbies = TagBuilder::new().with_name("hobbies").build();
+    let programming = TagBuilder::new().with_name("programming").with_parents(vec![hobbies.id()]).build();
+    let rust = TagBuilder::new().with_name("rust").with_parents(vec![programming.id()]).build();
+    for tag in [hobbies.clone(), programming.clone(), rust.clone()] {
+        let (status, _) = send(&router, "POST", "/api/tags", Some(tag_payload(tag))).await;
+        assert_eq!(status, StatusCode::OK);
+    }
+
+    let (_, body) = send(&router, "GET", &format!("/api/tag/{}/ancestors", rust.id()), None).await;
+    assert_eq!(body[0]["name"], "programming");
+    assert_eq!(body[1]["name"], "hobbies");
+
+    let (_, body) = send(&router, "GET", &format!("/api/tag/{}/descendants", hobbies.id()), None).await;
+    assert_eq!(body.as_array().unwrap().len(), 2);
+
+    let looping = TagBuilder::new()
+        .with_id(hobbies.id())
+        .with_name("hobbies")
+        .with_parents(vec![rust.id()])
+        .build();
+    let (status, _) = send(&router, "PUT", &format!("/api/tag/{}", hobbies.id()), Some(tag_payload(looping))).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let (_, body) = send(&router, "GET", &format!("/api/tag/{}/ancestors", hobbies.id()), None).await;
+    assert!(body.as_array().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_list_filters_by_tag_and_descendants() {
+    let state = AppState::mock();
+    let programming = TagBuilder::new().with_name("programming").build();
+    let rust = TagBuilder::new().with_name("rust").with_parents(vec![programming.id()]).build();
+    let cooking = TagBuilder::new().with_name("cooking").build();
+    for tag in [programming.clone(), rust.clone(), cooking.clone()] {
+        state.tag_repository.create(tag).await.unwrap();
+    }
+
+    let rust_project = ProjectBuilder::new().with_name("Rust project").with_tags(vec![rust.id()]).build();
+    let cooking_project = ProjectBuilder::new().with_name("Cooking project").with_tags(vec![cooking.id()]).build();
+    state.project_repository.create(rust_project).await.unwrap();
+    state.project_repository.create(cooking_project).await.unwrap();
+    let task = TaskBuilder::new().with_name("Tagged task").with_tags(vec![programming.id()]).build();
+    state.task_repository.create(task).await.unwrap();
+    let router = create_router(state);
+
+    let (_, body) = send(&router, "GET", &format!("/api/projects?tag={}", programming.id()), None).await;
+    assert_eq!(body.as_array().unwrap().len(), 1);
+    assert_eq!(body[0]["name"], "Rust project");
+
+    let (_, body) = send(&router, "GET", &format!("/api/tasks?tag={}", programming.id()), None).await;
+    assert_eq!(body[0]["name"], "Tagged task");
+
+    let (_, body) = send(&router, "GET", &format!("/api/tasks?tag={}", rust.id()), None).await;
+    assert!(body.as_array().unwrap().is_empty());
+
+    let (status, _) = send(&router, "GET", &format!("/api/projects?tag={}", Id::<Tag>::new()), None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_tag_routes_with_database_repository() {
+    let db = Arc::new(Database::connect().await.expect("Failed to connect to test database"));
+    let router = setup_router(Arc::new(ProdTagRepository::new(db)));
+    let tag = TagBuilder::new().with_name("stored").build();
+    let tag_id = tag.id();
+
+    let (status, _) = send(&router, "POST", "/api/tags", Some(tag_payload(tag))).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let replacement = TagBuilder::new()
+        .with_id(tag_id.clone())
+        .with_name("renamed")
+        .with_description("Replaced tag")
+        .build();
+    let (status, _) = send(&router, "PUT", &format!("/api/tag/{tag_id}"), Some(tag_payload(replacement))).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let payload = json!({ "names": ["renamed"] }).to_string();
+    let (status, body) = send(&router, "POST", "/api/tags/lookup", Some(payload)).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body[0]["id"], tag_id.to_string());
+    assert_eq!(body[0]["description"], "Replaced tag");
+
+    let (status, _) = send(&router, "DELETE", &format!("/api/tag/{tag_id}"), None).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, _) = send(&router, "GET", &format!("/api/tag/{tag_id}"), None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
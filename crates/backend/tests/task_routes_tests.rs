@@ -0,0 +1,166 @@
+mod common;
+
+use common::send;
+use axum::{Router, http::StatusCode};
+use chrono::{Duration, Utc};
+use project_tracker_backend::{
+    app::AppState,
+    db::task_repository::{MockTaskRepository, ProdTaskRepository, TaskRepository},
+    dto::task_dto::TaskDTO,
+    routes::create_router,
+};
+use project_tracker_core::{
+    HasId,
+    builders::task_builder::TaskBuilder,
+    id::Id,
+    models::{schedulable::SchedulableItemStatus, task::Task},
+};
+use project_tracker_db::database::Database;
+use serde_json::json;
+use std::sync::Arc;
+
+fn setup_router(repository: Arc<dyn TaskRepository>) -> Router {
+    let mut state = AppState::mock();
+    state.task_repository = repository;
+    create_router(state)
+}
+
+fn task_payload(task: Task) -> String {
+    serde_json::to_string(&TaskDTO::from(task)).unwrap()
+}
+
+#[tokio::test]
+async fn test_post_then_get_task() {
+    let router = setup_router(Arc::new(MockTaskRepository::new()));
+    let task = TaskBuilder::new().with_name("Persisted Task").build();
+    let task_id = task.id().to_string();
+
+    let (status, body) = send(&router, "POST", "/api/tasks", Some(task_payload(task))).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["task_id"], task_id);
+    assert!(body.get("project_id").is_none());
+
+    let (status, body) = send(&router, "GET", &format!("/api/task/{task_id}"), None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["name"], "Persisted Task");
+
+    let (status, body) = send(&router, "GET", "/api/tasks", None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body.as_array().unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_get_missing_task_returns_not_found() {
+    let router = setup_router(Arc::new(MockTaskRepository::new()));
+    let missing_id = Id::<Task>::new();
+
+    let (status, _) = send(&router, "GET", &format!("/api/task/{missing_id}"), None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_post_task_is_validated() {
+    let repository = Arc::new(MockTaskRepository::new());
+    let router = setup_router(repository.clone());
+
+    let task_id = Id::<Task>::new();
+    let self_dependent = TaskBuilder::new()
+        .with_id(task_id.clone())
+        .with_name("Self dependent")
+        .with_dependencies(vec![task_id.clone()])
+        .with_children(vec![task_id.clone()])
+        .build();
+    let (status, _) = send(&router, "POST", "/api/tasks", Some(task_payload(self_dependent))).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert!(repository.get_by_id(task_id).await.unwrap().is_none());
+
+    let badly_scheduled = TaskBuilder::new()
+        .with_name("Badly scheduled")
+        .with_start_date(Some(Utc::now() + Duration::days(5)))
+        .with_due_date(Some(Utc::now() + Duration::days(1)))
+        .build();
+    let (status, _) = send(&router, "POST", "/api/tasks", Some(task_payload(badly_scheduled))).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let inconsistent = TaskBuilder::new()
+        .with_name("Completed without dates")
+        .with_status(SchedulableItemStatus::Completed)
+        .build();
+    let (status, _) = send(&router, "POST", "/api/tasks", Some(task_payload(inconsistent))).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_put_patch_and_delete_task() {
+    let router = setup_router(Arc::new(MockTaskRepository::new()));
+    let task = TaskBuilder::new().with_name("Task").with_description("Original").build();
+    let task_id = task.id();
+    send(&router, "POST", "/api/tasks", Some(task_payload(task))).await;
+
+    let replacement = TaskBuilder::new().with_id(task_id.clone()).with_name("Replaced").build();
+    let (status, _) = send(&router, "PUT", &format!("/api/task/{task_id}"), Some(task_payload(replacement))).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let patch = json!({ "description": "Patched" }).to_string();
+    let (status, _) = send(&router, "PATCH", &format!("/api/task/{task_id}"), Some(patch)).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (_, body) = send(&router, "GET", &format!("/api/task/{task_id}"), None).await;
+    assert_eq!(body["name"], "Replaced");
+    assert_eq!(body["description"], "Patched");
+
+    let patch = json!({ "dependencies": [task_id.to_string()] }).to_string();
+    let (status, _) = send(&router, "PATCH", &format!("/api/task/{task_id}"), Some(patch)).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let (status, _) = send(&router, "DELETE", &format!("/api/task/{task_id}"), None).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, _) = send(&router, "GET", &format!("/api/task/{task_id}"), None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_dependency_cycles_are_rejected() {
+    let router = setup_router(Arc::new(MockTaskRepository::new()));
+    let design = TaskBuilder::new().with_name("Design").build();
+    let build = TaskBuilder::new().with_name("Build").with_dependencies(vec![design.id()]).build();
+    let ship = TaskBuilder::new().with_name("Ship").with_dependencies(vec![build.id()]).build();
+    for task in [design.clone(), build, ship.clone()] {
+        let (status, _) = send(&router, "POST", "/api/tasks", Some(task_payload(task))).await;
+        assert_eq!(status, StatusCode::OK);
+    }
+
+    let patch = json!({ "dependencies": [ship.id().to_string()] }).to_string();
+    let (status, _) = send(&router, "PATCH", &format!("/api/task/{}", design.id()), Some(patch)).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let (_, body) = send(&router, "GET", &format!("/api/task/{}", design.id()), None).await;
+    assert!(body["dependencies"].as_array().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_task_routes_with_database_repository() {
+    let db = Arc::new(Database::connect().await.expect("Failed to connect to test database"));
+    let router = setup_router(Arc::new(ProdTaskRepository::new(db)));
+    let task = TaskBuilder::new().with_name("Stored Task").build();
+    let task_id = task.id();
+
+    let (status, _) = send(&router, "POST", "/api/tasks", Some(task_payload(task))).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let patch = json!({ "name": "Renamed Task" }).to_string();
+    let (status, _) = send(&router, "PATCH", &format!("/api/task/{task_id}"), Some(patch)).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, body) = send(&router, "GET", "/api/tasks", None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body[0]["id"], task_id.to_string());
+    assert_eq!(body[0]["name"], "Renamed Task");
+
+    let (status, _) = send(&router, "DELETE", &format!("/api/task/{task_id}"), None).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, _) = send(&router, "GET", &format!("/api/task/{task_id}"), None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
@@ -0,0 +1,221 @@
+mod common;
+
+use common::send;
+use axum::http::StatusCode;
+use chrono::{Duration, Utc};
+use project_tracker_backend::{app::AppState, routes::create_router};
+use project_tracker_core::{
+    HasId,
+    builders::{
+        person_builder::PersonBuilder, project_builder::ProjectBuilder, tag_builder::TagBuilder,
+        task_builder::TaskBuilder, time_entry_builder::TimeEntryBuilder,
+    },
+    id::Id,
+    models::{person::Person, schedulable::SchedulableItem, tag::Tag, time_entry::TimeEntry},
+};
+use serde_json::json;
+
+#[tokio::test]
+async fn test_start_and_stop_timer() {
+    let state = AppState::mock();
+    let person = PersonBuilder::new().with_first_name("Ada").build();
+    let task = TaskBuilder::new().with_name("Write parser").build();
+    let task_id = task.id();
+    state.person_repository.create(person.clone()).await.unwrap();
+    state.task_repository.create(task).await.unwrap();
+    let router = create_router(state.clone());
+
+    let payload = json!({
+        "person_id": person.id().to_string(),
+        "item": { "Task": task_id.to_string() },
+        "note": "First pass",
+    })
+    .to_string();
+    let (status, body) = send(&router, "POST", "/api/timer/start", Some(payload.clone())).await;
+    assert_eq!(status, StatusCode::OK);
+    let entry_id = body["time_entry_id"].as_str().unwrap().to_string();
+
+    let (status, _) = send(&router, "POST", "/api/timer/start", Some(payload)).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    let payload = json!({ "person_id": person.id().to_string() }).to_string();
+    let (status, body) = send(&router, "POST", "/api/timer/stop", Some(payload.clone())).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["time_entry_id"], entry_id);
+
+    let (status, _) = send(&router, "POST", "/api/timer/stop", Some(payload)).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let (status, body) = send(&router, "GET", &format!("/api/time-entry/{entry_id}"), None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["note"], "First pass");
+    assert!(body["end"].is_string());
+}
+
+#[tokio::test]
+async fn test_time_entry_crud_and_validation() {
+    let state = AppState::mock();
+    let person = PersonBuilder::new().with_first_name("Bob").build();
+    let project = ProjectBuilder::new().with_name("Garden").build();
+    state.person_repository.create(person.clone()).await.unwrap();
+    state.project_repository.create(project.clone()).await.unwrap();
+    let router = create_router(state.clone());
+
+    let start = Utc::now() - Duration::hours(2);
+    let entry_id = Id::<TimeEntry>::new().to_string();
+    let mut payload = json!({
+        "id": entry_id,
+        "person_id": person.id().to_string(),
+        "item": { "Project": project.id().to_string() },
+        "start": start.to_rfc3339(),
+        "end": (start + Duration::minutes(30)).to_rfc3339(),
+    });
+    let (status, _) = send(&router, "POST", "/api/time-entries", Some(payload.to_string())).await;
+    assert_eq!(status, StatusCode::OK);
+
+    payload["end"] = json!((start - Duration::minutes(5)).to_rfc3339());
+    let (status, _) = send(&router, "PUT", &format!("/api/time-entry/{entry_id}"), Some(payload.to_string())).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let unknown = json!({
+        "id": Id::<TimeEntry>::new().to_string(),
+        "person_id": Id::<Person>::new().to_string(),
+        "item": { "Task": TaskBuilder::new().build().id().to_string() },
+        "start": start.to_rfc3339(),
+    });
+    let (status, _) = send(&router, "POST", "/api/time-entries", Some(unknown.to_string())).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let (_, body) = send(&router, "GET", "/api/time-entries", None).await;
+    assert_eq!(body.as_array().unwrap().len(), 1);
+
+    let (status, _) = send(&router, "DELETE", &format!("/api/time-entry/{entry_id}"), None).await;
+    assert_eq!(status, StatusCode::OK);
+    let (status, _) = send(&router, "GET", &format!("/api/time-entry/{entry_id}"), None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_only_one_running_entry_per_person() {
+    let state = AppState::mock();
+    let person = PersonBuilder::new().with_first_name("Cleo").build();
+    let task = TaskBuilder::new().with_name("Refactor").build();
+    state.person_repository.create(person.clone()).await.unwrap();
+    state.task_repository.create(task.clone()).await.unwrap();
+    let router = create_router(state.clone());
+
+    let start = Utc::now() - Duration::hours(1);
+    let running_id = Id::<TimeEntry>::new().to_string();
+    let mut running = json!({
+        "id": running_id,
+        "person_id": person.id().to_string(),
+        "item": { "Task": task.id().to_string() },
+        "start": start.to_rfc3339(),
+    });
+    let (status, _) = send(&router, "POST", "/api/time-entries", Some(running.to_string())).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let stopped_id = Id::<TimeEntry>::new().to_string();
+    let mut second = json!({
+        "id": stopped_id,
+        "person_id": person.id().to_string(),
+        "item": { "Task": task.id().to_string() },
+        "start": (start - Duration::hours(2)).to_rfc3339(),
+    });
+    let (status, _) = send(&router, "POST", "/api/time-entries", Some(second.to_string())).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    second["end"] = json!((start - Duration::hours(1)).to_rfc3339());
+    let (status, _) = send(&router, "POST", "/api/time-entries", Some(second.to_string())).await;
+    assert_eq!(status, StatusCode::OK);
+    second.as_object_mut().unwrap().remove("end");
+    let (status, _) = send(&router, "PUT", &format!("/api/time-entry/{stopped_id}"), Some(second.to_string())).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    // The running entry itself can still be edited
+    running["note"] = json!("Still going");
+    let (status, _) = send(&router, "PUT", &format!("/api/time-entry/{running_id}"), Some(running.to_string())).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let payload = json!({ "person_id": person.id().to_string() }).to_string();
+    let (status, body) = send(&router, "POST", "/api/timer/stop", Some(payload)).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["time_entry_id"], running_id);
+}
+
+#[tokio::test]
+async fn test_project_time_rolls_up_children_and_report_groups() {
+    let state = AppState::mock();
+    let ada = PersonBuilder::new().with_first_name("Ada").build();
+    let bob = PersonBuilder::new().with_first_name("Bob").build();
+    let rust = Id::<Tag>::new();
+    let task = TaskBuilder::new().with_name("Refactor").with_tags(vec![rust.clone()]).build();
+    let project = ProjectBuilder::new()
+        .with_name("Crate")
+        .with_children(vec![SchedulableItem::Task(task.id())])
+        .build();
+    let project_item = SchedulableItem::Project(project.id());
+    let task_item = SchedulableItem::Task(task.id());
+    state.tag_repository.create(TagBuilder::new().with_id(rust.clone()).with_name("rust").build()).await.unwrap();
+    state.task_repository.create(task.clone()).await.unwrap();
+    state.project_repository.create(project.clone()).await.unwrap();
+
+    let start = Utc::now() - Duration::days(1);
+    for (person, item, minutes) in [(&ada, &project_item, 20), (&ada, &task_item, 60), (&bob, &task_item, 40)] {
+        let entry = TimeEntryBuilder::new()
+            .with_person_id(person.id())
+            .with_item(item.clone())
+            .with_start(start)
+            .with_end(Some(start + Duration::minutes(minutes)))
+            .build();
+        state.time_entry_repository.create(entry).await.unwrap();
+    }
+    let router = create_router(state.clone());
+
+    let (status, body) = send(&router, "GET", &format!("/api/project/{}/time", project.id()), None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["logged_seconds"], 20 * 60);
+    assert_eq!(body["total_seconds"], 120 * 60);
+
+    let (status, body) = send(&router, "GET", &format!("/api/task/{}/time", task.id()), None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["total_seconds"], 100 * 60);
+
+    let (status, body) = send(&router, "GET", "/api/time-report", None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["total_seconds"], 120 * 60);
+    assert_eq!(body["per_item"].as_array().unwrap().len(), 2);
+    let per_person = body["per_person"].as_array().unwrap();
+    let ada_bucket = per_person.iter().find(|bucket| bucket["key"] == ada.id().to_string()).unwrap();
+    assert_eq!(ada_bucket["seconds"], 80 * 60);
+    assert_eq!(body["per_tag"][0]["key"], rust.to_string());
+    assert_eq!(body["per_tag"][0]["seconds"], 100 * 60);
+    assert_eq!(body["per_week"].as_array().unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_deleting_an_item_deletes_its_time_entries() {
+    let state = AppState::mock();
+    let person = PersonBuilder::new().with_first_name("Ada").build();
+    let removed = TaskBuilder::new().with_name("Removed").build();
+    let next = TaskBuilder::new().with_name("Next").build();
+    state.person_repository.create(person.clone()).await.unwrap();
+    state.task_repository.create(removed.clone()).await.unwrap();
+    state.task_repository.create(next.clone()).await.unwrap();
+    let router = create_router(state.clone());
+
+    let payload = json!({ "person_id": person.id().to_string(), "item": { "Task": removed.id().to_string() } }).to_string();
+    let (status, body) = send(&router, "POST", "/api/timer/start", Some(payload)).await;
+    assert_eq!(status, StatusCode::OK);
+    let entry_id = body["time_entry_id"].as_str().unwrap().to_string();
+
+    let (status, _) = send(&router, "DELETE", &format!("/api/task/{}", removed.id()), None).await;
+    assert_eq!(status, StatusCode::OK);
+    let (status, _) = send(&router, "GET", &format!("/api/time-entry/{entry_id}"), None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    // The deleted task's timer no longer counts as running
+    let payload = json!({ "person_id": person.id().to_string(), "item": { "Task": next.id().to_string() } }).to_string();
+    let (status, _) = send(&router, "POST", "/api/timer/start", Some(payload)).await;
+    assert_eq!(status, StatusCode::OK);
+}
@@ -0,0 +1,89 @@
+mod common;
+
+use common::send;
+use axum::http::StatusCode;
+use chrono::{Duration, Utc};
+use project_tracker_backend::{app::AppState, routes::create_router};
+use project_tracker_core::{
+    HasId,
+    builders::{project_builder::ProjectBuilder, task_builder::TaskBuilder},
+    factories::person_factory::basic_person,
+    models::schedulable::{Priority, Schedulable, SchedulableItemStatus},
+};
+use serde_json::json;
+
+#[tokio::test]
+async fn test_work_queue_ranks_open_items() {
+    let state = AppState::mock();
+    let prepare = TaskBuilder::new().with_name("Prepare").with_priority(Priority::Low).build();
+    let ship = TaskBuilder::new()
+        .with_name("Ship")
+        .with_priority(Priority::Critical)
+        .with_dependencies(vec![prepare.id()])
+        .build();
+    let overdue = TaskBuilder::new()
+        .with_name("Overdue")
+        .with_due_date(Some(Utc::now() - Duration::days(1)))
+        .build();
+    let done = TaskBuilder::new().with_name("Done").with_status(SchedulableItemStatus::Completed).build();
+    let project = ProjectBuilder::new().with_name("Launch").with_priority(Priority::High).build();
+    for task in [prepare.clone(), ship.clone(), overdue, done] {
+        state.task_repository.create(task).await.unwrap();
+    }
+    state.project_repository.create(project).await.unwrap();
+    let router = create_router(state);
+
+    let (status, body) = send(&router, "GET", "/api/work-queue", None).await;
+    assert_eq!(status, StatusCode::OK);
+    let names: Vec<&str> = body.as_array().unwrap().iter().map(|entry| entry["name"].as_str().unwrap()).collect();
+    assert_eq!(names, vec!["Overdue", "Launch", "Prepare", "Ship"]);
+    assert_eq!(body[0]["priority"], "Medium");
+    assert_eq!(body[0]["score"], 6);
+    assert_eq!(body[3]["ready"], false);
+    assert_eq!(body[3]["blockers"], json!([{ "Task": prepare.id().to_string() }]));
+
+    let (status, body) = send(&router, "GET", "/api/work-queue?ready=true&limit=2", None).await;
+    assert_eq!(status, StatusCode::OK);
+    let names: Vec<&str> = body.as_array().unwrap().iter().map(|entry| entry["name"].as_str().unwrap()).collect();
+    assert_eq!(names, vec!["Overdue", "Launch"]);
+}
+
+#[tokio::test]
+async fn test_work_queue_for_one_owner() {
+    let state = AppState::mock();
+    let person = basic_person();
+    state.person_repository.create(person.clone()).await.unwrap();
+    let mine = TaskBuilder::new().with_name("Mine").with_owner_id(Some(person.id())).build();
+    let theirs = TaskBuilder::new().with_name("Theirs").with_priority(Priority::Critical).build();
+    state.task_repository.create(mine).await.unwrap();
+    state.task_repository.create(theirs).await.unwrap();
+    let router = create_router(state);
+
+    let (status, body) = send(&router, "GET", &format!("/api/work-queue?owner_id={}", person.id()), None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body.as_array().unwrap().len(), 1);
+    assert_eq!(body[0]["name"], "Mine");
+
+    let (status, _) = send(&router, "GET", "/api/work-queue?owner_id=nobody", None).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_priority_can_be_patched() {
+    let state = AppState::mock();
+    let task = TaskBuilder::new().with_name("Triage").build();
+    let task_id = task.id();
+    state.task_repository.create(task).await.unwrap();
+    let router = create_router(state.clone());
+
+    let (status, body) = send(&router, "GET", &format!("/api/task/{task_id}"), None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["priority"], "Medium");
+
+    let payload = json!({ "priority": "High" }).to_string();
+    let (status, _) = send(&router, "PATCH", &format!("/api/task/{task_id}"), Some(payload)).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let stored = state.task_repository.get_by_id(task_id).await.unwrap().unwrap();
+    assert_eq!(stored.priority(), Priority::High);
+}
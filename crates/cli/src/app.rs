@@ -1,8 +1,10 @@
+use std::sync::Arc;
 use crossterm::event::KeyEvent;
 use project_tracker_core::{
     models::{person::Person, project::Project},
     factories::person_factory::basic_person,
 };
+use project_tracker_db::{config::DatabaseConfig, database::Database};
 use anyhow::Result;
 use crate::handlers::project::ProjectHandler;
 use crate::ui::{MainMenu, ProjectForm, ProjectPreview, ProjectOutput, MainMenuAction, ButtonAction};
@@ -14,7 +16,7 @@ pub enum Screen {
     MainMenu,
     ProjectForm,
     ProjectPreview,
-    ProjectOutput(Project),
+    ProjectOutput(Box<Project>),
 }
 
 pub struct App {
@@ -33,7 +35,8 @@ pub struct App {
 impl App {
     pub async fn new() -> AppResult<Self> {
         let default_user = basic_person();
-        let project_handler = ProjectHandler::new();
+        let db = Arc::new(Database::connect_with(&DatabaseConfig::from_env()?).await?);
+        let project_handler = ProjectHandler::new(db);
 
         Ok(Self {
             current_screen: Screen::MainMenu,
@@ -178,24 +181,24 @@ impl App {
                 }
             }
             KeyCode::Enter => {
-                if let Some(ref preview) = self.project_preview {
-                    if let Some(action) = preview.get_selected_action() {
-                        match action {
-                            ButtonAction::Submit => {
-                                let form_state = self.project_form.get_form_state();
-                                let project = self.project_handler.create_project(form_state, &self.default_user).await?;
-                                self.created_project = Some(project.clone());
-                                self.project_output = Some(ProjectOutput::new(project.clone()));
-                                self.current_screen = Screen::ProjectOutput(project);
-                            }
-                            ButtonAction::Back => {
-                                self.current_screen = Screen::ProjectForm;
-                            }
-                            ButtonAction::Cancel => {
-                                self.current_screen = Screen::MainMenu;
-                            }
-                            _ => {}
+                if let Some(ref preview) = self.project_preview
+                    && let Some(action) = preview.get_selected_action()
+                {
+                    match action {
+                        ButtonAction::Submit => {
+                            let form_state = self.project_form.get_form_state();
+                            let project = self.project_handler.create_project(form_state, &self.default_user).await?;
+                            self.created_project = Some(project.clone());
+                            self.project_output = Some(ProjectOutput::new(project.clone()));
+                            self.current_screen = Screen::ProjectOutput(Box::new(project));
                         }
+                        ButtonAction::Back => {
+                            self.current_screen = Screen::ProjectForm;
+                        }
+                        ButtonAction::Cancel => {
+                            self.current_screen = Screen::MainMenu;
+                        }
+                        _ => {}
                     }
                 }
             }
@@ -222,31 +225,31 @@ impl App {
                 }
             }
             KeyCode::Enter => {
-                if let Some(ref output) = self.project_output {
-                    if let Some(action) = output.get_selected_action() {
-                        match action {
-                            ButtonAction::Next => {
-                                // Create another project
-                                self.current_screen = Screen::ProjectForm;
-                                self.project_form = ProjectForm::new();
-                                self.project_preview = None;
-                                self.project_output = None;
-                                self.created_project = None;
-                            }
-                            ButtonAction::Back => {
-                                // Back to main menu
-                                self.current_screen = Screen::MainMenu;
-                                self.project_form = ProjectForm::new();
-                                self.project_preview = None;
-                                self.project_output = None;
-                                self.created_project = None;
-                            }
-                            ButtonAction::Cancel => {
-                                // Exit
-                                self.should_quit = true;
-                            }
-                            _ => {}
+                if let Some(ref output) = self.project_output
+                    && let Some(action) = output.get_selected_action()
+                {
+                    match action {
+                        ButtonAction::Next => {
+                            // Create another project
+                            self.current_screen = Screen::ProjectForm;
+                            self.project_form = ProjectForm::new();
+                            self.project_preview = None;
+                            self.project_output = None;
+                            self.created_project = None;
+                        }
+                        ButtonAction::Back => {
+                            // Back to main menu
+                            self.current_screen = Screen::MainMenu;
+                            self.project_form = ProjectForm::new();
+                            self.project_preview = None;
+                            self.project_output = None;
+                            self.created_project = None;
+                        }
+                        ButtonAction::Cancel => {
+                            // Exit
+                            self.should_quit = true;
                         }
+                        _ => {}
                     }
                 }
             }
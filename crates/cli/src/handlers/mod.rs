@@ -1,3 +1,2 @@
 pub mod project;
 
-pub use project::ProjectHandler;
\ No newline at end of file
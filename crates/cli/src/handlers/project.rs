@@ -1,18 +1,27 @@
+use std::sync::Arc;
 use project_tracker_core::{
     models::{person::Person, project::Project, tag::Tag},
-    builders::{project_builder::ProjectBuilder, tag_builder::TagBuilder},
+    builders::project_builder::ProjectBuilder,
     id::Id,
     HasId,
 };
+use project_tracker_db::{
+    database::Database,
+    tag_repository::{ProdTagRepository, TagRepository},
+};
 use anyhow::Result;
 
 use crate::models::ProjectFormState;
 
-pub struct ProjectHandler;
+pub struct ProjectHandler {
+    tag_repository: ProdTagRepository,
+}
 
 impl ProjectHandler {
-    pub fn new() -> Self {
-        Self
+    pub fn new(db: Arc<Database>) -> Self {
+        Self {
+            tag_repository: ProdTagRepository::new(db),
+        }
     }
 
     pub async fn create_project(
@@ -28,7 +37,7 @@ impl ProjectHandler {
             builder = builder.with_description(&form_state.description);
         }
 
-        // Convert string tags to Tag IDs
+        // Resolve string tags to stored Tag IDs
         let tag_ids = self.create_tags_from_strings(&form_state.tags).await?;
         if !tag_ids.is_empty() {
             builder = builder.with_tags(tag_ids);
@@ -41,9 +50,7 @@ impl ProjectHandler {
         let mut tag_ids = Vec::new();
         
         for tag_name in tag_names {
-            let tag = TagBuilder::new()
-                .with_name(tag_name)
-                .build();
+            let tag = self.tag_repository.get_or_create_by_name(tag_name).await?;
             tag_ids.push(tag.id());
         }
 
@@ -7,6 +7,7 @@ pub enum FormField {
 }
 
 #[derive(Debug, Clone)]
+#[allow(dead_code)]
 pub struct ProjectFormState {
     pub name: String,
     pub description: String,
@@ -29,6 +30,7 @@ impl Default for ProjectFormState {
     }
 }
 
+#[allow(dead_code)]
 impl ProjectFormState {
     pub fn new() -> Self {
         Self::default()
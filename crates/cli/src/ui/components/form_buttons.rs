@@ -1,7 +1,6 @@
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect, Alignment},
     style::{Color, Modifier, Style},
-    text::{Line, Span},
     widgets::{Block, Borders, Paragraph},
     Frame,
 };
@@ -17,6 +16,7 @@ pub enum ButtonAction {
 pub struct Button {
     pub label: String,
     pub action: ButtonAction,
+    #[allow(dead_code)]
     pub is_focused: bool,
 }
 
@@ -29,6 +29,7 @@ impl Button {
         }
     }
 
+    #[allow(dead_code)]
     pub fn focused(mut self, focused: bool) -> Self {
         self.is_focused = focused;
         self
@@ -48,6 +49,7 @@ impl FormButtons {
         }
     }
 
+    #[allow(dead_code)]
     pub fn with_buttons(buttons: Vec<Button>) -> Self {
         Self {
             buttons,
@@ -60,6 +62,7 @@ impl FormButtons {
         self
     }
 
+    #[allow(dead_code)]
     pub fn focused_index(mut self, index: usize) -> Self {
         if index < self.buttons.len() {
             self.focused_index = index;
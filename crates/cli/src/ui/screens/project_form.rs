@@ -29,6 +29,7 @@ impl ProjectForm {
         }
     }
 
+    #[allow(dead_code)]
     pub fn with_form_state(mut self, form_state: ProjectFormState) -> Self {
         self.form_state = form_state;
         self
@@ -38,6 +39,7 @@ impl ProjectForm {
         &self.form_state
     }
 
+    #[allow(dead_code)]
     pub fn get_form_state_mut(&mut self) -> &mut ProjectFormState {
         &mut self.form_state
     }
@@ -46,26 +48,26 @@ impl ProjectForm {
         match self.form_state.current_field {
             FormField::Name => {
                 // Name field: only allow printable characters and spaces, no newlines
-                if (!c.is_control() && c.is_ascii_graphic()) || c == ' ' {
-                    if self.form_state.name.len() < 100 {
-                        self.form_state.name.push(c);
-                    }
+                if ((!c.is_control() && c.is_ascii_graphic()) || c == ' ')
+                    && self.form_state.name.len() < 100
+                {
+                    self.form_state.name.push(c);
                 }
             }
             FormField::Description => {
                 // Description field: allow printable characters, spaces, and newlines
-                if (!c.is_control() && c.is_ascii_graphic()) || c == ' ' || c == '\n' {
-                    if self.form_state.description.len() < 500 {
-                        self.form_state.description.push(c);
-                    }
+                if ((!c.is_control() && c.is_ascii_graphic()) || c == ' ' || c == '\n')
+                    && self.form_state.description.len() < 500
+                {
+                    self.form_state.description.push(c);
                 }
             }
             FormField::Tags => {
                 // Tags field: only allow printable characters and spaces, no newlines
-                if (!c.is_control() && c.is_ascii_graphic()) || c == ' ' {
-                    if self.current_tag_input.len() < 50 {
-                        self.current_tag_input.push(c);
-                    }
+                if ((!c.is_control() && c.is_ascii_graphic()) || c == ' ')
+                    && self.current_tag_input.len() < 50
+                {
+                    self.current_tag_input.push(c);
                 }
             }
             FormField::Submit => {
@@ -1,42 +1,262 @@
-use std::fs;
-use surrealdb::{
-    Surreal,
-    engine::local::{Mem,Db},
-    // opt::auth::Root
+use std::{
+    collections::BTreeMap,
+    sync::{RwLock, RwLockReadGuard, RwLockWriteGuard},
 };
-use crate::{Result, DatabaseError};
 
+use project_tracker_core::{
+    HasId,
+    id::Id,
+    models::{
+        person::Person,
+        project::Project,
+        schedulable::{Schedulable, SchedulableItem},
+        tag::Tag,
+        task::Task,
+    },
+};
+use project_tracker_db::relations::{DeletePolicy, DeletedItems};
+
+use crate::{DatabaseError, Result};
+
+/// Every stored record, keyed by id so listings come out in the same order as SurrealDB's
+#[derive(Default, Debug)]
+pub(crate) struct Tables {
+    pub projects: BTreeMap<String, Project>,
+    pub tasks: BTreeMap<String, Task>,
+    pub tags: BTreeMap<String, Tag>,
+    pub people: BTreeMap<String, Person>,
+}
+
+/// In-memory stand-in for `project_tracker_db::database::Database`.
+/// Repositories built on the same `Database` see each other's records, so references between
+/// them are checked the same way as against SurrealDB.
+#[derive(Default, Debug)]
 pub struct Database {
-    client: Surreal<Db>
+    tables: RwLock<Tables>,
 }
 
 impl Database {
-    pub async fn connect() -> Result<Self> {
-        // memory:// for in-memory DB
-        let client = Surreal::new::<Mem>(()).await.map_err(|_| DatabaseError::ConnectionError("Could not start SurrealDB client".into()))?;
+    pub fn new() -> Self {
+        Self::default()
+    }
 
-        // client.signin(Root { // Not Relevant for in Mem DB
-        //     username: "root",
-        //     password: "root",
-        // }).await?;
+    pub(crate) fn read(&self) -> RwLockReadGuard<'_, Tables> {
+        self.tables.read().expect("Mock database lock poisoned")
+    }
 
-        client.use_ns("namespace").use_db("database").await.map_err(|_| DatabaseError::ConnectionError("Could not connect to namespace".into()))?;
+    pub(crate) fn write(&self) -> RwLockWriteGuard<'_, Tables> {
+        self.tables.write().expect("Mock database lock poisoned")
+    }
+}
+
+impl Tables {
+    pub fn contains_item(&self, item: &SchedulableItem) -> bool {
+        match item {
+            SchedulableItem::Project(id) => self.projects.contains_key(&id.to_string()),
+            SchedulableItem::Task(id) => self.tasks.contains_key(&id.to_string()),
+        }
+    }
 
-        let db = Self {client};
-        db.initialise_schema().await?;
+    /// Fails with `IntegrityError` naming every referenced record that does not exist,
+    /// like `relations::save_item` does
+    pub fn ensure_references(
+        &self,
+        owner_id: Option<&Id<Person>>,
+        tags: &[Id<Tag>],
+        linked: &[SchedulableItem],
+    ) -> Result<()> {
+        let mut missing: Vec<String> = owner_id
+            .map(|id| id.to_string())
+            .filter(|id| !self.people.contains_key(id))
+            .into_iter()
+            .collect();
+        missing.extend(tags.iter().map(|id| id.to_string()).filter(|id| !self.tags.contains_key(id)));
+        missing.extend(linked.iter().filter(|item| !self.contains_item(item)).map(|item| item.to_string()));
 
-        Ok(db)
+        if missing.is_empty() {
+            Ok(())
+        } else {
+            Err(DatabaseError::IntegrityError(format!("Referenced items do not exist: {}", missing.join(", "))))
+        }
     }
 
-    pub async fn initialise_schema(&self) -> Result<()> {
-        // let path = "crates/db-mock/schemas/project.surql";
-        // let schema = fs::read_to_string(&path)
-        //     .map_err(|e| {
-        //         eprintln!("Failed to read schema from '{path}': {e}"); // temp debug
-        //         DatabaseError::SchemaError("schema file could not be read".into())
-        //     })?;
-        let schema = fs::read_to_string("crates/db-mock/schemas/project.surql").map_err(|_| DatabaseError::SchemaError("schema file could not be read".into()))?;
-        self.client.query(schema).await.map_err(|_| DatabaseError::QueryError("Database query could not be operated".into()))?;
-        Ok(())
+    /// Fails with `QueryError` when `linked` names the same item twice, as the unique index on
+    /// SurrealDB's edges does, so nothing gets saved
+    pub fn ensure_distinct<T: PartialEq + std::fmt::Display>(item: &SchedulableItem, linked: &[T]) -> Result<()> {
+        match linked.iter().enumerate().find(|(index, link)| linked[..*index].contains(link)) {
+            Some((_, link)) => Err(DatabaseError::QueryError(format!("Failed to save {}: {} is linked twice", item, link))),
+            None => Ok(()),
+        }
+    }
+
+    /// Removes `item` under `policy`, returning what it removed and unlinked, or `None` if it was not there
+    pub fn delete_item(&mut self, item: &SchedulableItem, policy: DeletePolicy) -> Result<Option<DeletedItems>> {
+        if policy == DeletePolicy::Restrict {
+            self.ensure_unlinked(item)?;
+        }
+        if !self.contains_item(item) {
+            return Ok(None);
+        }
+
+        let mut removed = vec![item.clone()];
+        if policy == DeletePolicy::Cascade {
+            removed.extend(self.descendants(item));
+        }
+        let mut linked: Vec<SchedulableItem> = Vec::new();
+        for item in &removed {
+            for other in self.parents(item).into_iter().chain(self.dependents(item)) {
+                if !removed.contains(&other) && !linked.contains(&other) {
+                    linked.push(other);
+                }
+            }
+        }
+        let projects_before: Vec<Project> = linked
+            .iter()
+            .filter_map(|item| match item {
+                SchedulableItem::Project(id) => self.projects.get(&id.to_string()).cloned(),
+                SchedulableItem::Task(_) => None,
+            })
+            .collect();
+        let tasks_before: Vec<Task> = linked
+            .iter()
+            .filter_map(|item| match item {
+                SchedulableItem::Task(id) => self.tasks.get(&id.to_string()).cloned(),
+                SchedulableItem::Project(_) => None,
+            })
+            .collect();
+
+        let mut deleted = DeletedItems::default();
+        for item in &removed {
+            match item {
+                SchedulableItem::Project(id) => deleted.projects.extend(self.projects.remove(&id.to_string())),
+                SchedulableItem::Task(id) => deleted.tasks.extend(self.tasks.remove(&id.to_string())),
+            }
+        }
+        for item in &removed {
+            self.detach(item);
+        }
+        deleted.detached_projects = projects_before
+            .into_iter()
+            .map(|before| {
+                let after = self.projects[&before.id().to_string()].clone();
+                (before, after)
+            })
+            .collect();
+        deleted.detached_tasks = tasks_before
+            .into_iter()
+            .map(|before| {
+                let after = self.tasks[&before.id().to_string()].clone();
+                (before, after)
+            })
+            .collect();
+        Ok(Some(deleted))
     }
-}
\ No newline at end of file
+
+    pub fn children(&self, item: &SchedulableItem) -> Vec<SchedulableItem> {
+        match item {
+            SchedulableItem::Project(id) => self
+                .projects
+                .get(&id.to_string())
+                .map(|project| project.children())
+                .unwrap_or_default(),
+            SchedulableItem::Task(id) => self
+                .tasks
+                .get(&id.to_string())
+                .map(|task| task.children().into_iter().map(SchedulableItem::Task).collect())
+                .unwrap_or_default(),
+        }
+    }
+
+    pub fn parents(&self, item: &SchedulableItem) -> Vec<SchedulableItem> {
+        let projects = self
+            .projects
+            .values()
+            .filter(|project| project.has_child(item))
+            .map(|project| SchedulableItem::Project(project.id()));
+        let tasks = self
+            .tasks
+            .values()
+            .filter(|task| task.has_child(item))
+            .map(|task| SchedulableItem::Task(task.id()));
+        projects.chain(tasks).collect()
+    }
+
+    pub fn dependents(&self, item: &SchedulableItem) -> Vec<SchedulableItem> {
+        match item {
+            SchedulableItem::Project(id) => self
+                .projects
+                .values()
+                .filter(|project| project.dependencies().contains(id))
+                .map(|project| SchedulableItem::Project(project.id()))
+                .collect(),
+            SchedulableItem::Task(id) => self
+                .tasks
+                .values()
+                .filter(|task| task.dependencies().contains(id))
+                .map(|task| SchedulableItem::Task(task.id()))
+                .collect(),
+        }
+    }
+
+    /// Everything below `item`, following children all the way down
+    pub fn descendants(&self, item: &SchedulableItem) -> Vec<SchedulableItem> {
+        let mut descendants: Vec<SchedulableItem> = Vec::new();
+        let mut pending = self.children(item);
+        while let Some(child) = pending.pop() {
+            if &child == item || descendants.contains(&child) || !self.contains_item(&child) {
+                continue;
+            }
+            pending.extend(self.children(&child));
+            descendants.push(child);
+        }
+        descendants
+    }
+
+    fn ensure_unlinked(&self, item: &SchedulableItem) -> Result<()> {
+        let links = [
+            ("is a child of", self.parents(item)),
+            ("has children", self.children(item)),
+            ("is depended on by", self.dependents(item)),
+        ];
+        let reasons: Vec<String> = links
+            .into_iter()
+            .filter(|(_, linked)| !linked.is_empty())
+            .map(|(relation, linked)| {
+                let linked: Vec<String> = linked.iter().map(|item| item.to_string()).collect();
+                format!("{} {}", relation, linked.join(", "))
+            })
+            .collect();
+
+        if reasons.is_empty() {
+            Ok(())
+        } else {
+            Err(DatabaseError::IntegrityError(format!("Cannot delete {}: it {}", item, reasons.join(", "))))
+        }
+    }
+
+    // Drops a deleted item from every remaining children and dependencies list,
+    // as SurrealDB does when a record's edges go with it
+    fn detach(&mut self, item: &SchedulableItem) {
+        for project in self.projects.values_mut() {
+            if project.has_child(item) {
+                project.remove_child(item.clone());
+            }
+            match item {
+                SchedulableItem::Project(id) if project.dependencies().contains(id) => {
+                    project.remove_dependency(id.clone());
+                }
+                _ => (),
+            }
+        }
+        if let SchedulableItem::Task(id) = item {
+            for task in self.tasks.values_mut() {
+                if task.has_child(item) {
+                    task.remove_child(item.clone());
+                }
+                if task.dependencies().contains(id) {
+                    task.remove_dependency(id.clone());
+                }
+            }
+        }
+    }
+}
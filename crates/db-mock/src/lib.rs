@@ -1,4 +1,7 @@
-pub use self::error::{DatabaseError,Result};
+pub use project_tracker_db::{DatabaseError, Result};
 
 pub mod database;
-pub mod error;
\ No newline at end of file
+pub mod project_repository;
+pub mod task_repository;
+pub mod tag_repository;
+pub mod person_repository;
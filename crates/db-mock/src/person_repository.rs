@@ -0,0 +1,60 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use project_tracker_core::{HasId, id::Id, models::person::Person};
+use project_tracker_db::person_repository::PersonRepository;
+
+use crate::{DatabaseError, Result, database::Database};
+
+/// HashMap-backed `PersonRepository`, behaving like `ProdPersonRepository`
+pub struct MockPersonRepository {
+    db: Arc<Database>,
+}
+
+impl MockPersonRepository {
+    pub fn new(db: Arc<Database>) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl PersonRepository for MockPersonRepository {
+    async fn create(&self, person: Person) -> Result<()> {
+        let mut tables = self.db.write();
+
+        let id_str = person.id().to_string();
+        if tables.people.contains_key(&id_str) {
+            return Err(DatabaseError::QueryError(format!(
+                "Failed to create person: Person with ID {} already exists",
+                id_str
+            )));
+        }
+        tables.people.insert(id_str, person);
+        Ok(())
+    }
+
+    async fn get_by_id(&self, id: Id<Person>) -> Result<Option<Person>> {
+        Ok(self.db.read().people.get(&id.to_string()).cloned())
+    }
+
+    async fn get_all(&self) -> Result<Vec<Person>> {
+        Ok(self.db.read().people.values().cloned().collect())
+    }
+
+    async fn update(&self, person: Person) -> Result<()> {
+        match self.db.write().people.get_mut(&person.id().to_string()) {
+            Some(stored) => {
+                *stored = person;
+                Ok(())
+            }
+            None => Err(DatabaseError::QueryError(format!("Person with ID {} not found", person.id()))),
+        }
+    }
+
+    async fn delete(&self, id: Id<Person>) -> Result<()> {
+        match self.db.write().people.remove(&id.to_string()) {
+            Some(_) => Ok(()),
+            None => Err(DatabaseError::QueryError(format!("Person with ID {} not found", id))),
+        }
+    }
+}
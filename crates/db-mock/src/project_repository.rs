@@ -0,0 +1,141 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use project_tracker_core::{
+    HasId,
+    id::Id,
+    models::{
+        person::Person,
+        project::Project,
+        schedulable::{Schedulable, SchedulableItem},
+    },
+};
+use project_tracker_db::{
+    project_repository::ProjectRepository,
+    relations::{DeletePolicy, DeletedItems},
+};
+
+use crate::{
+    DatabaseError, Result,
+    database::{Database, Tables},
+};
+
+/// HashMap-backed `ProjectRepository`, behaving like `ProdProjectRepository`
+pub struct MockProjectRepository {
+    db: Arc<Database>,
+    delete_policy: DeletePolicy,
+}
+
+impl MockProjectRepository {
+    pub fn new(db: Arc<Database>) -> Self {
+        Self {
+            db,
+            delete_policy: DeletePolicy::default(),
+        }
+    }
+
+    /// What `delete` does to linked items, `DeletePolicy::Detach` unless set
+    pub fn with_delete_policy(mut self, delete_policy: DeletePolicy) -> Self {
+        self.delete_policy = delete_policy;
+        self
+    }
+
+    pub fn delete_policy(&self) -> DeletePolicy {
+        self.delete_policy
+    }
+}
+
+// Children and dependencies together, as the records they point at
+fn linked_items(project: &Project) -> Vec<SchedulableItem> {
+    let mut linked = project.children();
+    linked.extend(project.dependencies().into_iter().map(SchedulableItem::Project));
+    linked
+}
+
+#[async_trait]
+impl ProjectRepository for MockProjectRepository {
+    async fn create(&self, project: Project) -> Result<()> {
+        let mut tables = self.db.write();
+        tables.ensure_references(project.owner_id(), &project.tags(), &linked_items(&project))?;
+        let item = SchedulableItem::Project(project.id());
+        Tables::ensure_distinct(&item, &project.children())?;
+        Tables::ensure_distinct(&item, &project.dependencies())?;
+
+        let id_str = project.id().to_string();
+        if tables.projects.contains_key(&id_str) {
+            return Err(DatabaseError::QueryError(format!(
+                "Failed to create project: Project with ID {} already exists",
+                id_str
+            )));
+        }
+        tables.projects.insert(id_str, project);
+        Ok(())
+    }
+
+    async fn get_by_id(&self, id: Id<Project>) -> Result<Option<Project>> {
+        Ok(self.db.read().projects.get(&id.to_string()).cloned())
+    }
+
+    async fn get_all(&self) -> Result<Vec<Project>> {
+        Ok(self.db.read().projects.values().cloned().collect())
+    }
+
+    async fn update(&self, project: Project) -> Result<()> {
+        let mut tables = self.db.write();
+        tables.ensure_references(project.owner_id(), &project.tags(), &linked_items(&project))?;
+        let item = SchedulableItem::Project(project.id());
+        Tables::ensure_distinct(&item, &project.children())?;
+        Tables::ensure_distinct(&item, &project.dependencies())?;
+
+        match tables.projects.get_mut(&project.id().to_string()) {
+            Some(stored) => {
+                *stored = project;
+                Ok(())
+            }
+            None => Err(DatabaseError::QueryError(format!("Project with ID {} not found", project.id()))),
+        }
+    }
+
+    async fn delete(&self, id: Id<Project>) -> Result<DeletedItems> {
+        let item = SchedulableItem::Project(id.clone());
+        self.db
+            .write()
+            .delete_item(&item, self.delete_policy)?
+            .ok_or_else(|| DatabaseError::QueryError(format!("Project with ID {} not found", id)))
+    }
+
+    async fn get_by_owner(&self, owner_id: Id<Person>) -> Result<Vec<Project>> {
+        Ok(self
+            .db
+            .read()
+            .projects
+            .values()
+            .filter(|project| project.owner_id() == Some(&owner_id))
+            .cloned()
+            .collect())
+    }
+
+    async fn get_parent(&self, item: &SchedulableItem) -> Result<Option<Project>> {
+        let tables = self.db.read();
+        Ok(tables.parents(item).into_iter().find_map(|parent| match parent {
+            SchedulableItem::Project(id) => tables.projects.get(&id.to_string()).cloned(),
+            SchedulableItem::Task(_) => None,
+        }))
+    }
+
+    async fn get_descendants(&self, id: Id<Project>) -> Result<Vec<SchedulableItem>> {
+        Ok(self.db.read().descendants(&SchedulableItem::Project(id)))
+    }
+
+    async fn get_dependents(&self, id: Id<Project>) -> Result<Vec<Project>> {
+        let tables = self.db.read();
+        Ok(tables
+            .dependents(&SchedulableItem::Project(id))
+            .into_iter()
+            .filter_map(|dependent| match dependent {
+                SchedulableItem::Project(id) => tables.projects.get(&id.to_string()).cloned(),
+                SchedulableItem::Task(_) => None,
+            })
+            .collect())
+    }
+}
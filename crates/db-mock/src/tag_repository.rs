@@ -0,0 +1,83 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use project_tracker_core::{HasId, id::Id, models::tag::Tag};
+use project_tracker_db::tag_repository::TagRepository;
+
+use crate::{
+    DatabaseError, Result,
+    database::{Database, Tables},
+};
+
+/// HashMap-backed `TagRepository`, behaving like `ProdTagRepository`
+pub struct MockTagRepository {
+    db: Arc<Database>,
+}
+
+impl MockTagRepository {
+    pub fn new(db: Arc<Database>) -> Self {
+        Self { db }
+    }
+}
+
+// The tag schema asserts a non-empty name and keeps names unique
+fn check_name(tables: &Tables, tag: &Tag) -> Result<()> {
+    if tag.name().is_empty() {
+        return Err(DatabaseError::QueryError("Tag name must not be empty".into()));
+    }
+    let id_str = tag.id().to_string();
+    match tables.tags.iter().find(|(id, stored)| **id != id_str && stored.name() == tag.name()) {
+        Some(_) => Err(DatabaseError::QueryError(format!("Tag name {} is already in use", tag.name()))),
+        None => Ok(()),
+    }
+}
+
+#[async_trait]
+impl TagRepository for MockTagRepository {
+    async fn create(&self, tag: Tag) -> Result<()> {
+        let mut tables = self.db.write();
+        check_name(&tables, &tag)?;
+
+        let id_str = tag.id().to_string();
+        if tables.tags.contains_key(&id_str) {
+            return Err(DatabaseError::QueryError(format!(
+                "Failed to create tag: Tag with ID {} already exists",
+                id_str
+            )));
+        }
+        tables.tags.insert(id_str, tag);
+        Ok(())
+    }
+
+    async fn get_by_id(&self, id: Id<Tag>) -> Result<Option<Tag>> {
+        Ok(self.db.read().tags.get(&id.to_string()).cloned())
+    }
+
+    async fn get_by_name(&self, name: &str) -> Result<Option<Tag>> {
+        Ok(self.db.read().tags.values().find(|tag| tag.name() == name).cloned())
+    }
+
+    async fn get_all(&self) -> Result<Vec<Tag>> {
+        Ok(self.db.read().tags.values().cloned().collect())
+    }
+
+    async fn update(&self, tag: Tag) -> Result<()> {
+        let mut tables = self.db.write();
+        check_name(&tables, &tag)?;
+
+        match tables.tags.get_mut(&tag.id().to_string()) {
+            Some(stored) => {
+                *stored = tag;
+                Ok(())
+            }
+            None => Err(DatabaseError::QueryError(format!("Tag with ID {} not found", tag.id()))),
+        }
+    }
+
+    async fn delete(&self, id: Id<Tag>) -> Result<()> {
+        match self.db.write().tags.remove(&id.to_string()) {
+            Some(_) => Ok(()),
+            None => Err(DatabaseError::QueryError(format!("Tag with ID {} not found", id))),
+        }
+    }
+}
@@ -0,0 +1,141 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use project_tracker_core::{
+    HasId,
+    id::Id,
+    models::{
+        person::Person,
+        project::Project,
+        schedulable::{Schedulable, SchedulableItem},
+        task::Task,
+    },
+};
+use project_tracker_db::{relations::{DeletePolicy, DeletedItems}, task_repository::TaskRepository};
+
+use crate::{
+    DatabaseError, Result,
+    database::{Database, Tables},
+};
+
+/// HashMap-backed `TaskRepository`, behaving like `ProdTaskRepository`
+pub struct MockTaskRepository {
+    db: Arc<Database>,
+    delete_policy: DeletePolicy,
+}
+
+impl MockTaskRepository {
+    pub fn new(db: Arc<Database>) -> Self {
+        Self {
+            db,
+            delete_policy: DeletePolicy::default(),
+        }
+    }
+
+    /// What `delete` does to linked items, `DeletePolicy::Detach` unless set
+    pub fn with_delete_policy(mut self, delete_policy: DeletePolicy) -> Self {
+        self.delete_policy = delete_policy;
+        self
+    }
+
+    pub fn delete_policy(&self) -> DeletePolicy {
+        self.delete_policy
+    }
+}
+
+// Children and dependencies together, as the records they point at
+fn linked_items(task: &Task) -> Vec<SchedulableItem> {
+    task.children()
+        .into_iter()
+        .chain(task.dependencies())
+        .map(SchedulableItem::Task)
+        .collect()
+}
+
+#[async_trait]
+impl TaskRepository for MockTaskRepository {
+    async fn create(&self, task: Task) -> Result<()> {
+        let mut tables = self.db.write();
+        tables.ensure_references(task.owner_id(), &task.tags(), &linked_items(&task))?;
+        let item = SchedulableItem::Task(task.id());
+        Tables::ensure_distinct(&item, &task.children())?;
+        Tables::ensure_distinct(&item, &task.dependencies())?;
+
+        let id_str = task.id().to_string();
+        if tables.tasks.contains_key(&id_str) {
+            return Err(DatabaseError::QueryError(format!(
+                "Failed to create task: Task with ID {} already exists",
+                id_str
+            )));
+        }
+        tables.tasks.insert(id_str, task);
+        Ok(())
+    }
+
+    async fn get_by_id(&self, id: Id<Task>) -> Result<Option<Task>> {
+        Ok(self.db.read().tasks.get(&id.to_string()).cloned())
+    }
+
+    async fn get_all(&self) -> Result<Vec<Task>> {
+        Ok(self.db.read().tasks.values().cloned().collect())
+    }
+
+    async fn update(&self, task: Task) -> Result<()> {
+        let mut tables = self.db.write();
+        tables.ensure_references(task.owner_id(), &task.tags(), &linked_items(&task))?;
+        let item = SchedulableItem::Task(task.id());
+        Tables::ensure_distinct(&item, &task.children())?;
+        Tables::ensure_distinct(&item, &task.dependencies())?;
+
+        match tables.tasks.get_mut(&task.id().to_string()) {
+            Some(stored) => {
+                *stored = task;
+                Ok(())
+            }
+            None => Err(DatabaseError::QueryError(format!("Task with ID {} not found", task.id()))),
+        }
+    }
+
+    async fn delete(&self, id: Id<Task>) -> Result<DeletedItems> {
+        let item = SchedulableItem::Task(id.clone());
+        self.db
+            .write()
+            .delete_item(&item, self.delete_policy)?
+            .ok_or_else(|| DatabaseError::QueryError(format!("Task with ID {} not found", id)))
+    }
+
+    async fn get_by_owner(&self, owner_id: Id<Person>) -> Result<Vec<Task>> {
+        Ok(self
+            .db
+            .read()
+            .tasks
+            .values()
+            .filter(|task| task.owner_id() == Some(&owner_id))
+            .cloned()
+            .collect())
+    }
+
+    async fn get_by_parent_project(&self, project_id: Id<Project>) -> Result<Vec<Task>> {
+        let tables = self.db.read();
+        Ok(tables
+            .children(&SchedulableItem::Project(project_id))
+            .into_iter()
+            .filter_map(|child| match child {
+                SchedulableItem::Task(id) => tables.tasks.get(&id.to_string()).cloned(),
+                SchedulableItem::Project(_) => None,
+            })
+            .collect())
+    }
+
+    async fn get_dependents(&self, id: Id<Task>) -> Result<Vec<Task>> {
+        let tables = self.db.read();
+        Ok(tables
+            .dependents(&SchedulableItem::Task(id))
+            .into_iter()
+            .filter_map(|dependent| match dependent {
+                SchedulableItem::Task(id) => tables.tasks.get(&id.to_string()).cloned(),
+                SchedulableItem::Project(_) => None,
+            })
+            .collect())
+    }
+}
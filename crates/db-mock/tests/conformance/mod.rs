@@ -0,0 +1,419 @@
+//! Behaviour every repository implementation has to share, run against each of them
+//! from `conformance_tests.rs`
+
+use chrono::{Duration, Utc};
+use project_tracker_core::{
+    HasId,
+    builders::{
+        person_builder::PersonBuilder, project_builder::ProjectBuilder, tag_builder::TagBuilder,
+        task_builder::TaskBuilder,
+    },
+    id::Id,
+    models::{
+        person::Person,
+        project::Project,
+        schedulable::{Priority, Schedulable, SchedulableItem, SchedulableItemStatus},
+        tag::Tag,
+        task::Task,
+    },
+};
+use project_tracker_db::{
+    DatabaseError,
+    person_repository::PersonRepository,
+    project_repository::ProjectRepository,
+    relations::DeletePolicy,
+    tag_repository::TagRepository,
+    task_repository::TaskRepository,
+};
+use std::sync::Arc;
+
+/// Repositories of one implementation, all on the same store
+pub struct Repositories {
+    pub projects: Arc<dyn ProjectRepository>,
+    pub tasks: Arc<dyn TaskRepository>,
+    pub tags: Arc<dyn TagRepository>,
+    pub people: Arc<dyn PersonRepository>,
+}
+
+/// Builds an empty store and its repositories, with projects and tasks deleted under `delete_policy`
+pub trait Implementation {
+    fn repositories(delete_policy: DeletePolicy) -> impl Future<Output = Repositories>;
+}
+
+async fn store_person(repositories: &Repositories) -> Id<Person> {
+    let person = PersonBuilder::new().with_first_name("Ada").with_last_name("Lovelace").build();
+    repositories.people.create(person.clone()).await.expect("Failed to create person");
+    person.id()
+}
+
+async fn store_tag(repositories: &Repositories, name: &str) -> Id<Tag> {
+    let tag = TagBuilder::new().with_name(name).build();
+    repositories.tags.create(tag.clone()).await.expect("Failed to create tag");
+    tag.id()
+}
+
+async fn store_task(repositories: &Repositories, task: Task) -> Id<Task> {
+    repositories.tasks.create(task.clone()).await.expect("Failed to create task");
+    task.id()
+}
+
+async fn store_project(repositories: &Repositories, project: Project) -> Id<Project> {
+    repositories.projects.create(project.clone()).await.expect("Failed to create project");
+    project.id()
+}
+
+fn assert_integrity_error<T: std::fmt::Debug>(result: Result<T, DatabaseError>) {
+    assert!(
+        matches!(result, Err(DatabaseError::IntegrityError(_))),
+        "Expected an integrity error, got {:?}",
+        result
+    );
+}
+
+fn assert_query_error<T: std::fmt::Debug>(result: Result<T, DatabaseError>) {
+    assert!(
+        matches!(result, Err(DatabaseError::QueryError(_))),
+        "Expected a query error, got {:?}",
+        result
+    );
+}
+
+pub async fn round_trips_projects_and_tasks<I: Implementation>() {
+    let repositories = I::repositories(DeletePolicy::Detach).await;
+    let owner = store_person(&repositories).await;
+    let tag = store_tag(&repositories, "backend").await;
+    let blocker = store_task(&repositories, TaskBuilder::new().with_name("Blocker").build()).await;
+    let subtask = store_task(&repositories, TaskBuilder::new().with_name("Subtask").build()).await;
+
+    let task = TaskBuilder::new()
+        .with_name("Task")
+        .with_owner_id(Some(owner.clone()))
+        .with_description("Every field set")
+        .with_tags(vec![tag.clone()])
+        .with_children(vec![subtask.clone()])
+        .with_dependencies(vec![blocker.clone()])
+        .with_start_date(Some(Utc::now()))
+        .with_due_date(Some(Utc::now() + Duration::days(3)))
+        .with_status(SchedulableItemStatus::InProgress)
+        .with_priority(Priority::High)
+        .build();
+    store_task(&repositories, task.clone()).await;
+    let base = store_project(&repositories, ProjectBuilder::new().with_name("Base").build()).await;
+    let project = ProjectBuilder::new()
+        .with_name("Project")
+        .with_owner_id(Some(owner.clone()))
+        .with_tags(vec![tag])
+        .with_children(vec![SchedulableItem::Task(task.id())])
+        .with_dependencies(vec![base])
+        .with_priority(Priority::Low)
+        .build();
+    store_project(&repositories, project.clone()).await;
+
+    assert_eq!(repositories.tasks.get_by_id(task.id()).await.unwrap(), Some(task.clone()));
+    assert_eq!(repositories.projects.get_by_id(project.id()).await.unwrap(), Some(project.clone()));
+    assert!(repositories.projects.get_by_id(Id::new()).await.unwrap().is_none());
+
+    assert_eq!(repositories.tasks.get_all().await.unwrap().len(), 3);
+    assert!(repositories.projects.get_all().await.unwrap().contains(&project));
+    assert_eq!(repositories.tasks.get_by_owner(owner.clone()).await.unwrap(), vec![task]);
+    assert_eq!(repositories.projects.get_by_owner(owner).await.unwrap(), vec![project]);
+    assert!(repositories.projects.get_by_owner(Id::new()).await.unwrap().is_empty());
+}
+
+pub async fn updates_and_deletes_existing_records_only<I: Implementation>() {
+    let repositories = I::repositories(DeletePolicy::Detach).await;
+
+    let mut task = TaskBuilder::new().with_name("Before").build();
+    store_task(&repositories, task.clone()).await;
+    assert_query_error(repositories.tasks.create(task.clone()).await);
+
+    task.rename("After");
+    task.set_priority(Priority::Critical);
+    repositories.tasks.update(task.clone()).await.expect("Failed to update task");
+    assert_eq!(repositories.tasks.get_by_id(task.id()).await.unwrap(), Some(task.clone()));
+
+    repositories.tasks.delete(task.id()).await.expect("Failed to delete task");
+    assert!(repositories.tasks.get_by_id(task.id()).await.unwrap().is_none());
+    assert_query_error(repositories.tasks.delete(task.id()).await);
+    assert_query_error(repositories.tasks.update(task).await);
+
+    let project = ProjectBuilder::new().with_name("Never stored").build();
+    assert_query_error(repositories.projects.update(project.clone()).await);
+    assert_query_error(repositories.projects.delete(project.id()).await);
+    assert!(repositories.projects.get_all().await.unwrap().is_empty());
+}
+
+pub async fn rejects_missing_references<I: Implementation>() {
+    let repositories = I::repositories(DeletePolicy::Detach).await;
+
+    let owned = TaskBuilder::new().with_name("Owned").with_owner_id(Some(Id::new())).build();
+    assert_integrity_error(repositories.tasks.create(owned).await);
+    let tagged = ProjectBuilder::new().with_name("Tagged").with_tags(vec![Id::new()]).build();
+    assert_integrity_error(repositories.projects.create(tagged).await);
+    let parent = ProjectBuilder::new()
+        .with_name("Parent")
+        .with_children(vec![SchedulableItem::Task(Id::new())])
+        .build();
+    assert_integrity_error(repositories.projects.create(parent.clone()).await);
+    assert!(repositories.projects.get_by_id(parent.id()).await.unwrap().is_none());
+
+    let mut task = TaskBuilder::new().with_name("Valid").build();
+    store_task(&repositories, task.clone()).await;
+    task.add_dependency(Id::new());
+    assert_integrity_error(repositories.tasks.update(task.clone()).await);
+    assert!(repositories.tasks.get_by_id(task.id()).await.unwrap().unwrap().dependencies().is_empty());
+}
+
+pub async fn rejects_duplicate_links<I: Implementation>() {
+    let repositories = I::repositories(DeletePolicy::Detach).await;
+
+    let task = store_task(&repositories, TaskBuilder::new().with_name("Task").build()).await;
+    let repeated_child = ProjectBuilder::new()
+        .with_name("Repeated child")
+        .with_children(vec![SchedulableItem::Task(task.clone()), SchedulableItem::Task(task.clone())])
+        .build();
+    assert_query_error(repositories.projects.create(repeated_child.clone()).await);
+    assert!(repositories.projects.get_by_id(repeated_child.id()).await.unwrap().is_none());
+
+    let repeated_dependency = TaskBuilder::new()
+        .with_name("Repeated dependency")
+        .with_dependencies(vec![task.clone(), task.clone()])
+        .build();
+    assert_query_error(repositories.tasks.create(repeated_dependency.clone()).await);
+    assert!(repositories.tasks.get_by_id(repeated_dependency.id()).await.unwrap().is_none());
+
+    let base = store_project(&repositories, ProjectBuilder::new().with_name("Base").build()).await;
+    let project = ProjectBuilder::new().with_name("Project").with_dependencies(vec![base.clone()]).build();
+    store_project(&repositories, project.clone()).await;
+    let repeating = ProjectBuilder::new()
+        .with_id(project.id())
+        .with_name("Repeating")
+        .with_dependencies(vec![base.clone(), base])
+        .build();
+    assert_query_error(repositories.projects.update(repeating).await);
+    assert_eq!(repositories.projects.get_by_id(project.id()).await.unwrap(), Some(project));
+}
+
+pub async fn detach_drops_deleted_links<I: Implementation>() {
+    let repositories = I::repositories(DeletePolicy::Detach).await;
+
+    let kept = store_task(&repositories, TaskBuilder::new().with_name("Kept").build()).await;
+    let removed = store_task(&repositories, TaskBuilder::new().with_name("Removed").build()).await;
+    let waiting = TaskBuilder::new()
+        .with_name("Waiting")
+        .with_dependencies(vec![removed.clone(), kept.clone()])
+        .build();
+    store_task(&repositories, waiting.clone()).await;
+    let sub_project = store_project(&repositories, ProjectBuilder::new().with_name("Sub").build()).await;
+    let project = ProjectBuilder::new()
+        .with_name("Parent")
+        .with_children(vec![
+            SchedulableItem::Task(removed.clone()),
+            SchedulableItem::Project(sub_project.clone()),
+            SchedulableItem::Task(kept.clone()),
+        ])
+        .build();
+    store_project(&repositories, project.clone()).await;
+
+    let deleted = repositories.tasks.delete(removed.clone()).await.expect("Failed to delete task");
+    assert_eq!(deleted.items(), vec![SchedulableItem::Task(removed)]);
+    let detached = deleted.detached_items();
+    assert_eq!(detached.len(), 2);
+    assert!(detached.contains(&SchedulableItem::Project(project.id())));
+    assert!(detached.contains(&SchedulableItem::Task(waiting.id())));
+
+    let project = repositories.projects.get_by_id(project.id()).await.unwrap().unwrap();
+    assert_eq!(
+        project.children(),
+        vec![SchedulableItem::Project(sub_project.clone()), SchedulableItem::Task(kept.clone())]
+    );
+    let waiting = repositories.tasks.get_by_id(waiting.id()).await.unwrap().unwrap();
+    assert_eq!(waiting.dependencies(), vec![kept.clone()]);
+
+    repositories.projects.delete(project.id()).await.expect("Failed to delete project");
+    assert!(repositories.projects.get_by_id(sub_project).await.unwrap().is_some());
+    assert!(repositories.tasks.get_by_id(kept).await.unwrap().is_some());
+}
+
+pub async fn queries_links_between_items<I: Implementation>() {
+    let repositories = I::repositories(DeletePolicy::Detach).await;
+
+    let subtask = store_task(&repositories, TaskBuilder::new().with_name("Subtask").build()).await;
+    let task = store_task(
+        &repositories,
+        TaskBuilder::new().with_name("Task").with_children(vec![subtask.clone()]).build(),
+    )
+    .await;
+    let waiting = store_task(
+        &repositories,
+        TaskBuilder::new().with_name("Waiting").with_dependencies(vec![task.clone()]).build(),
+    )
+    .await;
+    let sub_project = store_project(
+        &repositories,
+        ProjectBuilder::new()
+            .with_name("Sub")
+            .with_children(vec![SchedulableItem::Task(task.clone())])
+            .build(),
+    )
+    .await;
+    let root = store_project(
+        &repositories,
+        ProjectBuilder::new()
+            .with_name("Root")
+            .with_children(vec![SchedulableItem::Project(sub_project.clone())])
+            .build(),
+    )
+    .await;
+    let follow_up = store_project(
+        &repositories,
+        ProjectBuilder::new().with_name("Follow-up").with_dependencies(vec![root.clone()]).build(),
+    )
+    .await;
+
+    let parent = repositories.projects.get_parent(&SchedulableItem::Task(task.clone())).await.unwrap();
+    assert_eq!(parent.map(|project| project.id()), Some(sub_project.clone()));
+    assert!(repositories.projects.get_parent(&SchedulableItem::Project(root.clone())).await.unwrap().is_none());
+
+    let mut descendants = repositories.projects.get_descendants(root.clone()).await.unwrap();
+    descendants.sort_by_key(|item| item.to_string());
+    let mut expected = vec![
+        SchedulableItem::Project(sub_project.clone()),
+        SchedulableItem::Task(task.clone()),
+        SchedulableItem::Task(subtask),
+    ];
+    expected.sort_by_key(|item| item.to_string());
+    assert_eq!(descendants, expected);
+
+    let tasks = repositories.tasks.get_by_parent_project(sub_project).await.unwrap();
+    assert_eq!(tasks.iter().map(|task| task.id()).collect::<Vec<_>>(), vec![task.clone()]);
+    assert!(repositories.tasks.get_by_parent_project(root.clone()).await.unwrap().is_empty());
+
+    let dependents = repositories.tasks.get_dependents(task).await.unwrap();
+    assert_eq!(dependents.iter().map(|task| task.id()).collect::<Vec<_>>(), vec![waiting]);
+    let dependents = repositories.projects.get_dependents(root).await.unwrap();
+    assert_eq!(dependents.iter().map(|project| project.id()).collect::<Vec<_>>(), vec![follow_up.clone()]);
+    assert!(repositories.projects.get_dependents(follow_up).await.unwrap().is_empty());
+}
+
+pub async fn restrict_refuses_linked_items<I: Implementation>() {
+    let repositories = I::repositories(DeletePolicy::Restrict).await;
+
+    let blocker = store_task(&repositories, TaskBuilder::new().with_name("Blocker").build()).await;
+    let waiting = TaskBuilder::new()
+        .with_name("Waiting")
+        .with_dependencies(vec![blocker.clone()])
+        .build();
+    store_task(&repositories, waiting.clone()).await;
+    let mut project = ProjectBuilder::new()
+        .with_name("Parent")
+        .with_children(vec![SchedulableItem::Task(waiting.id())])
+        .build();
+    store_project(&repositories, project.clone()).await;
+
+    assert_integrity_error(repositories.tasks.delete(blocker.clone()).await);
+    assert_integrity_error(repositories.tasks.delete(waiting.id()).await);
+    assert_integrity_error(repositories.projects.delete(project.id()).await);
+    assert_eq!(repositories.tasks.get_all().await.unwrap().len(), 2);
+
+    project.remove_all_children();
+    repositories.projects.update(project.clone()).await.unwrap();
+    repositories.projects.delete(project.id()).await.expect("Unlinked project was kept");
+    repositories.tasks.delete(waiting.id()).await.expect("Unlinked task was kept");
+    repositories.tasks.delete(blocker).await.expect("Unlinked task was kept");
+    assert_query_error(repositories.tasks.delete(waiting.id()).await);
+}
+
+pub async fn cascade_deletes_descendants<I: Implementation>() {
+    let repositories = I::repositories(DeletePolicy::Cascade).await;
+
+    let subtask = store_task(&repositories, TaskBuilder::new().with_name("Subtask").build()).await;
+    let task = TaskBuilder::new().with_name("Task").with_children(vec![subtask.clone()]).build();
+    store_task(&repositories, task.clone()).await;
+    let outside = TaskBuilder::new()
+        .with_name("Outside")
+        .with_dependencies(vec![task.id()])
+        .build();
+    store_task(&repositories, outside.clone()).await;
+    let sub_project = ProjectBuilder::new()
+        .with_name("Sub")
+        .with_children(vec![SchedulableItem::Task(task.id())])
+        .build();
+    store_project(&repositories, sub_project.clone()).await;
+    let root = ProjectBuilder::new()
+        .with_name("Root")
+        .with_children(vec![SchedulableItem::Project(sub_project.id())])
+        .build();
+    store_project(&repositories, root.clone()).await;
+    let sibling = ProjectBuilder::new()
+        .with_name("Sibling")
+        .with_dependencies(vec![sub_project.id()])
+        .build();
+    store_project(&repositories, sibling.clone()).await;
+
+    let deleted = repositories.projects.delete(root.id()).await.expect("Failed to delete project");
+    let removed = deleted.items();
+    assert_eq!(removed.len(), 4);
+    for item in [
+        SchedulableItem::Project(root.id()),
+        SchedulableItem::Project(sub_project.id()),
+        SchedulableItem::Task(task.id()),
+        SchedulableItem::Task(subtask.clone()),
+    ] {
+        assert!(removed.contains(&item), "{item} was not reported as deleted");
+    }
+    let [(before, after)] = deleted.detached_tasks.as_slice() else {
+        panic!("Expected one detached task, got {:?}", deleted.detached_tasks);
+    };
+    assert_eq!(before.id(), outside.id());
+    assert_eq!(before.dependencies(), vec![task.id()]);
+    assert!(after.dependencies().is_empty());
+    assert_eq!(
+        deleted.detached_items(),
+        vec![SchedulableItem::Project(sibling.id()), SchedulableItem::Task(outside.id())]
+    );
+
+    assert_eq!(repositories.projects.get_all().await.unwrap().len(), 1);
+    assert!(repositories.tasks.get_by_id(task.id()).await.unwrap().is_none());
+    assert!(repositories.tasks.get_by_id(subtask).await.unwrap().is_none());
+    let outside = repositories.tasks.get_by_id(outside.id()).await.unwrap().unwrap();
+    assert!(outside.dependencies().is_empty());
+    let sibling = repositories.projects.get_by_id(sibling.id()).await.unwrap().unwrap();
+    assert!(sibling.dependencies().is_empty());
+}
+
+pub async fn keeps_tag_names_unique<I: Implementation>() {
+    let repositories = I::repositories(DeletePolicy::Detach).await;
+
+    let urgent = store_tag(&repositories, "urgent").await;
+    assert_eq!(repositories.tags.get_by_name("urgent").await.unwrap().map(|tag| tag.id()), Some(urgent.clone()));
+    assert!(repositories.tags.get_by_name("later").await.unwrap().is_none());
+    assert_query_error(repositories.tags.create(TagBuilder::new().with_name("urgent").build()).await);
+    assert_query_error(repositories.tags.create(TagBuilder::new().with_name("").build()).await);
+
+    let later = repositories.tags.get_or_create_by_name("later").await.unwrap();
+    assert_eq!(repositories.tags.get_or_create_by_name("urgent").await.unwrap().id(), urgent);
+    assert_eq!(repositories.tags.get_all().await.unwrap().len(), 2);
+
+    let renamed = TagBuilder::new().with_id(later.id()).with_name("urgent").build();
+    assert_query_error(repositories.tags.update(renamed).await);
+    repositories.tags.delete(urgent.clone()).await.expect("Failed to delete tag");
+    assert_query_error(repositories.tags.delete(urgent).await);
+}
+
+pub async fn manages_people<I: Implementation>() {
+    let repositories = I::repositories(DeletePolicy::Detach).await;
+
+    let person_id = store_person(&repositories).await;
+    let renamed = PersonBuilder::new()
+        .with_id(person_id.clone())
+        .with_first_name("Grace")
+        .with_last_name("Hopper")
+        .build();
+    repositories.people.update(renamed.clone()).await.expect("Failed to update person");
+    assert_eq!(repositories.people.get_all().await.unwrap(), vec![renamed]);
+
+    repositories.people.delete(person_id.clone()).await.expect("Failed to delete person");
+    assert!(repositories.people.get_by_id(person_id.clone()).await.unwrap().is_none());
+    assert_query_error(repositories.people.delete(person_id).await);
+}
@@ -0,0 +1,83 @@
+mod conformance;
+
+use conformance::{Implementation, Repositories};
+use project_tracker_db::{
+    database::Database as ProdDatabase,
+    person_repository::ProdPersonRepository,
+    project_repository::ProdProjectRepository,
+    relations::DeletePolicy,
+    tag_repository::ProdTagRepository,
+    task_repository::ProdTaskRepository,
+};
+use project_tracker_db_mock::{
+    database::Database as MockDatabase,
+    person_repository::MockPersonRepository,
+    project_repository::MockProjectRepository,
+    tag_repository::MockTagRepository,
+    task_repository::MockTaskRepository,
+};
+use std::sync::Arc;
+
+struct Prod;
+
+impl Implementation for Prod {
+    async fn repositories(delete_policy: DeletePolicy) -> Repositories {
+        let db = Arc::new(ProdDatabase::connect().await.expect("Failed to connect to test database"));
+        Repositories {
+            projects: Arc::new(ProdProjectRepository::new(db.clone()).with_delete_policy(delete_policy)),
+            tasks: Arc::new(ProdTaskRepository::new(db.clone()).with_delete_policy(delete_policy)),
+            tags: Arc::new(ProdTagRepository::new(db.clone())),
+            people: Arc::new(ProdPersonRepository::new(db)),
+        }
+    }
+}
+
+struct Mock;
+
+impl Implementation for Mock {
+    async fn repositories(delete_policy: DeletePolicy) -> Repositories {
+        let db = Arc::new(MockDatabase::new());
+        Repositories {
+            projects: Arc::new(MockProjectRepository::new(db.clone()).with_delete_policy(delete_policy)),
+            tasks: Arc::new(MockTaskRepository::new(db.clone()).with_delete_policy(delete_policy)),
+            tags: Arc::new(MockTagRepository::new(db.clone())),
+            people: Arc::new(MockPersonRepository::new(db)),
+        }
+    }
+}
+
+// Runs each check once against SurrealDB and once against the mock
+macro_rules! conformance_tests {
+    ($($check:ident),* $(,)?) => {
+        mod prod {
+            $(
+                #[tokio::test]
+                async fn $check() {
+                    crate::conformance::$check::<crate::Prod>().await;
+                }
+            )*
+        }
+
+        mod mock {
+            $(
+                #[tokio::test]
+                async fn $check() {
+                    crate::conformance::$check::<crate::Mock>().await;
+                }
+            )*
+        }
+    };
+}
+
+conformance_tests!(
+    round_trips_projects_and_tasks,
+    updates_and_deletes_existing_records_only,
+    rejects_missing_references,
+    rejects_duplicate_links,
+    detach_drops_deleted_links,
+    queries_links_between_items,
+    restrict_refuses_linked_items,
+    cascade_deletes_descendants,
+    keeps_tag_names_unique,
+    manages_people,
+);
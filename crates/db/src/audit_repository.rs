@@ -0,0 +1,133 @@
+use crate::{DatabaseError, Result, database::Database, query::{Order, SelectQuery}};
+use async_trait::async_trait;
+use log::{debug, error};
+use project_tracker_core::{
+    HasId,
+    id::Id,
+    models::{audit::AuditEntry, person::Person},
+};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use surrealdb::sql::{Datetime as SurrealDatetime, Thing};
+
+// DTO for database operations (id is only read back, since SurrealDB manages it)
+#[derive(Serialize, Deserialize, Debug)]
+struct AuditRecord {
+    #[serde(default, skip_serializing)]
+    id: Option<Thing>,
+    entity_id: String,
+    field: String,
+    old_value: Option<String>,
+    new_value: Option<String>,
+    actor: Option<String>,
+    at: SurrealDatetime,
+}
+
+impl From<AuditEntry> for AuditRecord {
+    fn from(entry: AuditEntry) -> Self {
+        Self {
+            id: None,
+            entity_id: entry.entity_id().to_string(),
+            field: entry.field().to_string(),
+            old_value: entry.old_value().map(str::to_string),
+            new_value: entry.new_value().map(str::to_string),
+            actor: entry.actor().map(|id| id.to_string()),
+            at: SurrealDatetime::from(entry.at()),
+        }
+    }
+}
+
+impl AuditRecord {
+    fn into_stored_entry(self) -> Result<AuditEntry> {
+        let id = match &self.id {
+            Some(thing) => thing
+                .id
+                .to_raw()
+                .parse::<Id<AuditEntry>>()
+                .map_err(|e| DatabaseError::QueryError(format!("Invalid audit entry ID: {:?}", e)))?,
+            None => return Err(DatabaseError::QueryError("Audit record is missing its ID".into())),
+        };
+        let actor = self
+            .actor
+            .map(|actor| actor.parse::<Id<Person>>())
+            .transpose()
+            .map_err(|e| DatabaseError::QueryError(format!("Invalid actor ID: {:?}", e)))?;
+
+        Ok(AuditEntry::restore(
+            id,
+            self.entity_id,
+            self.field,
+            self.old_value,
+            self.new_value,
+            actor,
+            self.at.0,
+        ))
+    }
+}
+
+// Define the repository trait locally to avoid circular dependencies
+#[async_trait]
+pub trait AuditRepository {
+    /// Appends an entry; entries are never updated or deleted
+    async fn record(&self, entry: AuditEntry) -> Result<()>;
+    /// Changes made to one entity, oldest first
+    async fn get_for_entity(&self, entity_id: &str) -> Result<Vec<AuditEntry>>;
+}
+
+pub struct ProdAuditRepository {
+    db: Arc<Database>,
+}
+
+impl ProdAuditRepository {
+    pub fn new(db: Arc<Database>) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl AuditRepository for ProdAuditRepository {
+    async fn record(&self, entry: AuditEntry) -> Result<()> {
+        let entry_id = entry.id();
+        debug!("Recording audit entry for entity: {}", entry.entity_id());
+
+        let id_str = entry_id.to_string();
+        let audit_record = AuditRecord::from(entry);
+
+        let result: Result<Option<AuditRecord>> = self
+            .db
+            .client()
+            .create(("audit", id_str.as_str()))
+            .content(audit_record)
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to record audit entry: {}", e)));
+
+        match result {
+            Ok(_) => {
+                debug!("Successfully recorded audit entry with ID: {}", entry_id);
+                Ok(())
+            }
+            Err(e) => {
+                error!("Failed to record audit entry: {:?}", e);
+                Err(e)
+            }
+        }
+    }
+
+    async fn get_for_entity(&self, entity_id: &str) -> Result<Vec<AuditEntry>> {
+        debug!("Fetching audit history for entity: {}", entity_id);
+
+        let records: Vec<AuditRecord> = SelectQuery::table("audit")
+            .where_eq("entity_id", entity_id)
+            .order_by("at", Order::Asc)
+            .order_by("field", Order::Asc)
+            .fetch(&self.db)
+            .await?;
+
+        let entries = records
+            .into_iter()
+            .map(AuditRecord::into_stored_entry)
+            .collect::<Result<Vec<_>>>()?;
+        debug!("Found {} audit entries for entity: {}", entries.len(), entity_id);
+        Ok(entries)
+    }
+}
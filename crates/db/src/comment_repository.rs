@@ -0,0 +1,314 @@
+use crate::{DatabaseError, Result, database::Database, query::{Order, SelectQuery}};
+use async_trait::async_trait;
+use log::{debug, error};
+use project_tracker_core::{
+    HasId,
+    builders::comment_builder::CommentBuilder,
+    id::Id,
+    models::{
+        comment::{Comment, CommentEdit},
+        person::Person,
+        project::Project,
+        schedulable::SchedulableItem,
+        task::Task,
+    },
+};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use surrealdb::sql::{Datetime as SurrealDatetime, Thing};
+
+// DTO for database operations (id is only read back, since SurrealDB manages it)
+#[derive(Serialize, Deserialize, Debug)]
+struct CommentRecord {
+    #[serde(default, skip_serializing)]
+    id: Option<Thing>,
+    item: String,
+    author_id: String,
+    parent_id: Option<String>,
+    body: String,
+    created_at: SurrealDatetime,
+    #[serde(default)]
+    edits: Vec<CommentEditRecord>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct CommentEditRecord {
+    body: String,
+    edited_at: SurrealDatetime,
+}
+
+impl From<Comment> for CommentRecord {
+    fn from(comment: Comment) -> Self {
+        Self {
+            id: None,
+            item: comment.item().to_string(),
+            author_id: comment.author_id().to_string(),
+            parent_id: comment.parent_id().map(|id| id.to_string()),
+            body: comment.body().to_string(),
+            created_at: SurrealDatetime::from(comment.created_at()),
+            edits: comment
+                .edits()
+                .iter()
+                .map(|edit| CommentEditRecord {
+                    body: edit.body.clone(),
+                    edited_at: SurrealDatetime::from(edit.edited_at),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl CommentRecord {
+    fn record_id(&self) -> Result<Id<Comment>> {
+        match &self.id {
+            Some(thing) => thing
+                .id
+                .to_raw()
+                .parse::<Id<Comment>>()
+                .map_err(|e| DatabaseError::QueryError(format!("Invalid comment ID: {:?}", e))),
+            None => Err(DatabaseError::QueryError("Comment record is missing its ID".into())),
+        }
+    }
+
+    fn into_comment(self, id: Id<Comment>) -> Result<Comment> {
+        let author_id = self
+            .author_id
+            .parse::<Id<Person>>()
+            .map_err(|e| DatabaseError::QueryError(format!("Invalid author ID: {:?}", e)))?;
+        let parent_id = self
+            .parent_id
+            .map(|parent_id| parent_id.parse::<Id<Comment>>())
+            .transpose()
+            .map_err(|e| DatabaseError::QueryError(format!("Invalid parent comment ID: {:?}", e)))?;
+        let item = if self.item.starts_with("project-") {
+            self.item
+                .parse::<Id<Project>>()
+                .map(SchedulableItem::Project)
+                .map_err(|e| DatabaseError::QueryError(format!("Invalid project ID: {:?}", e)))?
+        } else if self.item.starts_with("task-") {
+            self.item
+                .parse::<Id<Task>>()
+                .map(SchedulableItem::Task)
+                .map_err(|e| DatabaseError::QueryError(format!("Invalid task ID: {:?}", e)))?
+        } else {
+            return Err(DatabaseError::QueryError(format!("Unknown item type: {}", self.item)));
+        };
+        let edits = self
+            .edits
+            .into_iter()
+            .map(|edit| CommentEdit {
+                body: edit.body,
+                edited_at: edit.edited_at.0,
+            })
+            .collect();
+
+        Ok(CommentBuilder::new()
+            .with_id(id)
+            .with_item(item)
+            .with_author_id(author_id)
+            .with_parent_id(parent_id)
+            .with_body(&self.body)
+            .with_created_at(self.created_at.0)
+            .with_edits(edits)
+            .build())
+    }
+
+    fn into_stored_comment(self) -> Result<Comment> {
+        let id = self.record_id()?;
+        self.into_comment(id)
+    }
+}
+
+// Define the repository trait locally to avoid circular dependencies
+#[async_trait]
+pub trait CommentRepository {
+    async fn create(&self, comment: Comment) -> Result<()>;
+    async fn get_by_id(&self, id: Id<Comment>) -> Result<Option<Comment>>;
+    async fn get_all(&self) -> Result<Vec<Comment>>;
+    /// Comments left on one item, replies included, oldest first
+    async fn get_for_item(&self, item: &SchedulableItem) -> Result<Vec<Comment>>;
+    async fn update(&self, comment: Comment) -> Result<()>;
+    async fn delete(&self, id: Id<Comment>) -> Result<()>;
+}
+
+pub struct ProdCommentRepository {
+    db: Arc<Database>,
+}
+
+impl ProdCommentRepository {
+    pub fn new(db: Arc<Database>) -> Self {
+        Self { db }
+    }
+
+    async fn query_comments(&self, query: SelectQuery) -> Result<Vec<Comment>> {
+        let records: Vec<CommentRecord> = query.fetch(&self.db).await?;
+
+        records
+            .into_iter()
+            .map(CommentRecord::into_stored_comment)
+            .collect::<Result<Vec<_>>>()
+    }
+}
+
+#[async_trait]
+impl CommentRepository for ProdCommentRepository {
+    async fn create(&self, comment: Comment) -> Result<()> {
+        let comment_id = comment.id();
+        debug!("Creating comment with ID: {}", comment_id);
+
+        let id_str = comment_id.to_string();
+        let comment_record = CommentRecord::from(comment);
+
+        let result: Result<Option<CommentRecord>> = self
+            .db
+            .client()
+            .create(("comment", id_str.as_str()))
+            .content(comment_record)
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to create comment: {}", e)));
+
+        match result {
+            Ok(_) => {
+                debug!("Successfully created comment with ID: {}", comment_id);
+                Ok(())
+            }
+            Err(e) => {
+                error!("Failed to create comment: {:?}", e);
+                Err(e)
+            }
+        }
+    }
+
+    async fn get_by_id(&self, id: Id<Comment>) -> Result<Option<Comment>> {
+        debug!("Fetching comment with ID: {}", id);
+
+        let id_str = id.to_string();
+
+        let result: Result<Option<CommentRecord>> = self
+            .db
+            .client()
+            .select(("comment", id_str.as_str()))
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to get comment by ID: {}", e)));
+
+        match result {
+            Ok(Some(comment_record)) => {
+                debug!("Found comment record with ID: {}", id);
+                comment_record.into_comment(id).map(Some)
+            }
+            Ok(None) => {
+                debug!("No comment found with ID: {}", id);
+                Ok(None)
+            }
+            Err(e) => {
+                error!("Failed to get comment by ID: {:?}", e);
+                Err(e)
+            }
+        }
+    }
+
+    async fn get_all(&self) -> Result<Vec<Comment>> {
+        debug!("Fetching all comments");
+
+        let result: Result<Vec<CommentRecord>> = self
+            .db
+            .client()
+            .select("comment")
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to get all comments: {}", e)));
+
+        match result {
+            Ok(records) => {
+                let comments = records
+                    .into_iter()
+                    .map(CommentRecord::into_stored_comment)
+                    .collect::<Result<Vec<_>>>()?;
+                debug!("Found {} comments", comments.len());
+                Ok(comments)
+            }
+            Err(e) => {
+                error!("Failed to get all comments: {:?}", e);
+                Err(e)
+            }
+        }
+    }
+
+    async fn get_for_item(&self, item: &SchedulableItem) -> Result<Vec<Comment>> {
+        debug!("Fetching comments for item: {}", item);
+
+        let comments = self
+            .query_comments(
+                SelectQuery::table("comment")
+                    .where_eq("item", item.to_string())
+                    .order_by("created_at", Order::Asc),
+            )
+            .await?;
+        debug!("Found {} comments for item: {}", comments.len(), item);
+        Ok(comments)
+    }
+
+    async fn update(&self, comment: Comment) -> Result<()> {
+        let comment_id = comment.id();
+        debug!("Updating comment with ID: {}", comment_id);
+
+        let id_str = comment_id.to_string();
+        let comment_record = CommentRecord::from(comment);
+
+        let result: Result<Option<CommentRecord>> = self
+            .db
+            .client()
+            .update(("comment", id_str.as_str()))
+            .content(comment_record)
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to update comment: {}", e)));
+
+        match result {
+            Ok(Some(_)) => {
+                debug!("Successfully updated comment with ID: {}", comment_id);
+                Ok(())
+            }
+            Ok(None) => {
+                error!("Comment not found for update: {}", comment_id);
+                Err(DatabaseError::QueryError(format!(
+                    "Comment with ID {} not found",
+                    comment_id
+                )))
+            }
+            Err(e) => {
+                error!("Failed to update comment: {:?}", e);
+                Err(e)
+            }
+        }
+    }
+
+    async fn delete(&self, id: Id<Comment>) -> Result<()> {
+        debug!("Deleting comment with ID: {}", id);
+
+        let id_str = id.to_string();
+
+        let result: Result<Option<CommentRecord>> = self
+            .db
+            .client()
+            .delete(("comment", id_str.as_str()))
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to delete comment: {}", e)));
+
+        match result {
+            Ok(Some(_)) => {
+                debug!("Successfully deleted comment with ID: {}", id);
+                Ok(())
+            }
+            Ok(None) => {
+                error!("Comment not found for deletion: {}", id);
+                Err(DatabaseError::QueryError(format!(
+                    "Comment with ID {} not found",
+                    id
+                )))
+            }
+            Err(e) => {
+                error!("Failed to delete comment: {:?}", e);
+                Err(e)
+            }
+        }
+    }
+}
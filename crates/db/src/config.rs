@@ -0,0 +1,201 @@
+use std::{env, fmt, path::PathBuf, str::FromStr};
+
+use crate::{DatabaseError, Result, relations::DeletePolicy};
+
+/// Storage engine the database runs on, picked from the scheme of its URL
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum DatabaseEngine {
+    /// `mem://`, lost when the process exits
+    Memory,
+    /// `surrealkv://path`, embedded on-disk storage
+    SurrealKv(PathBuf),
+    /// `rocksdb://path`, embedded on-disk storage, needs the `rocksdb` feature
+    RocksDb(PathBuf),
+    /// `ws://host:port` or `wss://host:port`, a running SurrealDB server
+    Remote(String),
+}
+
+impl DatabaseEngine {
+    /// Address handed to the SurrealDB client
+    pub fn endpoint(&self) -> String {
+        match self {
+            DatabaseEngine::Memory => "mem://".into(),
+            DatabaseEngine::SurrealKv(path) => format!("surrealkv://{}", path.display()),
+            DatabaseEngine::RocksDb(path) => format!("rocksdb://{}", path.display()),
+            DatabaseEngine::Remote(url) => url.clone(),
+        }
+    }
+
+    pub fn is_embedded(&self) -> bool {
+        !matches!(self, DatabaseEngine::Remote(_))
+    }
+}
+
+impl FromStr for DatabaseEngine {
+    type Err = DatabaseError;
+
+    fn from_str(url: &str) -> Result<Self> {
+        let url = url.trim();
+        let (scheme, rest) = url
+            .split_once("://")
+            .ok_or_else(|| DatabaseError::ConnectionError(format!("Database URL {url} has no scheme")))?;
+
+        match scheme {
+            "mem" | "memory" => Ok(DatabaseEngine::Memory),
+            "surrealkv" | "file" if !rest.is_empty() => Ok(DatabaseEngine::SurrealKv(PathBuf::from(rest))),
+            "rocksdb" if !rest.is_empty() => Ok(DatabaseEngine::RocksDb(PathBuf::from(rest))),
+            "ws" | "wss" if !rest.is_empty() => Ok(DatabaseEngine::Remote(url.to_string())),
+            "surrealkv" | "file" | "rocksdb" | "ws" | "wss" => Err(DatabaseError::ConnectionError(format!(
+                "Database URL {url} is missing its path or host"
+            ))),
+            _ => Err(DatabaseError::ConnectionError(format!(
+                "Unsupported database URL {url}, expected mem://, surrealkv://, rocksdb://, ws:// or wss://"
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for DatabaseEngine {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.endpoint())
+    }
+}
+
+/// Root user signed in after connecting
+#[derive(Clone, PartialEq, Eq)]
+pub struct Credentials {
+    pub username: String,
+    pub password: String,
+}
+
+impl fmt::Debug for Credentials {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Credentials {{ username: {:?}, password: \"***\" }}", self.username)
+    }
+}
+
+/// Where the database lives and which namespace and database to use inside it.
+///
+/// Read from the environment with `from_env`:
+/// - `DATABASE_URL`, e.g. `mem://`, `surrealkv://data/tracker.db` or `ws://localhost:8000`
+/// - `DATABASE_NAMESPACE` and `DATABASE_NAME`
+/// - `DATABASE_USER` and `DATABASE_PASSWORD`, both needed to sign in
+/// - `DATABASE_DELETE_POLICY`, `restrict`, `cascade` or `detach` (the default)
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct DatabaseConfig {
+    engine: DatabaseEngine,
+    namespace: String,
+    database: String,
+    credentials: Option<Credentials>,
+    delete_policy: DeletePolicy,
+}
+
+impl Default for DatabaseConfig {
+    fn default() -> Self {
+        Self {
+            engine: DatabaseEngine::Memory,
+            namespace: "namespace".into(),
+            database: "database".into(),
+            credentials: None,
+            delete_policy: DeletePolicy::default(),
+        }
+    }
+}
+
+impl DatabaseConfig {
+    pub fn new(engine: DatabaseEngine) -> Self {
+        Self {
+            engine,
+            ..Self::default()
+        }
+    }
+
+    /// Builds the configuration from `DATABASE_*` variables, defaulting to an in-memory database
+    pub fn from_env() -> Result<Self> {
+        Self::from_lookup(|key| env::var(key).ok())
+    }
+
+    /// Same as `from_env`, reading the variables through `lookup`
+    pub fn from_lookup(lookup: impl Fn(&str) -> Option<String>) -> Result<Self> {
+        let lookup = |key: &str| lookup(key).filter(|value| !value.trim().is_empty());
+
+        let mut config = match lookup("DATABASE_URL") {
+            Some(url) => Self::new(url.parse()?),
+            None => Self::default(),
+        };
+        if let Some(namespace) = lookup("DATABASE_NAMESPACE") {
+            config = config.with_namespace(namespace);
+        }
+        if let Some(database) = lookup("DATABASE_NAME") {
+            config = config.with_database(database);
+        }
+        match (lookup("DATABASE_USER"), lookup("DATABASE_PASSWORD")) {
+            (Some(username), Some(password)) => config = config.with_credentials(username, password),
+            (None, None) => (),
+            _ => {
+                return Err(DatabaseError::ConnectionError(
+                    "DATABASE_USER and DATABASE_PASSWORD must be set together".into(),
+                ));
+            }
+        }
+        if let Some(policy) = lookup("DATABASE_DELETE_POLICY") {
+            config = config.with_delete_policy(parse_delete_policy(&policy)?);
+        }
+        Ok(config)
+    }
+
+    pub fn with_namespace(mut self, namespace: impl Into<String>) -> Self {
+        self.namespace = namespace.into();
+        self
+    }
+
+    pub fn with_database(mut self, database: impl Into<String>) -> Self {
+        self.database = database.into();
+        self
+    }
+
+    pub fn with_credentials(mut self, username: impl Into<String>, password: impl Into<String>) -> Self {
+        self.credentials = Some(Credentials {
+            username: username.into(),
+            password: password.into(),
+        });
+        self
+    }
+
+    /// What deleting a project or task does to the items linked to it
+    pub fn with_delete_policy(mut self, delete_policy: DeletePolicy) -> Self {
+        self.delete_policy = delete_policy;
+        self
+    }
+
+    pub fn engine(&self) -> &DatabaseEngine {
+        &self.engine
+    }
+
+    pub fn namespace(&self) -> &str {
+        &self.namespace
+    }
+
+    pub fn database(&self) -> &str {
+        &self.database
+    }
+
+    pub fn credentials(&self) -> Option<&Credentials> {
+        self.credentials.as_ref()
+    }
+
+    pub fn delete_policy(&self) -> DeletePolicy {
+        self.delete_policy
+    }
+}
+
+fn parse_delete_policy(value: &str) -> Result<DeletePolicy> {
+    match value.trim().to_ascii_lowercase().as_str() {
+        "restrict" => Ok(DeletePolicy::Restrict),
+        "cascade" => Ok(DeletePolicy::Cascade),
+        "detach" => Ok(DeletePolicy::Detach),
+        _ => Err(DatabaseError::ConnectionError(format!(
+            "Unsupported delete policy {value}, expected restrict, cascade or detach"
+        ))),
+    }
+}
@@ -1,46 +1,54 @@
 use surrealdb::{
     Surreal,
-    engine::local::{Mem,Db},
-    // opt::auth::Root
+    engine::any::{self, Any},
+    opt::auth::Root,
 };
-use crate::{Result, DatabaseError};
+use crate::{Result, DatabaseError, config::{DatabaseConfig, DatabaseEngine}};
 
 pub struct Database {
-    client: Surreal<Db>
+    client: Surreal<Any>
 }
 
 impl Database {
+    /// Connects to a fresh in-memory database
     pub async fn connect() -> Result<Self> {
-        // memory:// for in-memory DB
-        let client = Surreal::new::<Mem>(()).await.map_err(|_| DatabaseError::ConnectionError("Could not start SurrealDB client".into()))?;
-
-        // client.signin(Root { // Not Relevant for in Mem DB
-        //     username: "root",
-        //     password: "root",
-        // }).await?;
-
-        client.use_ns("namespace").use_db("database").await.map_err(|_| DatabaseError::ConnectionError("Could not connect to namespace".into()))?;
+        Self::connect_with(&DatabaseConfig::default()).await
+    }
 
-        let db = Self {client};
-        db.initialise_schema().await?;
+    /// Connects to the engine `config` points at and brings its schema up to date
+    pub async fn connect_with(config: &DatabaseConfig) -> Result<Self> {
+        let db = Self::open(config).await?;
+        db.migrate().await?;
 
         Ok(db)
     }
 
-    pub async fn initialise_schema(&self) -> Result<()> {
-        // Fix the hardcoded path - use relative path from the crate root
-        let project_schema = include_str!("../schemas/project.surql");
-        let task_schema = include_str!("../schemas/task.surql");
-        
-        self.client.query(project_schema).await
-            .map_err(|e| DatabaseError::SchemaError(format!("Failed to initialize project schema: {}", e)))?;
-        self.client.query(task_schema).await
-            .map_err(|e| DatabaseError::SchemaError(format!("Failed to initialize task schema: {}", e)))?;
-        
-        Ok(())
+    /// Connects to the engine `config` points at, signing in when it carries credentials,
+    /// without running any migration
+    pub async fn open(config: &DatabaseConfig) -> Result<Self> {
+        if matches!(config.engine(), DatabaseEngine::RocksDb(_)) && !cfg!(feature = "rocksdb") {
+            return Err(DatabaseError::ConnectionError(
+                "RocksDB storage needs project-tracker-db built with the `rocksdb` feature".into(),
+            ));
+        }
+
+        let client = any::connect(config.engine().endpoint()).await.map_err(|e| {
+            DatabaseError::ConnectionError(format!("Could not start SurrealDB client for {}: {}", config.engine(), e))
+        })?;
+
+        if let Some(credentials) = config.credentials() {
+            client.signin(Root {
+                username: &credentials.username,
+                password: &credentials.password,
+            }).await.map_err(|_| DatabaseError::LoginFail)?;
+        }
+
+        client.use_ns(config.namespace()).use_db(config.database()).await.map_err(|_| DatabaseError::ConnectionError("Could not connect to namespace".into()))?;
+
+        Ok(Self {client})
     }
-    
-    pub fn client(&self) -> &Surreal<Db> {
+
+    pub fn client(&self) -> &Surreal<Any> {
         &self.client
     }
 }
\ No newline at end of file
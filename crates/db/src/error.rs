@@ -8,6 +8,8 @@ pub enum DatabaseError { // To be improved later
     ConnectionError(String),
     SchemaError(String),
     QueryError(String),
+    /// A write would leave a reference to a record that does not exist
+    IntegrityError(String),
     // etc.
     Multiple(Vec<DatabaseError>)
 }
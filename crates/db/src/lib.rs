@@ -1,6 +1,17 @@
 pub use self::error::{DatabaseError,Result};
 
+pub mod config;
 pub mod database;
+pub mod migrations;
+pub mod query;
+pub mod relations;
 pub mod error;
 pub mod project_repository;
-pub mod task_repository;
\ No newline at end of file
+pub mod task_repository;
+pub mod tag_repository;
+pub mod person_repository;
+pub mod status_transition_repository;
+pub mod milestone_repository;
+pub mod time_entry_repository;
+pub mod comment_repository;
+pub mod audit_repository;
@@ -0,0 +1,180 @@
+use std::{collections::BTreeMap, fmt};
+
+use chrono::{DateTime, Utc};
+use log::{debug, error, info};
+use serde::Deserialize;
+use surrealdb::sql::Datetime as SurrealDatetime;
+
+use crate::{DatabaseError, Result, database::Database};
+
+/// A numbered schema change, applied once and in order
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Migration {
+    pub version: u32,
+    pub name: &'static str,
+    pub script: &'static str,
+}
+
+impl Migration {
+    const fn new(version: u32, name: &'static str, script: &'static str) -> Self {
+        Self { version, name, script }
+    }
+}
+
+/// Every migration shipped with the crate, oldest first.
+/// New migrations go at the end with the next version; applied ones must never be edited.
+pub const MIGRATIONS: &[Migration] = &[
+    Migration::new(1, "create_project", include_str!("../migrations/0001_create_project.surql")),
+    Migration::new(2, "create_task", include_str!("../migrations/0002_create_task.surql")),
+    Migration::new(3, "create_tag", include_str!("../migrations/0003_create_tag.surql")),
+    Migration::new(4, "create_person", include_str!("../migrations/0004_create_person.surql")),
+    Migration::new(5, "create_status_transition", include_str!("../migrations/0005_create_status_transition.surql")),
+    Migration::new(6, "create_milestone", include_str!("../migrations/0006_create_milestone.surql")),
+    Migration::new(7, "create_time_entry", include_str!("../migrations/0007_create_time_entry.surql")),
+    Migration::new(8, "create_comment", include_str!("../migrations/0008_create_comment.surql")),
+    Migration::new(9, "create_audit", include_str!("../migrations/0009_create_audit.surql")),
+    Migration::new(10, "create_item_links", include_str!("../migrations/0010_create_item_links.surql")),
+    Migration::new(11, "add_task_series_dates", include_str!("../migrations/0011_add_task_series_dates.surql")),
+];
+
+const MIGRATIONS_TABLE: &str = "
+DEFINE TABLE IF NOT EXISTS _migrations SCHEMAFULL PERMISSIONS NONE;
+DEFINE FIELD IF NOT EXISTS version ON _migrations TYPE int;
+DEFINE FIELD IF NOT EXISTS name ON _migrations TYPE string;
+DEFINE FIELD IF NOT EXISTS applied_at ON _migrations TYPE datetime;
+";
+
+/// Where one migration stands against the connected database
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct MigrationStatus {
+    pub version: u32,
+    pub name: &'static str,
+    pub applied_at: Option<DateTime<Utc>>,
+}
+
+impl MigrationStatus {
+    pub fn is_applied(&self) -> bool {
+        self.applied_at.is_some()
+    }
+}
+
+impl fmt::Display for MigrationStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.applied_at {
+            Some(applied_at) => write!(f, "{:04} {} applied on {}", self.version, self.name, applied_at),
+            None => write!(f, "{:04} {} pending", self.version, self.name),
+        }
+    }
+}
+
+// DTO for reading back `_migrations`, which is only ever written by `migrate_with`
+#[derive(Deserialize, Debug)]
+struct MigrationRecord {
+    version: u32,
+    name: String,
+    applied_at: SurrealDatetime,
+}
+
+impl Database {
+    /// Applies every migration not recorded in `_migrations` yet, each in its own transaction,
+    /// returning the versions that ran
+    pub async fn migrate(&self) -> Result<Vec<u32>> {
+        self.migrate_with(MIGRATIONS).await
+    }
+
+    /// Applies pending migrations out of `migrations`, which must be ordered by version
+    pub async fn migrate_with(&self, migrations: &[Migration]) -> Result<Vec<u32>> {
+        let applied = self.applied_migrations(migrations).await?;
+
+        let mut ran = Vec::new();
+        for migration in migrations.iter().filter(|migration| !applied.contains_key(&migration.version)) {
+            debug!("Applying migration {:04} {}", migration.version, migration.name);
+
+            let script = format!(
+                "BEGIN TRANSACTION;\n{};\nCREATE type::thing('_migrations', $version) CONTENT {{ version: $version, name: $name, applied_at: time::now() }};\nCOMMIT TRANSACTION;",
+                migration.script.trim().trim_end_matches(';')
+            );
+            let result = match self
+                .client()
+                .query(script)
+                .bind(("version", migration.version))
+                .bind(("name", migration.name))
+                .await
+            {
+                Ok(response) => response.check().map(|_| ()),
+                Err(e) => Err(e),
+            };
+
+            match result {
+                Ok(_) => {
+                    info!("Applied migration {:04} {}", migration.version, migration.name);
+                    ran.push(migration.version);
+                }
+                Err(e) => {
+                    error!("Failed to apply migration {:04} {}: {:?}", migration.version, migration.name, e);
+                    return Err(DatabaseError::SchemaError(format!(
+                        "Failed to apply migration {:04} {}: {}",
+                        migration.version, migration.name, e
+                    )));
+                }
+            }
+        }
+        Ok(ran)
+    }
+
+    /// Every shipped migration with when it was applied, if it was
+    pub async fn migration_status(&self) -> Result<Vec<MigrationStatus>> {
+        self.migration_status_with(MIGRATIONS).await
+    }
+
+    pub async fn migration_status_with(&self, migrations: &[Migration]) -> Result<Vec<MigrationStatus>> {
+        let applied = self.applied_migrations(migrations).await?;
+
+        Ok(migrations
+            .iter()
+            .map(|migration| MigrationStatus {
+                version: migration.version,
+                name: migration.name,
+                applied_at: applied.get(&migration.version).copied(),
+            })
+            .collect())
+    }
+
+    /// When each recorded migration was applied, after checking the records match `migrations`
+    async fn applied_migrations(&self, migrations: &[Migration]) -> Result<BTreeMap<u32, DateTime<Utc>>> {
+        self.client()
+            .query(MIGRATIONS_TABLE)
+            .await
+            .map_err(|e| DatabaseError::SchemaError(format!("Failed to define migrations table: {}", e)))?
+            .check()
+            .map_err(|e| DatabaseError::SchemaError(format!("Failed to define migrations table: {}", e)))?;
+
+        let records: Vec<MigrationRecord> = self
+            .client()
+            .select("_migrations")
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to read applied migrations: {}", e)))?;
+
+        let mut applied = BTreeMap::new();
+        for record in records {
+            match migrations.iter().find(|migration| migration.version == record.version) {
+                Some(migration) if migration.name == record.name => {
+                    applied.insert(record.version, record.applied_at.0);
+                }
+                Some(migration) => {
+                    return Err(DatabaseError::SchemaError(format!(
+                        "Migration {:04} was applied as {} but is now named {}",
+                        record.version, record.name, migration.name
+                    )));
+                }
+                None => {
+                    return Err(DatabaseError::SchemaError(format!(
+                        "Database has migration {:04} {} that this build does not know about",
+                        record.version, record.name
+                    )));
+                }
+            }
+        }
+        Ok(applied)
+    }
+}
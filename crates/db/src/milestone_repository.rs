@@ -0,0 +1,257 @@
+use crate::{DatabaseError, Result, database::Database};
+use async_trait::async_trait;
+use log::{debug, error};
+use project_tracker_core::{
+    HasId,
+    builders::milestone_builder::MilestoneBuilder,
+    id::Id,
+    models::{milestone::Milestone, project::Project, schedulable::SchedulableItem, task::Task},
+};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use surrealdb::sql::{Datetime as SurrealDatetime, Thing};
+
+// DTO for database operations (id is only read back, since SurrealDB manages it)
+#[derive(Serialize, Deserialize, Debug)]
+struct MilestoneRecord {
+    #[serde(default, skip_serializing)]
+    id: Option<Thing>,
+    name: String,
+    description: Option<String>,
+    target_date: SurrealDatetime,
+    items: Vec<String>,
+    reached_at: Option<SurrealDatetime>,
+}
+
+impl From<Milestone> for MilestoneRecord {
+    fn from(milestone: Milestone) -> Self {
+        Self {
+            id: None,
+            name: milestone.name().to_string(),
+            description: milestone.has_description().then(|| milestone.description().to_string()),
+            target_date: SurrealDatetime::from(milestone.target_date()),
+            items: milestone.items().iter().map(|item| item.to_string()).collect(),
+            reached_at: milestone.reached_at().map(SurrealDatetime::from),
+        }
+    }
+}
+
+impl MilestoneRecord {
+    fn record_id(&self) -> Result<Id<Milestone>> {
+        match &self.id {
+            Some(thing) => thing
+                .id
+                .to_raw()
+                .parse::<Id<Milestone>>()
+                .map_err(|e| DatabaseError::QueryError(format!("Invalid milestone ID: {:?}", e))),
+            None => Err(DatabaseError::QueryError("Milestone record is missing its ID".into())),
+        }
+    }
+
+    fn into_milestone(self, id: Id<Milestone>) -> Result<Milestone> {
+        let items = self
+            .items
+            .into_iter()
+            .map(|item_str| {
+                if item_str.starts_with("project-") {
+                    item_str
+                        .parse::<Id<Project>>()
+                        .map(SchedulableItem::Project)
+                        .map_err(|e| DatabaseError::QueryError(format!("Invalid project item ID: {:?}", e)))
+                } else if item_str.starts_with("task-") {
+                    item_str
+                        .parse::<Id<Task>>()
+                        .map(SchedulableItem::Task)
+                        .map_err(|e| DatabaseError::QueryError(format!("Invalid task item ID: {:?}", e)))
+                } else {
+                    Err(DatabaseError::QueryError(format!("Unknown item type: {}", item_str)))
+                }
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut builder = MilestoneBuilder::new()
+            .with_id(id)
+            .with_name(&self.name)
+            .with_target_date(self.target_date.0)
+            .with_items(items)
+            .with_reached_at(self.reached_at.map(|date| date.0));
+        if let Some(description) = &self.description {
+            builder = builder.with_description(description);
+        }
+        Ok(builder.build())
+    }
+
+    fn into_stored_milestone(self) -> Result<Milestone> {
+        let id = self.record_id()?;
+        self.into_milestone(id)
+    }
+}
+
+// Define the repository trait locally to avoid circular dependencies
+#[async_trait]
+pub trait MilestoneRepository {
+    async fn create(&self, milestone: Milestone) -> Result<()>;
+    async fn get_by_id(&self, id: Id<Milestone>) -> Result<Option<Milestone>>;
+    async fn get_all(&self) -> Result<Vec<Milestone>>;
+    async fn update(&self, milestone: Milestone) -> Result<()>;
+    async fn delete(&self, id: Id<Milestone>) -> Result<()>;
+}
+
+pub struct ProdMilestoneRepository {
+    db: Arc<Database>,
+}
+
+impl ProdMilestoneRepository {
+    pub fn new(db: Arc<Database>) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl MilestoneRepository for ProdMilestoneRepository {
+    async fn create(&self, milestone: Milestone) -> Result<()> {
+        let milestone_id = milestone.id();
+        debug!("Creating milestone with ID: {}", milestone_id);
+
+        let id_str = milestone_id.to_string();
+        let milestone_record = MilestoneRecord::from(milestone);
+
+        let result: Result<Option<MilestoneRecord>> = self
+            .db
+            .client()
+            .create(("milestone", id_str.as_str()))
+            .content(milestone_record)
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to create milestone: {}", e)));
+
+        match result {
+            Ok(_) => {
+                debug!("Successfully created milestone with ID: {}", milestone_id);
+                Ok(())
+            }
+            Err(e) => {
+                error!("Failed to create milestone: {:?}", e);
+                Err(e)
+            }
+        }
+    }
+
+    async fn get_by_id(&self, id: Id<Milestone>) -> Result<Option<Milestone>> {
+        debug!("Fetching milestone with ID: {}", id);
+
+        let id_str = id.to_string();
+
+        let result: Result<Option<MilestoneRecord>> = self
+            .db
+            .client()
+            .select(("milestone", id_str.as_str()))
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to get milestone by ID: {}", e)));
+
+        match result {
+            Ok(Some(milestone_record)) => {
+                debug!("Found milestone record with ID: {}", id);
+                milestone_record.into_milestone(id).map(Some)
+            }
+            Ok(None) => {
+                debug!("No milestone found with ID: {}", id);
+                Ok(None)
+            }
+            Err(e) => {
+                error!("Failed to get milestone by ID: {:?}", e);
+                Err(e)
+            }
+        }
+    }
+
+    async fn get_all(&self) -> Result<Vec<Milestone>> {
+        debug!("Fetching all milestones");
+
+        let result: Result<Vec<MilestoneRecord>> = self
+            .db
+            .client()
+            .select("milestone")
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to get all milestones: {}", e)));
+
+        match result {
+            Ok(records) => {
+                let milestones = records
+                    .into_iter()
+                    .map(MilestoneRecord::into_stored_milestone)
+                    .collect::<Result<Vec<_>>>()?;
+                debug!("Found {} milestones", milestones.len());
+                Ok(milestones)
+            }
+            Err(e) => {
+                error!("Failed to get all milestones: {:?}", e);
+                Err(e)
+            }
+        }
+    }
+
+    async fn update(&self, milestone: Milestone) -> Result<()> {
+        let milestone_id = milestone.id();
+        debug!("Updating milestone with ID: {}", milestone_id);
+
+        let id_str = milestone_id.to_string();
+        let milestone_record = MilestoneRecord::from(milestone);
+
+        let result: Result<Option<MilestoneRecord>> = self
+            .db
+            .client()
+            .update(("milestone", id_str.as_str()))
+            .content(milestone_record)
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to update milestone: {}", e)));
+
+        match result {
+            Ok(Some(_)) => {
+                debug!("Successfully updated milestone with ID: {}", milestone_id);
+                Ok(())
+            }
+            Ok(None) => {
+                error!("Milestone not found for update: {}", milestone_id);
+                Err(DatabaseError::QueryError(format!(
+                    "Milestone with ID {} not found",
+                    milestone_id
+                )))
+            }
+            Err(e) => {
+                error!("Failed to update milestone: {:?}", e);
+                Err(e)
+            }
+        }
+    }
+
+    async fn delete(&self, id: Id<Milestone>) -> Result<()> {
+        debug!("Deleting milestone with ID: {}", id);
+
+        let id_str = id.to_string();
+
+        let result: Result<Option<MilestoneRecord>> = self
+            .db
+            .client()
+            .delete(("milestone", id_str.as_str()))
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to delete milestone: {}", e)));
+
+        match result {
+            Ok(Some(_)) => {
+                debug!("Successfully deleted milestone with ID: {}", id);
+                Ok(())
+            }
+            Ok(None) => {
+                error!("Milestone not found for deletion: {}", id);
+                Err(DatabaseError::QueryError(format!(
+                    "Milestone with ID {} not found",
+                    id
+                )))
+            }
+            Err(e) => {
+                error!("Failed to delete milestone: {:?}", e);
+                Err(e)
+            }
+        }
+    }
+}
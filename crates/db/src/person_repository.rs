@@ -0,0 +1,221 @@
+use crate::{DatabaseError, Result, database::Database};
+use async_trait::async_trait;
+use log::{debug, error};
+use project_tracker_core::{HasId, builders::person_builder::PersonBuilder, id::Id, models::person::Person};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use surrealdb::sql::Thing;
+
+// DTO for database operations (id is only read back, since SurrealDB manages it)
+#[derive(Serialize, Deserialize, Debug)]
+struct PersonRecord {
+    #[serde(default, skip_serializing)]
+    id: Option<Thing>,
+    first_name: String,
+    last_name: String,
+}
+
+impl From<Person> for PersonRecord {
+    fn from(person: Person) -> Self {
+        Self {
+            id: None,
+            first_name: person.first_name().to_string(),
+            last_name: person.last_name().to_string(),
+        }
+    }
+}
+
+impl PersonRecord {
+    fn record_id(&self) -> Result<Id<Person>> {
+        match &self.id {
+            Some(thing) => thing
+                .id
+                .to_raw()
+                .parse::<Id<Person>>()
+                .map_err(|e| DatabaseError::QueryError(format!("Invalid person ID: {:?}", e))),
+            None => Err(DatabaseError::QueryError("Person record is missing its ID".into())),
+        }
+    }
+
+    fn into_person(self, id: Id<Person>) -> Person {
+        PersonBuilder::new()
+            .with_id(id)
+            .with_first_name(&self.first_name)
+            .with_last_name(&self.last_name)
+            .build()
+    }
+
+    fn into_stored_person(self) -> Result<Person> {
+        let id = self.record_id()?;
+        Ok(self.into_person(id))
+    }
+}
+
+// Define the repository trait locally to avoid circular dependencies
+#[async_trait]
+pub trait PersonRepository: Send + Sync {
+    async fn create(&self, person: Person) -> Result<()>;
+    async fn get_by_id(&self, id: Id<Person>) -> Result<Option<Person>>;
+    async fn get_all(&self) -> Result<Vec<Person>>;
+    async fn update(&self, person: Person) -> Result<()>;
+    async fn delete(&self, id: Id<Person>) -> Result<()>;
+}
+
+pub struct ProdPersonRepository {
+    db: Arc<Database>,
+}
+
+impl ProdPersonRepository {
+    pub fn new(db: Arc<Database>) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl PersonRepository for ProdPersonRepository {
+    async fn create(&self, person: Person) -> Result<()> {
+        let person_id = person.id();
+        debug!("Creating person with ID: {}", person_id);
+
+        let id_str = person_id.to_string();
+        let person_record = PersonRecord::from(person);
+
+        let result: Result<Option<PersonRecord>> = self
+            .db
+            .client()
+            .create(("person", id_str.as_str()))
+            .content(person_record)
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to create person: {}", e)));
+
+        match result {
+            Ok(_) => {
+                debug!("Successfully created person with ID: {}", person_id);
+                Ok(())
+            }
+            Err(e) => {
+                error!("Failed to create person: {:?}", e);
+                Err(e)
+            }
+        }
+    }
+
+    async fn get_by_id(&self, id: Id<Person>) -> Result<Option<Person>> {
+        debug!("Fetching person with ID: {}", id);
+
+        let id_str = id.to_string();
+
+        let result: Result<Option<PersonRecord>> = self
+            .db
+            .client()
+            .select(("person", id_str.as_str()))
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to get person by ID: {}", e)));
+
+        match result {
+            Ok(Some(person_record)) => {
+                debug!("Found person record with ID: {}", id);
+                Ok(Some(person_record.into_person(id)))
+            }
+            Ok(None) => {
+                debug!("No person found with ID: {}", id);
+                Ok(None)
+            }
+            Err(e) => {
+                error!("Failed to get person by ID: {:?}", e);
+                Err(e)
+            }
+        }
+    }
+
+    async fn get_all(&self) -> Result<Vec<Person>> {
+        debug!("Fetching all people");
+
+        let result: Result<Vec<PersonRecord>> = self
+            .db
+            .client()
+            .select("person")
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to get all people: {}", e)));
+
+        match result {
+            Ok(records) => {
+                let people = records
+                    .into_iter()
+                    .map(PersonRecord::into_stored_person)
+                    .collect::<Result<Vec<_>>>()?;
+                debug!("Found {} people", people.len());
+                Ok(people)
+            }
+            Err(e) => {
+                error!("Failed to get all people: {:?}", e);
+                Err(e)
+            }
+        }
+    }
+
+    async fn update(&self, person: Person) -> Result<()> {
+        let person_id = person.id();
+        debug!("Updating person with ID: {}", person_id);
+
+        let id_str = person_id.to_string();
+        let person_record = PersonRecord::from(person);
+
+        let result: Result<Option<PersonRecord>> = self
+            .db
+            .client()
+            .update(("person", id_str.as_str()))
+            .content(person_record)
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to update person: {}", e)));
+
+        match result {
+            Ok(Some(_)) => {
+                debug!("Successfully updated person with ID: {}", person_id);
+                Ok(())
+            }
+            Ok(None) => {
+                error!("Person not found for update: {}", person_id);
+                Err(DatabaseError::QueryError(format!(
+                    "Person with ID {} not found",
+                    person_id
+                )))
+            }
+            Err(e) => {
+                error!("Failed to update person: {:?}", e);
+                Err(e)
+            }
+        }
+    }
+
+    async fn delete(&self, id: Id<Person>) -> Result<()> {
+        debug!("Deleting person with ID: {}", id);
+
+        let id_str = id.to_string();
+
+        let result: Result<Option<PersonRecord>> = self
+            .db
+            .client()
+            .delete(("person", id_str.as_str()))
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to delete person: {}", e)));
+
+        match result {
+            Ok(Some(_)) => {
+                debug!("Successfully deleted person with ID: {}", id);
+                Ok(())
+            }
+            Ok(None) => {
+                error!("Person not found for deletion: {}", id);
+                Err(DatabaseError::QueryError(format!(
+                    "Person with ID {} not found",
+                    id
+                )))
+            }
+            Err(e) => {
+                error!("Failed to delete person: {:?}", e);
+                Err(e)
+            }
+        }
+    }
+}
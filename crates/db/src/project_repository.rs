@@ -1,4 +1,9 @@
-use crate::{DatabaseError, Result, database::Database};
+use crate::{
+    DatabaseError, Result,
+    database::Database,
+    query::SelectQuery,
+    relations::{DESCENDANTS, DeletePolicy, DeletedItems, ItemReferences, delete_item, item_thing, save_item, thing_item},
+};
 use async_trait::async_trait;
 use log::{debug, error};
 use project_tracker_core::{
@@ -6,34 +11,43 @@ use project_tracker_core::{
     builders::project_builder::ProjectBuilder,
     id::Id,
     models::{
+        estimate::Estimate,
         person::Person,
         project::Project,
-        schedulable::{Schedulable, SchedulableItem, SchedulableItemStatus},
+        schedulable::{Priority, Schedulable, SchedulableItem, SchedulableItemStatus},
         tag::Tag,
         task::Task,
     },
 };
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
-use surrealdb::sql::Datetime as SurrealDatetime;
+use surrealdb::sql::{Datetime as SurrealDatetime, Thing};
 
-// DTO for database operations (excludes id since SurrealDB manages it)
+// DTO for database operations (id is only read back, since SurrealDB manages it)
 #[derive(Serialize, Deserialize)]
-struct ProjectRecord {
+pub(crate) struct ProjectRecord {
+    #[serde(default, skip_serializing)]
+    id: Option<Thing>,
     name: String,
     owner_id: Option<String>,
     description: Option<String>,
     tags: Vec<String>,
     start_date: Option<SurrealDatetime>,
     due_date: Option<SurrealDatetime>,
-    children: Vec<String>, // Store as string array instead of enum
+    // Stored as `contains` and `depends_on` edges, and read back through computed fields
+    #[serde(default, skip_serializing)]
+    children: Vec<String>,
+    #[serde(default, skip_serializing)]
     dependencies: Vec<String>,
     status: String,
+    priority: String,
+    estimate: Option<String>,
 }
 
 impl From<Project> for ProjectRecord {
     fn from(project: Project) -> Self {
         Self {
+            id: None,
             name: project.name().to_string(),
             owner_id: project.owner_id().map(|id| id.clone().to_string()),
             description: if project.description().is_empty() {
@@ -58,11 +72,42 @@ impl From<Project> for ProjectRecord {
                 .map(|id| id.to_string())
                 .collect(),
             status: project.status().to_string(),
+            priority: project.priority().to_string(),
+            estimate: project.estimate().map(|estimate| estimate.to_string()),
         }
     }
 }
 
 impl ProjectRecord {
+    fn record_id(&self) -> Result<Id<Project>> {
+        match &self.id {
+            Some(thing) => thing
+                .id
+                .to_raw()
+                .parse::<Id<Project>>()
+                .map_err(|e| DatabaseError::QueryError(format!("Invalid project ID: {:?}", e))),
+            None => Err(DatabaseError::QueryError(
+                "Project record is missing its ID".into(),
+            )),
+        }
+    }
+
+    // Everything the record points at, checked to exist when it is saved
+    fn references(&self) -> ItemReferences {
+        ItemReferences {
+            owner_id: self.owner_id.clone(),
+            tags: self.tags.clone(),
+            children: self.children.clone(),
+            dependencies: self.dependencies.clone(),
+        }
+    }
+
+    /// Rebuilds a project read back from a query, taking its id from the SurrealDB record id
+    pub(crate) fn into_stored_project(self) -> Result<Project> {
+        let id = self.record_id()?;
+        self.into_project(id)
+    }
+
     fn into_project(self, id: Id<Project>) -> Result<Project> {
         let record = self;
         // Parse status
@@ -77,6 +122,15 @@ impl ProjectRecord {
             s => return Err(DatabaseError::QueryError(format!("Invalid status: {}", s))),
         };
 
+        // Parse priority
+        let priority = match record.priority.as_str() {
+            "Low" => Priority::Low,
+            "Medium" => Priority::Medium,
+            "High" => Priority::High,
+            "Critical" => Priority::Critical,
+            s => return Err(DatabaseError::QueryError(format!("Invalid priority: {}", s))),
+        };
+
         // Parse owner_id
         let owner_id = if let Some(owner_str) = record.owner_id {
             Some(
@@ -137,6 +191,7 @@ impl ProjectRecord {
             .with_id(id)
             .with_name(&record.name)
             .with_status(status)
+            .with_priority(priority)
             .with_tags(tags)
             .with_children(children)
             .with_dependencies(dependencies);
@@ -145,10 +200,10 @@ impl ProjectRecord {
             builder = builder.with_owner_id(Some(owner_id));
         }
 
-        if let Some(description) = record.description {
-            if !description.is_empty() {
-                builder = builder.with_description(&description);
-            }
+        if let Some(description) = record.description
+            && !description.is_empty()
+        {
+            builder = builder.with_description(&description);
         }
 
         if let Some(start_date) = record.start_date {
@@ -159,24 +214,58 @@ impl ProjectRecord {
             builder = builder.with_due_date(Some(due_date.into()));
         }
 
+        // Parse estimate
+        if let Some(estimate_str) = record.estimate {
+            let estimate = estimate_str
+                .parse::<Estimate>()
+                .map_err(|e| DatabaseError::QueryError(format!("Invalid estimate: {}", e)))?;
+            builder = builder.with_estimate(Some(estimate));
+        }
+
         Ok(builder.build())
     }
 }
 
 // Define the repository trait locally to avoid circular dependencies
 #[async_trait]
-pub trait ProjectRepository {
+pub trait ProjectRepository: Send + Sync {
     async fn create(&self, project: Project) -> Result<()>;
     async fn get_by_id(&self, id: Id<Project>) -> Result<Option<Project>>;
+    async fn get_all(&self) -> Result<Vec<Project>>;
+    async fn update(&self, project: Project) -> Result<()>;
+    /// Deletes the project under the repository's `DeletePolicy`, returning every record it removed or unlinked
+    async fn delete(&self, id: Id<Project>) -> Result<DeletedItems>;
+    /// Projects owned by `owner_id`
+    async fn get_by_owner(&self, owner_id: Id<Person>) -> Result<Vec<Project>>;
+    /// Project that lists `item` among its children, if any
+    async fn get_parent(&self, item: &SchedulableItem) -> Result<Option<Project>>;
+    /// Every project and task below `id`, following children all the way down
+    async fn get_descendants(&self, id: Id<Project>) -> Result<Vec<SchedulableItem>>;
+    /// Projects that depend on `id`, i.e. what is waiting on it
+    async fn get_dependents(&self, id: Id<Project>) -> Result<Vec<Project>>;
 }
 
 pub struct ProdProjectRepository {
     db: Arc<Database>,
+    delete_policy: DeletePolicy,
 }
 
 impl ProdProjectRepository {
     pub fn new(db: Arc<Database>) -> Self {
-        Self { db }
+        Self {
+            db,
+            delete_policy: DeletePolicy::default(),
+        }
+    }
+
+    /// What `delete` does to linked items, `DeletePolicy::Detach` unless set
+    pub fn with_delete_policy(mut self, delete_policy: DeletePolicy) -> Self {
+        self.delete_policy = delete_policy;
+        self
+    }
+
+    pub fn delete_policy(&self) -> DeletePolicy {
+        self.delete_policy
     }
 }
 
@@ -188,14 +277,8 @@ impl ProjectRepository for ProdProjectRepository {
 
         let id_str = project_id.to_string();
         let project_record = ProjectRecord::from(project);
-
-        let result: Result<Option<ProjectRecord>> = self
-            .db
-            .client()
-            .create(("project", id_str.as_str()))
-            .content(project_record)
-            .await
-            .map_err(|e| DatabaseError::QueryError(format!("Failed to create project: {}", e)));
+        let references = project_record.references();
+        let result = save_item(&self.db, Thing::from(("project", id_str.as_str())), project_record, true, references).await;
 
         match result {
             Ok(_) => {
@@ -245,31 +328,22 @@ impl ProjectRepository for ProdProjectRepository {
             }
         }
     }
-}
 
-// Additional repository methods that can be added in the future
-impl ProdProjectRepository {
-    #[allow(dead_code)]
-    pub async fn update(&self, project: Project) -> Result<()> {
+    async fn update(&self, project: Project) -> Result<()> {
         let project_id = project.id();
         debug!("Updating project with ID: {}", project_id);
 
         let id_str = project_id.to_string();
-
-        let result: Result<Option<Project>> = self
-            .db
-            .client()
-            .update(("project", id_str.as_str()))
-            .content(project)
-            .await
-            .map_err(|e| DatabaseError::QueryError(format!("Failed to update project: {}", e)));
+        let project_record = ProjectRecord::from(project);
+        let references = project_record.references();
+        let result = save_item(&self.db, Thing::from(("project", id_str.as_str())), project_record, false, references).await;
 
         match result {
-            Ok(Some(_)) => {
+            Ok(true) => {
                 debug!("Successfully updated project with ID: {}", project_id);
                 Ok(())
             }
-            Ok(None) => {
+            Ok(false) => {
                 error!("Project not found for update: {}", project_id);
                 Err(DatabaseError::QueryError(format!(
                     "Project with ID {} not found",
@@ -283,23 +357,14 @@ impl ProdProjectRepository {
         }
     }
 
-    #[allow(dead_code)]
-    pub async fn delete(&self, id: Id<Project>) -> Result<()> {
+    async fn delete(&self, id: Id<Project>) -> Result<DeletedItems> {
         debug!("Deleting project with ID: {}", id);
 
         let id_str = id.to_string();
-
-        let result: Result<Option<Project>> = self
-            .db
-            .client()
-            .delete(("project", id_str.as_str()))
-            .await
-            .map_err(|e| DatabaseError::QueryError(format!("Failed to delete project: {}", e)));
-
-        match result {
-            Ok(Some(_)) => {
+        match delete_item(&self.db, Thing::from(("project", id_str.as_str())), self.delete_policy).await {
+            Ok(Some(deleted)) => {
                 debug!("Successfully deleted project with ID: {}", id);
-                Ok(())
+                Ok(deleted)
             }
             Ok(None) => {
                 error!("Project not found for deletion: {}", id);
@@ -315,17 +380,20 @@ impl ProdProjectRepository {
         }
     }
 
-    #[allow(dead_code)]
-    pub async fn get_all(&self) -> Result<Vec<Project>> {
+    async fn get_all(&self) -> Result<Vec<Project>> {
         debug!("Fetching all projects");
 
-        let result: Result<Vec<Project>> =
+        let result: Result<Vec<ProjectRecord>> =
             self.db.client().select("project").await.map_err(|e| {
                 DatabaseError::QueryError(format!("Failed to get all projects: {}", e))
             });
 
         match result {
-            Ok(projects) => {
+            Ok(records) => {
+                let projects = records
+                    .into_iter()
+                    .map(ProjectRecord::into_stored_project)
+                    .collect::<Result<Vec<_>>>()?;
                 debug!("Found {} projects", projects.len());
                 Ok(projects)
             }
@@ -336,28 +404,78 @@ impl ProdProjectRepository {
         }
     }
 
-    #[allow(dead_code)]
-    pub async fn get_by_owner(
-        &self,
-        owner_id: Id<project_tracker_core::models::person::Person>,
-    ) -> Result<Vec<Project>> {
+    async fn get_by_owner(&self, owner_id: Id<Person>) -> Result<Vec<Project>> {
         debug!("Fetching projects for owner: {}", owner_id);
 
-        let query = format!(
-            "SELECT * FROM project WHERE owner_id = '{}'",
-            owner_id.to_string()
-        );
-
-        let mut response = self.db.client().query(query).await.map_err(|e| {
-            DatabaseError::QueryError(format!("Failed to get projects by owner: {}", e))
-        })?;
-
-        let projects: Vec<Project> = response.take(0).map_err(|e| {
-            DatabaseError::QueryError(format!("Failed to parse projects by owner: {}", e))
-        })?;
+        let records: Vec<ProjectRecord> = SelectQuery::table("project")
+            .where_eq("owner_id", owner_id.to_string())
+            .fetch(&self.db)
+            .await?;
+        let projects = records
+            .into_iter()
+            .map(ProjectRecord::into_stored_project)
+            .collect::<Result<Vec<_>>>()?;
 
         debug!("Found {} projects for owner: {}", projects.len(), owner_id);
         Ok(projects)
     }
+
+    async fn get_parent(&self, item: &SchedulableItem) -> Result<Option<Project>> {
+        debug!("Fetching parent project of: {}", item);
+
+        let projects = self
+            .query_projects("SELECT * FROM $item<-contains<-project", item_thing(&item.to_string())?)
+            .await?;
+        Ok(projects.into_iter().next())
+    }
+
+    async fn get_descendants(&self, id: Id<Project>) -> Result<Vec<SchedulableItem>> {
+        debug!("Fetching descendants of project: {}", id);
+
+        let mut response = self
+            .db
+            .client()
+            .query(DESCENDANTS)
+            .bind(("item", Thing::from(("project", id.to_string().as_str()))))
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to get descendants: {}", e)))?;
+        let things: Vec<Thing> = response
+            .take(0)
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to parse descendants: {}", e)))?;
+
+        let descendants = things.iter().map(thing_item).collect::<Result<Vec<_>>>()?;
+        debug!("Found {} descendants of project: {}", descendants.len(), id);
+        Ok(descendants)
+    }
+
+    async fn get_dependents(&self, id: Id<Project>) -> Result<Vec<Project>> {
+        debug!("Fetching dependents of project: {}", id);
+
+        self.query_projects(
+            "SELECT * FROM $item<-depends_on<-project",
+            Thing::from(("project", id.to_string().as_str())),
+        )
+        .await
+    }
+}
+
+impl ProdProjectRepository {
+    async fn query_projects(&self, query: &'static str, item: Thing) -> Result<Vec<Project>> {
+        let mut response = self
+            .db
+            .client()
+            .query(query)
+            .bind(("item", item))
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to get projects: {}", e)))?;
+        let records: Vec<ProjectRecord> = response
+            .take(0)
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to parse projects: {}", e)))?;
+
+        records
+            .into_iter()
+            .map(ProjectRecord::into_stored_project)
+            .collect::<Result<Vec<_>>>()
+    }
 }
 
@@ -0,0 +1,147 @@
+use core::fmt;
+
+use serde::de::DeserializeOwned;
+use surrealdb::sql::Value;
+
+use crate::{DatabaseError, Result, database::Database};
+
+/// How a field is compared to a bound value
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Comparison {
+    Eq,
+    NotEq,
+    Lt,
+    Lte,
+    Gt,
+    Gte,
+    /// The array field holds the value
+    Contains,
+    /// The field is one of the values in a bound array
+    Inside,
+}
+
+impl fmt::Display for Comparison {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Comparison::Eq => write!(f, "="),
+            Comparison::NotEq => write!(f, "!="),
+            Comparison::Lt => write!(f, "<"),
+            Comparison::Lte => write!(f, "<="),
+            Comparison::Gt => write!(f, ">"),
+            Comparison::Gte => write!(f, ">="),
+            Comparison::Contains => write!(f, "CONTAINS"),
+            Comparison::Inside => write!(f, "INSIDE"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Order {
+    Asc,
+    Desc,
+}
+
+impl fmt::Display for Order {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Order::Asc => write!(f, "ASC"),
+            Order::Desc => write!(f, "DESC"),
+        }
+    }
+}
+
+/// A `SELECT` on one table, filtered by `AND`-ed conditions.
+///
+/// Table and field names are `&'static str`, so they come from the code and never from a request,
+/// while every compared value is sent as a bound parameter rather than spliced into the query text.
+#[derive(Clone, PartialEq, Debug)]
+pub struct SelectQuery {
+    table: &'static str,
+    conditions: Vec<(&'static str, Comparison)>,
+    values: Vec<Value>,
+    order_by: Vec<(&'static str, Order)>,
+    limit: Option<usize>,
+}
+
+impl SelectQuery {
+    pub fn table(table: &'static str) -> Self {
+        assert_identifier(table);
+        Self {
+            table,
+            conditions: Vec::new(),
+            values: Vec::new(),
+            order_by: Vec::new(),
+            limit: None,
+        }
+    }
+
+    pub fn filter(mut self, field: &'static str, comparison: Comparison, value: impl Into<Value>) -> Self {
+        assert_identifier(field);
+        self.conditions.push((field, comparison));
+        self.values.push(value.into());
+        self
+    }
+
+    pub fn where_eq(self, field: &'static str, value: impl Into<Value>) -> Self {
+        self.filter(field, Comparison::Eq, value)
+    }
+
+    pub fn where_contains(self, field: &'static str, value: impl Into<Value>) -> Self {
+        self.filter(field, Comparison::Contains, value)
+    }
+
+    pub fn order_by(mut self, field: &'static str, order: Order) -> Self {
+        assert_identifier(field);
+        self.order_by.push((field, order));
+        self
+    }
+
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Query text, with values referenced as `$p0`, `$p1`, ... in filter order
+    pub fn to_surql(&self) -> String {
+        let mut surql = format!("SELECT * FROM {}", self.table);
+        for (index, (field, comparison)) in self.conditions.iter().enumerate() {
+            let keyword = if index == 0 { "WHERE" } else { "AND" };
+            surql.push_str(&format!(" {keyword} {field} {comparison} $p{index}"));
+        }
+        if !self.order_by.is_empty() {
+            let order_by: Vec<String> = self.order_by.iter().map(|(field, order)| format!("{field} {order}")).collect();
+            surql.push_str(&format!(" ORDER BY {}", order_by.join(", ")));
+        }
+        if let Some(limit) = self.limit {
+            surql.push_str(&format!(" LIMIT {limit}"));
+        }
+        surql
+    }
+
+    /// Parameters to bind alongside `to_surql`
+    pub fn bindings(&self) -> impl Iterator<Item = (String, &Value)> {
+        self.values.iter().enumerate().map(|(index, value)| (format!("p{index}"), value))
+    }
+
+    /// Runs the query and reads the matching rows as `T`
+    pub async fn fetch<T: DeserializeOwned>(&self, db: &Database) -> Result<Vec<T>> {
+        let mut query = db.client().query(self.to_surql());
+        for (name, value) in self.bindings() {
+            query = query.bind((name, value.clone()));
+        }
+
+        let mut response = query
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to query {}: {}", self.table, e)))?;
+        response
+            .take(0)
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to parse {} rows: {}", self.table, e)))
+    }
+}
+
+/// Table and field names go into the query text as they are, so they must stay plain identifiers
+fn assert_identifier(name: &str) {
+    let valid = name.chars().next().is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '.');
+    assert!(valid, "{name:?} is not a valid SurrealQL identifier");
+}
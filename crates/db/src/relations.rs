@@ -0,0 +1,314 @@
+use project_tracker_core::{
+    HasId,
+    id::Id,
+    models::{project::Project, schedulable::SchedulableItem, task::Task},
+};
+use serde::{Deserialize, Serialize};
+use surrealdb::sql::Thing;
+
+use crate::{
+    DatabaseError, Result, database::Database, project_repository::ProjectRecord, task_repository::TaskRecord,
+};
+
+// Writes `$record` to `$item` and replaces its outgoing `contains` and `depends_on` edges in one go, so the
+// edges always mirror the item that was saved. Nothing is written when one of `$references` does not exist,
+// or when `$item` is to be updated but is not there.
+const SAVE_ITEM: &str = "
+BEGIN TRANSACTION;
+LET $missing = array::complement($references, (SELECT VALUE id FROM $references));
+LET $saved = IF array::len($missing) > 0 {
+    []
+} ELSE IF $create {
+    (CREATE $item CONTENT $record)
+} ELSE {
+    (UPDATE $item CONTENT $record)
+};
+IF array::len($saved) > 0 {
+    DELETE contains WHERE in = $item;
+    DELETE depends_on WHERE in = $item;
+    FOR $link IN $children {
+        LET $to = $link.to;
+        RELATE $item->contains->$to SET position = $link.position;
+    };
+    FOR $link IN $dependencies {
+        LET $to = $link.to;
+        RELATE $item->depends_on->$to SET position = $link.position;
+    };
+};
+RETURN { missing: $missing, saved: array::len($saved) > 0 };
+COMMIT TRANSACTION;
+";
+
+// Items under `$item` by `contains` edges, all the way down
+pub(crate) const DESCENDANTS: &str = "RETURN $item.{..+collect}(->contains->?)";
+
+// Removes `$item`, and everything below it when `$cascade` is set, in one go. Reads back the removed
+// records, and the ones linking to them before and after they lost that link; an empty `projects`
+// and `tasks` means `$item` did not exist. With `$restrict` set, nothing is removed while `$item`
+// has parents, children or dependents, which come back instead.
+const DELETE_ITEM: &str = "
+BEGIN TRANSACTION;
+LET $parents = SELECT VALUE in FROM contains WHERE out = $item;
+LET $children = SELECT VALUE out FROM contains WHERE in = $item;
+LET $dependents = SELECT VALUE in FROM depends_on WHERE out = $item;
+LET $restricted = $restrict AND array::len(array::concat($parents, $children, $dependents)) > 0;
+LET $deleted = IF $restricted { [] } ELSE IF $cascade { array::union([$item], $item.{..+collect}(->contains->?)) } ELSE { [$item] };
+LET $linked = array::complement(array::union(
+    (SELECT VALUE in FROM contains WHERE out INSIDE $deleted),
+    (SELECT VALUE in FROM depends_on WHERE out INSIDE $deleted)
+), $deleted);
+LET $removed = SELECT * FROM $deleted;
+LET $before = SELECT * FROM $linked;
+DELETE $deleted;
+LET $after = SELECT * FROM $linked;
+RETURN {
+    restricted: $restricted,
+    parents: $parents,
+    children: $children,
+    dependents: $dependents,
+    projects: $removed[WHERE record::tb(id) = 'project'],
+    tasks: $removed[WHERE record::tb(id) = 'task'],
+    projects_before: $before[WHERE record::tb(id) = 'project'],
+    projects_after: $after[WHERE record::tb(id) = 'project'],
+    tasks_before: $before[WHERE record::tb(id) = 'task'],
+    tasks_after: $after[WHERE record::tb(id) = 'task'],
+};
+COMMIT TRANSACTION;
+";
+
+/// What deleting a project or task does to the items linked to it
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum DeletePolicy {
+    /// Refuse to delete an item that has children, is a child or is depended on
+    Restrict,
+    /// Delete the item along with everything below it
+    Cascade,
+    /// Delete the item alone, dropping it from its parent's children and from dependencies;
+    /// its own children are left without a parent
+    #[default]
+    Detach,
+}
+
+/// Records a project or task delete removed or unlinked, so callers can log and clean up after every one of them
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct DeletedItems {
+    /// Projects removed, as they were just before
+    pub projects: Vec<Project>,
+    /// Tasks removed, as they were just before
+    pub tasks: Vec<Task>,
+    /// Projects that had a removed item among their children or dependencies, before and after
+    pub detached_projects: Vec<(Project, Project)>,
+    /// Tasks that had a removed item among their children or dependencies, before and after
+    pub detached_tasks: Vec<(Task, Task)>,
+}
+
+impl DeletedItems {
+    /// Every project and task removed
+    pub fn items(&self) -> Vec<SchedulableItem> {
+        let projects = self.projects.iter().map(|project| SchedulableItem::Project(project.id()));
+        let tasks = self.tasks.iter().map(|task| SchedulableItem::Task(task.id()));
+        projects.chain(tasks).collect()
+    }
+
+    /// Every project and task left in place with one link fewer
+    pub fn detached_items(&self) -> Vec<SchedulableItem> {
+        let projects = self.detached_projects.iter().map(|(project, _)| SchedulableItem::Project(project.id()));
+        let tasks = self.detached_tasks.iter().map(|(task, _)| SchedulableItem::Task(task.id()));
+        projects.chain(tasks).collect()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.projects.is_empty() && self.tasks.is_empty()
+    }
+}
+
+// What `DELETE_ITEM` reads back
+#[derive(Deserialize)]
+struct DeletedRecords {
+    restricted: bool,
+    parents: Vec<Thing>,
+    children: Vec<Thing>,
+    dependents: Vec<Thing>,
+    projects: Vec<ProjectRecord>,
+    tasks: Vec<TaskRecord>,
+    projects_before: Vec<ProjectRecord>,
+    projects_after: Vec<ProjectRecord>,
+    tasks_before: Vec<TaskRecord>,
+    tasks_after: Vec<TaskRecord>,
+}
+
+impl DeletedRecords {
+    // Fails with `IntegrityError` when the delete was refused because `item` is still linked
+    fn ensure_unrestricted(&self, item: &Thing) -> Result<()> {
+        if !self.restricted {
+            return Ok(());
+        }
+        let links = [
+            ("is a child of", &self.parents),
+            ("has children", &self.children),
+            ("is depended on by", &self.dependents),
+        ];
+        let reasons: Vec<String> = links
+            .into_iter()
+            .filter(|(_, linked)| !linked.is_empty())
+            .map(|(relation, linked)| {
+                let linked: Vec<String> = linked.iter().map(|thing| thing.id.to_raw()).collect();
+                format!("{} {}", relation, linked.join(", "))
+            })
+            .collect();
+        Err(DatabaseError::IntegrityError(format!(
+            "Cannot delete {}: it {}",
+            item.id.to_raw(),
+            reasons.join(", ")
+        )))
+    }
+
+    fn into_deleted_items(self) -> Result<DeletedItems> {
+        let projects = |records: Vec<ProjectRecord>| {
+            records.into_iter().map(ProjectRecord::into_stored_project).collect::<Result<Vec<_>>>()
+        };
+        let tasks = |records: Vec<TaskRecord>| records.into_iter().map(TaskRecord::into_stored_task).collect::<Result<Vec<_>>>();
+
+        // Both sides are read from the same list of linked records, so they line up
+        Ok(DeletedItems {
+            projects: projects(self.projects)?,
+            tasks: tasks(self.tasks)?,
+            detached_projects: projects(self.projects_before)?.into_iter().zip(projects(self.projects_after)?).collect(),
+            detached_tasks: tasks(self.tasks_before)?.into_iter().zip(tasks(self.tasks_after)?).collect(),
+        })
+    }
+}
+
+// What `SAVE_ITEM` reads back
+#[derive(Deserialize)]
+struct SavedRecord {
+    missing: Vec<Thing>,
+    saved: bool,
+}
+
+/// Records a project or task being saved points at, which all have to exist
+pub(crate) struct ItemReferences {
+    pub owner_id: Option<String>,
+    pub tags: Vec<String>,
+    pub children: Vec<String>,
+    pub dependencies: Vec<String>,
+}
+
+impl ItemReferences {
+    fn things(&self) -> Result<Vec<Thing>> {
+        let mut references: Vec<Thing> = self.owner_id.iter().map(|id| Thing::from(("person", id.as_str()))).collect();
+        references.extend(self.tags.iter().map(|id| Thing::from(("tag", id.as_str()))));
+        for id in self.children.iter().chain(&self.dependencies) {
+            references.push(item_thing(id)?);
+        }
+        Ok(references)
+    }
+}
+
+// One edge to write, `to` being the linked record
+#[derive(Serialize, Debug)]
+struct LinkRecord {
+    to: Thing,
+    position: u32,
+}
+
+/// SurrealDB record id of a project or task, told apart by the prefix of its id
+pub(crate) fn item_thing(id: &str) -> Result<Thing> {
+    if id.starts_with("project-") {
+        Ok(Thing::from(("project", id)))
+    } else if id.starts_with("task-") {
+        Ok(Thing::from(("task", id)))
+    } else {
+        Err(DatabaseError::QueryError(format!("Unknown item type: {}", id)))
+    }
+}
+
+/// Project or task a SurrealDB record id points at
+pub(crate) fn thing_item(thing: &Thing) -> Result<SchedulableItem> {
+    let id = thing.id.to_raw();
+    match thing.tb.as_str() {
+        "project" => id
+            .parse::<Id<Project>>()
+            .map(SchedulableItem::Project)
+            .map_err(|e| DatabaseError::QueryError(format!("Invalid project ID: {:?}", e))),
+        "task" => id
+            .parse::<Id<Task>>()
+            .map(SchedulableItem::Task)
+            .map_err(|e| DatabaseError::QueryError(format!("Invalid task ID: {:?}", e))),
+        table => Err(DatabaseError::QueryError(format!("Unknown item table: {}", table))),
+    }
+}
+
+/// Writes `record` to `item`, creating it when `create` is set and updating it otherwise, and stores its
+/// children and dependencies as `contains` and `depends_on` edges, all in one transaction.
+/// Returns `false`, with nothing written, when updating an item that does not exist.
+/// Fails with `IntegrityError` naming every reference that does not exist.
+pub(crate) async fn save_item<R: Serialize + 'static>(
+    db: &Database,
+    item: Thing,
+    record: R,
+    create: bool,
+    references: ItemReferences,
+) -> Result<bool> {
+    let children = links(&references.children)?;
+    let dependencies = links(&references.dependencies)?;
+    let name = item.id.to_raw();
+
+    let mut response = db
+        .client()
+        .query(SAVE_ITEM)
+        .bind(("item", item))
+        .bind(("record", record))
+        .bind(("create", create))
+        .bind(("references", references.things()?))
+        .bind(("children", children))
+        .bind(("dependencies", dependencies))
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to save {}: {}", name, e)))?
+        .check()
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to save {}: {}", name, e)))?;
+    let saved: Option<SavedRecord> = response
+        .take(0)
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to save {}: {}", name, e)))?;
+    let saved = saved.ok_or_else(|| DatabaseError::QueryError(format!("Failed to save {}: nothing returned", name)))?;
+
+    if saved.missing.is_empty() {
+        Ok(saved.saved)
+    } else {
+        let missing: Vec<String> = saved.missing.iter().map(|reference| reference.id.to_raw()).collect();
+        Err(DatabaseError::IntegrityError(format!("Referenced items do not exist: {}", missing.join(", "))))
+    }
+}
+
+fn links(ids: &[String]) -> Result<Vec<LinkRecord>> {
+    ids.iter()
+        .zip(0..)
+        .map(|(id, position)| Ok(LinkRecord { to: item_thing(id)?, position }))
+        .collect()
+}
+
+/// Deletes `item` under `policy`, along with the edges of every removed record, all in one transaction.
+/// Returns `None` when `item` does not exist.
+/// Fails with `IntegrityError` when `policy` is `Restrict` and `item` is still linked.
+pub(crate) async fn delete_item(db: &Database, item: Thing, policy: DeletePolicy) -> Result<Option<DeletedItems>> {
+    let mut response = db
+        .client()
+        .query(DELETE_ITEM)
+        .bind(("item", item.clone()))
+        .bind(("restrict", policy == DeletePolicy::Restrict))
+        .bind(("cascade", policy == DeletePolicy::Cascade))
+        .await
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to delete item: {}", e)))?;
+    let records: Option<DeletedRecords> = response
+        .take(0)
+        .map_err(|e| DatabaseError::QueryError(format!("Failed to delete item: {}", e)))?;
+
+    let deleted = match records {
+        Some(records) => {
+            records.ensure_unrestricted(&item)?;
+            records.into_deleted_items()?
+        }
+        None => return Ok(None),
+    };
+    Ok((!deleted.is_empty()).then_some(deleted))
+}
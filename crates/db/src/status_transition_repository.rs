@@ -0,0 +1,179 @@
+use crate::{DatabaseError, Result, database::Database, query::{Order, SelectQuery}};
+use async_trait::async_trait;
+use log::{debug, error};
+use project_tracker_core::{
+    id::Id,
+    models::{
+        person::Person,
+        project::Project,
+        schedulable::{SchedulableItem, SchedulableItemStatus},
+        status_transition::StatusTransition,
+        task::Task,
+    },
+};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use surrealdb::sql::Datetime as SurrealDatetime;
+
+// DTO for database operations (entries get a generated SurrealDB id that is never read back)
+#[derive(Serialize, Deserialize, Debug)]
+struct StatusTransitionRecord {
+    item: String,
+    from_status: String,
+    to_status: String,
+    changed_by: Option<String>,
+    changed_at: SurrealDatetime,
+}
+
+impl From<StatusTransition> for StatusTransitionRecord {
+    fn from(transition: StatusTransition) -> Self {
+        Self {
+            item: transition.item().to_string(),
+            from_status: transition.from().to_string(),
+            to_status: transition.to().to_string(),
+            changed_by: transition.by().map(|id| id.to_string()),
+            changed_at: SurrealDatetime::from(transition.at()),
+        }
+    }
+}
+
+impl StatusTransitionRecord {
+    fn into_transition(self) -> Result<StatusTransition> {
+        let item = if self.item.starts_with("project-") {
+            self.item
+                .parse::<Id<Project>>()
+                .map(SchedulableItem::Project)
+                .map_err(|e| DatabaseError::QueryError(format!("Invalid project ID: {:?}", e)))?
+        } else if self.item.starts_with("task-") {
+            self.item
+                .parse::<Id<Task>>()
+                .map(SchedulableItem::Task)
+                .map_err(|e| DatabaseError::QueryError(format!("Invalid task ID: {:?}", e)))?
+        } else {
+            return Err(DatabaseError::QueryError(format!("Unknown item type: {}", self.item)));
+        };
+
+        let changed_by = match self.changed_by {
+            Some(person_str) => Some(
+                person_str
+                    .parse::<Id<Person>>()
+                    .map_err(|e| DatabaseError::QueryError(format!("Invalid person ID: {:?}", e)))?,
+            ),
+            None => None,
+        };
+
+        Ok(StatusTransition::recorded_at(
+            item,
+            parse_status(&self.from_status)?,
+            parse_status(&self.to_status)?,
+            changed_by,
+            self.changed_at.0,
+        ))
+    }
+}
+
+fn parse_status(status: &str) -> Result<SchedulableItemStatus> {
+    match status {
+        "NotStarted" => Ok(SchedulableItemStatus::NotStarted),
+        "Planned" => Ok(SchedulableItemStatus::Planned),
+        "InProgress" => Ok(SchedulableItemStatus::InProgress),
+        "InReview" => Ok(SchedulableItemStatus::InReview),
+        "Completed" => Ok(SchedulableItemStatus::Completed),
+        "Archived" => Ok(SchedulableItemStatus::Archived),
+        "Canceled" => Ok(SchedulableItemStatus::Canceled),
+        s => Err(DatabaseError::QueryError(format!("Invalid status: {}", s))),
+    }
+}
+
+// Define the repository trait locally to avoid circular dependencies
+#[async_trait]
+pub trait StatusTransitionRepository {
+    async fn record(&self, transition: StatusTransition) -> Result<()>;
+    /// Records every transition in a single write, so either all of them are kept or none is
+    async fn record_all(&self, transitions: Vec<StatusTransition>) -> Result<()>;
+    /// Status history of one item, oldest first
+    async fn get_for_item(&self, item: &SchedulableItem) -> Result<Vec<StatusTransition>>;
+}
+
+pub struct ProdStatusTransitionRepository {
+    db: Arc<Database>,
+}
+
+impl ProdStatusTransitionRepository {
+    pub fn new(db: Arc<Database>) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl StatusTransitionRepository for ProdStatusTransitionRepository {
+    async fn record(&self, transition: StatusTransition) -> Result<()> {
+        let item = transition.item().clone();
+        debug!("Recording status transition for item: {}", item);
+
+        let transition_record = StatusTransitionRecord::from(transition);
+
+        let result: Result<Option<StatusTransitionRecord>> = self
+            .db
+            .client()
+            .create("status_transition")
+            .content(transition_record)
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to record status transition: {}", e)));
+
+        match result {
+            Ok(_) => {
+                debug!("Successfully recorded status transition for item: {}", item);
+                Ok(())
+            }
+            Err(e) => {
+                error!("Failed to record status transition: {:?}", e);
+                Err(e)
+            }
+        }
+    }
+
+    async fn record_all(&self, transitions: Vec<StatusTransition>) -> Result<()> {
+        debug!("Recording {} status transitions", transitions.len());
+        if transitions.is_empty() {
+            return Ok(());
+        }
+
+        let records: Vec<StatusTransitionRecord> = transitions.into_iter().map(StatusTransitionRecord::from).collect();
+        let result: Result<Vec<StatusTransitionRecord>> = self
+            .db
+            .client()
+            .insert("status_transition")
+            .content(records)
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to record status transitions: {}", e)));
+
+        match result {
+            Ok(records) => {
+                debug!("Successfully recorded {} status transitions", records.len());
+                Ok(())
+            }
+            Err(e) => {
+                error!("Failed to record status transitions: {:?}", e);
+                Err(e)
+            }
+        }
+    }
+
+    async fn get_for_item(&self, item: &SchedulableItem) -> Result<Vec<StatusTransition>> {
+        debug!("Fetching status history for item: {}", item);
+
+        let records: Vec<StatusTransitionRecord> = SelectQuery::table("status_transition")
+            .where_eq("item", item.to_string())
+            .order_by("changed_at", Order::Asc)
+            .fetch(&self.db)
+            .await?;
+
+        let transitions = records
+            .into_iter()
+            .map(StatusTransitionRecord::into_transition)
+            .collect::<Result<Vec<_>>>()?;
+        debug!("Found {} status transitions for item: {}", transitions.len(), item);
+        Ok(transitions)
+    }
+}
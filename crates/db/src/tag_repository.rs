@@ -0,0 +1,276 @@
+use crate::{DatabaseError, Result, database::Database, query::SelectQuery};
+use async_trait::async_trait;
+use log::{debug, error};
+use project_tracker_core::{HasId, builders::tag_builder::TagBuilder, id::Id, models::tag::Tag};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use surrealdb::sql::Thing;
+
+// DTO for database operations (id is only read back, since SurrealDB manages it)
+#[derive(Serialize, Deserialize, Debug)]
+struct TagRecord {
+    #[serde(default, skip_serializing)]
+    id: Option<Thing>,
+    name: String,
+    description: Option<String>,
+    parents: Option<Vec<String>>,
+}
+
+impl From<Tag> for TagRecord {
+    fn from(tag: Tag) -> Self {
+        let parents_strings: Vec<String> = tag.parents().iter().map(|id| id.to_string()).collect();
+
+        Self {
+            id: None,
+            name: tag.name().to_string(),
+            description: if tag.description().is_empty() {
+                None
+            } else {
+                Some(tag.description().to_string())
+            },
+            parents: if parents_strings.is_empty() {
+                None
+            } else {
+                Some(parents_strings)
+            },
+        }
+    }
+}
+
+impl TagRecord {
+    fn record_id(&self) -> Result<Id<Tag>> {
+        match &self.id {
+            Some(thing) => thing
+                .id
+                .to_raw()
+                .parse::<Id<Tag>>()
+                .map_err(|e| DatabaseError::QueryError(format!("Invalid tag ID: {:?}", e))),
+            None => Err(DatabaseError::QueryError("Tag record is missing its ID".into())),
+        }
+    }
+
+    fn into_tag(self, id: Id<Tag>) -> Result<Tag> {
+        let record = self;
+
+        // Parse parents
+        let parents = record
+            .parents
+            .unwrap_or_default()
+            .into_iter()
+            .map(|parent_str| parent_str.parse::<Id<Tag>>())
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .map_err(|e| DatabaseError::QueryError(format!("Invalid parent tag ID: {:?}", e)))?;
+
+        // Build the tag
+        let mut builder = TagBuilder::new()
+            .with_id(id)
+            .with_name(&record.name)
+            .with_parents(parents);
+
+        if let Some(description) = record.description {
+            builder = builder.with_description(&description);
+        }
+
+        Ok(builder.build())
+    }
+
+    fn into_stored_tag(self) -> Result<Tag> {
+        let id = self.record_id()?;
+        self.into_tag(id)
+    }
+}
+
+// Define the repository trait locally to avoid circular dependencies
+#[async_trait]
+pub trait TagRepository: Send + Sync {
+    async fn create(&self, tag: Tag) -> Result<()>;
+    async fn get_by_id(&self, id: Id<Tag>) -> Result<Option<Tag>>;
+    async fn get_by_name(&self, name: &str) -> Result<Option<Tag>>;
+    async fn get_all(&self) -> Result<Vec<Tag>>;
+    async fn update(&self, tag: Tag) -> Result<()>;
+    async fn delete(&self, id: Id<Tag>) -> Result<()>;
+
+    /// Returns the tag stored under `name`, creating it first if there is none yet
+    async fn get_or_create_by_name(&self, name: &str) -> Result<Tag> {
+        if let Some(tag) = self.get_by_name(name).await? {
+            return Ok(tag);
+        }
+
+        let tag = TagBuilder::new().with_name(name).build();
+        self.create(tag.clone()).await?;
+        Ok(tag)
+    }
+}
+
+pub struct ProdTagRepository {
+    db: Arc<Database>,
+}
+
+impl ProdTagRepository {
+    pub fn new(db: Arc<Database>) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl TagRepository for ProdTagRepository {
+    async fn create(&self, tag: Tag) -> Result<()> {
+        let tag_id = tag.id();
+        debug!("Creating tag with ID: {}", tag_id);
+
+        let id_str = tag_id.to_string();
+        let tag_record = TagRecord::from(tag);
+
+        let result: Result<Option<TagRecord>> = self
+            .db
+            .client()
+            .create(("tag", id_str.as_str()))
+            .content(tag_record)
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to create tag: {}", e)));
+
+        match result {
+            Ok(_) => {
+                debug!("Successfully created tag with ID: {}", tag_id);
+                Ok(())
+            }
+            Err(e) => {
+                error!("Failed to create tag: {:?}", e);
+                Err(e)
+            }
+        }
+    }
+
+    async fn get_by_id(&self, id: Id<Tag>) -> Result<Option<Tag>> {
+        debug!("Fetching tag with ID: {}", id);
+
+        let id_str = id.to_string();
+
+        let result: Result<Option<TagRecord>> = self
+            .db
+            .client()
+            .select(("tag", id_str.as_str()))
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to get tag by ID: {}", e)));
+
+        match result {
+            Ok(Some(tag_record)) => {
+                debug!("Found tag record with ID: {}", id);
+                tag_record.into_tag(id).map(Some)
+            }
+            Ok(None) => {
+                debug!("No tag found with ID: {}", id);
+                Ok(None)
+            }
+            Err(e) => {
+                error!("Failed to get tag by ID: {:?}", e);
+                Err(e)
+            }
+        }
+    }
+
+    async fn get_by_name(&self, name: &str) -> Result<Option<Tag>> {
+        debug!("Fetching tag with name: {}", name);
+
+        let records: Vec<TagRecord> = SelectQuery::table("tag").where_eq("name", name).limit(1).fetch(&self.db).await?;
+
+        match records.into_iter().next() {
+            Some(record) => record.into_stored_tag().map(Some),
+            None => {
+                debug!("No tag found with name: {}", name);
+                Ok(None)
+            }
+        }
+    }
+
+    async fn get_all(&self) -> Result<Vec<Tag>> {
+        debug!("Fetching all tags");
+
+        let result: Result<Vec<TagRecord>> = self
+            .db
+            .client()
+            .select("tag")
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to get all tags: {}", e)));
+
+        match result {
+            Ok(records) => {
+                let tags = records
+                    .into_iter()
+                    .map(TagRecord::into_stored_tag)
+                    .collect::<Result<Vec<_>>>()?;
+                debug!("Found {} tags", tags.len());
+                Ok(tags)
+            }
+            Err(e) => {
+                error!("Failed to get all tags: {:?}", e);
+                Err(e)
+            }
+        }
+    }
+
+    async fn update(&self, tag: Tag) -> Result<()> {
+        let tag_id = tag.id();
+        debug!("Updating tag with ID: {}", tag_id);
+
+        let id_str = tag_id.to_string();
+        let tag_record = TagRecord::from(tag);
+
+        let result: Result<Option<TagRecord>> = self
+            .db
+            .client()
+            .update(("tag", id_str.as_str()))
+            .content(tag_record)
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to update tag: {}", e)));
+
+        match result {
+            Ok(Some(_)) => {
+                debug!("Successfully updated tag with ID: {}", tag_id);
+                Ok(())
+            }
+            Ok(None) => {
+                error!("Tag not found for update: {}", tag_id);
+                Err(DatabaseError::QueryError(format!(
+                    "Tag with ID {} not found",
+                    tag_id
+                )))
+            }
+            Err(e) => {
+                error!("Failed to update tag: {:?}", e);
+                Err(e)
+            }
+        }
+    }
+
+    async fn delete(&self, id: Id<Tag>) -> Result<()> {
+        debug!("Deleting tag with ID: {}", id);
+
+        let id_str = id.to_string();
+
+        let result: Result<Option<TagRecord>> = self
+            .db
+            .client()
+            .delete(("tag", id_str.as_str()))
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to delete tag: {}", e)));
+
+        match result {
+            Ok(Some(_)) => {
+                debug!("Successfully deleted tag with ID: {}", id);
+                Ok(())
+            }
+            Ok(None) => {
+                error!("Tag not found for deletion: {}", id);
+                Err(DatabaseError::QueryError(format!(
+                    "Tag with ID {} not found",
+                    id
+                )))
+            }
+            Err(e) => {
+                error!("Failed to delete tag: {:?}", e);
+                Err(e)
+            }
+        }
+    }
+}
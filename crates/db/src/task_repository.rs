@@ -1,4 +1,9 @@
-use crate::{DatabaseError, Result, database::Database};
+use crate::{
+    DatabaseError, Result,
+    database::Database,
+    query::SelectQuery,
+    relations::{DeletePolicy, DeletedItems, ItemReferences, delete_item, save_item},
+};
 use async_trait::async_trait;
 use log::{debug, error};
 use project_tracker_core::{
@@ -6,28 +11,43 @@ use project_tracker_core::{
     builders::task_builder::TaskBuilder,
     id::Id,
     models::{
+        estimate::Estimate,
         person::Person,
-        schedulable::{Schedulable, SchedulableItemStatus},
+        project::Project,
+        recurrence::RecurrenceRule,
+        schedulable::{Priority, Schedulable, SchedulableItemStatus},
         tag::Tag,
         task::Task,
     },
 };
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
-use surrealdb::sql::Datetime as SurrealDatetime;
+use surrealdb::sql::{Datetime as SurrealDatetime, Thing};
 
-// DTO for database operations (excludes id since SurrealDB manages it)
+// DTO for database operations (id is only read back, since SurrealDB manages it)
 #[derive(Serialize, Deserialize, Debug)]
-struct TaskRecord {
+pub(crate) struct TaskRecord {
+    #[serde(default, skip_serializing)]
+    id: Option<Thing>,
     name: String,
     owner_id: Option<String>,
     description: Option<String>,
     tags: Option<Vec<String>>,
     start_date: Option<SurrealDatetime>,
     due_date: Option<SurrealDatetime>,
+    // Stored as `contains` and `depends_on` edges, and read back through computed fields
+    #[serde(default, skip_serializing)]
     children: Option<Vec<String>>,
+    #[serde(default, skip_serializing)]
     dependencies: Option<Vec<String>>,
     status: String,
+    priority: String,
+    recurrence: Option<String>,
+    series_id: Option<String>,
+    occurrence: Option<u32>,
+    series_start_date: Option<SurrealDatetime>,
+    series_due_date: Option<SurrealDatetime>,
+    estimate: Option<String>,
 }
 
 impl From<Task> for TaskRecord {
@@ -42,6 +62,7 @@ impl From<Task> for TaskRecord {
         let tags_strings: Vec<String> = task.tags().iter().map(|id| id.to_string()).collect();
 
         Self {
+            id: None,
             name: task.name().to_string(),
             owner_id: task.owner_id().map(|id| id.clone().to_string()),
             description: if task.description().is_empty() {
@@ -67,11 +88,47 @@ impl From<Task> for TaskRecord {
                 Some(dependencies_strings)
             },
             status: task.status().to_string(),
+            priority: task.priority().to_string(),
+            recurrence: task.recurrence().map(|rule| rule.to_string()),
+            series_id: task.is_recurring().then(|| task.series_id().to_string()),
+            occurrence: task.is_recurring().then(|| task.occurrence()),
+            series_start_date: task.series_start_date().map(SurrealDatetime::from),
+            series_due_date: task.series_due_date().map(SurrealDatetime::from),
+            estimate: task.estimate().map(|estimate| estimate.to_string()),
         }
     }
 }
 
 impl TaskRecord {
+    fn record_id(&self) -> Result<Id<Task>> {
+        match &self.id {
+            Some(thing) => thing
+                .id
+                .to_raw()
+                .parse::<Id<Task>>()
+                .map_err(|e| DatabaseError::QueryError(format!("Invalid task ID: {:?}", e))),
+            None => Err(DatabaseError::QueryError(
+                "Task record is missing its ID".into(),
+            )),
+        }
+    }
+
+    // Everything the record points at, checked to exist when it is saved
+    fn references(&self) -> ItemReferences {
+        ItemReferences {
+            owner_id: self.owner_id.clone(),
+            tags: self.tags.clone().unwrap_or_default(),
+            children: self.children.clone().unwrap_or_default(),
+            dependencies: self.dependencies.clone().unwrap_or_default(),
+        }
+    }
+
+    /// Rebuilds a task read back from a query, taking its id from the SurrealDB record id
+    pub(crate) fn into_stored_task(self) -> Result<Task> {
+        let id = self.record_id()?;
+        self.into_task(id)
+    }
+
     fn into_task(self, id: Id<Task>) -> Result<Task> {
         let record = self;
 
@@ -87,6 +144,15 @@ impl TaskRecord {
             s => return Err(DatabaseError::QueryError(format!("Invalid status: {}", s))),
         };
 
+        // Parse priority
+        let priority = match record.priority.as_str() {
+            "Low" => Priority::Low,
+            "Medium" => Priority::Medium,
+            "High" => Priority::High,
+            "Critical" => Priority::Critical,
+            s => return Err(DatabaseError::QueryError(format!("Invalid priority: {}", s))),
+        };
+
         // Parse owner_id
         let owner_id = if let Some(owner_str) = record.owner_id {
             Some(
@@ -130,6 +196,7 @@ impl TaskRecord {
             .with_id(id)
             .with_name(&record.name)
             .with_status(status)
+            .with_priority(priority)
             .with_tags(tags)
             .with_children(children)
             .with_dependencies(dependencies);
@@ -138,10 +205,10 @@ impl TaskRecord {
             builder = builder.with_owner_id(Some(owner_id));
         }
 
-        if let Some(description) = record.description {
-            if !description.is_empty() {
-                builder = builder.with_description(&description);
-            }
+        if let Some(description) = record.description
+            && !description.is_empty()
+        {
+            builder = builder.with_description(&description);
         }
 
         if let Some(start_date) = record.start_date {
@@ -152,24 +219,83 @@ impl TaskRecord {
             builder = builder.with_due_date(Some(due_date.into()));
         }
 
+        // Parse recurrence
+        if let Some(rule_str) = record.recurrence {
+            let rule = rule_str
+                .parse::<RecurrenceRule>()
+                .map_err(|e| DatabaseError::QueryError(format!("Invalid recurrence: {}", e)))?;
+            builder = builder.with_recurrence(Some(rule));
+        }
+
+        if let Some(series_str) = record.series_id {
+            let series_id = series_str
+                .parse::<Id<Task>>()
+                .map_err(|e| DatabaseError::QueryError(format!("Invalid series ID: {:?}", e)))?;
+            builder = builder.with_series_id(Some(series_id));
+        }
+
+        if let Some(occurrence) = record.occurrence {
+            builder = builder.with_occurrence(occurrence);
+        }
+
+        if let Some(series_start_date) = record.series_start_date {
+            builder = builder.with_series_start_date(Some(series_start_date.into()));
+        }
+
+        if let Some(series_due_date) = record.series_due_date {
+            builder = builder.with_series_due_date(Some(series_due_date.into()));
+        }
+
+        // Parse estimate
+        if let Some(estimate_str) = record.estimate {
+            let estimate = estimate_str
+                .parse::<Estimate>()
+                .map_err(|e| DatabaseError::QueryError(format!("Invalid estimate: {}", e)))?;
+            builder = builder.with_estimate(Some(estimate));
+        }
+
         Ok(builder.build())
     }
 }
 
 // Define the repository trait locally to avoid circular dependencies
 #[async_trait]
-pub trait TaskRepository {
+pub trait TaskRepository: Send + Sync {
     async fn create(&self, task: Task) -> Result<()>;
     async fn get_by_id(&self, id: Id<Task>) -> Result<Option<Task>>;
+    async fn get_all(&self) -> Result<Vec<Task>>;
+    async fn update(&self, task: Task) -> Result<()>;
+    /// Deletes the task under the repository's `DeletePolicy`, returning every record it removed or unlinked
+    async fn delete(&self, id: Id<Task>) -> Result<DeletedItems>;
+    /// Tasks owned by `owner_id`
+    async fn get_by_owner(&self, owner_id: Id<Person>) -> Result<Vec<Task>>;
+    /// Tasks listed directly among the children of `project_id`
+    async fn get_by_parent_project(&self, project_id: Id<Project>) -> Result<Vec<Task>>;
+    /// Tasks that depend on `id`, i.e. what is waiting on it
+    async fn get_dependents(&self, id: Id<Task>) -> Result<Vec<Task>>;
 }
 
 pub struct ProdTaskRepository {
     db: Arc<Database>,
+    delete_policy: DeletePolicy,
 }
 
 impl ProdTaskRepository {
     pub fn new(db: Arc<Database>) -> Self {
-        Self { db }
+        Self {
+            db,
+            delete_policy: DeletePolicy::default(),
+        }
+    }
+
+    /// What `delete` does to linked items, `DeletePolicy::Detach` unless set
+    pub fn with_delete_policy(mut self, delete_policy: DeletePolicy) -> Self {
+        self.delete_policy = delete_policy;
+        self
+    }
+
+    pub fn delete_policy(&self) -> DeletePolicy {
+        self.delete_policy
     }
 }
 
@@ -181,17 +307,11 @@ impl TaskRepository for ProdTaskRepository {
 
         let id_str = task_id.to_string();
         let task_record = TaskRecord::from(task);
-
-        let result: Result<Option<TaskRecord>> = self
-            .db
-            .client()
-            .create(("task", id_str.as_str()))
-            .content(task_record)
-            .await
-            .map_err(|e| DatabaseError::QueryError(format!("Failed to create task: {}", e)));
+        let references = task_record.references();
+        let result = save_item(&self.db, Thing::from(("task", id_str.as_str())), task_record, true, references).await;
 
         match result {
-            Ok(_created_record) => {
+            Ok(_) => {
                 debug!("Successfully created task with ID: {}", task_id);
                 Ok(())
             }
@@ -238,31 +358,22 @@ impl TaskRepository for ProdTaskRepository {
             }
         }
     }
-}
 
-// Additional repository methods that can be added in the future
-impl ProdTaskRepository {
-    #[allow(dead_code)]
-    pub async fn update(&self, task: Task) -> Result<()> {
+    async fn update(&self, task: Task) -> Result<()> {
         let task_id = task.id();
         debug!("Updating task with ID: {}", task_id);
 
         let id_str = task_id.to_string();
-
-        let result: Result<Option<Task>> = self
-            .db
-            .client()
-            .update(("task", id_str.as_str()))
-            .content(task)
-            .await
-            .map_err(|e| DatabaseError::QueryError(format!("Failed to update task: {}", e)));
+        let task_record = TaskRecord::from(task);
+        let references = task_record.references();
+        let result = save_item(&self.db, Thing::from(("task", id_str.as_str())), task_record, false, references).await;
 
         match result {
-            Ok(Some(_)) => {
+            Ok(true) => {
                 debug!("Successfully updated task with ID: {}", task_id);
                 Ok(())
             }
-            Ok(None) => {
+            Ok(false) => {
                 error!("Task not found for update: {}", task_id);
                 Err(DatabaseError::QueryError(format!(
                     "Task with ID {} not found",
@@ -276,23 +387,14 @@ impl ProdTaskRepository {
         }
     }
 
-    #[allow(dead_code)]
-    pub async fn delete(&self, id: Id<Task>) -> Result<()> {
+    async fn delete(&self, id: Id<Task>) -> Result<DeletedItems> {
         debug!("Deleting task with ID: {}", id);
 
         let id_str = id.to_string();
-
-        let result: Result<Option<Task>> = self
-            .db
-            .client()
-            .delete(("task", id_str.as_str()))
-            .await
-            .map_err(|e| DatabaseError::QueryError(format!("Failed to delete task: {}", e)));
-
-        match result {
-            Ok(Some(_)) => {
+        match delete_item(&self.db, Thing::from(("task", id_str.as_str())), self.delete_policy).await {
+            Ok(Some(deleted)) => {
                 debug!("Successfully deleted task with ID: {}", id);
-                Ok(())
+                Ok(deleted)
             }
             Ok(None) => {
                 error!("Task not found for deletion: {}", id);
@@ -308,11 +410,10 @@ impl ProdTaskRepository {
         }
     }
 
-    #[allow(dead_code)]
-    pub async fn get_all(&self) -> Result<Vec<Task>> {
+    async fn get_all(&self) -> Result<Vec<Task>> {
         debug!("Fetching all tasks");
 
-        let result: Result<Vec<Task>> = self
+        let result: Result<Vec<TaskRecord>> = self
             .db
             .client()
             .select("task")
@@ -320,7 +421,11 @@ impl ProdTaskRepository {
             .map_err(|e| DatabaseError::QueryError(format!("Failed to get all tasks: {}", e)));
 
         match result {
-            Ok(tasks) => {
+            Ok(records) => {
+                let tasks = records
+                    .into_iter()
+                    .map(TaskRecord::into_stored_task)
+                    .collect::<Result<Vec<_>>>()?;
                 debug!("Found {} tasks", tasks.len());
                 Ok(tasks)
             }
@@ -331,51 +436,63 @@ impl ProdTaskRepository {
         }
     }
 
-    #[allow(dead_code)]
-    pub async fn get_by_owner(
-        &self,
-        owner_id: Id<project_tracker_core::models::person::Person>,
-    ) -> Result<Vec<Task>> {
+    async fn get_by_owner(&self, owner_id: Id<Person>) -> Result<Vec<Task>> {
         debug!("Fetching tasks for owner: {}", owner_id);
 
-        let query = format!(
-            "SELECT * FROM task WHERE owner_id = '{}'",
-            owner_id.to_string()
-        );
-
-        let mut response = self.db.client().query(query).await.map_err(|e| {
-            DatabaseError::QueryError(format!("Failed to get tasks by owner: {}", e))
-        })?;
-
-        let tasks: Vec<Task> = response.take(0).map_err(|e| {
-            DatabaseError::QueryError(format!("Failed to parse tasks by owner: {}", e))
-        })?;
+        let records: Vec<TaskRecord> = SelectQuery::table("task")
+            .where_eq("owner_id", owner_id.to_string())
+            .fetch(&self.db)
+            .await?;
+        let tasks = records
+            .into_iter()
+            .map(TaskRecord::into_stored_task)
+            .collect::<Result<Vec<_>>>()?;
 
         debug!("Found {} tasks for owner: {}", tasks.len(), owner_id);
         Ok(tasks)
     }
 
-    #[allow(dead_code)]
-    pub async fn get_by_parent_project(
-        &self,
-        project_id: Id<project_tracker_core::models::project::Project>,
-    ) -> Result<Vec<Task>> {
+    async fn get_by_parent_project(&self, project_id: Id<Project>) -> Result<Vec<Task>> {
         debug!("Fetching tasks for project: {}", project_id);
 
-        // This would require tracking parent project in tasks or using a junction table
-        // For now, we can search through all projects and find tasks that are children
-        let query = format!(
-            "SELECT * FROM project WHERE id = '{}' FETCH children",
-            project_id.to_string()
-        );
+        let tasks = self
+            .query_tasks(
+                "SELECT * FROM $item->contains->task",
+                Thing::from(("project", project_id.to_string().as_str())),
+            )
+            .await?;
+        debug!("Found {} tasks for project: {}", tasks.len(), project_id);
+        Ok(tasks)
+    }
+
+    async fn get_dependents(&self, id: Id<Task>) -> Result<Vec<Task>> {
+        debug!("Fetching dependents of task: {}", id);
 
-        let _response = self.db.client().query(query).await.map_err(|e| {
-            DatabaseError::QueryError(format!("Failed to get tasks by project: {}", e))
-        })?;
+        self.query_tasks(
+            "SELECT * FROM $item<-depends_on<-task",
+            Thing::from(("task", id.to_string().as_str())),
+        )
+        .await
+    }
+}
+
+impl ProdTaskRepository {
+    async fn query_tasks(&self, query: &'static str, item: Thing) -> Result<Vec<Task>> {
+        let mut response = self
+            .db
+            .client()
+            .query(query)
+            .bind(("item", item))
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to get tasks: {}", e)))?;
+        let records: Vec<TaskRecord> = response
+            .take(0)
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to parse tasks: {}", e)))?;
 
-        // Extract task IDs from project children and fetch them
-        // This is a placeholder - actual implementation would depend on how parent-child relationships are stored
-        Ok(vec![])
+        records
+            .into_iter()
+            .map(TaskRecord::into_stored_task)
+            .collect::<Result<Vec<_>>>()
     }
 }
 
@@ -0,0 +1,296 @@
+use crate::{DatabaseError, Result, database::Database, query::{Order, SelectQuery}};
+use async_trait::async_trait;
+use log::{debug, error};
+use project_tracker_core::{
+    HasId,
+    builders::time_entry_builder::TimeEntryBuilder,
+    id::Id,
+    models::{person::Person, project::Project, schedulable::SchedulableItem, task::Task, time_entry::TimeEntry},
+};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use surrealdb::sql::{Datetime as SurrealDatetime, Thing};
+
+// DTO for database operations (id is only read back, since SurrealDB manages it)
+#[derive(Serialize, Deserialize, Debug)]
+struct TimeEntryRecord {
+    #[serde(default, skip_serializing)]
+    id: Option<Thing>,
+    person_id: String,
+    item: String,
+    started_at: SurrealDatetime,
+    ended_at: Option<SurrealDatetime>,
+    note: Option<String>,
+}
+
+impl From<TimeEntry> for TimeEntryRecord {
+    fn from(entry: TimeEntry) -> Self {
+        Self {
+            id: None,
+            person_id: entry.person_id().to_string(),
+            item: entry.item().to_string(),
+            started_at: SurrealDatetime::from(entry.start()),
+            ended_at: entry.end().map(SurrealDatetime::from),
+            note: entry.has_note().then(|| entry.note().to_string()),
+        }
+    }
+}
+
+impl TimeEntryRecord {
+    fn record_id(&self) -> Result<Id<TimeEntry>> {
+        match &self.id {
+            Some(thing) => thing
+                .id
+                .to_raw()
+                .parse::<Id<TimeEntry>>()
+                .map_err(|e| DatabaseError::QueryError(format!("Invalid time entry ID: {:?}", e))),
+            None => Err(DatabaseError::QueryError("Time entry record is missing its ID".into())),
+        }
+    }
+
+    fn into_time_entry(self, id: Id<TimeEntry>) -> Result<TimeEntry> {
+        let person_id = self
+            .person_id
+            .parse::<Id<Person>>()
+            .map_err(|e| DatabaseError::QueryError(format!("Invalid person ID: {:?}", e)))?;
+        let item = if self.item.starts_with("project-") {
+            self.item
+                .parse::<Id<Project>>()
+                .map(SchedulableItem::Project)
+                .map_err(|e| DatabaseError::QueryError(format!("Invalid project ID: {:?}", e)))?
+        } else if self.item.starts_with("task-") {
+            self.item
+                .parse::<Id<Task>>()
+                .map(SchedulableItem::Task)
+                .map_err(|e| DatabaseError::QueryError(format!("Invalid task ID: {:?}", e)))?
+        } else {
+            return Err(DatabaseError::QueryError(format!("Unknown item type: {}", self.item)));
+        };
+
+        let mut builder = TimeEntryBuilder::new()
+            .with_id(id)
+            .with_person_id(person_id)
+            .with_item(item)
+            .with_start(self.started_at.0)
+            .with_end(self.ended_at.map(|date| date.0));
+        if let Some(note) = &self.note {
+            builder = builder.with_note(note);
+        }
+        Ok(builder.build())
+    }
+
+    fn into_stored_time_entry(self) -> Result<TimeEntry> {
+        let id = self.record_id()?;
+        self.into_time_entry(id)
+    }
+}
+
+// Define the repository trait locally to avoid circular dependencies
+#[async_trait]
+pub trait TimeEntryRepository {
+    async fn create(&self, entry: TimeEntry) -> Result<()>;
+    async fn get_by_id(&self, id: Id<TimeEntry>) -> Result<Option<TimeEntry>>;
+    async fn get_all(&self) -> Result<Vec<TimeEntry>>;
+    /// Entries logged directly against one item, oldest first
+    async fn get_for_item(&self, item: &SchedulableItem) -> Result<Vec<TimeEntry>>;
+    /// Entries logged by one person, oldest first
+    async fn get_for_person(&self, person_id: &Id<Person>) -> Result<Vec<TimeEntry>>;
+    async fn update(&self, entry: TimeEntry) -> Result<()>;
+    async fn delete(&self, id: Id<TimeEntry>) -> Result<()>;
+}
+
+pub struct ProdTimeEntryRepository {
+    db: Arc<Database>,
+}
+
+impl ProdTimeEntryRepository {
+    pub fn new(db: Arc<Database>) -> Self {
+        Self { db }
+    }
+
+    async fn query_entries(&self, query: SelectQuery) -> Result<Vec<TimeEntry>> {
+        let records: Vec<TimeEntryRecord> = query.fetch(&self.db).await?;
+
+        records
+            .into_iter()
+            .map(TimeEntryRecord::into_stored_time_entry)
+            .collect::<Result<Vec<_>>>()
+    }
+}
+
+#[async_trait]
+impl TimeEntryRepository for ProdTimeEntryRepository {
+    async fn create(&self, entry: TimeEntry) -> Result<()> {
+        let entry_id = entry.id();
+        debug!("Creating time entry with ID: {}", entry_id);
+
+        let id_str = entry_id.to_string();
+        let entry_record = TimeEntryRecord::from(entry);
+
+        let result: Result<Option<TimeEntryRecord>> = self
+            .db
+            .client()
+            .create(("time_entry", id_str.as_str()))
+            .content(entry_record)
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to create time entry: {}", e)));
+
+        match result {
+            Ok(_) => {
+                debug!("Successfully created time entry with ID: {}", entry_id);
+                Ok(())
+            }
+            Err(e) => {
+                error!("Failed to create time entry: {:?}", e);
+                Err(e)
+            }
+        }
+    }
+
+    async fn get_by_id(&self, id: Id<TimeEntry>) -> Result<Option<TimeEntry>> {
+        debug!("Fetching time entry with ID: {}", id);
+
+        let id_str = id.to_string();
+
+        let result: Result<Option<TimeEntryRecord>> = self
+            .db
+            .client()
+            .select(("time_entry", id_str.as_str()))
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to get time entry by ID: {}", e)));
+
+        match result {
+            Ok(Some(entry_record)) => {
+                debug!("Found time entry record with ID: {}", id);
+                entry_record.into_time_entry(id).map(Some)
+            }
+            Ok(None) => {
+                debug!("No time entry found with ID: {}", id);
+                Ok(None)
+            }
+            Err(e) => {
+                error!("Failed to get time entry by ID: {:?}", e);
+                Err(e)
+            }
+        }
+    }
+
+    async fn get_all(&self) -> Result<Vec<TimeEntry>> {
+        debug!("Fetching all time entries");
+
+        let result: Result<Vec<TimeEntryRecord>> = self
+            .db
+            .client()
+            .select("time_entry")
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to get all time entries: {}", e)));
+
+        match result {
+            Ok(records) => {
+                let entries = records
+                    .into_iter()
+                    .map(TimeEntryRecord::into_stored_time_entry)
+                    .collect::<Result<Vec<_>>>()?;
+                debug!("Found {} time entries", entries.len());
+                Ok(entries)
+            }
+            Err(e) => {
+                error!("Failed to get all time entries: {:?}", e);
+                Err(e)
+            }
+        }
+    }
+
+    async fn get_for_item(&self, item: &SchedulableItem) -> Result<Vec<TimeEntry>> {
+        debug!("Fetching time entries for item: {}", item);
+
+        let entries = self
+            .query_entries(
+                SelectQuery::table("time_entry")
+                    .where_eq("item", item.to_string())
+                    .order_by("started_at", Order::Asc),
+            )
+            .await?;
+        debug!("Found {} time entries for item: {}", entries.len(), item);
+        Ok(entries)
+    }
+
+    async fn get_for_person(&self, person_id: &Id<Person>) -> Result<Vec<TimeEntry>> {
+        debug!("Fetching time entries for person: {}", person_id);
+
+        let entries = self
+            .query_entries(
+                SelectQuery::table("time_entry")
+                    .where_eq("person_id", person_id.to_string())
+                    .order_by("started_at", Order::Asc),
+            )
+            .await?;
+        debug!("Found {} time entries for person: {}", entries.len(), person_id);
+        Ok(entries)
+    }
+
+    async fn update(&self, entry: TimeEntry) -> Result<()> {
+        let entry_id = entry.id();
+        debug!("Updating time entry with ID: {}", entry_id);
+
+        let id_str = entry_id.to_string();
+        let entry_record = TimeEntryRecord::from(entry);
+
+        let result: Result<Option<TimeEntryRecord>> = self
+            .db
+            .client()
+            .update(("time_entry", id_str.as_str()))
+            .content(entry_record)
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to update time entry: {}", e)));
+
+        match result {
+            Ok(Some(_)) => {
+                debug!("Successfully updated time entry with ID: {}", entry_id);
+                Ok(())
+            }
+            Ok(None) => {
+                error!("Time entry not found for update: {}", entry_id);
+                Err(DatabaseError::QueryError(format!(
+                    "Time entry with ID {} not found",
+                    entry_id
+                )))
+            }
+            Err(e) => {
+                error!("Failed to update time entry: {:?}", e);
+                Err(e)
+            }
+        }
+    }
+
+    async fn delete(&self, id: Id<TimeEntry>) -> Result<()> {
+        debug!("Deleting time entry with ID: {}", id);
+
+        let id_str = id.to_string();
+
+        let result: Result<Option<TimeEntryRecord>> = self
+            .db
+            .client()
+            .delete(("time_entry", id_str.as_str()))
+            .await
+            .map_err(|e| DatabaseError::QueryError(format!("Failed to delete time entry: {}", e)));
+
+        match result {
+            Ok(Some(_)) => {
+                debug!("Successfully deleted time entry with ID: {}", id);
+                Ok(())
+            }
+            Ok(None) => {
+                error!("Time entry not found for deletion: {}", id);
+                Err(DatabaseError::QueryError(format!(
+                    "Time entry with ID {} not found",
+                    id
+                )))
+            }
+            Err(e) => {
+                error!("Failed to delete time entry: {:?}", e);
+                Err(e)
+            }
+        }
+    }
+}
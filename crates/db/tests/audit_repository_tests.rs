@@ -0,0 +1,73 @@
+use chrono::{Duration, Utc};
+use project_tracker_core::{
+    HasId,
+    builders::project_builder::ProjectBuilder,
+    id::Id,
+    models::{audit::AuditEntry, person::Person, schedulable::Schedulable},
+};
+use project_tracker_db::{
+    audit_repository::{AuditRepository, ProdAuditRepository},
+    database::Database,
+};
+use std::sync::Arc;
+
+async fn setup_test_db() -> Arc<Database> {
+    Arc::new(
+        Database::connect()
+            .await
+            .expect("Failed to connect to test database"),
+    )
+}
+
+#[tokio::test]
+async fn test_history_is_kept_per_entity() {
+    let db = setup_test_db().await;
+    let repo = ProdAuditRepository::new(db.clone());
+
+    let project = ProjectBuilder::new().with_name("Garden").build();
+    let mut renamed = project.clone();
+    renamed.rename("Vegetable garden");
+    let actor = Id::<Person>::new();
+    let created_at = Utc::now() - Duration::minutes(5);
+
+    let mut expected = AuditEntry::changes(None, Some(&project), None, created_at);
+    expected.extend(AuditEntry::changes(Some(&project), Some(&renamed), Some(actor.clone()), Utc::now()));
+    for entry in expected.iter().rev() {
+        repo.record(entry.clone()).await.expect("Failed to record audit entry");
+    }
+    let other = ProjectBuilder::new().with_name("Other").build();
+    for entry in AuditEntry::changes(None, Some(&other), None, Utc::now()) {
+        repo.record(entry).await.expect("Failed to record audit entry");
+    }
+
+    let history = repo
+        .get_for_entity(&project.id().to_string())
+        .await
+        .expect("Failed to get audit history");
+    assert_eq!(history, expected);
+    let last = history.last().unwrap();
+    assert_eq!(last.field(), "name");
+    assert_eq!(last.old_value(), Some("Garden"));
+    assert_eq!(last.new_value(), Some("Vegetable garden"));
+    assert_eq!(last.actor(), Some(&actor));
+}
+
+#[tokio::test]
+async fn test_entries_cannot_be_changed() {
+    let db = setup_test_db().await;
+    let repo = ProdAuditRepository::new(db.clone());
+
+    let project = ProjectBuilder::new().with_name("Garden").build();
+    let entry = AuditEntry::changes(None, Some(&project), None, Utc::now()).remove(0);
+    repo.record(entry.clone()).await.expect("Failed to record audit entry");
+
+    let mut response = db
+        .client()
+        .query("UPDATE type::thing('audit', $id) SET new_value = 'Tampered'")
+        .bind(("id", entry.id().to_string()))
+        .await
+        .expect("Failed to send update");
+    assert!(response.take::<Vec<serde_json::Value>>(0).is_err());
+    let history = repo.get_for_entity(&project.id().to_string()).await.unwrap();
+    assert_eq!(history, vec![entry]);
+}
@@ -0,0 +1,78 @@
+use chrono::{Duration, Utc};
+use project_tracker_core::{
+    HasId,
+    builders::comment_builder::CommentBuilder,
+    id::Id,
+    models::{person::Person, project::Project, schedulable::SchedulableItem, task::Task},
+};
+use project_tracker_db::{
+    comment_repository::{CommentRepository, ProdCommentRepository},
+    database::Database,
+};
+use std::sync::Arc;
+
+async fn setup_test_db() -> Arc<Database> {
+    Arc::new(
+        Database::connect()
+            .await
+            .expect("Failed to connect to test database"),
+    )
+}
+
+#[tokio::test]
+async fn test_create_edit_and_get_comment() {
+    let db = setup_test_db().await;
+    let repo = ProdCommentRepository::new(db.clone());
+
+    let created_at = Utc::now() - Duration::hours(1);
+    let mut comment = CommentBuilder::new()
+        .with_item(SchedulableItem::Task(Id::<Task>::new()))
+        .with_author_id(Id::<Person>::new())
+        .with_body("First draft")
+        .with_created_at(created_at)
+        .build();
+    let comment_id = comment.id();
+    repo.create(comment.clone()).await.expect("Failed to create comment");
+
+    let stored = repo.get_by_id(comment_id.clone()).await.unwrap().expect("Comment not found");
+    assert_eq!(stored, comment);
+
+    comment.edit("Second draft", created_at + Duration::minutes(10)).unwrap();
+    repo.update(comment.clone()).await.expect("Failed to update comment");
+
+    let stored = repo.get_by_id(comment_id.clone()).await.unwrap().expect("Comment not found");
+    assert_eq!(stored.body(), "Second draft");
+    assert_eq!(stored.edits().len(), 1);
+    assert_eq!(stored.edits()[0].body, "First draft");
+    assert_eq!(stored, comment);
+
+    repo.delete(comment_id.clone()).await.expect("Failed to delete comment");
+    assert!(repo.get_by_id(comment_id).await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn test_comments_are_kept_per_item() {
+    let db = setup_test_db().await;
+    let repo = ProdCommentRepository::new(db.clone());
+
+    let item = SchedulableItem::Project(Id::<Project>::new());
+    let start = Utc::now() - Duration::hours(2);
+    let first = CommentBuilder::new()
+        .with_item(item.clone())
+        .with_body("Kick-off")
+        .with_created_at(start)
+        .build();
+    let reply = first.reply(Id::<Person>::new(), "Agreed", start + Duration::minutes(5));
+    let elsewhere = CommentBuilder::new()
+        .with_item(SchedulableItem::Task(Id::<Task>::new()))
+        .with_body("Unrelated")
+        .build();
+    for comment in [reply.clone(), elsewhere, first.clone()] {
+        repo.create(comment).await.expect("Failed to create comment");
+    }
+
+    let comments = repo.get_for_item(&item).await.expect("Failed to get comments");
+    assert_eq!(comments, vec![first.clone(), reply]);
+    assert_eq!(comments[1].parent_id(), Some(&first.id()));
+    assert_eq!(repo.get_all().await.unwrap().len(), 3);
+}
@@ -0,0 +1,94 @@
+use std::{collections::HashMap, path::PathBuf, sync::Arc};
+
+use project_tracker_core::{HasId, builders::project_builder::ProjectBuilder, id::Id, models::project::Project};
+use project_tracker_db::{
+    DatabaseError,
+    config::{DatabaseConfig, DatabaseEngine},
+    database::Database,
+    project_repository::{ProdProjectRepository, ProjectRepository},
+    relations::DeletePolicy,
+};
+
+fn lookup(vars: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+    let vars: HashMap<String, String> = vars.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect();
+    move |key| vars.get(key).cloned()
+}
+
+#[test]
+fn test_engine_from_url() {
+    assert_eq!("mem://".parse::<DatabaseEngine>().unwrap(), DatabaseEngine::Memory);
+    assert_eq!(
+        "surrealkv://data/tracker".parse::<DatabaseEngine>().unwrap(),
+        DatabaseEngine::SurrealKv(PathBuf::from("data/tracker"))
+    );
+    assert_eq!(
+        "rocksdb:///var/lib/tracker".parse::<DatabaseEngine>().unwrap(),
+        DatabaseEngine::RocksDb(PathBuf::from("/var/lib/tracker"))
+    );
+    assert_eq!(
+        "ws://localhost:8000".parse::<DatabaseEngine>().unwrap(),
+        DatabaseEngine::Remote("ws://localhost:8000".into())
+    );
+
+    for url in ["surreal://localhost:8000", "localhost:8000", "surrealkv://", "ws://"] {
+        assert!(url.parse::<DatabaseEngine>().is_err(), "{url} should be rejected");
+    }
+}
+
+#[test]
+fn test_config_from_environment() {
+    let config = DatabaseConfig::from_lookup(lookup(&[])).unwrap();
+    assert_eq!(config, DatabaseConfig::default());
+    assert_eq!(config.engine(), &DatabaseEngine::Memory);
+
+    let config = DatabaseConfig::from_lookup(lookup(&[
+        ("DATABASE_URL", "ws://db.internal:8000"),
+        ("DATABASE_NAMESPACE", "tracker"),
+        ("DATABASE_NAME", "prod"),
+        ("DATABASE_USER", "root"),
+        ("DATABASE_PASSWORD", "secret"),
+    ]))
+    .unwrap();
+    assert_eq!(config.engine(), &DatabaseEngine::Remote("ws://db.internal:8000".into()));
+    assert_eq!(config.namespace(), "tracker");
+    assert_eq!(config.database(), "prod");
+    assert_eq!(config.credentials().unwrap().username, "root");
+    assert!(!format!("{config:?}").contains("secret"));
+
+    let result = DatabaseConfig::from_lookup(lookup(&[("DATABASE_USER", "root")]));
+    assert!(matches!(result, Err(DatabaseError::ConnectionError(_))));
+}
+
+#[test]
+fn test_delete_policy_from_environment() {
+    let config = DatabaseConfig::from_lookup(lookup(&[])).unwrap();
+    assert_eq!(config.delete_policy(), DeletePolicy::Detach);
+
+    for (value, policy) in [("restrict", DeletePolicy::Restrict), ("Cascade", DeletePolicy::Cascade), ("detach", DeletePolicy::Detach)] {
+        let config = DatabaseConfig::from_lookup(lookup(&[("DATABASE_DELETE_POLICY", value)])).unwrap();
+        assert_eq!(config.delete_policy(), policy);
+    }
+
+    let result = DatabaseConfig::from_lookup(lookup(&[("DATABASE_DELETE_POLICY", "orphan")]));
+    assert!(matches!(result, Err(DatabaseError::ConnectionError(_))));
+}
+
+#[tokio::test]
+async fn test_surrealkv_storage_survives_reconnect() {
+    let path = std::env::temp_dir().join(format!("project-tracker-{}", Id::<Project>::new()));
+    let config = DatabaseConfig::new(DatabaseEngine::SurrealKv(path.clone())).with_namespace("tracker");
+    let project = ProjectBuilder::new().with_name("Kept on disk").build();
+
+    {
+        let db = Arc::new(Database::connect_with(&config).await.expect("Failed to open on-disk database"));
+        let repo = ProdProjectRepository::new(db);
+        repo.create(project.clone()).await.expect("Failed to create project");
+    }
+
+    let db = Arc::new(Database::connect_with(&config).await.expect("Failed to reopen on-disk database"));
+    let repo = ProdProjectRepository::new(db);
+    let stored = repo.get_by_id(project.id()).await.unwrap();
+    assert_eq!(stored.map(|project| project.id()), Some(project.id()));
+
+    let _ = std::fs::remove_dir_all(path);
+}
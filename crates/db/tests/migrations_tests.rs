@@ -0,0 +1,88 @@
+use project_tracker_db::{
+    DatabaseError,
+    config::DatabaseConfig,
+    database::Database,
+    migrations::{MIGRATIONS, Migration},
+};
+
+async fn open_unmigrated_db() -> Database {
+    Database::open(&DatabaseConfig::default())
+        .await
+        .expect("Failed to open test database")
+}
+
+fn with_extra(extra: Migration) -> Vec<Migration> {
+    let mut migrations = MIGRATIONS.to_vec();
+    migrations.push(extra);
+    migrations
+}
+
+#[tokio::test]
+async fn test_migrate_applies_pending_migrations_once() {
+    let db = open_unmigrated_db().await;
+
+    let status = db.migration_status().await.unwrap();
+    assert_eq!(status.len(), MIGRATIONS.len());
+    assert!(status.iter().all(|migration| !migration.is_applied()));
+
+    let ran = db.migrate().await.expect("Failed to migrate");
+    assert_eq!(ran, MIGRATIONS.iter().map(|migration| migration.version).collect::<Vec<_>>());
+
+    let ran = db.migrate().await.expect("Failed to migrate again");
+    assert!(ran.is_empty());
+    let status = db.migration_status().await.unwrap();
+    assert!(status.iter().all(|migration| migration.is_applied()));
+    assert!(status[0].to_string().starts_with("0001 create_project applied on"));
+}
+
+#[tokio::test]
+async fn test_connect_migrates_forward() {
+    let db = Database::connect().await.expect("Failed to connect to test database");
+    let status = db.migration_status().await.unwrap();
+    assert!(status.iter().all(|migration| migration.is_applied()));
+
+    let extra = Migration {
+        version: 100,
+        name: "add_project_color",
+        script: "DEFINE FIELD color ON project TYPE option<string>;",
+    };
+    let migrations = with_extra(extra);
+    assert_eq!(db.migrate_with(&migrations).await.unwrap(), vec![100]);
+
+    let status = db.migration_status_with(&migrations).await.unwrap();
+    assert!(status.last().unwrap().is_applied());
+    assert_eq!(status.last().unwrap().name, "add_project_color");
+}
+
+#[tokio::test]
+async fn test_failed_migration_is_rolled_back() {
+    let db = Database::connect().await.expect("Failed to connect to test database");
+    let broken = Migration {
+        version: 100,
+        name: "broken",
+        script: "DEFINE FIELD color ON project TYPE option<string>; THROW 'stop here';",
+    };
+    let migrations = with_extra(broken);
+
+    let result = db.migrate_with(&migrations).await;
+    assert!(matches!(result, Err(DatabaseError::SchemaError(_))));
+
+    let status = db.migration_status_with(&migrations).await.unwrap();
+    assert!(!status.last().unwrap().is_applied());
+    let mut response = db.client().query("INFO FOR TABLE project").await.unwrap();
+    let info: Option<serde_json::Value> = response.take(0).unwrap();
+    assert!(info.unwrap()["fields"].get("color").is_none());
+}
+
+#[tokio::test]
+async fn test_unknown_applied_migration_is_reported() {
+    let db = Database::connect().await.expect("Failed to connect to test database");
+
+    let result = db.migration_status_with(&MIGRATIONS[..MIGRATIONS.len() - 1]).await;
+    assert!(matches!(result, Err(DatabaseError::SchemaError(_))));
+
+    let mut renamed = MIGRATIONS.to_vec();
+    renamed[0].name = "create_projects";
+    let result = db.migrate_with(&renamed).await;
+    assert!(matches!(result, Err(DatabaseError::SchemaError(_))));
+}
@@ -0,0 +1,73 @@
+use chrono::{Duration, Utc};
+use project_tracker_core::{
+    HasId,
+    builders::{milestone_builder::MilestoneBuilder, project_builder::ProjectBuilder, task_builder::TaskBuilder},
+    id::Id,
+    models::{milestone::Milestone, schedulable::SchedulableItem},
+};
+use project_tracker_db::{
+    database::Database,
+    milestone_repository::{MilestoneRepository, ProdMilestoneRepository},
+};
+use std::sync::Arc;
+
+async fn setup_test_db() -> Arc<Database> {
+    Arc::new(
+        Database::connect()
+            .await
+            .expect("Failed to connect to test database"),
+    )
+}
+
+#[tokio::test]
+async fn test_create_and_get_milestone() {
+    let db = setup_test_db().await;
+    let repo = ProdMilestoneRepository::new(db.clone());
+
+    let items = vec![
+        SchedulableItem::Project(ProjectBuilder::new().build().id()),
+        SchedulableItem::Task(TaskBuilder::new().build().id()),
+    ];
+    let milestone = MilestoneBuilder::new()
+        .with_name("Launch")
+        .with_description("Public launch")
+        .with_target_date(Utc::now() + Duration::days(30))
+        .with_items(items.clone())
+        .build();
+    let milestone_id = milestone.id();
+
+    repo.create(milestone.clone()).await.expect("Failed to create milestone");
+
+    let retrieved = repo
+        .get_by_id(milestone_id)
+        .await
+        .expect("Failed to get milestone")
+        .expect("Milestone not found");
+
+    assert_eq!(retrieved.name(), "Launch");
+    assert_eq!(retrieved.description(), "Public launch");
+    assert_eq!(retrieved.items(), items);
+    assert_eq!(retrieved.reached_at(), None);
+    assert_eq!(retrieved.target_date().timestamp(), milestone.target_date().timestamp());
+}
+
+#[tokio::test]
+async fn test_update_and_delete_milestone() {
+    let db = setup_test_db().await;
+    let repo = ProdMilestoneRepository::new(db.clone());
+
+    let mut milestone = MilestoneBuilder::new().with_name("Beta").build();
+    let milestone_id = milestone.id();
+    repo.create(milestone.clone()).await.expect("Failed to create milestone");
+
+    milestone.mark_reached(Utc::now());
+    repo.update(milestone).await.expect("Failed to update milestone");
+
+    let all = repo.get_all().await.expect("Failed to get milestones");
+    assert_eq!(all.len(), 1);
+    assert!(all[0].is_reached());
+
+    repo.delete(milestone_id.clone()).await.expect("Failed to delete milestone");
+    assert!(repo.get_by_id(milestone_id).await.unwrap().is_none());
+    assert!(repo.delete(Id::<Milestone>::new()).await.is_err());
+}
@@ -0,0 +1,66 @@
+use project_tracker_core::{
+    HasId,
+    builders::person_builder::PersonBuilder,
+    id::Id,
+    models::person::Person,
+};
+use project_tracker_db::{
+    database::Database,
+    person_repository::{PersonRepository, ProdPersonRepository},
+};
+use std::sync::Arc;
+
+async fn setup_test_db() -> Arc<Database> {
+    Arc::new(
+        Database::connect()
+            .await
+            .expect("Failed to connect to test database"),
+    )
+}
+
+#[tokio::test]
+async fn test_create_and_get_person() {
+    let db = setup_test_db().await;
+    let repo = ProdPersonRepository::new(db.clone());
+
+    let person = PersonBuilder::new()
+        .with_first_name("Ada")
+        .with_last_name("Lovelace")
+        .build();
+    let person_id = person.id();
+
+    let create_result = repo.create(person.clone()).await;
+    assert!(
+        create_result.is_ok(),
+        "Failed to create person: {:?}",
+        create_result
+    );
+
+    let retrieved = repo.get_by_id(person_id).await.unwrap();
+    assert_eq!(retrieved, Some(person));
+    assert!(repo.get_by_id(Id::<Person>::new()).await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn test_list_update_and_delete_people() {
+    let db = setup_test_db().await;
+    let repo = ProdPersonRepository::new(db.clone());
+
+    let first = PersonBuilder::new().with_first_name("Grace").with_last_name("Hopper").build();
+    let second = PersonBuilder::new().with_first_name("Alan").with_last_name("Turing").build();
+    repo.create(first.clone()).await.unwrap();
+    repo.create(second.clone()).await.unwrap();
+
+    let people = repo.get_all().await.unwrap();
+    assert_eq!(people.len(), 2);
+    assert!(people.contains(&first));
+
+    let mut renamed = first.clone();
+    renamed.rename("Grace", "Brewster Hopper");
+    assert!(repo.update(renamed.clone()).await.is_ok());
+    assert_eq!(repo.get_by_id(first.id()).await.unwrap(), Some(renamed));
+
+    assert!(repo.delete(second.id()).await.is_ok());
+    assert!(repo.get_by_id(second.id()).await.unwrap().is_none());
+    assert!(repo.delete(second.id()).await.is_err());
+}
@@ -1,17 +1,22 @@
-use chrono::Utc;
+use chrono::{Duration, Utc};
 use project_tracker_core::{
     HasId,
-    builders::project_builder::ProjectBuilder,
+    builders::{person_builder::PersonBuilder, project_builder::ProjectBuilder, tag_builder::TagBuilder, task_builder::TaskBuilder},
     id::Id,
     models::{
+        estimate::Estimate,
         person::Person,
         project::Project,
-        schedulable::{Schedulable, SchedulableItemStatus},
+        schedulable::{Priority, Schedulable, SchedulableItem, SchedulableItemStatus},
+        tag::Tag,
     },
 };
 use project_tracker_db::{
     database::Database,
+    person_repository::{PersonRepository, ProdPersonRepository},
     project_repository::{ProdProjectRepository, ProjectRepository},
+    tag_repository::{ProdTagRepository, TagRepository},
+    task_repository::{ProdTaskRepository, TaskRepository},
 };
 use std::sync::Arc;
 
@@ -23,6 +28,37 @@ async fn setup_test_db() -> Arc<Database> {
     )
 }
 
+// Stores the owner and tags an item points at, which have to exist before it is saved
+async fn store_owner_and_tags(db: &Arc<Database>, owner_id: Option<Id<Person>>, tags: Vec<Id<Tag>>) {
+    if let Some(owner_id) = owner_id {
+        let owner = PersonBuilder::new().with_id(owner_id).with_first_name("Ada").with_last_name("Owner").build();
+        ProdPersonRepository::new(db.clone()).create(owner).await.expect("Failed to create owner");
+    }
+    for tag_id in tags {
+        let tag = TagBuilder::new().with_id(tag_id.clone()).with_name(&tag_id.to_string()).build();
+        ProdTagRepository::new(db.clone()).create(tag).await.expect("Failed to create tag");
+    }
+}
+
+// Stores everything `project` references, so it can be saved itself
+async fn store_references(db: &Arc<Database>, project: &Project) {
+    store_owner_and_tags(db, project.owner_id().cloned(), project.tags()).await;
+
+    let projects = ProdProjectRepository::new(db.clone());
+    let tasks = ProdTaskRepository::new(db.clone());
+    for child in project.children() {
+        match child {
+            SchedulableItem::Project(id) => {
+                projects.create(ProjectBuilder::new().with_id(id).with_name("Child").build()).await.unwrap()
+            }
+            SchedulableItem::Task(id) => tasks.create(TaskBuilder::new().with_id(id).with_name("Child").build()).await.unwrap(),
+        }
+    }
+    for id in project.dependencies() {
+        projects.create(ProjectBuilder::new().with_id(id).with_name("Dependency").build()).await.unwrap();
+    }
+}
+
 #[tokio::test]
 async fn test_create_and_get_project() {
     let db = setup_test_db().await;
@@ -102,6 +138,7 @@ async fn test_project_with_complex_data() {
         .build();
 
     let project_id = project.id();
+    store_references(&db, &project).await;
 
     // Create and retrieve
     let create_result = repo.create(project.clone()).await;
@@ -168,3 +205,100 @@ async fn test_project_serialization_deserialization() {
     assert!(retrieved.description().contains("tabs"));
 }
 
+#[tokio::test]
+async fn test_project_estimate_round_trip() {
+    let db = setup_test_db().await;
+    let repo = ProdProjectRepository::new(db.clone());
+
+    let mut project = ProjectBuilder::new()
+        .with_name("Estimated Project")
+        .with_estimate(Some(Estimate::Points(13)))
+        .build();
+    let project_id = project.id();
+    repo.create(project.clone()).await.expect("Failed to create project");
+
+    let retrieved = repo.get_by_id(project_id.clone()).await.unwrap().expect("Project not found");
+    assert_eq!(retrieved.estimate(), Some(Estimate::Points(13)));
+
+    project.set_estimate(Estimate::Hours(40));
+    repo.update(project).await.expect("Failed to update project");
+
+    let retrieved = repo.get_by_id(project_id.clone()).await.unwrap().expect("Project not found");
+    assert_eq!(retrieved.estimate(), Some(Estimate::Hours(40)));
+}
+
+fn detailed_project(name: &str, owner: Option<Id<Person>>) -> Project {
+    ProjectBuilder::new()
+        .with_name(name)
+        .with_owner_id(owner)
+        .with_description("Stored and read back")
+        .with_tags(vec![Id::<Tag>::new(), Id::<Tag>::new()])
+        .with_children(vec![SchedulableItem::Project(Id::new()), SchedulableItem::Task(Id::new())])
+        .with_dependencies(vec![Id::new()])
+        .with_start_date(Some(Utc::now()))
+        .with_due_date(Some(Utc::now() + Duration::days(5)))
+        .with_status(SchedulableItemStatus::InProgress)
+        .with_priority(Priority::High)
+        .build()
+}
+
+#[tokio::test]
+async fn test_get_all_round_trips_every_field() {
+    let db = setup_test_db().await;
+    let repo = ProdProjectRepository::new(db.clone());
+
+    let projects = vec![detailed_project("First", None), detailed_project("Second", Some(Id::new()))];
+    for project in &projects {
+        store_references(&db, project).await;
+        repo.create(project.clone()).await.expect("Failed to create project");
+    }
+
+    // Leave out the children and dependencies stored alongside
+    let mut stored = repo.get_all().await.expect("Failed to list projects");
+    stored.retain(|stored| projects.iter().any(|project| project.id() == stored.id()));
+    stored.sort_by(|a, b| a.name().cmp(b.name()));
+    assert_eq!(stored.len(), 2);
+    for (stored, project) in stored.iter().zip(&projects) {
+        assert!(stored == project, "{stored:?} != {project:?}");
+    }
+}
+
+#[tokio::test]
+async fn test_get_by_owner_returns_only_their_projects() {
+    let db = setup_test_db().await;
+    let repo = ProdProjectRepository::new(db.clone());
+    let owner = Id::<Person>::new();
+
+    let owned = detailed_project("Owned", Some(owner.clone()));
+    for project in [owned.clone(), detailed_project("Someone else's", Some(Id::new())), detailed_project("Unowned", None)] {
+        store_references(&db, &project).await;
+        repo.create(project).await.unwrap();
+    }
+
+    let stored = repo.get_by_owner(owner).await.expect("Failed to query by owner");
+    assert_eq!(stored.len(), 1);
+    assert!(stored[0] == owned);
+    assert!(repo.get_by_owner(Id::new()).await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_update_round_trips_through_record() {
+    let db = setup_test_db().await;
+    let repo = ProdProjectRepository::new(db.clone());
+
+    let mut project = detailed_project("Before", None);
+    store_references(&db, &project).await;
+    repo.create(project.clone()).await.unwrap();
+
+    project.rename("After");
+    project.remove_all_tags();
+    project.remove_all_children();
+    project.remove_due_date();
+    project.promote();
+    repo.update(project.clone()).await.expect("Failed to update project");
+
+    let stored = repo.get_by_id(project.id()).await.unwrap().expect("Project not found");
+    assert!(stored == project);
+    let listed = repo.get_all().await.unwrap();
+    assert!(listed.contains(&project));
+}
@@ -0,0 +1,80 @@
+use project_tracker_core::{
+    HasId,
+    builders::{project_builder::ProjectBuilder, tag_builder::TagBuilder, task_builder::TaskBuilder},
+    models::schedulable::SchedulableItem,
+};
+use project_tracker_db::{
+    database::Database,
+    project_repository::{ProdProjectRepository, ProjectRepository},
+    query::{Comparison, Order, SelectQuery},
+    tag_repository::{ProdTagRepository, TagRepository},
+    task_repository::{ProdTaskRepository, TaskRepository},
+};
+use std::sync::Arc;
+
+async fn setup_test_db() -> Arc<Database> {
+    Arc::new(
+        Database::connect()
+            .await
+            .expect("Failed to connect to test database"),
+    )
+}
+
+#[test]
+fn test_query_text_only_references_parameters() {
+    let query = SelectQuery::table("task")
+        .where_eq("owner_id", "person-01' OR true --")
+        .filter("due_date", Comparison::Lt, "2030-01-01")
+        .order_by("due_date", Order::Asc)
+        .order_by("name", Order::Desc)
+        .limit(10);
+
+    assert_eq!(
+        query.to_surql(),
+        "SELECT * FROM task WHERE owner_id = $p0 AND due_date < $p1 ORDER BY due_date ASC, name DESC LIMIT 10"
+    );
+    let names: Vec<String> = query.bindings().map(|(name, _)| name).collect();
+    assert_eq!(names, vec!["p0", "p1"]);
+}
+
+#[test]
+#[should_panic(expected = "not a valid SurrealQL identifier")]
+fn test_field_names_must_be_identifiers() {
+    let _ = SelectQuery::table("task").where_eq("name = '' OR true", "x");
+}
+
+#[tokio::test]
+async fn test_injected_value_matches_nothing() {
+    let db = setup_test_db().await;
+    let repo = ProdTagRepository::new(db.clone());
+    repo.create(TagBuilder::new().with_name("backend").build()).await.unwrap();
+
+    let found = repo.get_by_name("' OR name != '").await.unwrap();
+    assert!(found.is_none());
+    let found = repo.get_by_name("backend").await.unwrap();
+    assert!(found.is_some());
+}
+
+#[tokio::test]
+async fn test_get_by_parent_project_reads_child_tasks() {
+    let db = setup_test_db().await;
+    let project_repo = ProdProjectRepository::new(db.clone());
+    let task_repo = ProdTaskRepository::new(db.clone());
+
+    let child = TaskBuilder::new().with_name("Child task").build();
+    let other = TaskBuilder::new().with_name("Unrelated task").build();
+    task_repo.create(child.clone()).await.unwrap();
+    task_repo.create(other).await.unwrap();
+    let project = ProjectBuilder::new()
+        .with_name("Parent")
+        .with_children(vec![SchedulableItem::Task(child.id())])
+        .build();
+    project_repo.create(project.clone()).await.unwrap();
+
+    let tasks = task_repo.get_by_parent_project(project.id()).await.unwrap();
+    assert_eq!(tasks.iter().map(|task| task.id()).collect::<Vec<_>>(), vec![child.id()]);
+
+    let empty = ProjectBuilder::new().with_name("Empty").build();
+    project_repo.create(empty.clone()).await.unwrap();
+    assert!(task_repo.get_by_parent_project(empty.id()).await.unwrap().is_empty());
+}
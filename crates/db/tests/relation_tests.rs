@@ -0,0 +1,350 @@
+use project_tracker_core::{
+    HasId,
+    builders::{project_builder::ProjectBuilder, task_builder::TaskBuilder},
+    id::Id,
+    models::{
+        person::Person,
+        schedulable::{Schedulable, SchedulableItem},
+        tag::Tag,
+        task::Task,
+    },
+};
+use project_tracker_db::{
+    DatabaseError,
+    database::Database,
+    project_repository::{ProdProjectRepository, ProjectRepository},
+    relations::DeletePolicy,
+    task_repository::{ProdTaskRepository, TaskRepository},
+};
+use std::sync::Arc;
+
+async fn setup_test_db() -> Arc<Database> {
+    Arc::new(
+        Database::connect()
+            .await
+            .expect("Failed to connect to test database"),
+    )
+}
+
+#[tokio::test]
+async fn test_get_parent_and_tasks_of_project() {
+    let db = setup_test_db().await;
+    let projects = ProdProjectRepository::new(db.clone());
+    let tasks = ProdTaskRepository::new(db.clone());
+
+    let first = TaskBuilder::new().with_name("First").build();
+    let second = TaskBuilder::new().with_name("Second").build();
+    tasks.create(first.clone()).await.unwrap();
+    tasks.create(second.clone()).await.unwrap();
+    let project = ProjectBuilder::new()
+        .with_name("Parent")
+        .with_children(vec![
+            SchedulableItem::Task(first.id()),
+            SchedulableItem::Task(second.id()),
+        ])
+        .build();
+    projects.create(project.clone()).await.unwrap();
+
+    let parent = projects
+        .get_parent(&SchedulableItem::Task(first.id()))
+        .await
+        .unwrap()
+        .expect("Task has no parent");
+    assert_eq!(parent.id(), project.id());
+    assert_eq!(parent.children(), project.children(), "Children lost their order");
+
+    let mut children: Vec<_> = tasks
+        .get_by_parent_project(project.id())
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|task| task.id())
+        .collect();
+    children.sort_by_key(|id| id.to_string());
+    let mut expected = vec![first.id(), second.id()];
+    expected.sort_by_key(|id| id.to_string());
+    assert_eq!(children, expected);
+
+    let orphan = projects
+        .get_parent(&SchedulableItem::Project(project.id()))
+        .await
+        .unwrap();
+    assert!(orphan.is_none());
+}
+
+#[tokio::test]
+async fn test_get_descendants_follows_nested_projects() {
+    let db = setup_test_db().await;
+    let projects = ProdProjectRepository::new(db.clone());
+    let tasks = ProdTaskRepository::new(db.clone());
+
+    let subtask = TaskBuilder::new().with_name("Subtask").build();
+    let task = TaskBuilder::new()
+        .with_name("Task")
+        .with_children(vec![subtask.id()])
+        .build();
+    let sub_project = ProjectBuilder::new()
+        .with_name("Sub project")
+        .with_children(vec![SchedulableItem::Task(task.id())])
+        .build();
+    let root = ProjectBuilder::new()
+        .with_name("Root")
+        .with_children(vec![SchedulableItem::Project(sub_project.id())])
+        .build();
+    tasks.create(subtask.clone()).await.unwrap();
+    tasks.create(task.clone()).await.unwrap();
+    projects.create(sub_project.clone()).await.unwrap();
+    projects.create(root.clone()).await.unwrap();
+
+    let descendants = projects.get_descendants(root.id()).await.unwrap();
+    assert_eq!(descendants.len(), 3, "Unexpected descendants: {:?}", descendants);
+    assert!(descendants.contains(&SchedulableItem::Project(sub_project.id())));
+    assert!(descendants.contains(&SchedulableItem::Task(task.id())));
+    assert!(descendants.contains(&SchedulableItem::Task(subtask.id())));
+
+    let leaf = projects.get_descendants(sub_project.id()).await.unwrap();
+    assert_eq!(leaf.len(), 2);
+}
+
+#[tokio::test]
+async fn test_get_dependents_returns_what_is_waiting() {
+    let db = setup_test_db().await;
+    let projects = ProdProjectRepository::new(db.clone());
+    let tasks = ProdTaskRepository::new(db.clone());
+
+    let blocker = TaskBuilder::new().with_name("Blocker").build();
+    let waiting = TaskBuilder::new()
+        .with_name("Waiting")
+        .with_dependencies(vec![blocker.id()])
+        .build();
+    let unrelated = TaskBuilder::new().with_name("Unrelated").build();
+    tasks.create(blocker.clone()).await.unwrap();
+    tasks.create(waiting.clone()).await.unwrap();
+    tasks.create(unrelated.clone()).await.unwrap();
+
+    let dependents = tasks.get_dependents(blocker.id()).await.unwrap();
+    assert_eq!(dependents.len(), 1);
+    assert_eq!(dependents[0].id(), waiting.id());
+    assert_eq!(dependents[0].dependencies(), vec![blocker.id()]);
+    assert!(tasks.get_dependents(unrelated.id()).await.unwrap().is_empty());
+
+    let base = ProjectBuilder::new().with_name("Base").build();
+    let follow_up = ProjectBuilder::new()
+        .with_name("Follow up")
+        .with_dependencies(vec![base.id()])
+        .build();
+    projects.create(base.clone()).await.unwrap();
+    projects.create(follow_up.clone()).await.unwrap();
+
+    let dependents = projects.get_dependents(base.id()).await.unwrap();
+    assert_eq!(dependents.len(), 1);
+    assert_eq!(dependents[0].id(), follow_up.id());
+}
+
+#[tokio::test]
+async fn test_deleting_an_item_removes_its_links() {
+    let db = setup_test_db().await;
+    let projects = ProdProjectRepository::new(db.clone());
+    let tasks = ProdTaskRepository::new(db.clone());
+
+    let kept = TaskBuilder::new().with_name("Kept").build();
+    let removed = TaskBuilder::new().with_name("Removed").build();
+    let waiting = TaskBuilder::new()
+        .with_name("Waiting")
+        .with_dependencies(vec![removed.id(), kept.id()])
+        .build();
+    tasks.create(kept.clone()).await.unwrap();
+    tasks.create(removed.clone()).await.unwrap();
+    tasks.create(waiting.clone()).await.unwrap();
+    let project = ProjectBuilder::new()
+        .with_name("Parent")
+        .with_children(vec![
+            SchedulableItem::Task(removed.id()),
+            SchedulableItem::Task(kept.id()),
+        ])
+        .build();
+    projects.create(project.clone()).await.unwrap();
+
+    tasks.delete(removed.id()).await.unwrap();
+
+    let project = projects.get_by_id(project.id()).await.unwrap().unwrap();
+    assert_eq!(project.children(), vec![SchedulableItem::Task(kept.id())]);
+    let waiting = tasks.get_by_id(waiting.id()).await.unwrap().unwrap();
+    assert_eq!(waiting.dependencies(), vec![kept.id()]);
+}
+
+#[tokio::test]
+async fn test_update_replaces_links() {
+    let db = setup_test_db().await;
+    let projects = ProdProjectRepository::new(db.clone());
+    let tasks = ProdTaskRepository::new(db.clone());
+
+    let old_child = TaskBuilder::new().with_name("Old").build();
+    let new_child = TaskBuilder::new().with_name("New").build();
+    tasks.create(old_child.clone()).await.unwrap();
+    tasks.create(new_child.clone()).await.unwrap();
+    let mut project = ProjectBuilder::new()
+        .with_name("Parent")
+        .with_children(vec![SchedulableItem::Task(old_child.id())])
+        .build();
+    projects.create(project.clone()).await.unwrap();
+
+    project.remove_all_children();
+    project.add_child(SchedulableItem::Task(new_child.id()));
+    projects.update(project.clone()).await.unwrap();
+
+    let stored = projects.get_by_id(project.id()).await.unwrap().unwrap();
+    assert_eq!(stored.children(), vec![SchedulableItem::Task(new_child.id())]);
+    assert!(
+        projects
+            .get_parent(&SchedulableItem::Task(old_child.id()))
+            .await
+            .unwrap()
+            .is_none()
+    );
+}
+
+#[tokio::test]
+async fn test_saving_with_missing_references_is_rejected() {
+    let db = setup_test_db().await;
+    let projects = ProdProjectRepository::new(db.clone());
+    let tasks = ProdTaskRepository::new(db.clone());
+
+    let missing_task = Id::<Task>::new();
+    let project = ProjectBuilder::new()
+        .with_name("Dangling")
+        .with_children(vec![SchedulableItem::Task(missing_task.clone())])
+        .build();
+    let result = projects.create(project.clone()).await;
+    match result {
+        Err(DatabaseError::IntegrityError(message)) => assert!(message.contains(&missing_task.to_string())),
+        other => panic!("Expected an integrity error, got {:?}", other),
+    }
+    assert!(projects.get_by_id(project.id()).await.unwrap().is_none());
+
+    let owned = TaskBuilder::new().with_name("Owned").with_owner_id(Some(Id::<Person>::new())).build();
+    assert!(matches!(tasks.create(owned).await, Err(DatabaseError::IntegrityError(_))));
+    let tagged = TaskBuilder::new().with_name("Tagged").with_tags(vec![Id::<Tag>::new()]).build();
+    assert!(matches!(tasks.create(tagged).await, Err(DatabaseError::IntegrityError(_))));
+
+    let mut task = TaskBuilder::new().with_name("Valid").build();
+    tasks.create(task.clone()).await.unwrap();
+    task.add_dependency(Id::new());
+    assert!(matches!(tasks.update(task).await, Err(DatabaseError::IntegrityError(_))));
+}
+
+#[tokio::test]
+async fn test_failed_links_leave_nothing_saved() {
+    let db = setup_test_db().await;
+    let projects = ProdProjectRepository::new(db.clone());
+    let tasks = ProdTaskRepository::new(db.clone());
+
+    let task = TaskBuilder::new().with_name("Task").build();
+    tasks.create(task.clone()).await.unwrap();
+    let repeated = vec![SchedulableItem::Task(task.id()), SchedulableItem::Task(task.id())];
+    let project = ProjectBuilder::new().with_name("Repeated").with_children(repeated.clone()).build();
+    assert!(matches!(projects.create(project.clone()).await, Err(DatabaseError::QueryError(_))));
+    assert!(projects.get_by_id(project.id()).await.unwrap().is_none());
+
+    let project = ProjectBuilder::new()
+        .with_name("Linked")
+        .with_children(vec![SchedulableItem::Task(task.id())])
+        .build();
+    projects.create(project.clone()).await.unwrap();
+    let renamed = ProjectBuilder::new()
+        .with_id(project.id())
+        .with_name("Renamed")
+        .with_children(repeated)
+        .build();
+    assert!(matches!(projects.update(renamed).await, Err(DatabaseError::QueryError(_))));
+    let stored = projects.get_by_id(project.id()).await.unwrap().unwrap();
+    assert_eq!(stored.name(), "Linked");
+    assert_eq!(stored.children(), vec![SchedulableItem::Task(task.id())]);
+}
+
+#[tokio::test]
+async fn test_restrict_refuses_to_delete_linked_items() {
+    let db = setup_test_db().await;
+    let projects = ProdProjectRepository::new(db.clone()).with_delete_policy(DeletePolicy::Restrict);
+    let tasks = ProdTaskRepository::new(db.clone()).with_delete_policy(DeletePolicy::Restrict);
+
+    let blocker = TaskBuilder::new().with_name("Blocker").build();
+    let waiting = TaskBuilder::new()
+        .with_name("Waiting")
+        .with_dependencies(vec![blocker.id()])
+        .build();
+    tasks.create(blocker.clone()).await.unwrap();
+    tasks.create(waiting.clone()).await.unwrap();
+    let project = ProjectBuilder::new()
+        .with_name("Parent")
+        .with_children(vec![SchedulableItem::Task(waiting.id())])
+        .build();
+    projects.create(project.clone()).await.unwrap();
+
+    assert!(matches!(tasks.delete(blocker.id()).await, Err(DatabaseError::IntegrityError(_))));
+    assert!(matches!(tasks.delete(waiting.id()).await, Err(DatabaseError::IntegrityError(_))));
+    assert!(matches!(projects.delete(project.id()).await, Err(DatabaseError::IntegrityError(_))));
+    assert!(tasks.get_by_id(blocker.id()).await.unwrap().is_some());
+
+    // Unlinking from the top down lets everything go
+    let mut project = project;
+    project.remove_all_children();
+    projects.update(project.clone()).await.unwrap();
+    projects.delete(project.id()).await.unwrap();
+    tasks.delete(waiting.id()).await.unwrap();
+    tasks.delete(blocker.id()).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_cascade_deletes_descendants() {
+    let db = setup_test_db().await;
+    let projects = ProdProjectRepository::new(db.clone()).with_delete_policy(DeletePolicy::Cascade);
+    let tasks = ProdTaskRepository::new(db.clone());
+
+    let subtask = TaskBuilder::new().with_name("Subtask").build();
+    let task = TaskBuilder::new()
+        .with_name("Task")
+        .with_children(vec![subtask.id()])
+        .build();
+    let outside = TaskBuilder::new()
+        .with_name("Outside")
+        .with_dependencies(vec![task.id()])
+        .build();
+    tasks.create(subtask.clone()).await.unwrap();
+    tasks.create(task.clone()).await.unwrap();
+    tasks.create(outside.clone()).await.unwrap();
+    let project = ProjectBuilder::new()
+        .with_name("Root")
+        .with_children(vec![SchedulableItem::Task(task.id())])
+        .build();
+    projects.create(project.clone()).await.unwrap();
+
+    projects.delete(project.id()).await.unwrap();
+
+    assert!(projects.get_by_id(project.id()).await.unwrap().is_none());
+    assert!(tasks.get_by_id(task.id()).await.unwrap().is_none());
+    assert!(tasks.get_by_id(subtask.id()).await.unwrap().is_none());
+    let outside = tasks.get_by_id(outside.id()).await.unwrap().expect("Unrelated task was deleted");
+    assert!(outside.dependencies().is_empty());
+}
+
+#[tokio::test]
+async fn test_detach_keeps_children() {
+    let db = setup_test_db().await;
+    let projects = ProdProjectRepository::new(db.clone());
+    let tasks = ProdTaskRepository::new(db.clone());
+    assert_eq!(projects.delete_policy(), DeletePolicy::Detach);
+
+    let task = TaskBuilder::new().with_name("Task").build();
+    tasks.create(task.clone()).await.unwrap();
+    let project = ProjectBuilder::new()
+        .with_name("Root")
+        .with_children(vec![SchedulableItem::Task(task.id())])
+        .build();
+    projects.create(project.clone()).await.unwrap();
+
+    projects.delete(project.id()).await.unwrap();
+
+    assert!(tasks.get_by_id(task.id()).await.unwrap().is_some());
+    assert!(projects.get_parent(&SchedulableItem::Task(task.id())).await.unwrap().is_none());
+}
@@ -0,0 +1,69 @@
+use project_tracker_core::{
+    HasId,
+    builders::{person_builder::PersonBuilder, project_builder::ProjectBuilder},
+    models::{
+        schedulable::{Schedulable, SchedulableItem, SchedulableItemStatus},
+        status_transition::StatusAction,
+    },
+};
+use project_tracker_db::{
+    database::Database,
+    status_transition_repository::{ProdStatusTransitionRepository, StatusTransitionRepository},
+};
+use std::sync::Arc;
+
+async fn setup_test_db() -> Arc<Database> {
+    Arc::new(
+        Database::connect()
+            .await
+            .expect("Failed to connect to test database"),
+    )
+}
+
+#[tokio::test]
+async fn test_record_and_get_history() {
+    let db = setup_test_db().await;
+    let repo = ProdStatusTransitionRepository::new(db.clone());
+
+    let person = PersonBuilder::new().with_first_name("Ada").build();
+    let mut project = ProjectBuilder::new().with_name("Workflow").build();
+
+    for _ in 0..3 {
+        let transition = project
+            .transition(StatusAction::Promote, Some(person.id()))
+            .expect("Promotion should be allowed");
+        repo.record(transition).await.expect("Failed to record transition");
+    }
+
+    let history = repo
+        .get_for_item(&SchedulableItem::Project(project.id()))
+        .await
+        .expect("Failed to get history");
+
+    assert_eq!(history.len(), 3);
+    assert_eq!(history[0].from(), SchedulableItemStatus::NotStarted);
+    assert_eq!(history[2].to(), SchedulableItemStatus::InReview);
+    assert_eq!(history[2].by(), Some(&person.id()));
+    assert!(history.windows(2).all(|pair| pair[0].at() <= pair[1].at()));
+    assert_eq!(project.status(), SchedulableItemStatus::InReview);
+}
+
+#[tokio::test]
+async fn test_history_is_kept_per_item() {
+    let db = setup_test_db().await;
+    let repo = ProdStatusTransitionRepository::new(db.clone());
+
+    let mut first = ProjectBuilder::new().with_name("First").build();
+    let second = ProjectBuilder::new().with_name("Second").build();
+
+    let transition = first.transition(StatusAction::Cancel, None).unwrap();
+    repo.record(transition).await.expect("Failed to record transition");
+
+    let first_history = repo.get_for_item(&SchedulableItem::Project(first.id())).await.unwrap();
+    let second_history = repo.get_for_item(&SchedulableItem::Project(second.id())).await.unwrap();
+
+    assert_eq!(first_history.len(), 1);
+    assert_eq!(first_history[0].to(), SchedulableItemStatus::Canceled);
+    assert_eq!(first_history[0].by(), None);
+    assert!(second_history.is_empty());
+}
@@ -0,0 +1,98 @@
+use project_tracker_core::{
+    HasId,
+    builders::tag_builder::TagBuilder,
+    id::Id,
+    models::tag::Tag,
+};
+use project_tracker_db::{
+    database::Database,
+    tag_repository::{ProdTagRepository, TagRepository},
+};
+use std::sync::Arc;
+
+async fn setup_test_db() -> Arc<Database> {
+    Arc::new(
+        Database::connect()
+            .await
+            .expect("Failed to connect to test database"),
+    )
+}
+
+#[tokio::test]
+async fn test_create_and_get_tag() {
+    let db = setup_test_db().await;
+    let repo = ProdTagRepository::new(db.clone());
+
+    let parent_id = Id::<Tag>::new();
+    let tag = TagBuilder::new()
+        .with_name("backend")
+        .with_description("Server side work")
+        .with_parents(vec![parent_id.clone()])
+        .build();
+    let tag_id = tag.id();
+
+    let create_result = repo.create(tag.clone()).await;
+    assert!(
+        create_result.is_ok(),
+        "Failed to create tag: {:?}",
+        create_result
+    );
+
+    let retrieved = repo.get_by_id(tag_id.clone()).await.unwrap().unwrap();
+    assert_eq!(retrieved.id(), tag_id);
+    assert_eq!(retrieved.name(), "backend");
+    assert_eq!(retrieved.description(), "Server side work");
+    assert_eq!(retrieved.parents(), vec![parent_id]);
+
+    let by_name = repo.get_by_name("backend").await.unwrap();
+    assert_eq!(by_name.map(|tag| tag.id()), Some(tag_id));
+    assert!(repo.get_by_name("frontend").await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn test_get_or_create_reuses_tag_ids() {
+    let db = setup_test_db().await;
+    let repo = ProdTagRepository::new(db.clone());
+
+    let first = repo.get_or_create_by_name("urgent").await.unwrap();
+    let second = repo.get_or_create_by_name("urgent").await.unwrap();
+    let other = repo.get_or_create_by_name("later").await.unwrap();
+
+    assert_eq!(first.id(), second.id());
+    assert_ne!(first.id(), other.id());
+    assert_eq!(repo.get_all().await.unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn test_duplicate_tag_name_is_rejected() {
+    let db = setup_test_db().await;
+    let repo = ProdTagRepository::new(db.clone());
+
+    let tag = TagBuilder::new().with_name("duplicate").build();
+    let clash = TagBuilder::new().with_name("duplicate").build();
+
+    assert!(repo.create(tag).await.is_ok());
+    assert!(repo.create(clash).await.is_err());
+}
+
+#[tokio::test]
+async fn test_update_and_delete_tag() {
+    let db = setup_test_db().await;
+    let repo = ProdTagRepository::new(db.clone());
+
+    let tag = TagBuilder::new().with_name("draft").build();
+    let tag_id = tag.id();
+    repo.create(tag.clone()).await.unwrap();
+
+    let mut renamed = tag;
+    renamed.rename("final");
+    assert!(repo.update(renamed).await.is_ok());
+    assert_eq!(
+        repo.get_by_id(tag_id.clone()).await.unwrap().unwrap().name(),
+        "final"
+    );
+
+    assert!(repo.delete(tag_id.clone()).await.is_ok());
+    assert!(repo.get_by_id(tag_id.clone()).await.unwrap().is_none());
+    assert!(repo.delete(tag_id).await.is_err());
+}
@@ -1,16 +1,20 @@
-use chrono::Utc;
+use chrono::{Duration, Utc};
 use project_tracker_core::{
     HasId,
-    builders::task_builder::TaskBuilder,
+    builders::{person_builder::PersonBuilder, tag_builder::TagBuilder, task_builder::TaskBuilder},
     id::Id,
     models::{
         person::Person,
-        schedulable::{Schedulable, SchedulableItemStatus},
+        recurrence::RecurrenceRule,
+        schedulable::{Priority, Schedulable, SchedulableItemStatus},
+        tag::Tag,
         task::Task,
     },
 };
 use project_tracker_db::{
     database::Database,
+    person_repository::{PersonRepository, ProdPersonRepository},
+    tag_repository::{ProdTagRepository, TagRepository},
     task_repository::{ProdTaskRepository, TaskRepository},
 };
 use std::sync::Arc;
@@ -23,6 +27,28 @@ async fn setup_test_db() -> Arc<Database> {
     )
 }
 
+// Stores the owner and tags an item points at, which have to exist before it is saved
+async fn store_owner_and_tags(db: &Arc<Database>, owner_id: Option<Id<Person>>, tags: Vec<Id<Tag>>) {
+    if let Some(owner_id) = owner_id {
+        let owner = PersonBuilder::new().with_id(owner_id).with_first_name("Ada").with_last_name("Owner").build();
+        ProdPersonRepository::new(db.clone()).create(owner).await.expect("Failed to create owner");
+    }
+    for tag_id in tags {
+        let tag = TagBuilder::new().with_id(tag_id.clone()).with_name(&tag_id.to_string()).build();
+        ProdTagRepository::new(db.clone()).create(tag).await.expect("Failed to create tag");
+    }
+}
+
+// Stores everything `task` references, so it can be saved itself
+async fn store_references(db: &Arc<Database>, task: &Task) {
+    store_owner_and_tags(db, task.owner_id().cloned(), task.tags()).await;
+
+    let tasks = ProdTaskRepository::new(db.clone());
+    for id in task.children().into_iter().chain(task.dependencies()) {
+        tasks.create(TaskBuilder::new().with_id(id).with_name("Linked").build()).await.unwrap();
+    }
+}
+
 #[tokio::test]
 async fn test_create_and_get_task() {
     let db = setup_test_db().await;
@@ -97,6 +123,7 @@ async fn test_task_with_complex_data() {
         .build();
 
     let task_id = task.id();
+    store_references(&db, &task).await;
 
     // Create and retrieve
     let create_result = repo.create(task.clone()).await;
@@ -169,7 +196,7 @@ async fn test_task_status_transitions() {
     let repo = ProdTaskRepository::new(db.clone());
 
     // Test different status values
-    let statuses = vec![
+    let statuses = [
         SchedulableItemStatus::NotStarted,
         SchedulableItemStatus::Planned,
         SchedulableItemStatus::InProgress,
@@ -192,3 +219,126 @@ async fn test_task_status_transitions() {
     }
 }
 
+#[tokio::test]
+async fn test_recurring_task_keeps_its_series() {
+    let db = setup_test_db().await;
+    let repo = ProdTaskRepository::new(db.clone());
+
+    let first = TaskBuilder::new()
+        .with_name("Monthly review")
+        .with_start_date(Some(Utc::now()))
+        .with_recurrence(Some(RecurrenceRule::monthly().with_count(12)))
+        .build();
+    let second = first.next_occurrence().expect("Series should continue");
+    let second_id = second.id();
+
+    repo.create(first.clone()).await.expect("Failed to create first occurrence");
+    repo.create(second).await.expect("Failed to create second occurrence");
+
+    let retrieved = repo
+        .get_by_id(second_id)
+        .await
+        .expect("Failed to get task")
+        .expect("Task not found");
+
+    assert_eq!(retrieved.recurrence(), Some(&RecurrenceRule::monthly().with_count(12)));
+    assert_eq!(retrieved.series_id(), first.id());
+    assert_eq!(retrieved.occurrence(), 2);
+    assert_eq!(retrieved.series_start_date(), first.start_date());
+}
+
+#[tokio::test]
+async fn test_task_priority_round_trip() {
+    let db = setup_test_db().await;
+    let repo = ProdTaskRepository::new(db.clone());
+
+    let mut task = TaskBuilder::new().with_name("Urgent Task").with_priority(Priority::Critical).build();
+    let task_id = task.id();
+    repo.create(task.clone()).await.expect("Failed to create task");
+
+    let retrieved = repo.get_by_id(task_id.clone()).await.unwrap().expect("Task not found");
+    assert_eq!(retrieved.priority(), Priority::Critical);
+
+    task.set_priority(Priority::Low);
+    repo.update(task).await.expect("Failed to update task");
+
+    let retrieved = repo.get_by_id(task_id).await.unwrap().expect("Task not found");
+    assert_eq!(retrieved.priority(), Priority::Low);
+}
+
+fn detailed_task(name: &str, owner: Option<Id<Person>>) -> Task {
+    TaskBuilder::new()
+        .with_name(name)
+        .with_owner_id(owner)
+        .with_description("Stored and read back")
+        .with_tags(vec![Id::<Tag>::new(), Id::<Tag>::new()])
+        .with_children(vec![Id::new(), Id::new()])
+        .with_dependencies(vec![Id::new()])
+        .with_start_date(Some(Utc::now()))
+        .with_due_date(Some(Utc::now() + Duration::days(3)))
+        .with_status(SchedulableItemStatus::InProgress)
+        .with_priority(Priority::Low)
+        .build()
+}
+
+#[tokio::test]
+async fn test_get_all_round_trips_every_field() {
+    let db = setup_test_db().await;
+    let repo = ProdTaskRepository::new(db.clone());
+
+    let tasks = vec![detailed_task("First", None), detailed_task("Second", Some(Id::new()))];
+    for task in &tasks {
+        store_references(&db, task).await;
+        repo.create(task.clone()).await.expect("Failed to create task");
+    }
+
+    // Leave out the children and dependencies stored alongside
+    let mut stored = repo.get_all().await.expect("Failed to list tasks");
+    stored.retain(|stored| tasks.iter().any(|task| task.id() == stored.id()));
+    stored.sort_by(|a, b| a.name().cmp(b.name()));
+    assert_eq!(stored.len(), 2);
+    for (stored, task) in stored.iter().zip(&tasks) {
+        assert!(stored == task, "{stored:?} != {task:?}");
+    }
+}
+
+#[tokio::test]
+async fn test_get_by_owner_returns_only_their_tasks() {
+    let db = setup_test_db().await;
+    let repo = ProdTaskRepository::new(db.clone());
+    let owner = Id::<Person>::new();
+
+    let owned = detailed_task("Owned", Some(owner.clone()));
+    for task in [owned.clone(), detailed_task("Someone else's", Some(Id::new())), detailed_task("Unowned", None)] {
+        store_references(&db, &task).await;
+        repo.create(task).await.unwrap();
+    }
+
+    let stored = repo.get_by_owner(owner).await.expect("Failed to query by owner");
+    assert_eq!(stored.len(), 1);
+    assert!(stored[0] == owned);
+}
+
+#[tokio::test]
+async fn test_update_round_trips_through_record() {
+    let db = setup_test_db().await;
+    let repo = ProdTaskRepository::new(db.clone());
+
+    let mut task = detailed_task("Before", None);
+    store_references(&db, &task).await;
+    repo.create(task.clone()).await.unwrap();
+
+    let new_owner = Id::<Person>::new();
+    store_owner_and_tags(&db, Some(new_owner.clone()), vec![]).await;
+    task.rename("After");
+    task.transfer_ownership(new_owner);
+    task.remove_all_dependencies();
+    task.remove_start_date();
+    task.set_priority(Priority::Critical);
+    repo.update(task.clone()).await.expect("Failed to update task");
+
+    let stored = repo.get_by_id(task.id()).await.unwrap().expect("Task not found");
+    assert!(stored == task);
+    let listed = repo.get_all().await.unwrap();
+    assert!(listed.contains(&task));
+}
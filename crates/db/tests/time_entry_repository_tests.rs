@@ -0,0 +1,87 @@
+use chrono::{Duration, Utc};
+use project_tracker_core::{
+    HasId,
+    builders::time_entry_builder::TimeEntryBuilder,
+    id::Id,
+    models::{person::Person, schedulable::SchedulableItem, task::Task, time_entry::TimeEntry},
+};
+use project_tracker_db::{
+    database::Database,
+    time_entry_repository::{ProdTimeEntryRepository, TimeEntryRepository},
+};
+use std::sync::Arc;
+
+async fn setup_test_db() -> Arc<Database> {
+    Arc::new(
+        Database::connect()
+            .await
+            .expect("Failed to connect to test database"),
+    )
+}
+
+#[tokio::test]
+async fn test_start_stop_and_get_time_entry() {
+    let db = setup_test_db().await;
+    let repo = ProdTimeEntryRepository::new(db.clone());
+
+    let person_id = Id::<Person>::new();
+    let item = SchedulableItem::Task(Id::<Task>::new());
+    let start = Utc::now() - Duration::hours(1);
+    let mut entry = TimeEntry::start_timer(person_id.clone(), item.clone(), start);
+    entry.set_note("Debugging the importer");
+    let entry_id = entry.id();
+
+    repo.create(entry.clone()).await.expect("Failed to create time entry");
+
+    let running = repo
+        .get_by_id(entry_id.clone())
+        .await
+        .expect("Failed to get time entry")
+        .expect("Time entry not found");
+    assert!(running.is_running());
+    assert_eq!(running.person_id(), &person_id);
+    assert_eq!(running.item(), &item);
+    assert_eq!(running.note(), "Debugging the importer");
+
+    entry.stop_timer(start + Duration::minutes(40)).unwrap();
+    repo.update(entry).await.expect("Failed to update time entry");
+
+    let stopped = repo.get_by_id(entry_id.clone()).await.unwrap().unwrap();
+    assert_eq!(stopped.duration(), Some(Duration::minutes(40)));
+
+    repo.delete(entry_id.clone()).await.expect("Failed to delete time entry");
+    assert!(repo.get_by_id(entry_id).await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn test_get_time_entries_for_item_and_person() {
+    let db = setup_test_db().await;
+    let repo = ProdTimeEntryRepository::new(db.clone());
+
+    let ada = Id::<Person>::new();
+    let bob = Id::<Person>::new();
+    let item = SchedulableItem::Task(Id::<Task>::new());
+    let other_item = SchedulableItem::Task(Id::<Task>::new());
+    let start = Utc::now() - Duration::days(1);
+
+    for (person_id, item, offset) in [(&ada, &item, 2), (&bob, &item, 1), (&ada, &other_item, 3)] {
+        let entry = TimeEntryBuilder::new()
+            .with_person_id(person_id.clone())
+            .with_item(item.clone())
+            .with_start(start + Duration::hours(offset))
+            .with_end(Some(start + Duration::hours(offset) + Duration::minutes(30)))
+            .build();
+        repo.create(entry).await.unwrap();
+    }
+
+    let for_item = repo.get_for_item(&item).await.expect("Failed to get entries for item");
+    assert_eq!(for_item.len(), 2);
+    assert_eq!(for_item[0].person_id(), &bob);
+    assert_eq!(for_item[1].person_id(), &ada);
+
+    let for_ada = repo.get_for_person(&ada).await.expect("Failed to get entries for person");
+    assert_eq!(for_ada.len(), 2);
+    assert_eq!(for_ada[1].item(), &other_item);
+
+    assert_eq!(repo.get_all().await.unwrap().len(), 3);
+}
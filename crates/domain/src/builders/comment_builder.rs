@@ -0,0 +1,115 @@
+use crate::id::Id;
+use crate::models::{
+    comment::{Comment, CommentEdit}, person::Person, project::Project, schedulable::SchedulableItem,
+};
+use crate::{EntityType, HasId};
+
+use chrono::{DateTime, Utc};
+
+#[derive(Clone, PartialEq, Eq)]
+pub struct CommentBuilder {
+    id: Id<Comment>,
+    item: SchedulableItem,
+    author_id: Id<Person>,
+    parent_id: Option<Id<Comment>>,
+    body: String,
+    created_at: DateTime<Utc>,
+    edits: Vec<CommentEdit>,
+}
+
+impl CommentBuilder {
+    pub fn new() -> Self {
+        CommentBuilder {
+            id: Id::<Comment>::new(),
+            item: SchedulableItem::Project(Id::<Project>::new()),
+            author_id: Id::<Person>::new(),
+            parent_id: None,
+            body: String::new(),
+            created_at: Utc::now(),
+            edits: Vec::new(),
+        }
+    }
+
+    pub fn with_id(mut self, id: Id<Comment>) -> Self {
+        self.id = id;
+        self
+    }
+
+    pub fn with_item(mut self, item: SchedulableItem) -> Self {
+        self.item = item;
+        self
+    }
+
+    pub fn with_author_id(mut self, author_id: Id<Person>) -> Self {
+        self.author_id = author_id;
+        self
+    }
+
+    pub fn with_parent_id(mut self, parent_id: Option<Id<Comment>>) -> Self {
+        self.parent_id = parent_id;
+        self
+    }
+
+    pub fn with_body(mut self, body: &str) -> Self {
+        self.body = body.into();
+        self
+    }
+
+    pub fn with_created_at(mut self, created_at: DateTime<Utc>) -> Self {
+        self.created_at = created_at;
+        self
+    }
+
+    pub fn with_edits(mut self, edits: Vec<CommentEdit>) -> Self {
+        self.edits = edits;
+        self
+    }
+
+    pub fn item(&self) -> SchedulableItem {
+        self.item.clone()
+    }
+
+    pub fn author_id(&self) -> Id<Person> {
+        self.author_id.clone()
+    }
+
+    pub fn parent_id(&self) -> Option<Id<Comment>> {
+        self.parent_id.clone()
+    }
+
+    pub fn body(&self) -> String {
+        self.body.clone()
+    }
+
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    pub fn edits(&self) -> Vec<CommentEdit> {
+        self.edits.clone()
+    }
+
+    pub fn build(self) -> Comment {
+        Comment::from_builder(self)
+    }
+}
+
+impl Default for CommentBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EntityType for CommentBuilder {
+    fn prefix() -> &'static str {
+        "comment"
+    }
+}
+
+impl HasId for CommentBuilder {
+    type Entity = Comment;
+
+    fn id(&self) -> Id<Comment> {
+        self.id.clone()
+    }
+}
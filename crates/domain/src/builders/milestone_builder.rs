@@ -0,0 +1,103 @@
+use crate::id::Id;
+use crate::models::milestone::Milestone;
+use crate::models::schedulable::SchedulableItem;
+use crate::{EntityType, HasId};
+
+use chrono::{DateTime, Utc};
+
+#[derive(Clone, PartialEq, Eq)]
+pub struct MilestoneBuilder {
+    id: Id<Milestone>,
+    name: String,
+    description: Option<String>,
+    target_date: DateTime<Utc>,
+    items: Vec<SchedulableItem>,
+    reached_at: Option<DateTime<Utc>>,
+}
+
+impl MilestoneBuilder {
+    pub fn new() -> Self {
+        MilestoneBuilder {
+            id: Id::<Milestone>::new(),
+            name: String::new(),
+            description: None,
+            target_date: Utc::now(),
+            items: Vec::new(),
+            reached_at: None,
+        }
+    }
+
+    pub fn with_id(mut self, id: Id<Milestone>) -> Self {
+        self.id = id;
+        self
+    }
+
+    pub fn with_name(mut self, name: &str) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    pub fn with_description(mut self, description: &str) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn with_target_date(mut self, target_date: DateTime<Utc>) -> Self {
+        self.target_date = target_date;
+        self
+    }
+
+    pub fn with_items(mut self, items: Vec<SchedulableItem>) -> Self {
+        self.items = items;
+        self
+    }
+
+    pub fn with_reached_at(mut self, reached_at: Option<DateTime<Utc>>) -> Self {
+        self.reached_at = reached_at;
+        self
+    }
+
+    pub fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    pub fn description(&self) -> Option<String> {
+        self.description.clone()
+    }
+
+    pub fn target_date(&self) -> DateTime<Utc> {
+        self.target_date
+    }
+
+    pub fn items(&self) -> Vec<SchedulableItem> {
+        self.items.clone()
+    }
+
+    pub fn reached_at(&self) -> Option<DateTime<Utc>> {
+        self.reached_at
+    }
+
+    pub fn build(self) -> Milestone {
+        Milestone::from_builder(self)
+    }
+}
+
+impl Default for MilestoneBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EntityType for MilestoneBuilder {
+    fn prefix() -> &'static str {
+        "milestone"
+    }
+}
+
+impl HasId for MilestoneBuilder {
+    type Entity = Milestone;
+
+    fn id(&self) -> Id<Milestone> {
+        self.id.clone()
+    }
+}
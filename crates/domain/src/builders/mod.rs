@@ -1,4 +1,7 @@
 pub mod project_builder;
 pub mod task_builder;
 pub mod tag_builder;
-pub mod person_builder;
\ No newline at end of file
+pub mod person_builder;
+pub mod milestone_builder;
+pub mod time_entry_builder;
+pub mod comment_builder;
@@ -18,6 +18,11 @@ impl PersonBuilder {
         }
     }
 
+    pub fn with_id(mut self, id: Id<Person>) -> Self {
+        self.id = id;
+        self
+    }
+
     pub fn with_first_name(mut self, first_name: &str) -> Self {
         self.first_name = first_name.into();
         self
@@ -41,6 +46,12 @@ impl PersonBuilder {
     }
 }
 
+impl Default for PersonBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl EntityType for PersonBuilder {
     fn prefix() -> &'static str {
         "person"
@@ -1,7 +1,8 @@
 use crate::id::Id;
+use crate::models::estimate::Estimate;
 use crate::models::person::Person;
 use crate::models::project::Project;
-use crate::models::schedulable::{SchedulableItem, SchedulableItemStatus};
+use crate::models::schedulable::{Priority, SchedulableItem, SchedulableItemStatus};
 use crate::models::tag::Tag;
 use crate::{EntityType, HasId};
 
@@ -19,6 +20,8 @@ pub struct ProjectBuilder {
     children: Vec<SchedulableItem>,
     dependencies: Vec<Id<Project>>,
     status: SchedulableItemStatus,
+    priority: Priority,
+    estimate: Option<Estimate>,
 }
 
 impl ProjectBuilder {
@@ -34,6 +37,8 @@ impl ProjectBuilder {
             children: Vec::new(),
             dependencies: Vec::new(),
             status: SchedulableItemStatus::NotStarted,
+            priority: Priority::default(),
+            estimate: None,
         }
     }
 
@@ -87,6 +92,16 @@ impl ProjectBuilder {
         self
     }
 
+    pub fn with_priority(mut self, priority: Priority) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    pub fn with_estimate(mut self, estimate: Option<Estimate>) -> Self {
+        self.estimate = estimate;
+        self
+    }
+
     pub fn name(&self) -> String {
         self.name.clone()
     }
@@ -104,11 +119,11 @@ impl ProjectBuilder {
     }
 
     pub fn start_date(&self) -> Option<DateTime<Utc>> {
-        self.start_date.clone()
+        self.start_date
     }
 
     pub fn due_date(&self) -> Option<DateTime<Utc>> {
-        self.due_date.clone()
+        self.due_date
     }
 
     pub fn children(&self) -> Vec<SchedulableItem> {
@@ -123,6 +138,14 @@ impl ProjectBuilder {
         self.status.clone()
     }
 
+    pub fn priority(&self) -> Priority {
+        self.priority
+    }
+
+    pub fn estimate(&self) -> Option<Estimate> {
+        self.estimate
+    }
+
     pub fn build(self) -> Project {
         Project::from_builder(self)
     }
@@ -20,6 +20,11 @@ impl TagBuilder {
         }
     }
 
+    pub fn with_id(mut self, id: Id<Tag>) -> Self {
+        self.id = id;
+        self
+    }
+
     pub fn with_name(mut self, name: &str) -> Self {
         self.name = name.into();
         self
@@ -52,6 +57,12 @@ impl TagBuilder {
     }
 }
 
+impl Default for TagBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl EntityType for TagBuilder {
     fn prefix() -> &'static str {
         "tag"
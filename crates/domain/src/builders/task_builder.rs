@@ -1,5 +1,7 @@
 use crate::id::Id;
-use crate::models::{person::Person, schedulable::SchedulableItemStatus, tag::Tag, task::Task};
+use crate::models::{
+    estimate::Estimate, person::Person, recurrence::RecurrenceRule, schedulable::{Priority, SchedulableItemStatus}, tag::Tag, task::Task,
+};
 use crate::{EntityType, HasId};
 
 use chrono::{DateTime, Utc};
@@ -16,6 +18,13 @@ pub struct TaskBuilder {
     children: Vec<Id<Task>>,
     dependencies: Vec<Id<Task>>,
     status: SchedulableItemStatus,
+    priority: Priority,
+    recurrence: Option<RecurrenceRule>,
+    series_id: Option<Id<Task>>,
+    occurrence: u32,
+    series_start_date: Option<DateTime<Utc>>,
+    series_due_date: Option<DateTime<Utc>>,
+    estimate: Option<Estimate>,
 }
 
 impl TaskBuilder {
@@ -31,6 +40,13 @@ impl TaskBuilder {
             children: Vec::new(),
             dependencies: Vec::new(),
             status: SchedulableItemStatus::NotStarted,
+            priority: Priority::default(),
+            recurrence: None,
+            series_id: None,
+            occurrence: 1,
+            series_start_date: None,
+            series_due_date: None,
+            estimate: None,
         }
     }
 
@@ -84,6 +100,41 @@ impl TaskBuilder {
         self
     }
 
+    pub fn with_recurrence(mut self, recurrence: Option<RecurrenceRule>) -> Self {
+        self.recurrence = recurrence;
+        self
+    }
+
+    pub fn with_series_id(mut self, series_id: Option<Id<Task>>) -> Self {
+        self.series_id = series_id;
+        self
+    }
+
+    pub fn with_occurrence(mut self, occurrence: u32) -> Self {
+        self.occurrence = occurrence.max(1);
+        self
+    }
+
+    pub fn with_series_start_date(mut self, series_start_date: Option<DateTime<Utc>>) -> Self {
+        self.series_start_date = series_start_date;
+        self
+    }
+
+    pub fn with_series_due_date(mut self, series_due_date: Option<DateTime<Utc>>) -> Self {
+        self.series_due_date = series_due_date;
+        self
+    }
+
+    pub fn with_priority(mut self, priority: Priority) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    pub fn with_estimate(mut self, estimate: Option<Estimate>) -> Self {
+        self.estimate = estimate;
+        self
+    }
+
     pub fn name(&self) -> String {
         self.name.clone()
     }
@@ -101,11 +152,11 @@ impl TaskBuilder {
     }
 
     pub fn start_date(&self) -> Option<DateTime<Utc>> {
-        self.start_date.clone()
+        self.start_date
     }
 
     pub fn due_date(&self) -> Option<DateTime<Utc>> {
-        self.due_date.clone()
+        self.due_date
     }
 
     pub fn children(&self) -> Vec<Id<Task>> {
@@ -120,11 +171,45 @@ impl TaskBuilder {
         self.status.clone()
     }
 
+    pub fn recurrence(&self) -> Option<RecurrenceRule> {
+        self.recurrence
+    }
+
+    pub fn series_id(&self) -> Option<Id<Task>> {
+        self.series_id.clone()
+    }
+
+    pub fn occurrence(&self) -> u32 {
+        self.occurrence
+    }
+
+    pub fn series_start_date(&self) -> Option<DateTime<Utc>> {
+        self.series_start_date
+    }
+
+    pub fn series_due_date(&self) -> Option<DateTime<Utc>> {
+        self.series_due_date
+    }
+
+    pub fn priority(&self) -> Priority {
+        self.priority
+    }
+
+    pub fn estimate(&self) -> Option<Estimate> {
+        self.estimate
+    }
+
     pub fn build(self) -> Task {
         Task::from_builder(self)
     }
 }
 
+impl Default for TaskBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl EntityType for TaskBuilder {
     fn prefix() -> &'static str {
         "task"
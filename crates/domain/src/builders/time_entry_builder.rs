@@ -0,0 +1,104 @@
+use crate::id::Id;
+use crate::models::{
+    person::Person, project::Project, schedulable::SchedulableItem, time_entry::TimeEntry,
+};
+use crate::{EntityType, HasId};
+
+use chrono::{DateTime, Utc};
+
+#[derive(Clone, PartialEq, Eq)]
+pub struct TimeEntryBuilder {
+    id: Id<TimeEntry>,
+    person_id: Id<Person>,
+    item: SchedulableItem,
+    start: DateTime<Utc>,
+    end: Option<DateTime<Utc>>,
+    note: Option<String>,
+}
+
+impl TimeEntryBuilder {
+    pub fn new() -> Self {
+        TimeEntryBuilder {
+            id: Id::<TimeEntry>::new(),
+            person_id: Id::<Person>::new(),
+            item: SchedulableItem::Project(Id::<Project>::new()),
+            start: Utc::now(),
+            end: None,
+            note: None,
+        }
+    }
+
+    pub fn with_id(mut self, id: Id<TimeEntry>) -> Self {
+        self.id = id;
+        self
+    }
+
+    pub fn with_person_id(mut self, person_id: Id<Person>) -> Self {
+        self.person_id = person_id;
+        self
+    }
+
+    pub fn with_item(mut self, item: SchedulableItem) -> Self {
+        self.item = item;
+        self
+    }
+
+    pub fn with_start(mut self, start: DateTime<Utc>) -> Self {
+        self.start = start;
+        self
+    }
+
+    pub fn with_end(mut self, end: Option<DateTime<Utc>>) -> Self {
+        self.end = end;
+        self
+    }
+
+    pub fn with_note(mut self, note: &str) -> Self {
+        self.note = Some(note.into());
+        self
+    }
+
+    pub fn person_id(&self) -> Id<Person> {
+        self.person_id.clone()
+    }
+
+    pub fn item(&self) -> SchedulableItem {
+        self.item.clone()
+    }
+
+    pub fn start(&self) -> DateTime<Utc> {
+        self.start
+    }
+
+    pub fn end(&self) -> Option<DateTime<Utc>> {
+        self.end
+    }
+
+    pub fn note(&self) -> Option<String> {
+        self.note.clone()
+    }
+
+    pub fn build(self) -> TimeEntry {
+        TimeEntry::from_builder(self)
+    }
+}
+
+impl Default for TimeEntryBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EntityType for TimeEntryBuilder {
+    fn prefix() -> &'static str {
+        "time_entry"
+    }
+}
+
+impl HasId for TimeEntryBuilder {
+    type Entity = TimeEntry;
+
+    fn id(&self) -> Id<TimeEntry> {
+        self.id.clone()
+    }
+}
@@ -0,0 +1,23 @@
+use chrono::{Duration, Utc};
+
+use crate::HasId;
+use crate::builders::comment_builder::CommentBuilder;
+use crate::factories::{person_factory::sample_person, task_factory::sample_task};
+use crate::models::comment::Comment;
+use crate::models::schedulable::SchedulableItem;
+
+/// Basic **Comment** with default values
+pub fn basic_comment() -> Comment {
+    CommentBuilder::new().with_body("Comment").build()
+}
+
+// region: Factories for Tests
+pub fn sample_comment() -> Comment {
+    CommentBuilder::new()
+        .with_item(SchedulableItem::Task(sample_task().id()))
+        .with_author_id(sample_person().id())
+        .with_body("Parser handles nested lists now")
+        .with_created_at(Utc::now() - Duration::hours(2))
+        .build()
+}
+// endregion: Factories for Tests
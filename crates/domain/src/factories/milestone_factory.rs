@@ -0,0 +1,41 @@
+use chrono::{Duration, Utc};
+
+use crate::HasId;
+use crate::builders::milestone_builder::MilestoneBuilder;
+use crate::factories::{project_factory::sample_project, task_factory::sample_task};
+use crate::models::milestone::Milestone;
+use crate::models::schedulable::SchedulableItem;
+
+/// Basic **Milestone** with default values
+pub fn basic_milestone() -> Milestone {
+    MilestoneBuilder::new().build()
+}
+
+// region: Factories for Tests
+pub fn sample_milestone() -> Milestone {
+    MilestoneBuilder::new()
+        .with_name("Beta release")
+        .with_description("Feature complete and ready for testers")
+        .with_target_date(Utc::now() + Duration::days(14))
+        .with_items(vec![
+            SchedulableItem::Project(sample_project().id()),
+            SchedulableItem::Task(sample_task().id()),
+        ])
+        .build()
+}
+
+pub fn sample_reached_milestone() -> Milestone {
+    MilestoneBuilder::new()
+        .with_name("Alpha release")
+        .with_target_date(Utc::now() - Duration::days(7))
+        .with_reached_at(Some(Utc::now() - Duration::days(8)))
+        .build()
+}
+
+pub fn sample_missed_milestone() -> Milestone {
+    MilestoneBuilder::new()
+        .with_name("Prototype")
+        .with_target_date(Utc::now() - Duration::days(1))
+        .build()
+}
+// endregion: Factories for Tests
@@ -1,4 +1,7 @@
 pub mod project_factory;
 pub mod task_factory;
 pub mod tag_factory;
-pub mod person_factory;
\ No newline at end of file
+pub mod person_factory;
+pub mod milestone_factory;
+pub mod time_entry_factory;
+pub mod comment_factory;
@@ -4,7 +4,7 @@ use crate::HasId;
 use crate::builders::task_builder::TaskBuilder;
 use crate::factories::person_factory::*;
 use crate::id::Id;
-use crate::models::{schedulable::SchedulableItemStatus, task::Task};
+use crate::models::{recurrence::RecurrenceRule, schedulable::SchedulableItemStatus, task::Task};
 
 use super::tag_factory::*;
 
@@ -113,5 +113,15 @@ pub fn sample_scheduled_task() -> Task {
         .build()
 }
 
+pub fn sample_weekly_task() -> Task {
+    let start_date = Utc::now() + Duration::days(1);
+    TaskBuilder::new()
+        .with_name("Weekly review")
+        .with_start_date(Some(start_date))
+        .with_due_date(Some(start_date + Duration::hours(2)))
+        .with_recurrence(Some(RecurrenceRule::weekly()))
+        .build()
+}
+
 // endregion: Factories for Tests
 
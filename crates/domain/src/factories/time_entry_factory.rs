@@ -0,0 +1,33 @@
+use chrono::{Duration, Utc};
+
+use crate::HasId;
+use crate::builders::time_entry_builder::TimeEntryBuilder;
+use crate::factories::{person_factory::sample_person, project_factory::sample_project, task_factory::sample_task};
+use crate::models::schedulable::SchedulableItem;
+use crate::models::time_entry::TimeEntry;
+
+/// Basic **TimeEntry** with default values
+pub fn basic_time_entry() -> TimeEntry {
+    TimeEntryBuilder::new().build()
+}
+
+// region: Factories for Tests
+pub fn sample_time_entry() -> TimeEntry {
+    let start = Utc::now() - Duration::hours(3);
+    TimeEntryBuilder::new()
+        .with_person_id(sample_person().id())
+        .with_item(SchedulableItem::Task(sample_task().id()))
+        .with_start(start)
+        .with_end(Some(start + Duration::minutes(90)))
+        .with_note("Paired on the parser")
+        .build()
+}
+
+pub fn sample_running_time_entry() -> TimeEntry {
+    TimeEntryBuilder::new()
+        .with_person_id(sample_person().id())
+        .with_item(SchedulableItem::Project(sample_project().id()))
+        .with_start(Utc::now() - Duration::minutes(20))
+        .build()
+}
+// endregion: Factories for Tests
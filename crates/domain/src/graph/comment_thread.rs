@@ -0,0 +1,62 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::id::Id;
+use crate::models::comment::Comment;
+use crate::HasId;
+
+/// A comment with the replies below it, oldest first
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CommentThread {
+    pub comment: Comment,
+    pub replies: Vec<CommentThread>,
+}
+
+impl CommentThread {
+    /// Nests comments under the ones they reply to.
+    ///
+    /// Replies to a comment that is not part of the set start a thread of their own,
+    /// so nothing gets dropped when a parent went missing.
+    pub fn build(comments: impl IntoIterator<Item = Comment>) -> Vec<CommentThread> {
+        let mut comments: Vec<Comment> = comments.into_iter().collect();
+        comments.sort_by_key(|comment| comment.created_at());
+
+        let known: HashSet<Id<Comment>> = comments.iter().map(|comment| comment.id()).collect();
+        let mut replies: HashMap<Id<Comment>, Vec<Comment>> = HashMap::new();
+        let mut roots = Vec::new();
+        for comment in comments {
+            match comment.parent_id() {
+                Some(parent_id) if known.contains(parent_id) => {
+                    replies.entry(parent_id.clone()).or_default().push(comment)
+                }
+                _ => roots.push(comment),
+            }
+        }
+
+        roots
+            .into_iter()
+            .map(|comment| Self::nest(comment, &mut replies))
+            .collect()
+    }
+
+    fn nest(comment: Comment, replies: &mut HashMap<Id<Comment>, Vec<Comment>>) -> CommentThread {
+        let children = replies.remove(&comment.id()).unwrap_or_default();
+        CommentThread {
+            comment,
+            replies: children.into_iter().map(|reply| Self::nest(reply, replies)).collect(),
+        }
+    }
+
+    /// Comments in the thread, counting the one it starts with
+    pub fn comment_count(&self) -> usize {
+        1 + self.replies.iter().map(CommentThread::comment_count).sum::<usize>()
+    }
+
+    /// Ids of every comment in the thread, the first comment before its replies
+    pub fn ids(&self) -> Vec<Id<Comment>> {
+        let mut ids = vec![self.comment.id()];
+        for reply in &self.replies {
+            ids.extend(reply.ids());
+        }
+        ids
+    }
+}
@@ -0,0 +1,248 @@
+use core::fmt;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use serde::Serialize;
+
+use crate::id::Id;
+use crate::models::schedulable::Schedulable;
+use crate::{EntityType, HasId};
+
+/// Dependency view over a set of projects or tasks, where each item points to the items it depends on.
+///
+/// Dependencies on items outside the set are skipped when walking the graph.
+#[derive(Clone, Debug)]
+pub struct DependencyGraph<T> {
+    items: HashMap<Id<T>, T>,
+    // Insertion order, so orderings are stable for the same input
+    order: Vec<Id<T>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub enum DependencyGraphError {
+    UnknownItem(String),
+    SelfDependency(String),
+    /// The items forming the loop, starting and ending with the same item
+    Cycle(Vec<String>),
+}
+
+impl fmt::Display for DependencyGraphError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DependencyGraphError::UnknownItem(id) => write!(f, "{id} is not part of the graph"),
+            DependencyGraphError::SelfDependency(id) => write!(f, "{id} cannot depend on itself"),
+            DependencyGraphError::Cycle(path) => write!(f, "Dependency cycle: {}", path.join(" -> ")),
+        }
+    }
+}
+
+impl std::error::Error for DependencyGraphError {}
+
+impl<T> Default for DependencyGraph<T> {
+    fn default() -> Self {
+        Self {
+            items: HashMap::new(),
+            order: Vec::new(),
+        }
+    }
+}
+
+impl<T> DependencyGraph<T>
+where
+    T: Schedulable<DependencyType = Id<T>> + HasId<Entity = T> + EntityType + Clone + Eq,
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn from_items(items: impl IntoIterator<Item = T>) -> Self {
+        let mut graph = Self::new();
+        for item in items {
+            graph.insert(item);
+        }
+        graph
+    }
+
+    /// Adds or replaces an item without checking for cycles; see [`Self::find_cycle`]
+    pub fn insert(&mut self, item: T) {
+        let id = item.id();
+        if !self.items.contains_key(&id) {
+            self.order.push(id.clone());
+        }
+        self.items.insert(id, item);
+    }
+
+    pub fn get(&self, id: &Id<T>) -> Option<&T> {
+        self.items.get(id)
+    }
+
+    /// Items in the order they were first inserted
+    pub fn items(&self) -> impl Iterator<Item = &T> {
+        self.order.iter().map(|id| &self.items[id])
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Checks whether `item_id` may depend on `dependency_id` without closing a loop
+    pub fn check_dependency(&self, item_id: &Id<T>, dependency_id: &Id<T>) -> Result<(), DependencyGraphError> {
+        if !self.items.contains_key(item_id) {
+            return Err(DependencyGraphError::UnknownItem(item_id.to_string()));
+        }
+        if item_id == dependency_id {
+            return Err(DependencyGraphError::SelfDependency(item_id.to_string()));
+        }
+        match self.path_between(dependency_id, item_id) {
+            Some(path) => {
+                let mut cycle = vec![item_id.to_string()];
+                cycle.extend(path.iter().map(|id| id.to_string()));
+                Err(DependencyGraphError::Cycle(cycle))
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// Returns one loop in the graph, if there is any
+    pub fn find_cycle(&self) -> Option<Vec<String>> {
+        let mut finished: HashSet<Id<T>> = HashSet::new();
+
+        for start in &self.order {
+            if finished.contains(start) {
+                continue;
+            }
+            let mut path: Vec<Id<T>> = Vec::new();
+            if let Some(cycle) = self.visit(start, &mut path, &mut finished) {
+                return Some(cycle.iter().map(|id| id.to_string()).collect());
+            }
+        }
+        None
+    }
+
+    /// Orders the items so that every item comes after the items it depends on
+    pub fn topological_order(&self) -> Result<Vec<Id<T>>, DependencyGraphError> {
+        let mut remaining: HashMap<Id<T>, usize> = self
+            .order
+            .iter()
+            .map(|id| (id.clone(), self.dependencies(id).len()))
+            .collect();
+        let dependents = self.dependents_index();
+
+        let mut ready: VecDeque<Id<T>> = self
+            .order
+            .iter()
+            .filter(|id| remaining[*id] == 0)
+            .cloned()
+            .collect();
+        let mut sorted = Vec::with_capacity(self.order.len());
+
+        while let Some(id) = ready.pop_front() {
+            for dependent in dependents.get(&id).into_iter().flatten() {
+                let count = remaining.get_mut(dependent).expect("dependents are graph items");
+                *count -= 1;
+                if *count == 0 {
+                    ready.push_back(dependent.clone());
+                }
+            }
+            sorted.push(id);
+        }
+
+        if sorted.len() == self.order.len() {
+            Ok(sorted)
+        } else {
+            let cycle = self.find_cycle().unwrap_or_default();
+            Err(DependencyGraphError::Cycle(cycle))
+        }
+    }
+
+    /// Dependencies of the item that are part of the graph and still open.
+    /// Finished and canceled dependencies no longer hold the item back.
+    pub fn blockers(&self, id: &Id<T>) -> Vec<Id<T>> {
+        self.dependencies(id)
+            .into_iter()
+            .filter(|dependency| self.items.get(dependency).is_some_and(|item| item.status().is_open()))
+            .collect()
+    }
+
+    /// Items still open that wait on at least one open dependency
+    pub fn blocked_items(&self) -> Vec<Id<T>> {
+        self.order
+            .iter()
+            .filter(|id| self.items[*id].status().is_open())
+            .filter(|id| !self.blockers(id).is_empty())
+            .cloned()
+            .collect()
+    }
+
+    /// Dependencies of the item that are part of the graph
+    pub fn dependencies(&self, id: &Id<T>) -> Vec<Id<T>> {
+        let mut dependencies = self
+            .items
+            .get(id)
+            .map(|item| item.dependencies())
+            .unwrap_or_default();
+        dependencies.retain(|dependency| self.items.contains_key(dependency));
+        dependencies.dedup();
+        dependencies
+    }
+
+    fn dependents_index(&self) -> HashMap<Id<T>, Vec<Id<T>>> {
+        let mut dependents: HashMap<Id<T>, Vec<Id<T>>> = HashMap::new();
+        for id in &self.order {
+            for dependency in self.dependencies(id) {
+                dependents.entry(dependency).or_default().push(id.clone());
+            }
+        }
+        dependents
+    }
+
+    fn visit(&self, id: &Id<T>, path: &mut Vec<Id<T>>, finished: &mut HashSet<Id<T>>) -> Option<Vec<Id<T>>> {
+        if let Some(position) = path.iter().position(|visited| visited == id) {
+            let mut cycle = path[position..].to_vec();
+            cycle.push(id.clone());
+            return Some(cycle);
+        }
+        if finished.contains(id) {
+            return None;
+        }
+
+        path.push(id.clone());
+        for dependency in self.dependencies(id) {
+            if let Some(cycle) = self.visit(&dependency, path, finished) {
+                return Some(cycle);
+            }
+        }
+        path.pop();
+        finished.insert(id.clone());
+        None
+    }
+
+    /// Dependency chain from `from` to `to`, both included, if `from` depends on `to` at all
+    fn path_between(&self, from: &Id<T>, to: &Id<T>) -> Option<Vec<Id<T>>> {
+        let mut previous: HashMap<Id<T>, Id<T>> = HashMap::new();
+        let mut queue = VecDeque::from([from.clone()]);
+
+        while let Some(current) = queue.pop_front() {
+            if &current == to {
+                let mut path = vec![current.clone()];
+                let mut step = current;
+                while let Some(prior) = previous.get(&step) {
+                    path.push(prior.clone());
+                    step = prior.clone();
+                }
+                path.reverse();
+                return Some(path);
+            }
+            for dependency in self.dependencies(&current) {
+                if &dependency != from && !previous.contains_key(&dependency) {
+                    previous.insert(dependency.clone(), current.clone());
+                    queue.push_back(dependency);
+                }
+            }
+        }
+        None
+    }
+}
@@ -0,0 +1,80 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::Serialize;
+
+use crate::graph::{project_tree::ProjectTree, time_report::TimeReport};
+use crate::models::{
+    estimate::EstimateTotal,
+    schedulable::{Schedulable, SchedulableItem, SchedulableItemStatus},
+};
+
+/// Estimate of an item set against the time it actually took
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub struct EstimateReport {
+    /// Rolled up from the item's descendant tree
+    pub estimate: EstimateTotal,
+    /// Logged against the item and everything below it
+    pub logged: Duration,
+    /// From the start date up to the due date once finished, or up to now while open
+    pub elapsed: Option<Duration>,
+}
+
+impl EstimateReport {
+    pub fn for_item(
+        tree: &ProjectTree,
+        time: &TimeReport,
+        item: &SchedulableItem,
+        now: DateTime<Utc>,
+    ) -> Option<Self> {
+        let estimate = tree.estimate(item)?;
+        let (start_date, due_date, status) = match item {
+            SchedulableItem::Project(id) => tree
+                .get_project(id)
+                .map(|project| (project.start_date(), project.due_date(), project.status()))?,
+            SchedulableItem::Task(id) => tree
+                .get_task(id)
+                .map(|task| (task.start_date(), task.due_date(), task.status()))?,
+        };
+
+        Some(Self {
+            estimate,
+            logged: time.total_with_descendants(tree, item),
+            elapsed: elapsed(start_date, due_date, &status, now),
+        })
+    }
+
+    /// Logged time when there is any, the elapsed duration otherwise
+    pub fn actual(&self) -> Option<Duration> {
+        if self.logged > Duration::zero() {
+            Some(self.logged)
+        } else {
+            self.elapsed
+        }
+    }
+
+    /// How far the actual time runs over the hour estimate, negative when under it.
+    /// There is none without an hour estimate or any actual time to compare with.
+    pub fn variance(&self) -> Option<Duration> {
+        if self.estimate.hours == 0 {
+            return None;
+        }
+        self.actual().map(|actual| actual - self.estimate.duration())
+    }
+
+    pub fn is_over_estimate(&self) -> bool {
+        self.variance().is_some_and(|variance| variance > Duration::zero())
+    }
+}
+
+fn elapsed(
+    start_date: Option<DateTime<Utc>>,
+    due_date: Option<DateTime<Utc>>,
+    status: &SchedulableItemStatus,
+    now: DateTime<Utc>,
+) -> Option<Duration> {
+    let start_date = start_date.filter(|start_date| *start_date <= now)?;
+    let end = match (status, due_date) {
+        (SchedulableItemStatus::Completed | SchedulableItemStatus::Archived, Some(due_date)) => due_date.min(now),
+        _ => now,
+    };
+    Some((end - start_date).max(Duration::zero()))
+}
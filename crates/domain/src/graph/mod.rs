@@ -0,0 +1,8 @@
+pub mod dependency_graph;
+pub mod schedule;
+pub mod tag_graph;
+pub mod project_tree;
+pub mod time_report;
+pub mod estimate_report;
+pub mod work_queue;
+pub mod comment_thread;
@@ -0,0 +1,256 @@
+use std::collections::{HashMap, HashSet};
+
+use serde::Serialize;
+
+use crate::id::Id;
+use crate::models::{
+    estimate::EstimateTotal,
+    project::Project,
+    schedulable::{Schedulable, SchedulableItem, SchedulableItemStatus},
+    task::Task,
+};
+use crate::HasId;
+
+/// Share of finished work below an item, counted over the leaves of its tree
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct Progress {
+    pub finished: usize,
+    pub total: usize,
+}
+
+impl Progress {
+    /// Percent complete, rounded down
+    pub fn percent(&self) -> u8 {
+        if self.total == 0 {
+            return 0;
+        }
+        (self.finished * 100 / self.total) as u8
+    }
+
+    fn add(&mut self, other: Progress) {
+        self.finished += other.finished;
+        self.total += other.total;
+    }
+}
+
+/// Parent/child view over projects and tasks, following `children` down through
+/// child projects and subtasks.
+///
+/// Children that were never added to the tree are skipped.
+#[derive(Clone, Debug, Default)]
+pub struct ProjectTree {
+    projects: HashMap<Id<Project>, Project>,
+    tasks: HashMap<Id<Task>, Task>,
+}
+
+impl ProjectTree {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn from_items(projects: impl IntoIterator<Item = Project>, tasks: impl IntoIterator<Item = Task>) -> Self {
+        let mut tree = Self::new();
+        for project in projects {
+            tree.insert_project(project);
+        }
+        for task in tasks {
+            tree.insert_task(task);
+        }
+        tree
+    }
+
+    /// Adds or replaces a project
+    pub fn insert_project(&mut self, project: Project) {
+        self.projects.insert(project.id(), project);
+    }
+
+    /// Adds or replaces a task
+    pub fn insert_task(&mut self, task: Task) {
+        self.tasks.insert(task.id(), task);
+    }
+
+    pub fn get_project(&self, id: &Id<Project>) -> Option<&Project> {
+        self.projects.get(id)
+    }
+
+    pub fn get_task(&self, id: &Id<Task>) -> Option<&Task> {
+        self.tasks.get(id)
+    }
+
+    /// Progress of the item's descendant tree.
+    ///
+    /// Every descendant without children of its own counts as one unit of work, finished once
+    /// `Completed` or `Archived`. Canceled items are left out together with everything below them,
+    /// and an item with nothing left to count is measured by its own status.
+    pub fn progress(&self, item: &SchedulableItem) -> Option<Progress> {
+        if !self.contains(item) {
+            return None;
+        }
+        Some(self.progress_below(item, &mut HashSet::new()))
+    }
+
+    /// Estimate of the item rolled up from its descendant tree.
+    ///
+    /// Estimated children replace the item's own estimate, so a project broken down into
+    /// estimated tasks is sized by those tasks. Canceled items are left out together with
+    /// everything below them, and an item with no estimate anywhere below it falls back
+    /// to its own.
+    pub fn estimate(&self, item: &SchedulableItem) -> Option<EstimateTotal> {
+        if !self.contains(item) {
+            return None;
+        }
+        Some(self.estimate_below(item, &mut HashSet::new()))
+    }
+
+    /// Direct children that are part of the tree and neither finished nor canceled
+    pub fn open_children(&self, item: &SchedulableItem) -> Vec<SchedulableItem> {
+        self.open_items(&self.children(item))
+    }
+
+    /// Items from `items` that are part of the tree and neither finished nor canceled,
+    /// such as the ones a milestone still waits on
+    pub fn open_items(&self, items: &[SchedulableItem]) -> Vec<SchedulableItem> {
+        items
+            .iter()
+            .filter(|item| self.status(item).is_some_and(|status| status.is_open()))
+            .cloned()
+            .collect()
+    }
+
+    /// Projects holding the item among their children, directly or through other projects
+    /// and parent tasks, nearest first
+    pub fn ancestor_projects(&self, item: &SchedulableItem) -> Vec<Id<Project>> {
+        let mut ancestors = Vec::new();
+        let mut visited = HashSet::from([item.clone()]);
+        let mut current = vec![item.clone()];
+
+        while !current.is_empty() {
+            let mut next = Vec::new();
+            for child in &current {
+                for parent in self.parents(child) {
+                    if visited.insert(parent.clone()) {
+                        if let SchedulableItem::Project(id) = &parent {
+                            ancestors.push(id.clone());
+                        }
+                        next.push(parent);
+                    }
+                }
+            }
+            current = next;
+        }
+        ancestors
+    }
+
+    /// Everything below the item, through child projects and subtasks, nearest first
+    pub fn descendants(&self, item: &SchedulableItem) -> Vec<SchedulableItem> {
+        let mut descendants = Vec::new();
+        let mut visited = HashSet::from([item.clone()]);
+        let mut current = vec![item.clone()];
+
+        while !current.is_empty() {
+            let mut next = Vec::new();
+            for parent in &current {
+                for child in self.children(parent) {
+                    if visited.insert(child.clone()) {
+                        descendants.push(child.clone());
+                        next.push(child);
+                    }
+                }
+            }
+            current = next;
+        }
+        descendants
+    }
+
+    fn contains(&self, item: &SchedulableItem) -> bool {
+        match item {
+            SchedulableItem::Project(id) => self.projects.contains_key(id),
+            SchedulableItem::Task(id) => self.tasks.contains_key(id),
+        }
+    }
+
+    /// Status of a project or task that is part of the tree
+    pub fn status(&self, item: &SchedulableItem) -> Option<SchedulableItemStatus> {
+        match item {
+            SchedulableItem::Project(id) => self.projects.get(id).map(Schedulable::status),
+            SchedulableItem::Task(id) => self.tasks.get(id).map(Schedulable::status),
+        }
+    }
+
+    /// Children that are part of the tree
+    fn children(&self, item: &SchedulableItem) -> Vec<SchedulableItem> {
+        let children = match item {
+            SchedulableItem::Project(id) => self.projects.get(id).map(Schedulable::children).unwrap_or_default(),
+            SchedulableItem::Task(id) => self
+                .tasks
+                .get(id)
+                .map(|task| task.children().into_iter().map(SchedulableItem::Task).collect())
+                .unwrap_or_default(),
+        };
+        children.into_iter().filter(|child| self.contains(child)).collect()
+    }
+
+    fn parents(&self, item: &SchedulableItem) -> Vec<SchedulableItem> {
+        let project_parents = self
+            .projects
+            .values()
+            .filter(|project| project.children().contains(item))
+            .map(|project| SchedulableItem::Project(project.id()));
+        let task_parents = match item {
+            SchedulableItem::Task(id) => self
+                .tasks
+                .values()
+                .filter(|task| task.children().contains(id))
+                .map(|task| SchedulableItem::Task(task.id()))
+                .collect(),
+            SchedulableItem::Project(_) => Vec::new(),
+        };
+        project_parents.chain(task_parents).collect()
+    }
+
+    fn progress_below(&self, item: &SchedulableItem, visited: &mut HashSet<SchedulableItem>) -> Progress {
+        visited.insert(item.clone());
+
+        let mut progress = Progress::default();
+        for child in self.children(item) {
+            if visited.contains(&child) || self.status(&child) == Some(SchedulableItemStatus::Canceled) {
+                continue;
+            }
+            progress.add(self.progress_below(&child, visited));
+        }
+
+        if progress.total == 0 {
+            let finished = self.status(item).is_some_and(|status| status.is_finished());
+            progress = Progress {
+                finished: usize::from(finished),
+                total: 1,
+            };
+        }
+        progress
+    }
+
+    fn own_estimate(&self, item: &SchedulableItem) -> EstimateTotal {
+        let estimate = match item {
+            SchedulableItem::Project(id) => self.projects.get(id).and_then(Project::estimate),
+            SchedulableItem::Task(id) => self.tasks.get(id).and_then(Task::estimate),
+        };
+        estimate.map(EstimateTotal::from).unwrap_or_default()
+    }
+
+    fn estimate_below(&self, item: &SchedulableItem, visited: &mut HashSet<SchedulableItem>) -> EstimateTotal {
+        visited.insert(item.clone());
+
+        let mut total = EstimateTotal::default();
+        for child in self.children(item) {
+            if visited.contains(&child) || self.status(&child) == Some(SchedulableItemStatus::Canceled) {
+                continue;
+            }
+            total.merge(self.estimate_below(&child, visited));
+        }
+
+        if total.is_empty() {
+            total = self.own_estimate(item);
+        }
+        total
+    }
+}
@@ -0,0 +1,149 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, TimeDelta, Utc};
+
+use crate::graph::dependency_graph::{DependencyGraph, DependencyGraphError};
+use crate::id::Id;
+use crate::models::schedulable::Schedulable;
+use crate::{EntityType, HasId};
+
+/// Forecast for one item of a [`Schedule`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ScheduledItem<T> {
+    pub id: Id<T>,
+    pub earliest_start: DateTime<Utc>,
+    pub earliest_finish: DateTime<Utc>,
+    pub latest_start: DateTime<Utc>,
+    pub latest_finish: DateTime<Utc>,
+    /// How long the item can slip without delaying the finish of the whole schedule
+    pub slack: TimeDelta,
+    pub critical: bool,
+    /// The due date cannot be met once upstream dependencies are accounted for
+    pub impossible_due_date: bool,
+}
+
+/// Critical path forecast over a set of projects or tasks and their dependencies.
+///
+/// An item lasts from its start date to its due date when it has both, and is treated as
+/// a zero-length milestone otherwise. It cannot start before its own start date (or `origin`
+/// when it has none) nor before any of its dependencies finish, and an item with a due date
+/// is not forecast to finish earlier than that date.
+#[derive(Clone, Debug)]
+pub struct Schedule<T> {
+    items: Vec<ScheduledItem<T>>,
+    finish: DateTime<Utc>,
+}
+
+impl<T> Schedule<T>
+where
+    T: Schedulable<DependencyType = Id<T>> + HasId<Entity = T> + EntityType + Clone + Eq,
+{
+    pub fn compute(items: Vec<T>, origin: DateTime<Utc>) -> Result<Self, DependencyGraphError> {
+        let graph = DependencyGraph::from_items(items);
+        let order = graph.topological_order()?;
+
+        // Forward pass: earliest start and finish, dependencies first
+        let mut earliest: HashMap<Id<T>, (DateTime<Utc>, DateTime<Utc>)> = HashMap::new();
+        let mut overdue: HashMap<Id<T>, bool> = HashMap::new();
+        for id in &order {
+            let item = graph.get(id).expect("ordered ids belong to the graph");
+            let upstream_finish = graph
+                .dependencies(id)
+                .iter()
+                .map(|dependency| earliest[dependency].1)
+                .max();
+
+            let own_start = item.start_date().unwrap_or(origin);
+            let earliest_start = upstream_finish.map_or(own_start, |finish| finish.max(own_start));
+            let earliest_finish = earliest_start + duration(item);
+
+            overdue.insert(id.clone(), item.due_date().is_some_and(|due_date| earliest_finish > due_date));
+            let forecast_finish = item.due_date().map_or(earliest_finish, |due_date| due_date.max(earliest_finish));
+            earliest.insert(id.clone(), (earliest_start, forecast_finish));
+        }
+
+        let finish = earliest.values().map(|(_, finish)| *finish).max().unwrap_or(origin);
+
+        // Backward pass: latest start and finish, dependents first
+        let mut dependents: HashMap<Id<T>, Vec<Id<T>>> = HashMap::new();
+        for id in &order {
+            for dependency in graph.dependencies(id) {
+                dependents.entry(dependency).or_default().push(id.clone());
+            }
+        }
+
+        let mut latest: HashMap<Id<T>, (DateTime<Utc>, DateTime<Utc>)> = HashMap::new();
+        for id in order.iter().rev() {
+            let item = graph.get(id).expect("ordered ids belong to the graph");
+            let downstream_start = dependents
+                .get(id)
+                .into_iter()
+                .flatten()
+                .map(|dependent| latest[dependent].0)
+                .min();
+
+            let latest_finish = downstream_start.map_or(finish, |start| start.min(finish));
+            latest.insert(id.clone(), (latest_finish - duration(item), latest_finish));
+        }
+
+        let items = order
+            .into_iter()
+            .map(|id| {
+                let (earliest_start, earliest_finish) = earliest[&id];
+                let (latest_start, latest_finish) = latest[&id];
+                let slack = latest_finish - earliest_finish;
+                ScheduledItem {
+                    impossible_due_date: overdue[&id],
+                    critical: slack <= TimeDelta::zero(),
+                    id,
+                    earliest_start,
+                    earliest_finish,
+                    latest_start,
+                    latest_finish,
+                    slack,
+                }
+            })
+            .collect();
+
+        Ok(Self { items, finish })
+    }
+
+    /// Every scheduled item, dependencies first
+    pub fn items(&self) -> &[ScheduledItem<T>] {
+        &self.items
+    }
+
+    pub fn get(&self, id: &Id<T>) -> Option<&ScheduledItem<T>> {
+        self.items.iter().find(|item| &item.id == id)
+    }
+
+    /// Forecast finish of the whole schedule
+    pub fn finish(&self) -> DateTime<Utc> {
+        self.finish
+    }
+
+    /// Items without slack, dependencies first
+    pub fn critical_path(&self) -> Vec<Id<T>> {
+        self.items
+            .iter()
+            .filter(|item| item.critical)
+            .map(|item| item.id.clone())
+            .collect()
+    }
+
+    /// Items whose due date cannot be met given their upstream dependencies
+    pub fn impossible_due_dates(&self) -> Vec<Id<T>> {
+        self.items
+            .iter()
+            .filter(|item| item.impossible_due_date)
+            .map(|item| item.id.clone())
+            .collect()
+    }
+}
+
+fn duration<T: Schedulable>(item: &T) -> TimeDelta {
+    match (item.start_date(), item.due_date()) {
+        (Some(start_date), Some(due_date)) if due_date > start_date => due_date - start_date,
+        _ => TimeDelta::zero(),
+    }
+}
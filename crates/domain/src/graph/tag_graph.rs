@@ -0,0 +1,247 @@
+use core::fmt;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use serde::Serialize;
+
+use crate::id::Id;
+use crate::models::{schedulable::Schedulable, tag::Tag};
+use crate::HasId;
+
+/// In-memory view of the tag hierarchy, where each tag points to its parents.
+///
+/// Parents that are not part of the graph are kept on the tags but ignored when walking it,
+/// so a partially loaded taxonomy still answers queries for the tags it knows about.
+#[derive(Clone, Debug, Default)]
+pub struct TagGraph {
+    tags: HashMap<Id<Tag>, Tag>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub enum TagGraphError {
+    UnknownTag(String),
+    SelfParent(String),
+    /// The tags forming the loop, starting and ending with the same tag
+    Cycle(Vec<String>),
+}
+
+impl fmt::Display for TagGraphError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TagGraphError::UnknownTag(id) => write!(f, "Tag {id} is not part of the graph"),
+            TagGraphError::SelfParent(id) => write!(f, "Tag {id} cannot be its own parent"),
+            TagGraphError::Cycle(path) => write!(f, "Tag hierarchy cycle: {}", path.join(" -> ")),
+        }
+    }
+}
+
+impl std::error::Error for TagGraphError {}
+
+impl TagGraph {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builds a graph from stored tags, rejecting them if their parents already form a cycle
+    pub fn from_tags(tags: impl IntoIterator<Item = Tag>) -> Result<Self, TagGraphError> {
+        let graph = Self {
+            tags: tags.into_iter().map(|tag| (tag.id(), tag)).collect(),
+        };
+
+        match graph.find_cycle() {
+            Some(cycle) => Err(TagGraphError::Cycle(cycle)),
+            None => Ok(graph),
+        }
+    }
+
+    pub fn get(&self, id: &Id<Tag>) -> Option<&Tag> {
+        self.tags.get(id)
+    }
+
+    pub fn contains(&self, id: &Id<Tag>) -> bool {
+        self.tags.contains_key(id)
+    }
+
+    pub fn len(&self) -> usize {
+        self.tags.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tags.is_empty()
+    }
+
+    /// Adds or replaces a tag, as long as its parents do not lead back to it
+    pub fn insert(&mut self, tag: Tag) -> Result<(), TagGraphError> {
+        let tag_id = tag.id();
+        for parent in tag.parents() {
+            self.check_parent(&tag_id, &parent)?;
+        }
+        self.tags.insert(tag_id, tag);
+        Ok(())
+    }
+
+    pub fn remove(&mut self, id: &Id<Tag>) -> Option<Tag> {
+        self.tags.remove(id)
+    }
+
+    /// Links `tag_id` under `parent_id`, refusing links that would close a loop
+    pub fn add_parent(&mut self, tag_id: &Id<Tag>, parent_id: Id<Tag>) -> Result<(), TagGraphError> {
+        if !self.contains(tag_id) {
+            return Err(TagGraphError::UnknownTag(tag_id.to_string()));
+        }
+        self.check_parent(tag_id, &parent_id)?;
+
+        let tag = self.tags.get_mut(tag_id).expect("tag presence checked above");
+        if !tag.parents().contains(&parent_id) {
+            tag.add_parent(parent_id);
+        }
+        Ok(())
+    }
+
+    /// Every tag reachable by following parents, nearest first
+    pub fn ancestors(&self, id: &Id<Tag>) -> Vec<Id<Tag>> {
+        self.walk(id, |tag_id| {
+            self.tags
+                .get(tag_id)
+                .map(|tag| tag.parents())
+                .unwrap_or_default()
+        })
+    }
+
+    /// Every tag that has `id` among its ancestors, nearest first
+    pub fn descendants(&self, id: &Id<Tag>) -> Vec<Id<Tag>> {
+        let children = self.children_index();
+        self.walk(id, |tag_id| children.get(tag_id).cloned().unwrap_or_default())
+    }
+
+    /// Whether `tag_id` is `filter` itself or sits anywhere below it
+    pub fn is_within(&self, tag_id: &Id<Tag>, filter: &Id<Tag>) -> bool {
+        tag_id == filter || self.ancestors(tag_id).contains(filter)
+    }
+
+    /// Whether the item carries `filter` or any descendant of it
+    pub fn is_tagged_with<T: Schedulable>(&self, item: &T, filter: &Id<Tag>) -> bool {
+        item.tags().iter().any(|tag_id| self.is_within(tag_id, filter))
+    }
+
+    /// Keeps the items tagged with `filter` or any descendant of it
+    pub fn filter_tagged<T: Schedulable>(&self, items: Vec<T>, filter: &Id<Tag>) -> Vec<T> {
+        let mut accepted: HashSet<Id<Tag>> = self.descendants(filter).into_iter().collect();
+        accepted.insert(filter.clone());
+
+        items
+            .into_iter()
+            .filter(|item| item.tags().iter().any(|tag_id| accepted.contains(tag_id)))
+            .collect()
+    }
+
+    /// Returns one loop in the hierarchy, if there is any
+    pub fn find_cycle(&self) -> Option<Vec<String>> {
+        let mut finished: HashSet<Id<Tag>> = HashSet::new();
+
+        for start in self.tags.keys() {
+            if finished.contains(start) {
+                continue;
+            }
+            let mut path: Vec<Id<Tag>> = Vec::new();
+            if let Some(cycle) = self.visit(start, &mut path, &mut finished) {
+                return Some(cycle.iter().map(|id| id.to_string()).collect());
+            }
+        }
+        None
+    }
+
+    fn visit(
+        &self,
+        id: &Id<Tag>,
+        path: &mut Vec<Id<Tag>>,
+        finished: &mut HashSet<Id<Tag>>,
+    ) -> Option<Vec<Id<Tag>>> {
+        if let Some(position) = path.iter().position(|visited| visited == id) {
+            let mut cycle = path[position..].to_vec();
+            cycle.push(id.clone());
+            return Some(cycle);
+        }
+        if finished.contains(id) {
+            return None;
+        }
+
+        path.push(id.clone());
+        if let Some(tag) = self.tags.get(id) {
+            for parent in tag.parents() {
+                if let Some(cycle) = self.visit(&parent, path, finished) {
+                    return Some(cycle);
+                }
+            }
+        }
+        path.pop();
+        finished.insert(id.clone());
+        None
+    }
+
+    fn check_parent(&self, tag_id: &Id<Tag>, parent_id: &Id<Tag>) -> Result<(), TagGraphError> {
+        if tag_id == parent_id {
+            return Err(TagGraphError::SelfParent(tag_id.to_string()));
+        }
+        // The new link closes a loop when the tag is already an ancestor of its future parent
+        if self.ancestors(parent_id).contains(tag_id) {
+            let mut cycle = vec![tag_id.to_string()];
+            cycle.extend(self.path_between(parent_id, tag_id));
+            return Err(TagGraphError::Cycle(cycle));
+        }
+        Ok(())
+    }
+
+    /// Parent chain from `from` up to `to`, both included, as tag id strings
+    fn path_between(&self, from: &Id<Tag>, to: &Id<Tag>) -> Vec<String> {
+        let mut previous: HashMap<Id<Tag>, Id<Tag>> = HashMap::new();
+        let mut queue = VecDeque::from([from.clone()]);
+
+        while let Some(current) = queue.pop_front() {
+            if &current == to {
+                let mut path = vec![current.to_string()];
+                let mut step = current;
+                while let Some(prior) = previous.get(&step) {
+                    path.push(prior.to_string());
+                    step = prior.clone();
+                }
+                path.reverse();
+                return path;
+            }
+            if let Some(tag) = self.tags.get(&current) {
+                for parent in tag.parents() {
+                    if parent != *from && !previous.contains_key(&parent) {
+                        previous.insert(parent.clone(), current.clone());
+                        queue.push_back(parent);
+                    }
+                }
+            }
+        }
+        Vec::new()
+    }
+
+    fn children_index(&self) -> HashMap<Id<Tag>, Vec<Id<Tag>>> {
+        let mut children: HashMap<Id<Tag>, Vec<Id<Tag>>> = HashMap::new();
+        for tag in self.tags.values() {
+            for parent in tag.parents() {
+                children.entry(parent).or_default().push(tag.id());
+            }
+        }
+        children
+    }
+
+    fn walk(&self, id: &Id<Tag>, next: impl Fn(&Id<Tag>) -> Vec<Id<Tag>>) -> Vec<Id<Tag>> {
+        let mut seen: HashSet<Id<Tag>> = HashSet::from([id.clone()]);
+        let mut found = Vec::new();
+        let mut queue = VecDeque::from([id.clone()]);
+
+        while let Some(current) = queue.pop_front() {
+            for neighbour in next(&current) {
+                if seen.insert(neighbour.clone()) {
+                    found.push(neighbour.clone());
+                    queue.push_back(neighbour);
+                }
+            }
+        }
+        found
+    }
+}
@@ -0,0 +1,101 @@
+use std::collections::{BTreeMap, HashMap};
+
+use chrono::{DateTime, Datelike, Duration, NaiveDate, Utc};
+
+use crate::graph::project_tree::ProjectTree;
+use crate::id::Id;
+use crate::models::{
+    person::Person,
+    schedulable::{Schedulable, SchedulableItem},
+    tag::Tag,
+    time_entry::TimeEntry,
+};
+
+/// Totals over a set of time entries. Running timers count up to the report's `now`.
+#[derive(Clone, Debug)]
+pub struct TimeReport {
+    entries: Vec<TimeEntry>,
+    now: DateTime<Utc>,
+}
+
+impl TimeReport {
+    pub fn new(entries: impl IntoIterator<Item = TimeEntry>, now: DateTime<Utc>) -> Self {
+        Self {
+            entries: entries.into_iter().collect(),
+            now,
+        }
+    }
+
+    pub fn entries(&self) -> &[TimeEntry] {
+        &self.entries
+    }
+
+    pub fn total(&self) -> Duration {
+        self.sum(|_| true)
+    }
+
+    /// Time logged directly against the item
+    pub fn total_for_item(&self, item: &SchedulableItem) -> Duration {
+        self.sum(|entry| entry.item() == item)
+    }
+
+    pub fn total_for_person(&self, person_id: &Id<Person>) -> Duration {
+        self.sum(|entry| entry.person_id() == person_id)
+    }
+
+    /// Time logged against the item and everything below it in the tree
+    pub fn total_with_descendants(&self, tree: &ProjectTree, item: &SchedulableItem) -> Duration {
+        let mut items = tree.descendants(item);
+        items.push(item.clone());
+        self.sum(|entry| items.contains(entry.item()))
+    }
+
+    pub fn per_item(&self) -> HashMap<SchedulableItem, Duration> {
+        self.group_by(|entry| vec![entry.item().clone()])
+    }
+
+    pub fn per_person(&self) -> HashMap<Id<Person>, Duration> {
+        self.group_by(|entry| vec![entry.person_id().clone()])
+    }
+
+    /// Time per tag of the logged item; an entry counts towards each of its item's tags,
+    /// and items unknown to the tree are left out
+    pub fn per_tag(&self, tree: &ProjectTree) -> HashMap<Id<Tag>, Duration> {
+        self.group_by(|entry| match entry.item() {
+            SchedulableItem::Project(id) => tree.get_project(id).map(Schedulable::tags).unwrap_or_default(),
+            SchedulableItem::Task(id) => tree.get_task(id).map(Schedulable::tags).unwrap_or_default(),
+        })
+    }
+
+    /// Time per week, keyed by the Monday of the week each entry started in
+    pub fn per_week(&self) -> BTreeMap<NaiveDate, Duration> {
+        let mut weeks = BTreeMap::new();
+        for entry in &self.entries {
+            *weeks.entry(week_start(entry.start())).or_insert_with(Duration::zero) += entry.duration_at(self.now);
+        }
+        weeks
+    }
+
+    fn sum(&self, filter: impl Fn(&TimeEntry) -> bool) -> Duration {
+        self.entries
+            .iter()
+            .filter(|entry| filter(entry))
+            .fold(Duration::zero(), |total, entry| total + entry.duration_at(self.now))
+    }
+
+    fn group_by<K: std::hash::Hash + Eq>(&self, keys: impl Fn(&TimeEntry) -> Vec<K>) -> HashMap<K, Duration> {
+        let mut groups = HashMap::new();
+        for entry in &self.entries {
+            for key in keys(entry) {
+                *groups.entry(key).or_insert_with(Duration::zero) += entry.duration_at(self.now);
+            }
+        }
+        groups
+    }
+}
+
+/// Monday starting the ISO week of `date`
+pub fn week_start(date: DateTime<Utc>) -> NaiveDate {
+    let day = date.date_naive();
+    day - Duration::days(day.weekday().num_days_from_monday().into())
+}
@@ -0,0 +1,149 @@
+use std::cmp::Ordering;
+
+use chrono::{DateTime, Duration, Utc};
+use serde::Serialize;
+
+use crate::graph::dependency_graph::DependencyGraph;
+use crate::models::{
+    project::Project,
+    schedulable::{Priority, Schedulable, SchedulableItem},
+    task::Task,
+};
+use crate::HasId;
+
+/// An open project or task, with what it takes to rank it against the rest of the work
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct NextWork {
+    pub item: SchedulableItem,
+    pub name: String,
+    pub priority: Priority,
+    pub due_date: Option<DateTime<Utc>>,
+    /// Dependencies the item still waits on
+    pub blockers: Vec<SchedulableItem>,
+    /// Priority weight plus due-date urgency, higher comes first
+    pub score: u32,
+}
+
+impl NextWork {
+    fn new(
+        item: SchedulableItem,
+        schedulable: &impl Schedulable,
+        blockers: Vec<SchedulableItem>,
+        now: DateTime<Utc>,
+    ) -> Self {
+        let priority = schedulable.priority();
+        let due_date = schedulable.due_date();
+        Self {
+            item,
+            name: schedulable.name().to_string(),
+            priority,
+            due_date,
+            blockers,
+            score: priority_weight(priority) + urgency(due_date, now),
+        }
+    }
+
+    /// Every dependency is finished, so work can start right away
+    pub fn is_ready(&self) -> bool {
+        self.blockers.is_empty()
+    }
+
+    fn rank(&self, other: &Self) -> Ordering {
+        other
+            .is_ready()
+            .cmp(&self.is_ready())
+            .then_with(|| other.score.cmp(&self.score))
+            .then_with(|| match (self.due_date, other.due_date) {
+                (Some(due), Some(other_due)) => due.cmp(&other_due),
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (None, None) => Ordering::Equal,
+            })
+            .then_with(|| self.name.cmp(&other.name))
+    }
+}
+
+/// Open projects and tasks ordered by what to work on next.
+///
+/// Items whose dependencies are all finished come first, then the ones with the highest
+/// score, then the ones due soonest. Blocked items stay in the queue, behind the ready ones,
+/// so they are not lost from view.
+#[derive(Clone, Debug, Default)]
+pub struct WorkQueue {
+    entries: Vec<NextWork>,
+}
+
+impl WorkQueue {
+    pub fn new(
+        projects: impl IntoIterator<Item = Project>,
+        tasks: impl IntoIterator<Item = Task>,
+        now: DateTime<Utc>,
+    ) -> Self {
+        let projects = DependencyGraph::from_items(projects);
+        let tasks = DependencyGraph::from_items(tasks);
+
+        let project_entries = projects.items().filter(|project| project.status().is_open()).map(|project| {
+            let blockers = projects.blockers(&project.id()).into_iter().map(SchedulableItem::Project).collect();
+            NextWork::new(SchedulableItem::Project(project.id()), project, blockers, now)
+        });
+        let task_entries = tasks.items().filter(|task| task.status().is_open()).map(|task| {
+            let blockers = tasks.blockers(&task.id()).into_iter().map(SchedulableItem::Task).collect();
+            NextWork::new(SchedulableItem::Task(task.id()), task, blockers, now)
+        });
+
+        let mut entries: Vec<NextWork> = project_entries.chain(task_entries).collect();
+        entries.sort_by(NextWork::rank);
+        Self { entries }
+    }
+
+    pub fn entries(&self) -> &[NextWork] {
+        &self.entries
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Items that can be started right away, best first
+    pub fn ready(&self) -> impl Iterator<Item = &NextWork> {
+        self.entries.iter().filter(|entry| entry.is_ready())
+    }
+
+    /// The single best item to pick up, if anything is ready
+    pub fn next(&self) -> Option<&NextWork> {
+        self.ready().next()
+    }
+}
+
+/// Score a priority adds, two points per level above `Low`
+pub fn priority_weight(priority: Priority) -> u32 {
+    match priority {
+        Priority::Low => 0,
+        Priority::Medium => 2,
+        Priority::High => 4,
+        Priority::Critical => 6,
+    }
+}
+
+/// Score a due date adds as it gets closer: overdue items get the most, undated ones nothing
+pub fn urgency(due_date: Option<DateTime<Utc>>, now: DateTime<Utc>) -> u32 {
+    let Some(due_date) = due_date else {
+        return 0;
+    };
+    let left = due_date - now;
+    if left < Duration::zero() {
+        4
+    } else if left <= Duration::days(1) {
+        3
+    } else if left <= Duration::days(7) {
+        2
+    } else if left <= Duration::days(30) {
+        1
+    } else {
+        0
+    }
+}
@@ -27,6 +27,12 @@ impl<T: EntityType> Id<T> {
     }
 }
 
+impl<T> std::hash::Hash for Id<T> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.ulid.hash(state);
+    }
+}
+
 impl<T: EntityType> Default for Id<T> {
     fn default() -> Self {
         Self::new()
@@ -37,9 +43,7 @@ impl<T: EntityType> std::str::FromStr for Id<T> {
     type Err = ParseIdError; // to be improved
 
     fn from_str(id: &str) -> Result<Self, Self::Err> {
-        let string_parts: Vec<&str> = id.split("-").collect();
-        let prefix = string_parts[0];
-        let ulid_part = string_parts[1];
+        let (prefix, ulid_part) = id.split_once("-").ok_or(ParseIdError::InvalidFormat)?;
 
         if prefix != T::prefix() {
             return Err(ParseIdError::WrongPrefix);
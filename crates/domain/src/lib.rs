@@ -1,6 +1,7 @@
 pub mod models;
 pub mod factories;
 pub mod builders;
+pub mod graph;
 pub mod id;
 
 use id::Id;
@@ -0,0 +1,160 @@
+use crate::id::Id;
+use crate::models::person::Person;
+use crate::{EntityType, HasId};
+use chrono::{DateTime, Utc};
+use core::fmt;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Entities whose changes are recorded in the audit log
+pub trait Auditable: HasId {
+    /// Current value of every tracked field, rendered as text; unset fields are left out
+    fn audit_fields(&self) -> BTreeMap<&'static str, String>;
+}
+
+/// One field of one entity changing value, as kept in the append-only audit log
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuditEntry {
+    id: Id<AuditEntry>,
+    entity_id: String,
+    field: String,
+    old_value: Option<String>,
+    new_value: Option<String>,
+    actor: Option<Id<Person>>,
+    at: DateTime<Utc>,
+}
+
+impl AuditEntry {
+    pub fn new(
+        entity_id: impl Into<String>,
+        field: impl Into<String>,
+        old_value: Option<String>,
+        new_value: Option<String>,
+        actor: Option<Id<Person>>,
+        at: DateTime<Utc>,
+    ) -> Self {
+        Self::restore(Id::new(), entity_id, field, old_value, new_value, actor, at)
+    }
+
+    /// Rebuilds an entry that was already logged, keeping its id
+    pub fn restore(
+        id: Id<AuditEntry>,
+        entity_id: impl Into<String>,
+        field: impl Into<String>,
+        old_value: Option<String>,
+        new_value: Option<String>,
+        actor: Option<Id<Person>>,
+        at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            entity_id: entity_id.into(),
+            field: field.into(),
+            old_value,
+            new_value,
+            actor,
+            at,
+        }
+    }
+
+    /// Entries for every field that differs between two versions of an entity.
+    /// A missing `before` records a creation, a missing `after` a deletion.
+    pub fn changes<T: Auditable>(
+        before: Option<&T>,
+        after: Option<&T>,
+        actor: Option<Id<Person>>,
+        at: DateTime<Utc>,
+    ) -> Vec<AuditEntry>
+    where
+        T::Entity: EntityType,
+    {
+        let Some(entity_id) = after.or(before).map(|entity| entity.id().to_string()) else {
+            return Vec::new();
+        };
+        let mut old_fields = before.map(Auditable::audit_fields).unwrap_or_default();
+        let mut new_fields = after.map(Auditable::audit_fields).unwrap_or_default();
+
+        let mut fields: Vec<&'static str> = old_fields.keys().chain(new_fields.keys()).copied().collect();
+        fields.sort_unstable();
+        fields.dedup();
+
+        fields
+            .into_iter()
+            .filter_map(|field| {
+                let old_value = old_fields.remove(field);
+                let new_value = new_fields.remove(field);
+                (old_value != new_value)
+                    .then(|| AuditEntry::new(&entity_id, field, old_value, new_value, actor.clone(), at))
+            })
+            .collect()
+    }
+
+    pub fn entity_id(&self) -> &str {
+        &self.entity_id
+    }
+
+    pub fn field(&self) -> &str {
+        &self.field
+    }
+
+    pub fn old_value(&self) -> Option<&str> {
+        self.old_value.as_deref()
+    }
+
+    pub fn new_value(&self) -> Option<&str> {
+        self.new_value.as_deref()
+    }
+
+    pub fn actor(&self) -> Option<&Id<Person>> {
+        self.actor.as_ref()
+    }
+
+    pub fn at(&self) -> DateTime<Utc> {
+        self.at
+    }
+}
+
+impl fmt::Debug for AuditEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Audit Entry [[{}.{}]]", self.entity_id, self.field)?;
+        writeln!(f, "- Audit Entry Id:{:?}", self.id)?;
+        writeln!(f, "- Changed from: {:?}", self.old_value)?;
+        writeln!(f, "- Changed to: {:?}", self.new_value)?;
+        if let Some(actor) = &self.actor {
+            writeln!(f, "- Changed by: {:?}", actor)?;
+        }
+        writeln!(f, "- Changed on: {}", self.at)?;
+        Ok(())
+    }
+}
+
+impl fmt::Display for AuditEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let old_value = self.old_value.as_deref().unwrap_or("<none>");
+        let new_value = self.new_value.as_deref().unwrap_or("<none>");
+        write!(f, "{}.{}: {old_value} -> {new_value} on {}", self.entity_id, self.field, self.at)?;
+        if let Some(actor) = &self.actor {
+            write!(f, " by {actor}")?;
+        }
+        Ok(())
+    }
+}
+
+impl EntityType for AuditEntry {
+    fn prefix() -> &'static str {
+        "audit"
+    }
+}
+
+impl HasId for AuditEntry {
+    type Entity = AuditEntry;
+
+    fn id(&self) -> Id<AuditEntry> {
+        self.id.clone()
+    }
+}
+
+/// Renders a list of ids or items as one comma-separated value
+pub(crate) fn join<T: fmt::Display>(values: impl IntoIterator<Item = T>) -> String {
+    values.into_iter().map(|value| value.to_string()).collect::<Vec<_>>().join(",")
+}
@@ -0,0 +1,269 @@
+use crate::id::Id;
+use crate::models::{
+    person::Person,
+    schedulable::{Priority, Schedulable, SchedulableItem},
+    tag::Tag,
+};
+use chrono::{DateTime, Utc};
+use core::fmt;
+use serde::{Deserialize, Serialize};
+
+/// A `Schedulable` mutation kept as data, so it can be run through a `CommandHistory`
+/// and reverted later.
+/// Status changes go through the workflow, which keeps its own record as `StatusTransition`,
+/// so they are not commands.
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub enum SchedulableCommand {
+    Rename(String),
+    TransferOwnership(Id<Person>),
+    SetDescription(String),
+    ClearDescription,
+    AddTag(Id<Tag>),
+    AddTags(Vec<Id<Tag>>),
+    RemoveTag(Id<Tag>),
+    RemoveTags(Vec<Id<Tag>>),
+    RemoveAllTags,
+    Start,
+    StartAtDate(DateTime<Utc>),
+    RemoveStartDate,
+    SetDueDate(DateTime<Utc>),
+    RemoveDueDate,
+    SetPriority(Priority),
+    AddChild(SchedulableItem),
+    AddChildren(Vec<SchedulableItem>),
+    RemoveChild(SchedulableItem),
+    RemoveChildren(Vec<SchedulableItem>),
+    RemoveAllChildren,
+    RemoveAllDependencies,
+}
+
+impl SchedulableCommand {
+    /// Runs the mutation against `item`
+    pub fn apply<T: Schedulable>(&self, item: &mut T) {
+        match self {
+            SchedulableCommand::Rename(name) => {
+                item.rename(name);
+            }
+            SchedulableCommand::TransferOwnership(owner_id) => {
+                item.transfer_ownership(owner_id.clone());
+            }
+            SchedulableCommand::SetDescription(description) => {
+                item.set_description(description.clone());
+            }
+            SchedulableCommand::ClearDescription => {
+                item.clear_description();
+            }
+            SchedulableCommand::AddTag(tag_id) => {
+                item.add_tag(tag_id.clone());
+            }
+            SchedulableCommand::AddTags(tags) => {
+                item.add_tags(tags.clone());
+            }
+            SchedulableCommand::RemoveTag(tag_id) => {
+                item.remove_tag(tag_id.clone());
+            }
+            SchedulableCommand::RemoveTags(tags) => {
+                item.remove_tags(tags.clone());
+            }
+            SchedulableCommand::RemoveAllTags => {
+                item.remove_all_tags();
+            }
+            SchedulableCommand::Start => {
+                item.start();
+            }
+            SchedulableCommand::StartAtDate(start_date) => {
+                item.start_at_date(*start_date);
+            }
+            SchedulableCommand::RemoveStartDate => {
+                item.remove_start_date();
+            }
+            SchedulableCommand::SetDueDate(due_date) => {
+                item.set_due_date(*due_date);
+            }
+            SchedulableCommand::RemoveDueDate => {
+                item.remove_due_date();
+            }
+            SchedulableCommand::SetPriority(priority) => {
+                item.set_priority(*priority);
+            }
+            SchedulableCommand::AddChild(child) => {
+                item.add_child(child.clone());
+            }
+            SchedulableCommand::AddChildren(children) => {
+                item.add_children(children.clone());
+            }
+            SchedulableCommand::RemoveChild(child) => {
+                // `remove_child` expects the child to be there
+                if item.has_child(child) {
+                    item.remove_child(child.clone());
+                }
+            }
+            SchedulableCommand::RemoveChildren(children) => {
+                let present = children.iter().filter(|child| item.has_child(child)).cloned().collect();
+                item.remove_children(present);
+            }
+            SchedulableCommand::RemoveAllChildren => {
+                item.remove_all_children();
+            }
+            SchedulableCommand::RemoveAllDependencies => {
+                item.remove_all_dependencies();
+            }
+        }
+    }
+}
+
+impl fmt::Display for SchedulableCommand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SchedulableCommand::Rename(name) => write!(f, "Rename to {name}"),
+            SchedulableCommand::TransferOwnership(owner_id) => write!(f, "Transfer ownership to {owner_id}"),
+            SchedulableCommand::SetDescription(_) => write!(f, "Set description"),
+            SchedulableCommand::ClearDescription => write!(f, "Clear description"),
+            SchedulableCommand::AddTag(tag_id) => write!(f, "Add tag {tag_id}"),
+            SchedulableCommand::AddTags(tags) => write!(f, "Add {} tags", tags.len()),
+            SchedulableCommand::RemoveTag(tag_id) => write!(f, "Remove tag {tag_id}"),
+            SchedulableCommand::RemoveTags(tags) => write!(f, "Remove {} tags", tags.len()),
+            SchedulableCommand::RemoveAllTags => write!(f, "Remove all tags"),
+            SchedulableCommand::Start => write!(f, "Start now"),
+            SchedulableCommand::StartAtDate(start_date) => write!(f, "Start on {start_date}"),
+            SchedulableCommand::RemoveStartDate => write!(f, "Remove start date"),
+            SchedulableCommand::SetDueDate(due_date) => write!(f, "Set due date to {due_date}"),
+            SchedulableCommand::RemoveDueDate => write!(f, "Remove due date"),
+            SchedulableCommand::SetPriority(priority) => write!(f, "Set priority to {priority}"),
+            SchedulableCommand::AddChild(child) => write!(f, "Add child {child}"),
+            SchedulableCommand::AddChildren(children) => write!(f, "Add {} children", children.len()),
+            SchedulableCommand::RemoveChild(child) => write!(f, "Remove child {child}"),
+            SchedulableCommand::RemoveChildren(children) => write!(f, "Remove {} children", children.len()),
+            SchedulableCommand::RemoveAllChildren => write!(f, "Remove all children"),
+            SchedulableCommand::RemoveAllDependencies => write!(f, "Remove all dependencies"),
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Serialize)]
+pub enum CommandError {
+    NothingToUndo,
+    NothingToRedo,
+    /// The item was changed outside the history since the command ran,
+    /// so reverting would silently drop that change
+    Diverged,
+}
+
+impl fmt::Display for CommandError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CommandError::NothingToUndo => write!(f, "Nothing to undo"),
+            CommandError::NothingToRedo => write!(f, "Nothing to redo"),
+            CommandError::Diverged => write!(f, "Item was changed outside the command history"),
+        }
+    }
+}
+
+impl std::error::Error for CommandError {}
+
+/// A command that ran, with the item as it was on both sides of it
+#[derive(Clone, PartialEq, Eq, Debug)]
+struct AppliedCommand<T> {
+    command: SchedulableCommand,
+    before: T,
+    after: T,
+}
+
+/// Undo and redo stacks for the commands run against one item.
+///
+/// Each entry keeps a copy of the item from before and after the command, since several
+/// mutations cannot be worked back out from their arguments: `remove_all_tags` forgets which
+/// tags there were, and `start` stamps the current time.
+///
+/// The caller holds the history and stores the item itself; the back-end keeps one per project
+/// and task edited through its command routes.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct CommandHistory<T> {
+    done: Vec<AppliedCommand<T>>,
+    undone: Vec<AppliedCommand<T>>,
+}
+
+impl<T> Default for CommandHistory<T> {
+    fn default() -> Self {
+        Self {
+            done: Vec::new(),
+            undone: Vec::new(),
+        }
+    }
+}
+
+impl<T: Schedulable + Clone + PartialEq> CommandHistory<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Runs `command` against `item` and records it for undo.
+    /// Returns whether the item changed; commands that change nothing are not recorded.
+    /// Running a new command drops anything that could have been redone.
+    pub fn execute(&mut self, item: &mut T, command: SchedulableCommand) -> bool {
+        let before = item.clone();
+        command.apply(item);
+        if *item == before {
+            return false;
+        }
+
+        self.undone.clear();
+        self.done.push(AppliedCommand {
+            command,
+            before,
+            after: item.clone(),
+        });
+        true
+    }
+
+    /// Puts `item` back the way it was before the last command, returning that command
+    pub fn undo(&mut self, item: &mut T) -> Result<SchedulableCommand, CommandError> {
+        let applied = self.done.last().ok_or(CommandError::NothingToUndo)?;
+        if *item != applied.after {
+            return Err(CommandError::Diverged);
+        }
+
+        let applied = self.done.pop().expect("checked above");
+        *item = applied.before.clone();
+        let command = applied.command.clone();
+        self.undone.push(applied);
+        Ok(command)
+    }
+
+    /// Runs the last undone command again, returning it
+    pub fn redo(&mut self, item: &mut T) -> Result<SchedulableCommand, CommandError> {
+        let applied = self.undone.last().ok_or(CommandError::NothingToRedo)?;
+        if *item != applied.before {
+            return Err(CommandError::Diverged);
+        }
+
+        let applied = self.undone.pop().expect("checked above");
+        *item = applied.after.clone();
+        let command = applied.command.clone();
+        self.done.push(applied);
+        Ok(command)
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.done.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.undone.is_empty()
+    }
+
+    /// Commands that can be undone, oldest first
+    pub fn undo_stack(&self) -> impl Iterator<Item = &SchedulableCommand> {
+        self.done.iter().map(|applied| &applied.command)
+    }
+
+    /// Commands that can be redone, the next one to redo last
+    pub fn redo_stack(&self) -> impl Iterator<Item = &SchedulableCommand> {
+        self.undone.iter().map(|applied| &applied.command)
+    }
+
+    pub fn clear(&mut self) {
+        self.done.clear();
+        self.undone.clear();
+    }
+}
@@ -0,0 +1,202 @@
+use crate::builders::comment_builder::CommentBuilder;
+use crate::id::Id;
+use crate::models::{
+    audit::Auditable,
+    person::Person,
+    schedulable::SchedulableItem,
+};
+use crate::{EntityType, HasId};
+use chrono::{DateTime, Utc};
+use core::fmt;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// A note someone left on a project or task, possibly in reply to another comment
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Comment {
+    id: Id<Comment>,
+    item: SchedulableItem,
+    author_id: Id<Person>,
+    parent_id: Option<Id<Comment>>,
+    body: String,
+    created_at: DateTime<Utc>,
+    edits: Vec<CommentEdit>,
+}
+
+/// An earlier version of a comment, kept when the comment gets edited
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub struct CommentEdit {
+    /// Body as it was before the edit
+    pub body: String,
+    pub edited_at: DateTime<Utc>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Serialize)]
+pub enum CommentError {
+    EmptyBody,
+    Unchanged,
+    EditedBeforeCreated {
+        created_at: DateTime<Utc>,
+        edited_at: DateTime<Utc>,
+    },
+    /// The comment answered was left on another item
+    ParentOnOtherItem {
+        parent_id: Id<Comment>,
+        item: SchedulableItem,
+    },
+}
+
+impl fmt::Display for CommentError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CommentError::EmptyBody => write!(f, "Comment cannot be empty"),
+            CommentError::Unchanged => write!(f, "Comment is unchanged"),
+            CommentError::EditedBeforeCreated { created_at, edited_at } => {
+                write!(f, "Comment cannot be edited at {edited_at} before it was written at {created_at}")
+            }
+            CommentError::ParentOnOtherItem { parent_id, item } => write!(f, "Comment {parent_id} is not on {item}"),
+        }
+    }
+}
+
+impl std::error::Error for CommentError {}
+
+impl Comment {
+    pub fn from_builder(builder: CommentBuilder) -> Self {
+        Comment {
+            id: builder.id(),
+            item: builder.item(),
+            author_id: builder.author_id(),
+            parent_id: builder.parent_id(),
+            body: builder.body(),
+            created_at: builder.created_at(),
+            edits: builder.edits(),
+        }
+    }
+
+    /// Answers this comment, on the same item
+    pub fn reply(&self, author_id: Id<Person>, body: &str, at: DateTime<Utc>) -> Comment {
+        CommentBuilder::new()
+            .with_item(self.item.clone())
+            .with_author_id(author_id)
+            .with_parent_id(Some(self.id()))
+            .with_body(body)
+            .with_created_at(at)
+            .build()
+    }
+
+    /// Replaces the body, keeping the previous one in the edit history
+    pub fn edit(&mut self, body: &str, at: DateTime<Utc>) -> Result<&Self, CommentError> {
+        if body.trim().is_empty() {
+            return Err(CommentError::EmptyBody);
+        }
+        if body == self.body {
+            return Err(CommentError::Unchanged);
+        }
+        if at < self.created_at {
+            return Err(CommentError::EditedBeforeCreated { created_at: self.created_at, edited_at: at });
+        }
+        let previous = std::mem::replace(&mut self.body, body.into());
+        self.edits.push(CommentEdit { body: previous, edited_at: at });
+        Ok(self)
+    }
+
+    pub fn item(&self) -> &SchedulableItem {
+        &self.item
+    }
+
+    pub fn author_id(&self) -> &Id<Person> {
+        &self.author_id
+    }
+
+    pub fn parent_id(&self) -> Option<&Id<Comment>> {
+        self.parent_id.as_ref()
+    }
+
+    pub fn is_reply(&self) -> bool {
+        self.parent_id.is_some()
+    }
+
+    pub fn body(&self) -> &str {
+        &self.body
+    }
+
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    /// When the comment was last edited, or written when it never was
+    pub fn updated_at(&self) -> DateTime<Utc> {
+        self.edits.last().map_or(self.created_at, |edit| edit.edited_at)
+    }
+
+    pub fn is_edited(&self) -> bool {
+        !self.edits.is_empty()
+    }
+
+    /// Earlier versions of the body, oldest first
+    pub fn edits(&self) -> &[CommentEdit] {
+        &self.edits
+    }
+
+    /// A comment needs a body, and edits cannot predate it
+    pub fn is_valid(&self) -> bool {
+        !self.body.trim().is_empty() && self.edits.iter().all(|edit| edit.edited_at >= self.created_at)
+    }
+}
+
+impl fmt::Debug for Comment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Comment [[{}]]", self.item)?;
+        writeln!(f, "- Comment Id:{:?}", self.id)?;
+        writeln!(f, "- Written by: {:?}", self.author_id)?;
+        if let Some(parent_id) = &self.parent_id {
+            writeln!(f, "- In reply to: {:?}", parent_id)?;
+        }
+        writeln!(f, "- Written on: {}", self.created_at)?;
+        writeln!(f, "- Comment has {} edits", self.edits.len())?;
+        writeln!(f, "- Body: {}", self.body)?;
+        Ok(())
+    }
+}
+
+impl fmt::Display for Comment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "[[{}]]", self.item)?;
+        writeln!(f, "- Comment Id: {}", self.id)?;
+        writeln!(f, "- Written by: {}", self.author_id)?;
+        if self.is_edited() {
+            writeln!(f, "- Edited on: {}", self.updated_at())?;
+        }
+        writeln!(f, "- {}", self.body)?;
+        Ok(())
+    }
+}
+
+impl EntityType for Comment {
+    fn prefix() -> &'static str {
+        "comment"
+    }
+}
+
+impl HasId for Comment {
+    type Entity = Comment;
+
+    fn id(&self) -> Id<Comment> {
+        self.id.clone()
+    }
+}
+
+impl Auditable for Comment {
+    fn audit_fields(&self) -> BTreeMap<&'static str, String> {
+        let mut fields = BTreeMap::from([
+            ("item", self.item.to_string()),
+            ("author_id", self.author_id.to_string()),
+            ("body", self.body.clone()),
+        ]);
+        if let Some(parent_id) = &self.parent_id {
+            fields.insert("parent_id", parent_id.to_string());
+        }
+        fields
+    }
+}
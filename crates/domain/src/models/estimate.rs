@@ -0,0 +1,102 @@
+use chrono::Duration;
+use core::fmt;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// Expected size of a project or task, either in hours of work or in story points
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Estimate {
+    Hours(u32),
+    Points(u32),
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Serialize)]
+pub enum EstimateError {
+    /// The estimate text could not be read, e.g. neither `8h` nor `5pt`
+    InvalidEstimate(String),
+}
+
+impl fmt::Display for EstimateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EstimateError::InvalidEstimate(estimate) => write!(f, "Invalid estimate: {estimate}"),
+        }
+    }
+}
+
+impl std::error::Error for EstimateError {}
+
+impl Estimate {
+    /// Expected working time; story points have no fixed duration
+    pub fn duration(&self) -> Option<Duration> {
+        match self {
+            Estimate::Hours(hours) => Some(Duration::hours((*hours).into())),
+            Estimate::Points(_) => None,
+        }
+    }
+}
+
+impl fmt::Display for Estimate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Estimate::Hours(hours) => write!(f, "{hours}h"),
+            Estimate::Points(points) => write!(f, "{points}pt"),
+        }
+    }
+}
+
+impl FromStr for Estimate {
+    type Err = EstimateError;
+
+    fn from_str(estimate: &str) -> Result<Self, Self::Err> {
+        let invalid = || EstimateError::InvalidEstimate(estimate.to_string());
+        let trimmed = estimate.trim();
+
+        if let Some(hours) = trimmed.strip_suffix('h') {
+            hours.trim().parse().map(Estimate::Hours).map_err(|_| invalid())
+        } else if let Some(points) = trimmed.strip_suffix("pt") {
+            points.trim().parse().map(Estimate::Points).map_err(|_| invalid())
+        } else {
+            Err(invalid())
+        }
+    }
+}
+
+/// Estimates added up per unit, since hours and story points do not convert into each other
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, Serialize)]
+pub struct EstimateTotal {
+    pub hours: u32,
+    pub points: u32,
+}
+
+impl EstimateTotal {
+    pub fn is_empty(&self) -> bool {
+        self.hours == 0 && self.points == 0
+    }
+
+    pub fn add(&mut self, estimate: Estimate) {
+        match estimate {
+            Estimate::Hours(hours) => self.hours += hours,
+            Estimate::Points(points) => self.points += points,
+        }
+    }
+
+    pub fn merge(&mut self, other: EstimateTotal) {
+        self.hours += other.hours;
+        self.points += other.points;
+    }
+
+    /// Expected working time of the hour estimates
+    pub fn duration(&self) -> Duration {
+        Duration::hours(self.hours.into())
+    }
+}
+
+impl From<Estimate> for EstimateTotal {
+    fn from(estimate: Estimate) -> Self {
+        let mut total = EstimateTotal::default();
+        total.add(estimate);
+        total
+    }
+}
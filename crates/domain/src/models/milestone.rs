@@ -1 +1,198 @@
+use crate::builders::milestone_builder::MilestoneBuilder;
+use crate::id::Id;
+use crate::models::audit::{Auditable, join};
+use crate::models::schedulable::SchedulableItem;
+use crate::{EntityType, HasId};
+use chrono::{DateTime, Utc};
+use core::fmt;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Milestone {
+    id: Id<Milestone>,
+    name: String,
+    description: Option<String>,
+    target_date: DateTime<Utc>,
+    items: Vec<SchedulableItem>,
+    reached_at: Option<DateTime<Utc>>,
+}
+
+/// Where a milestone stands against its target date
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub enum MilestoneState {
+    Pending,
+    Reached,
+    Missed,
+}
+
+impl fmt::Display for MilestoneState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MilestoneState::Pending => write!(f, "Pending"),
+            MilestoneState::Reached => write!(f, "Reached"),
+            MilestoneState::Missed => write!(f, "Missed"),
+        }
+    }
+}
+
+impl Milestone {
+    pub fn from_builder(builder: MilestoneBuilder) -> Self {
+        Milestone {
+            id: builder.id(),
+            name: builder.name(),
+            description: builder.description(),
+            target_date: builder.target_date(),
+            items: builder.items(),
+            reached_at: builder.reached_at(),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    pub fn rename(&mut self, name: &str) -> &Self {
+        self.name = name.into();
+        self
+    }
+
+    pub fn has_description(&self) -> bool {
+        self.description.is_some()
+    }
+
+    pub fn description(&self) -> &str {
+        if let Some(description) = &self.description {
+            description.as_str()
+        } else {
+            ""
+        }
+    }
+
+    pub fn set_description(&mut self, description: impl Into<String>) -> &Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn clear_description(&mut self) -> &Self {
+        self.description = None;
+        self
+    }
+
+    pub fn target_date(&self) -> DateTime<Utc> {
+        self.target_date
+    }
+
+    pub fn set_target_date(&mut self, target_date: DateTime<Utc>) -> &Self {
+        self.target_date = target_date;
+        self
+    }
+
+    pub fn items(&self) -> Vec<SchedulableItem> {
+        self.items.clone()
+    }
+
+    pub fn has_item(&self, item: &SchedulableItem) -> bool {
+        self.items.contains(item)
+    }
+
+    pub fn link(&mut self, item: SchedulableItem) -> &Self {
+        if !self.has_item(&item) {
+            self.items.push(item);
+        }
+        self
+    }
+
+    pub fn unlink(&mut self, item: &SchedulableItem) -> &Self {
+        self.items.retain(|linked| linked != item);
+        self
+    }
+
+    pub fn reached_at(&self) -> Option<DateTime<Utc>> {
+        self.reached_at
+    }
+
+    pub fn is_reached(&self) -> bool {
+        self.reached_at.is_some()
+    }
+
+    pub fn mark_reached(&mut self, reached_at: DateTime<Utc>) -> &Self {
+        self.reached_at = Some(reached_at);
+        self
+    }
+
+    pub fn reopen(&mut self) -> &Self {
+        self.reached_at = None;
+        self
+    }
+
+    pub fn state(&self) -> MilestoneState {
+        self.state_at(Utc::now())
+    }
+
+    /// Reached once marked so, missed when the target date passed before that
+    pub fn state_at(&self, now: DateTime<Utc>) -> MilestoneState {
+        match self.reached_at {
+            Some(reached_at) if reached_at <= self.target_date => MilestoneState::Reached,
+            Some(_) => MilestoneState::Missed,
+            None if now > self.target_date => MilestoneState::Missed,
+            None => MilestoneState::Pending,
+        }
+    }
+}
+
+impl fmt::Debug for Milestone {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Milestone [[{}]]", self.name)?;
+        writeln!(f, "- Milestone Id:{:?}", self.id)?;
+        writeln!(f, "- Milestone targets: {}", self.target_date)?;
+        if let Some(reached_at) = self.reached_at {
+            writeln!(f, "- Milestone reached on: {}", reached_at)?;
+        }
+        writeln!(f, "- Milestone links {} items", self.items.len())?;
+        Ok(())
+    }
+}
+
+impl fmt::Display for Milestone {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "[[{}]]", self.name)?;
+        writeln!(f, "- Milestone Id: {}", self.id)?;
+        writeln!(f, "- Milestone targets: {}", self.target_date)?;
+        writeln!(f, "- Milestone is {}", self.state())?;
+        Ok(())
+    }
+}
+
+impl EntityType for Milestone {
+    fn prefix() -> &'static str {
+        "milestone"
+    }
+}
+
+impl HasId for Milestone {
+    type Entity = Milestone;
+
+    fn id(&self) -> Id<Milestone> {
+        self.id.clone()
+    }
+}
+
+impl Auditable for Milestone {
+    fn audit_fields(&self) -> BTreeMap<&'static str, String> {
+        let mut fields = BTreeMap::from([
+            ("name", self.name.clone()),
+            ("target_date", self.target_date.to_rfc3339()),
+        ]);
+        if let Some(description) = &self.description {
+            fields.insert("description", description.clone());
+        }
+        if !self.items.is_empty() {
+            fields.insert("items", join(&self.items));
+        }
+        if let Some(reached_at) = self.reached_at {
+            fields.insert("reached_at", reached_at.to_rfc3339());
+        }
+        fields
+    }
+}
@@ -1,6 +1,13 @@
+pub mod estimate;
 pub mod milestone;
 pub mod person;
 pub mod project;
+pub mod recurrence;
 pub mod schedulable;
+pub mod status_transition;
 pub mod tag;
 pub mod task;
+pub mod time_entry;
+pub mod comment;
+pub mod audit;
+pub mod command;
@@ -1,9 +1,12 @@
 use crate::builders::person_builder::PersonBuilder;
 use crate::id::Id;
+use crate::models::audit::Auditable;
 use crate::{EntityType, HasId};
 use core::fmt;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
-#[derive(Clone, PartialEq, Eq)]
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Person {
     id: Id<Person>,
     first_name: String,
@@ -77,3 +80,12 @@ impl HasId for Person {
         self.id.clone()
     }
 }
+
+impl Auditable for Person {
+    fn audit_fields(&self) -> BTreeMap<&'static str, String> {
+        BTreeMap::from([
+            ("first_name", self.first_name.clone()),
+            ("last_name", self.last_name.clone()),
+        ])
+    }
+}
@@ -1,8 +1,11 @@
 use crate::builders::project_builder::ProjectBuilder;
 use crate::id::Id;
 use crate::models::{
+    audit::{Auditable, join},
+    estimate::Estimate,
     person::Person,
-    schedulable::{Schedulable, SchedulableItem, SchedulableItemStatus},
+    schedulable::{Priority, Schedulable, SchedulableItem, SchedulableItemStatus},
+    status_transition::{StatusAction, StatusTransition, TransitionError},
     tag::Tag,
     task::Task,
 };
@@ -12,6 +15,7 @@ use chrono::{DateTime, Datelike, Utc};
 use core::fmt;
 use log::{error, info};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 pub type Result<T> = core::result::Result<T, Error>;
 pub enum Error {
@@ -41,6 +45,8 @@ pub struct Project {
     children: Vec<SchedulableItem>,
     dependencies: Vec<Id<Project>>,
     status: SchedulableItemStatus,
+    priority: Priority,
+    estimate: Option<Estimate>,
 }
 
 impl Project {
@@ -56,6 +62,8 @@ impl Project {
             children: builder.children(),
             dependencies: builder.dependencies(),
             status: builder.status(),
+            priority: builder.priority(),
+            estimate: builder.estimate(),
         }
     }
 
@@ -116,6 +124,24 @@ impl Project {
         self
     }
 
+    pub fn has_estimate(&self) -> bool {
+        self.estimate.is_some()
+    }
+
+    pub fn estimate(&self) -> Option<Estimate> {
+        self.estimate
+    }
+
+    pub fn set_estimate(&mut self, estimate: Estimate) -> &Self {
+        self.estimate = Some(estimate);
+        self
+    }
+
+    pub fn clear_estimate(&mut self) -> &Self {
+        self.estimate = None;
+        self
+    }
+
     /* ### Validation Methods */
     pub fn is_valid_dependency(&self, dependency_project_id: &Id<Project>) -> bool {
         dependency_project_id != &HasId::id(self)
@@ -158,6 +184,10 @@ impl fmt::Debug for Project {
         } else {
             writeln!(f, "! No due date defined")?;
         }
+        if let Some(estimate) = &self.estimate {
+            writeln!(f, "- Project is estimated at: {estimate}")?;
+        }
+        writeln!(f, "- Project priority: {}", self.priority)?;
         writeln!(f, "- Project has {} children", self.children.len())?;
         {
             let child_projects: Vec<Id<Project>> = self.project_children();
@@ -205,6 +235,7 @@ impl fmt::Display for Project {
             let week = due_date.iso_week().week();
             writeln!(f, "- Project is due on: {day}-{month}-{year} [Week {week}]")?;
         }
+        writeln!(f, "- Project priority: {}", self.priority)?;
         writeln!(f, "- Project has {} children", self.children.len())?;
         {
             let child_projects: Vec<Id<Project>> = self.project_children();
@@ -282,6 +313,10 @@ impl Schedulable for Project {
         self.status.clone()
     }
 
+    fn priority(&self) -> Priority {
+        self.priority
+    }
+
     fn children(&self) -> Vec<Self::ChildType> {
         self.children.clone()
     }
@@ -412,6 +447,11 @@ impl Schedulable for Project {
         self
     }
 
+    fn set_priority(&mut self, priority: Priority) -> &Self {
+        self.priority = priority;
+        self
+    }
+
     fn add_child(&mut self, child: SchedulableItem) -> &Self {
         if self.is_valid_child(&child) {
             self.children.push(child);
@@ -494,6 +534,17 @@ impl Schedulable for Project {
         self
     }
 
+    fn transition(
+        &mut self,
+        action: StatusAction,
+        by: Option<Id<Person>>,
+    ) -> core::result::Result<StatusTransition, TransitionError> {
+        let from = self.status.clone();
+        let to = from.after(action)?;
+        self.status = to.clone();
+        Ok(StatusTransition::new(SchedulableItem::Project(self.id()), from, to, by))
+    }
+
     fn is_valid_tag(&self, tag_id: &Id<Tag>) -> bool {
         !self.tags().contains(tag_id)
     }
@@ -520,3 +571,38 @@ impl Schedulable for Project {
         }
     }
 }
+
+impl Auditable for Project {
+    fn audit_fields(&self) -> BTreeMap<&'static str, String> {
+        let mut fields = BTreeMap::from([
+            ("name", self.name.clone()),
+            ("status", self.status.to_string()),
+            ("priority", self.priority.to_string()),
+        ]);
+        if let Some(owner_id) = &self.owner_id {
+            fields.insert("owner_id", owner_id.to_string());
+        }
+        if let Some(description) = &self.description {
+            fields.insert("description", description.clone());
+        }
+        if !self.tags.is_empty() {
+            fields.insert("tags", join(&self.tags));
+        }
+        if let Some(start_date) = self.start_date {
+            fields.insert("start_date", start_date.to_rfc3339());
+        }
+        if let Some(due_date) = self.due_date {
+            fields.insert("due_date", due_date.to_rfc3339());
+        }
+        if !self.children.is_empty() {
+            fields.insert("children", join(&self.children));
+        }
+        if !self.dependencies.is_empty() {
+            fields.insert("dependencies", join(&self.dependencies));
+        }
+        if let Some(estimate) = self.estimate {
+            fields.insert("estimate", estimate.to_string());
+        }
+        fields
+    }
+}
@@ -0,0 +1,178 @@
+use chrono::{DateTime, Duration, Months, NaiveDateTime, Utc};
+use core::fmt;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// Unit a recurrence rule steps by
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub enum Frequency {
+    Daily,
+    Weekly,
+    Monthly,
+}
+
+/// When a series stops producing occurrences
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub enum RecurrenceEnd {
+    Never,
+    /// No occurrence is dated after this instant
+    Until(DateTime<Utc>),
+    /// Total number of occurrences in the series, the first one included
+    Count(u32),
+}
+
+/// RRULE-style repetition of a task, e.g. `FREQ=WEEKLY;INTERVAL=2;COUNT=6`.
+///
+/// Monthly steps keep the day of the month when the target month has it, and fall back
+/// to its last day otherwise.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub struct RecurrenceRule {
+    frequency: Frequency,
+    interval: u32,
+    end: RecurrenceEnd,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Serialize)]
+pub enum RecurrenceError {
+    /// The rule text could not be read, with the offending part
+    InvalidRule(String),
+}
+
+impl fmt::Display for RecurrenceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RecurrenceError::InvalidRule(part) => write!(f, "Invalid recurrence rule: {part}"),
+        }
+    }
+}
+
+impl std::error::Error for RecurrenceError {}
+
+impl RecurrenceRule {
+    pub fn new(frequency: Frequency) -> Self {
+        Self {
+            frequency,
+            interval: 1,
+            end: RecurrenceEnd::Never,
+        }
+    }
+
+    pub fn daily() -> Self {
+        Self::new(Frequency::Daily)
+    }
+
+    pub fn weekly() -> Self {
+        Self::new(Frequency::Weekly)
+    }
+
+    pub fn monthly() -> Self {
+        Self::new(Frequency::Monthly)
+    }
+
+    /// Repeats every `interval` days, weeks or months; an interval of zero is read as one
+    pub fn with_interval(mut self, interval: u32) -> Self {
+        self.interval = interval.max(1);
+        self
+    }
+
+    pub fn with_until(mut self, until: DateTime<Utc>) -> Self {
+        self.end = RecurrenceEnd::Until(until);
+        self
+    }
+
+    pub fn with_count(mut self, count: u32) -> Self {
+        self.end = RecurrenceEnd::Count(count);
+        self
+    }
+
+    pub fn frequency(&self) -> Frequency {
+        self.frequency
+    }
+
+    pub fn interval(&self) -> u32 {
+        self.interval
+    }
+
+    pub fn end(&self) -> RecurrenceEnd {
+        self.end
+    }
+
+    /// Date of the `occurrence`-th item (starting at 1) of a series whose first item is dated `anchor`.
+    /// Every date is counted from the anchor, so a clamped month does not carry over to the next ones.
+    pub fn occurrence_date(&self, anchor: DateTime<Utc>, occurrence: u32) -> Option<DateTime<Utc>> {
+        let steps = occurrence.checked_sub(1)?.checked_mul(self.interval)?;
+        match self.frequency {
+            Frequency::Daily => anchor.checked_add_signed(Duration::days(i64::from(steps))),
+            Frequency::Weekly => anchor.checked_add_signed(Duration::weeks(i64::from(steps))),
+            Frequency::Monthly => anchor.checked_add_months(Months::new(steps)),
+        }
+    }
+
+    /// Whether the `occurrence`-th item of the series (starting at 1), dated `date`, is still part of it
+    pub fn allows(&self, occurrence: u32, date: DateTime<Utc>) -> bool {
+        match self.end {
+            RecurrenceEnd::Never => true,
+            RecurrenceEnd::Until(until) => date <= until,
+            RecurrenceEnd::Count(count) => occurrence <= count,
+        }
+    }
+}
+
+impl fmt::Display for RecurrenceRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let frequency = match self.frequency {
+            Frequency::Daily => "DAILY",
+            Frequency::Weekly => "WEEKLY",
+            Frequency::Monthly => "MONTHLY",
+        };
+        write!(f, "FREQ={frequency};INTERVAL={}", self.interval)?;
+        match self.end {
+            RecurrenceEnd::Never => Ok(()),
+            RecurrenceEnd::Until(until) => write!(f, ";UNTIL={}", until.format("%Y%m%dT%H%M%SZ")),
+            RecurrenceEnd::Count(count) => write!(f, ";COUNT={count}"),
+        }
+    }
+}
+
+impl FromStr for RecurrenceRule {
+    type Err = RecurrenceError;
+
+    fn from_str(rule: &str) -> Result<Self, Self::Err> {
+        let mut frequency = None;
+        let mut interval = 1;
+        let mut end = RecurrenceEnd::Never;
+
+        for part in rule.split(';').map(str::trim).filter(|part| !part.is_empty()) {
+            let invalid = || RecurrenceError::InvalidRule(part.to_string());
+            let (key, value) = part.split_once('=').ok_or_else(invalid)?;
+
+            match key.to_ascii_uppercase().as_str() {
+                "FREQ" => {
+                    frequency = Some(match value.to_ascii_uppercase().as_str() {
+                        "DAILY" => Frequency::Daily,
+                        "WEEKLY" => Frequency::Weekly,
+                        "MONTHLY" => Frequency::Monthly,
+                        _ => return Err(invalid()),
+                    })
+                }
+                "INTERVAL" => interval = value.parse::<u32>().ok().filter(|n| *n > 0).ok_or_else(invalid)?,
+                "COUNT" => end = RecurrenceEnd::Count(value.parse::<u32>().map_err(|_| invalid())?),
+                "UNTIL" => {
+                    let until = NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%SZ")
+                        .map(|date| date.and_utc())
+                        .or_else(|_| value.parse::<DateTime<Utc>>())
+                        .map_err(|_| invalid())?;
+                    end = RecurrenceEnd::Until(until);
+                }
+                _ => return Err(invalid()),
+            }
+        }
+
+        let frequency = frequency.ok_or_else(|| RecurrenceError::InvalidRule("missing FREQ".into()))?;
+        Ok(Self {
+            frequency,
+            interval,
+            end,
+        })
+    }
+}
@@ -1,6 +1,12 @@
 use crate::{
     id::Id,
-    models::{person::Person, project::Project, tag::Tag, task::Task},
+    models::{
+        person::Person,
+        project::Project,
+        status_transition::{StatusAction, StatusTransition, TransitionError},
+        tag::Tag,
+        task::Task,
+    },
 };
 use chrono::{DateTime, Utc};
 use core::fmt;
@@ -18,6 +24,7 @@ pub trait Schedulable {
     fn start_date(&self) -> Option<DateTime<Utc>>;
     fn due_date(&self) -> Option<DateTime<Utc>>;
     fn status(&self) -> SchedulableItemStatus;
+    fn priority(&self) -> Priority;
     fn children(&self) -> Vec<Self::ChildType>;
     fn dependencies(&self) -> Vec<Self::DependencyType>;
 
@@ -49,6 +56,7 @@ pub trait Schedulable {
     fn remove_start_date(&mut self) -> &Self;
     fn set_due_date(&mut self, due_date: DateTime<Utc>) -> &Self;
     fn remove_due_date(&mut self) -> &Self;
+    fn set_priority(&mut self, priority: Priority) -> &Self;
 
     fn add_child(&mut self, child: SchedulableItem) -> &Self;
     fn add_children(&mut self, children: Vec<SchedulableItem>) -> &Self;
@@ -62,6 +70,12 @@ pub trait Schedulable {
     fn demote(&mut self) -> &Self;
     fn archive(&mut self) -> &Self;
     fn cancel(&mut self) -> &Self;
+    /// Applies a workflow move, returning the history entry or why the move is not allowed
+    fn transition(
+        &mut self,
+        action: StatusAction,
+        by: Option<Id<Person>>,
+    ) -> Result<StatusTransition, TransitionError>;
 
     // Validation
     fn is_valid_tag(&self, tag_id: &Id<Tag>) -> bool;
@@ -70,12 +84,21 @@ pub trait Schedulable {
     fn is_valid_child(&self, child_to_validate: &SchedulableItem) -> bool;
 }
 
-#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Serialize, Deserialize)]
 pub enum SchedulableItem {
     Project(Id<Project>),
     Task(Id<Task>),
 }
 
+impl fmt::Display for SchedulableItem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SchedulableItem::Project(id) => write!(f, "{id}"),
+            SchedulableItem::Task(id) => write!(f, "{id}"),
+        }
+    }
+}
+
 #[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
 pub enum SchedulableItemStatus {
     NotStarted,
@@ -100,3 +123,36 @@ impl fmt::Display for SchedulableItemStatus {
         }
     }
 }
+
+impl SchedulableItemStatus {
+    /// `Completed` or `Archived`
+    pub fn is_finished(&self) -> bool {
+        matches!(self, SchedulableItemStatus::Completed | SchedulableItemStatus::Archived)
+    }
+
+    /// Neither finished nor `Canceled`, so the item still needs work
+    pub fn is_open(&self) -> bool {
+        !self.is_finished() && *self != SchedulableItemStatus::Canceled
+    }
+}
+
+/// How urgently an item should be picked up, ordered from `Low` to `Critical`
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default, Serialize, Deserialize)]
+pub enum Priority {
+    Low,
+    #[default]
+    Medium,
+    High,
+    Critical,
+}
+
+impl fmt::Display for Priority {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Priority::Low => write!(f, "Low"),
+            Priority::Medium => write!(f, "Medium"),
+            Priority::High => write!(f, "High"),
+            Priority::Critical => write!(f, "Critical"),
+        }
+    }
+}
@@ -0,0 +1,132 @@
+use crate::id::Id;
+use crate::models::{
+    person::Person,
+    schedulable::{SchedulableItem, SchedulableItemStatus},
+};
+use chrono::{DateTime, Utc};
+use core::fmt;
+use serde::{Deserialize, Serialize};
+
+/// Workflow moves a schedulable item can make
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StatusAction {
+    Promote,
+    /// Jumps a `NotStarted` or `Planned` item straight to `InProgress`
+    Start,
+    Demote,
+    Archive,
+    Cancel,
+}
+
+impl fmt::Display for StatusAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StatusAction::Promote => write!(f, "promote"),
+            StatusAction::Start => write!(f, "start"),
+            StatusAction::Demote => write!(f, "demote"),
+            StatusAction::Archive => write!(f, "archive"),
+            StatusAction::Cancel => write!(f, "cancel"),
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Serialize)]
+pub enum TransitionError {
+    IllegalTransition {
+        from: SchedulableItemStatus,
+        action: StatusAction,
+    },
+}
+
+impl fmt::Display for TransitionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TransitionError::IllegalTransition { from, action } => {
+                write!(f, "Cannot {action} an item that is {from}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for TransitionError {}
+
+impl SchedulableItemStatus {
+    /// Status reached by applying `action`, or an error when the workflow does not allow it
+    pub fn after(&self, action: StatusAction) -> Result<SchedulableItemStatus, TransitionError> {
+        use SchedulableItemStatus::*;
+
+        let next = match (action, self) {
+            (StatusAction::Promote, NotStarted) => Some(Planned),
+            (StatusAction::Promote, Planned) => Some(InProgress),
+            (StatusAction::Promote, InProgress) => Some(InReview),
+            (StatusAction::Promote, InReview) => Some(Completed),
+            (StatusAction::Start, NotStarted | Planned) => Some(InProgress),
+            (StatusAction::Demote, InReview) => Some(InProgress),
+            (StatusAction::Demote, InProgress) => Some(Planned),
+            (StatusAction::Demote, Planned) => Some(NotStarted),
+            (StatusAction::Archive, Archived) => None,
+            (StatusAction::Archive, _) => Some(Archived),
+            (StatusAction::Cancel, Archived | Completed | Canceled) => None,
+            (StatusAction::Cancel, _) => Some(Canceled),
+            _ => None,
+        };
+
+        next.ok_or(TransitionError::IllegalTransition {
+            from: self.clone(),
+            action,
+        })
+    }
+}
+
+/// One entry of an item's status history
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub struct StatusTransition {
+    item: SchedulableItem,
+    from: SchedulableItemStatus,
+    to: SchedulableItemStatus,
+    by: Option<Id<Person>>,
+    at: DateTime<Utc>,
+}
+
+impl StatusTransition {
+    pub fn new(
+        item: SchedulableItem,
+        from: SchedulableItemStatus,
+        to: SchedulableItemStatus,
+        by: Option<Id<Person>>,
+    ) -> Self {
+        Self::recorded_at(item, from, to, by, Utc::now())
+    }
+
+    /// Rebuilds a transition that was recorded earlier
+    pub fn recorded_at(
+        item: SchedulableItem,
+        from: SchedulableItemStatus,
+        to: SchedulableItemStatus,
+        by: Option<Id<Person>>,
+        at: DateTime<Utc>,
+    ) -> Self {
+        Self { item, from, to, by, at }
+    }
+
+    pub fn item(&self) -> &SchedulableItem {
+        &self.item
+    }
+
+    pub fn from(&self) -> SchedulableItemStatus {
+        self.from.clone()
+    }
+
+    pub fn to(&self) -> SchedulableItemStatus {
+        self.to.clone()
+    }
+
+    pub fn by(&self) -> Option<&Id<Person>> {
+        self.by.as_ref()
+    }
+
+    pub fn at(&self) -> DateTime<Utc> {
+        self.at
+    }
+}
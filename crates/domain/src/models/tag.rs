@@ -1,10 +1,13 @@
 use crate::builders::tag_builder::TagBuilder;
 use crate::id::Id;
+use crate::models::audit::{Auditable, join};
 use crate::{EntityType, HasId};
 use core::fmt;
 use log::error;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
-#[derive(Clone, PartialEq, Eq)]
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Tag {
     id: Id<Tag>,
     name: String,
@@ -149,3 +152,15 @@ impl HasId for Tag {
     }
 }
 
+impl Auditable for Tag {
+    fn audit_fields(&self) -> BTreeMap<&'static str, String> {
+        let mut fields = BTreeMap::from([("name", self.name.clone())]);
+        if let Some(description) = &self.description {
+            fields.insert("description", description.clone());
+        }
+        if !self.parents.is_empty() {
+            fields.insert("parents", join(&self.parents));
+        }
+        fields
+    }
+}
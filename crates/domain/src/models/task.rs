@@ -1,7 +1,11 @@
 use crate::builders::task_builder::TaskBuilder;
 use crate::id::Id;
+use crate::models::audit::{Auditable, join};
+use crate::models::estimate::Estimate;
 use crate::models::person::Person;
-use crate::models::schedulable::{Schedulable, SchedulableItem, SchedulableItemStatus};
+use crate::models::recurrence::RecurrenceRule;
+use crate::models::schedulable::{Priority, Schedulable, SchedulableItem, SchedulableItemStatus};
+use crate::models::status_transition::{StatusAction, StatusTransition, TransitionError};
 use crate::models::tag::Tag;
 use crate::{EntityType, HasId};
 
@@ -9,6 +13,7 @@ use chrono::{DateTime, Datelike, Utc};
 use core::fmt;
 use log::{error, info};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 #[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Task {
@@ -22,6 +27,13 @@ pub struct Task {
     children: Vec<Id<Task>>,
     dependencies: Vec<Id<Task>>,
     status: SchedulableItemStatus,
+    priority: Priority,
+    recurrence: Option<RecurrenceRule>,
+    series_id: Option<Id<Task>>,
+    occurrence: u32,
+    series_start_date: Option<DateTime<Utc>>,
+    series_due_date: Option<DateTime<Utc>>,
+    estimate: Option<Estimate>,
 }
 
 impl Task {
@@ -37,6 +49,13 @@ impl Task {
             children: builder.children(),
             dependencies: builder.dependencies(),
             status: builder.status(),
+            priority: builder.priority(),
+            recurrence: builder.recurrence(),
+            series_id: builder.series_id(),
+            occurrence: builder.occurrence(),
+            series_start_date: builder.series_start_date(),
+            series_due_date: builder.series_due_date(),
+            estimate: builder.estimate(),
         }
     }
 
@@ -77,6 +96,122 @@ impl Task {
         self
     }
 
+    pub fn is_recurring(&self) -> bool {
+        self.recurrence.is_some()
+    }
+
+    pub fn recurrence(&self) -> Option<&RecurrenceRule> {
+        self.recurrence.as_ref()
+    }
+
+    pub fn set_recurrence(&mut self, recurrence: RecurrenceRule) -> &Self {
+        self.recurrence = Some(recurrence);
+        self
+    }
+
+    pub fn clear_recurrence(&mut self) -> &Self {
+        self.recurrence = None;
+        self
+    }
+
+    /// Id of the first task of the series this task belongs to, which is its own id when it starts one
+    pub fn series_id(&self) -> Id<Task> {
+        self.series_id.clone().unwrap_or_else(|| HasId::id(self))
+    }
+
+    /// Position of the task in its series, starting at 1
+    pub fn occurrence(&self) -> u32 {
+        self.occurrence
+    }
+
+    /// Start date of the first task of the series, kept on later occurrences so their dates
+    /// are counted from it
+    pub fn series_start_date(&self) -> Option<DateTime<Utc>> {
+        self.series_start_date
+    }
+
+    /// Due date of the first task of the series, kept on later occurrences like `series_start_date`
+    pub fn series_due_date(&self) -> Option<DateTime<Utc>> {
+        self.series_due_date
+    }
+
+    // Dates the series is counted from and the occurrence they belong to. A task starting its
+    // series is its own anchor, as are later ones stored before the series dates were kept.
+    fn series_anchor(&self) -> (Option<DateTime<Utc>>, Option<DateTime<Utc>>, u32) {
+        if self.series_start_date.is_some() || self.series_due_date.is_some() {
+            (self.series_start_date, self.series_due_date, 1)
+        } else {
+            (self.start_date, self.due_date, self.occurrence)
+        }
+    }
+
+    pub fn has_estimate(&self) -> bool {
+        self.estimate.is_some()
+    }
+
+    pub fn estimate(&self) -> Option<Estimate> {
+        self.estimate
+    }
+
+    pub fn set_estimate(&mut self, estimate: Estimate) -> &Self {
+        self.estimate = Some(estimate);
+        self
+    }
+
+    pub fn clear_estimate(&mut self) -> &Self {
+        self.estimate = None;
+        self
+    }
+
+    /// Next task of the series: same details, dates counted from the series' first task by the
+    /// recurrence rule, and back to `NotStarted`. There is none when the task does not recur,
+    /// has no date to shift, or the rule has run out.
+    pub fn next_occurrence(&self) -> Option<Task> {
+        let rule = self.recurrence?;
+        let (anchor_start, anchor_due, anchor_occurrence) = self.series_anchor();
+        let occurrence = self.occurrence + 1;
+        let shift = |date: Option<DateTime<Utc>>| match date {
+            Some(date) => rule.occurrence_date(date, occurrence - anchor_occurrence + 1).map(Some),
+            None => Some(None),
+        };
+        let start_date = shift(anchor_start)?;
+        let due_date = shift(anchor_due)?;
+        // Only dates of the first occurrence can anchor the tasks after this one
+        let anchored = anchor_occurrence == 1;
+
+        if !rule.allows(occurrence, start_date.or(due_date)?) {
+            return None;
+        }
+
+        let mut builder = TaskBuilder::new()
+            .with_name(&self.name)
+            .with_owner_id(self.owner_id.clone())
+            .with_tags(self.tags.clone())
+            .with_start_date(start_date)
+            .with_due_date(due_date)
+            .with_recurrence(Some(rule))
+            .with_series_id(Some(self.series_id()))
+            .with_occurrence(occurrence)
+            .with_series_start_date(anchor_start.filter(|_| anchored))
+            .with_series_due_date(anchor_due.filter(|_| anchored))
+            .with_priority(self.priority)
+            .with_estimate(self.estimate);
+        if let Some(description) = &self.description {
+            builder = builder.with_description(description);
+        }
+        Some(builder.build())
+    }
+
+    /// First occurrence after this one that is dated later than `after`, skipping the ones
+    /// whose date already went by
+    pub fn next_occurrence_after(&self, after: DateTime<Utc>) -> Option<Task> {
+        let mut next = self.next_occurrence()?;
+        while next.start_date.or(next.due_date)? <= after {
+            next = next.next_occurrence()?;
+        }
+        Some(next)
+    }
+
     /* ### Validation Methods */
     pub fn is_valid_dependency(&self, dependency_project_id: &Id<Task>) -> bool {
         dependency_project_id != &HasId::id(self)
@@ -107,6 +242,13 @@ impl fmt::Display for Task {
             let week = due_date.iso_week().week();
             writeln!(f, "- Task is due on: {day}-{month}-{year} [Week {week}]")?;
         }
+        if let Some(recurrence) = &self.recurrence {
+            writeln!(f, "- Task repeats: {recurrence} [Occurrence {}]", self.occurrence)?;
+        }
+        if let Some(estimate) = &self.estimate {
+            writeln!(f, "- Task is estimated at: {estimate}")?;
+        }
+        writeln!(f, "- Task priority: {}", self.priority)?;
         writeln!(f, "- Task has {} children", self.children.len())?;
         writeln!(f, "- Task has {} dependencies", self.dependencies.len())?;
         Ok(())
@@ -137,6 +279,10 @@ impl fmt::Debug for Task {
             let week = due_date.iso_week().week();
             writeln!(f, "- Task is due on: {day}-{month}-{year} [Week {week}]")?;
         }
+        if let Some(recurrence) = &self.recurrence {
+            writeln!(f, "- Task repeats: {recurrence} [Occurrence {}]", self.occurrence)?;
+        }
+        writeln!(f, "- Task priority: {}", self.priority)?;
         writeln!(f, "- Task has {} children", self.children.len())?;
         writeln!(f, "- Task has {} dependencies", self.dependencies.len())?;
         Ok(())
@@ -195,6 +341,10 @@ impl Schedulable for Task {
         self.status.clone()
     }
 
+    fn priority(&self) -> Priority {
+        self.priority
+    }
+
     fn children(&self) -> Vec<Self::ChildType> {
         self.children.clone()
     }
@@ -332,11 +482,16 @@ impl Schedulable for Task {
         self
     }
 
+    fn set_priority(&mut self, priority: Priority) -> &Self {
+        self.priority = priority;
+        self
+    }
+
     fn add_child(&mut self, child: SchedulableItem) -> &Self {
-        if self.is_valid_child(&child) {
-            if let SchedulableItem::Task(task_id) = child {
-                self.children.push(task_id);
-            }
+        if self.is_valid_child(&child)
+            && let SchedulableItem::Task(task_id) = child
+        {
+            self.children.push(task_id);
         }
         self
     }
@@ -418,6 +573,17 @@ impl Schedulable for Task {
         self
     }
 
+    fn transition(
+        &mut self,
+        action: StatusAction,
+        by: Option<Id<Person>>,
+    ) -> Result<StatusTransition, TransitionError> {
+        let from = self.status.clone();
+        let to = from.after(action)?;
+        self.status = to.clone();
+        Ok(StatusTransition::new(SchedulableItem::Task(self.id()), from, to, by))
+    }
+
     // endregion: Core Mutators
 
     fn is_valid_tag(&self, tag_id: &Id<Tag>) -> bool {
@@ -447,3 +613,41 @@ impl Schedulable for Task {
         }
     }
 }
+
+impl Auditable for Task {
+    fn audit_fields(&self) -> BTreeMap<&'static str, String> {
+        let mut fields = BTreeMap::from([
+            ("name", self.name.clone()),
+            ("status", self.status.to_string()),
+            ("priority", self.priority.to_string()),
+        ]);
+        if let Some(owner_id) = &self.owner_id {
+            fields.insert("owner_id", owner_id.to_string());
+        }
+        if let Some(description) = &self.description {
+            fields.insert("description", description.clone());
+        }
+        if !self.tags.is_empty() {
+            fields.insert("tags", join(&self.tags));
+        }
+        if let Some(start_date) = self.start_date {
+            fields.insert("start_date", start_date.to_rfc3339());
+        }
+        if let Some(due_date) = self.due_date {
+            fields.insert("due_date", due_date.to_rfc3339());
+        }
+        if !self.children.is_empty() {
+            fields.insert("children", join(&self.children));
+        }
+        if !self.dependencies.is_empty() {
+            fields.insert("dependencies", join(&self.dependencies));
+        }
+        if let Some(estimate) = self.estimate {
+            fields.insert("estimate", estimate.to_string());
+        }
+        if let Some(recurrence) = &self.recurrence {
+            fields.insert("recurrence", recurrence.to_string());
+        }
+        fields
+    }
+}
@@ -0,0 +1,203 @@
+use crate::builders::time_entry_builder::TimeEntryBuilder;
+use crate::id::Id;
+use crate::models::{
+    audit::Auditable,
+    person::Person,
+    schedulable::SchedulableItem,
+};
+use crate::{EntityType, HasId};
+use chrono::{DateTime, Duration, Utc};
+use core::fmt;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// A work session someone logged against a project or task.
+/// The session is a running timer until it gets an end.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TimeEntry {
+    id: Id<TimeEntry>,
+    person_id: Id<Person>,
+    item: SchedulableItem,
+    start: DateTime<Utc>,
+    end: Option<DateTime<Utc>>,
+    note: Option<String>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Serialize)]
+pub enum TimeEntryError {
+    /// The person already has a timer running, kept as this entry
+    AlreadyRunning(Id<TimeEntry>),
+    AlreadyStopped,
+    EndsBeforeStart {
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    },
+}
+
+impl fmt::Display for TimeEntryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TimeEntryError::AlreadyRunning(id) => write!(f, "A timer is already running as {id}"),
+            TimeEntryError::AlreadyStopped => write!(f, "Timer was already stopped"),
+            TimeEntryError::EndsBeforeStart { start, end } => {
+                write!(f, "Time entry cannot end at {end} before it starts at {start}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for TimeEntryError {}
+
+impl TimeEntry {
+    pub fn from_builder(builder: TimeEntryBuilder) -> Self {
+        TimeEntry {
+            id: builder.id(),
+            person_id: builder.person_id(),
+            item: builder.item(),
+            start: builder.start(),
+            end: builder.end(),
+            note: builder.note(),
+        }
+    }
+
+    /// Starts a timer for `person_id` on `item`
+    pub fn start_timer(person_id: Id<Person>, item: SchedulableItem, at: DateTime<Utc>) -> Self {
+        TimeEntryBuilder::new()
+            .with_person_id(person_id)
+            .with_item(item)
+            .with_start(at)
+            .build()
+    }
+
+    /// Stops a running timer
+    pub fn stop_timer(&mut self, at: DateTime<Utc>) -> Result<&Self, TimeEntryError> {
+        if self.end.is_some() {
+            return Err(TimeEntryError::AlreadyStopped);
+        }
+        if at < self.start {
+            return Err(TimeEntryError::EndsBeforeStart { start: self.start, end: at });
+        }
+        self.end = Some(at);
+        Ok(self)
+    }
+
+    pub fn person_id(&self) -> &Id<Person> {
+        &self.person_id
+    }
+
+    pub fn item(&self) -> &SchedulableItem {
+        &self.item
+    }
+
+    pub fn start(&self) -> DateTime<Utc> {
+        self.start
+    }
+
+    pub fn end(&self) -> Option<DateTime<Utc>> {
+        self.end
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.end.is_none()
+    }
+
+    pub fn has_note(&self) -> bool {
+        self.note.is_some()
+    }
+
+    pub fn note(&self) -> &str {
+        if let Some(note) = &self.note {
+            note.as_str()
+        } else {
+            ""
+        }
+    }
+
+    pub fn set_note(&mut self, note: impl Into<String>) -> &Self {
+        self.note = Some(note.into());
+        self
+    }
+
+    pub fn clear_note(&mut self) -> &Self {
+        self.note = None;
+        self
+    }
+
+    /// Logged time, or `None` while the timer is still running
+    pub fn duration(&self) -> Option<Duration> {
+        self.end.map(|end| end - self.start)
+    }
+
+    /// Logged time, counting a running timer up to `now`
+    pub fn duration_at(&self, now: DateTime<Utc>) -> Duration {
+        let end = self.end.unwrap_or(now);
+        (end - self.start).max(Duration::zero())
+    }
+
+    /// An entry may not end before it starts
+    pub fn is_valid(&self) -> bool {
+        self.end.is_none_or(|end| end >= self.start)
+    }
+}
+
+impl fmt::Debug for TimeEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Time Entry [[{}]]", self.item)?;
+        writeln!(f, "- Time Entry Id:{:?}", self.id)?;
+        writeln!(f, "- Logged by: {:?}", self.person_id)?;
+        writeln!(f, "- Started on: {}", self.start)?;
+        if let Some(end) = self.end {
+            writeln!(f, "- Ended on: {}", end)?;
+        } else {
+            writeln!(f, "! Timer is running")?;
+        }
+        if let Some(note) = &self.note {
+            writeln!(f, "- Note: {}", note)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for TimeEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "[[{}]]", self.item)?;
+        writeln!(f, "- Time Entry Id: {}", self.id)?;
+        writeln!(f, "- Logged by: {}", self.person_id)?;
+        match self.duration() {
+            Some(duration) => writeln!(f, "- Logged {} minutes", duration.num_minutes())?,
+            None => writeln!(f, "- Running since {}", self.start)?,
+        }
+        Ok(())
+    }
+}
+
+impl EntityType for TimeEntry {
+    fn prefix() -> &'static str {
+        "time_entry"
+    }
+}
+
+impl HasId for TimeEntry {
+    type Entity = TimeEntry;
+
+    fn id(&self) -> Id<TimeEntry> {
+        self.id.clone()
+    }
+}
+
+impl Auditable for TimeEntry {
+    fn audit_fields(&self) -> BTreeMap<&'static str, String> {
+        let mut fields = BTreeMap::from([
+            ("person_id", self.person_id.to_string()),
+            ("item", self.item.to_string()),
+            ("start", self.start.to_rfc3339()),
+        ]);
+        if let Some(end) = self.end {
+            fields.insert("end", end.to_rfc3339());
+        }
+        if let Some(note) = &self.note {
+            fields.insert("note", note.clone());
+        }
+        fields
+    }
+}
@@ -1 +1,2 @@
-mod models;
\ No newline at end of file
+mod models;
+mod graph;
\ No newline at end of file
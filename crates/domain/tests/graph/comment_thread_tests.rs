@@ -0,0 +1,51 @@
+use chrono::{Duration, Utc};
+use project_tracker_core::HasId;
+use project_tracker_core::builders::comment_builder::CommentBuilder;
+use project_tracker_core::graph::comment_thread::CommentThread;
+use project_tracker_core::id::Id;
+use project_tracker_core::models::{comment::Comment, person::Person};
+
+#[test]
+fn replies_nest_under_their_parent_oldest_first() {
+    let start = Utc::now() - Duration::hours(1);
+    let author = Id::<Person>::new();
+    let first = CommentBuilder::new().with_body("First").with_created_at(start).build();
+    let second = CommentBuilder::new()
+        .with_body("Second")
+        .with_item(first.item().clone())
+        .with_created_at(start + Duration::minutes(1))
+        .build();
+    let late_reply = first.reply(author.clone(), "Late reply", start + Duration::minutes(30));
+    let early_reply = first.reply(author.clone(), "Early reply", start + Duration::minutes(5));
+    let nested = early_reply.reply(author, "Nested", start + Duration::minutes(10));
+
+    let threads = CommentThread::build(vec![
+        nested.clone(),
+        late_reply.clone(),
+        second.clone(),
+        early_reply.clone(),
+        first.clone(),
+    ]);
+
+    assert_eq!(threads.len(), 2);
+    assert_eq!(threads[0].comment, first);
+    assert_eq!(threads[0].comment_count(), 4);
+    assert_eq!(threads[0].replies[0].comment, early_reply);
+    assert_eq!(threads[0].replies[0].replies[0].comment, nested);
+    assert_eq!(threads[0].replies[1].comment, late_reply);
+    assert_eq!(threads[0].ids(), vec![first.id(), early_reply.id(), nested.id(), late_reply.id()]);
+    assert_eq!(threads[1].comment, second);
+    assert!(threads[1].replies.is_empty());
+}
+
+#[test]
+fn orphaned_replies_start_their_own_thread() {
+    let parent = CommentBuilder::new().with_body("Deleted").build();
+    let reply = parent.reply(Id::<Person>::new(), "Still here", Utc::now());
+
+    let threads = CommentThread::build(vec![reply.clone()]);
+
+    assert_eq!(threads.len(), 1);
+    assert_eq!(threads[0].comment, reply);
+    assert!(CommentThread::build(Vec::<Comment>::new()).is_empty());
+}
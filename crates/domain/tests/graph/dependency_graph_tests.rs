@@ -0,0 +1,130 @@
+use project_tracker_core::HasId;
+use project_tracker_core::builders::{project_builder::ProjectBuilder, task_builder::TaskBuilder};
+use project_tracker_core::graph::dependency_graph::{DependencyGraph, DependencyGraphError};
+use project_tracker_core::id::Id;
+use project_tracker_core::models::{schedulable::SchedulableItemStatus, task::Task};
+
+fn task(name: &str, dependencies: Vec<Id<Task>>) -> Task {
+    TaskBuilder::new().with_name(name).with_dependencies(dependencies).build()
+}
+
+/// design <- build <- ship, with docs depending on design only
+fn sample_tasks() -> (Task, Task, Task, Task) {
+    let design = task("design", vec![]);
+    let build = task("build", vec![design.id()]);
+    let ship = task("ship", vec![build.id()]);
+    let docs = task("docs", vec![design.id()]);
+    (design, build, ship, docs)
+}
+
+#[test]
+fn topological_order_puts_dependencies_first() {
+    let (design, build, ship, docs) = sample_tasks();
+    let graph = DependencyGraph::from_items(vec![ship.clone(), docs.clone(), build.clone(), design.clone()]);
+
+    let order = graph.topological_order().unwrap();
+    let position = |id: &Id<Task>| order.iter().position(|item| item == id).unwrap();
+    assert_eq!(order.len(), 4);
+    assert!(position(&design.id()) < position(&build.id()));
+    assert!(position(&build.id()) < position(&ship.id()));
+    assert!(position(&design.id()) < position(&docs.id()));
+}
+
+#[test]
+fn cycles_are_reported_with_their_path() {
+    let first = task("first", vec![]);
+    let second = task("second", vec![first.id()]);
+    let third = task("third", vec![second.id()]);
+    let mut first = first;
+    first.add_dependency(third.id());
+    let graph = DependencyGraph::from_items(vec![first.clone(), second.clone(), third.clone()]);
+
+    let expected = vec![
+        first.id().to_string(),
+        third.id().to_string(),
+        second.id().to_string(),
+        first.id().to_string(),
+    ];
+    assert_eq!(graph.find_cycle(), Some(expected.clone()));
+    assert_eq!(graph.topological_order(), Err(DependencyGraphError::Cycle(expected)));
+}
+
+#[test]
+fn acyclic_graph_has_no_cycle() {
+    let (design, build, ship, docs) = sample_tasks();
+    let graph = DependencyGraph::from_items(vec![design, build, ship, docs]);
+    assert_eq!(graph.find_cycle(), None);
+}
+
+#[test]
+fn check_dependency_rejects_closing_a_loop() {
+    let (design, build, ship, _) = sample_tasks();
+    let graph = DependencyGraph::from_items(vec![design.clone(), build.clone(), ship.clone()]);
+
+    assert_eq!(
+        graph.check_dependency(&design.id(), &ship.id()),
+        Err(DependencyGraphError::Cycle(vec![
+            design.id().to_string(),
+            ship.id().to_string(),
+            build.id().to_string(),
+            design.id().to_string(),
+        ]))
+    );
+    assert_eq!(
+        graph.check_dependency(&design.id(), &design.id()),
+        Err(DependencyGraphError::SelfDependency(design.id().to_string()))
+    );
+    assert!(graph.check_dependency(&ship.id(), &design.id()).is_ok());
+}
+
+#[test]
+fn blocked_items_wait_on_unfinished_dependencies() {
+    let design = TaskBuilder::new().with_name("design").with_status(SchedulableItemStatus::Completed).build();
+    let build = task("build", vec![design.id()]);
+    let ship = task("ship", vec![build.id()]);
+    let canceled = TaskBuilder::new()
+        .with_name("canceled")
+        .with_dependencies(vec![build.id()])
+        .with_status(SchedulableItemStatus::Canceled)
+        .build();
+    let graph = DependencyGraph::from_items(vec![design, build.clone(), ship.clone(), canceled]);
+
+    assert_eq!(graph.blocked_items(), vec![ship.id()]);
+    assert_eq!(graph.blockers(&ship.id()), vec![build.id()]);
+    assert!(graph.blockers(&build.id()).is_empty());
+}
+
+#[test]
+fn canceled_dependencies_do_not_block() {
+    let dropped = TaskBuilder::new().with_name("dropped").with_status(SchedulableItemStatus::Canceled).build();
+    let open = task("open", vec![]);
+    let waiting = task("waiting", vec![dropped.id(), open.id()]);
+    let graph = DependencyGraph::from_items(vec![dropped.clone(), open.clone(), waiting.clone()]);
+
+    assert_eq!(graph.blockers(&waiting.id()), vec![open.id()]);
+    assert_eq!(graph.blocked_items(), vec![waiting.id()]);
+
+    let freed = DependencyGraph::from_items(vec![dropped, waiting.clone()]);
+    assert!(freed.blockers(&waiting.id()).is_empty());
+    assert!(freed.blocked_items().is_empty());
+}
+
+#[test]
+fn unknown_dependencies_are_ignored() {
+    let outside = Id::<Task>::new();
+    let lonely = task("lonely", vec![outside]);
+    let graph = DependencyGraph::from_items(vec![lonely.clone()]);
+
+    assert_eq!(graph.topological_order().unwrap(), vec![lonely.id()]);
+    assert!(graph.blocked_items().is_empty());
+}
+
+#[test]
+fn works_for_projects() {
+    let base = ProjectBuilder::new().with_name("base").build();
+    let app = ProjectBuilder::new().with_name("app").with_dependencies(vec![base.id()]).build();
+    let graph = DependencyGraph::from_items(vec![app.clone(), base.clone()]);
+
+    assert_eq!(graph.topological_order().unwrap(), vec![base.id(), app.id()]);
+    assert_eq!(graph.blocked_items(), vec![app.id()]);
+}
@@ -0,0 +1,99 @@
+use chrono::{Duration, Utc};
+use project_tracker_core::HasId;
+use project_tracker_core::builders::{
+    project_builder::ProjectBuilder, task_builder::TaskBuilder, time_entry_builder::TimeEntryBuilder,
+};
+use project_tracker_core::graph::{
+    estimate_report::EstimateReport, project_tree::ProjectTree, time_report::TimeReport,
+};
+use project_tracker_core::models::{
+    estimate::{Estimate, EstimateTotal},
+    schedulable::{SchedulableItem, SchedulableItemStatus},
+};
+
+#[test]
+fn estimates_roll_up_through_child_projects() {
+    let task_a = TaskBuilder::new().with_estimate(Some(Estimate::Hours(3))).build();
+    let task_b = TaskBuilder::new().with_estimate(Some(Estimate::Points(5))).build();
+    let canceled = TaskBuilder::new()
+        .with_estimate(Some(Estimate::Hours(40)))
+        .with_status(SchedulableItemStatus::Canceled)
+        .build();
+    let unestimated = TaskBuilder::new().build();
+    let sub = ProjectBuilder::new()
+        .with_estimate(Some(Estimate::Hours(100)))
+        .with_children(vec![SchedulableItem::Task(task_a.id()), SchedulableItem::Task(task_b.id())])
+        .build();
+    let sized = ProjectBuilder::new().with_estimate(Some(Estimate::Hours(6))).build();
+    let root = ProjectBuilder::new()
+        .with_children(vec![
+            SchedulableItem::Project(sub.id()),
+            SchedulableItem::Project(sized.id()),
+            SchedulableItem::Task(canceled.id()),
+            SchedulableItem::Task(unestimated.id()),
+        ])
+        .build();
+    let tree = ProjectTree::from_items(
+        vec![root.clone(), sub.clone(), sized.clone()],
+        vec![task_a, task_b, canceled, unestimated.clone()],
+    );
+
+    assert_eq!(
+        tree.estimate(&SchedulableItem::Project(sub.id())),
+        Some(EstimateTotal { hours: 3, points: 5 })
+    );
+    assert_eq!(
+        tree.estimate(&SchedulableItem::Project(root.id())),
+        Some(EstimateTotal { hours: 9, points: 5 })
+    );
+    assert_eq!(
+        tree.estimate(&SchedulableItem::Task(unestimated.id())),
+        Some(EstimateTotal::default())
+    );
+    assert_eq!(tree.estimate(&SchedulableItem::Task(TaskBuilder::new().build().id())), None);
+}
+
+#[test]
+fn report_compares_logged_time_with_estimate() {
+    let now = Utc::now();
+    let task = TaskBuilder::new()
+        .with_estimate(Some(Estimate::Hours(2)))
+        .with_start_date(Some(now - Duration::hours(10)))
+        .with_status(SchedulableItemStatus::InProgress)
+        .build();
+    let item = SchedulableItem::Task(task.id());
+    let tree = ProjectTree::from_items(Vec::new(), vec![task]);
+
+    let untracked = EstimateReport::for_item(&tree, &TimeReport::new(Vec::new(), now), &item, now).unwrap();
+    assert_eq!(untracked.logged, Duration::zero());
+    assert_eq!(untracked.elapsed, Some(Duration::hours(10)));
+    assert_eq!(untracked.variance(), Some(Duration::hours(8)));
+    assert!(untracked.is_over_estimate());
+
+    let entry = TimeEntryBuilder::new()
+        .with_item(item.clone())
+        .with_start(now - Duration::hours(3))
+        .with_end(Some(now - Duration::hours(2)))
+        .build();
+    let tracked = EstimateReport::for_item(&tree, &TimeReport::new(vec![entry], now), &item, now).unwrap();
+    assert_eq!(tracked.actual(), Some(Duration::hours(1)));
+    assert_eq!(tracked.variance(), Some(Duration::hours(-1)));
+    assert!(!tracked.is_over_estimate());
+}
+
+#[test]
+fn finished_items_stop_elapsing_at_their_due_date() {
+    let now = Utc::now();
+    let task = TaskBuilder::new()
+        .with_estimate(Some(Estimate::Points(3)))
+        .with_start_date(Some(now - Duration::days(5)))
+        .with_due_date(Some(now - Duration::days(2)))
+        .with_status(SchedulableItemStatus::Completed)
+        .build();
+    let item = SchedulableItem::Task(task.id());
+    let tree = ProjectTree::from_items(Vec::new(), vec![task]);
+
+    let report = EstimateReport::for_item(&tree, &TimeReport::new(Vec::new(), now), &item, now).unwrap();
+    assert_eq!(report.elapsed, Some(Duration::days(3)));
+    assert_eq!(report.variance(), None);
+}
@@ -0,0 +1,8 @@
+pub mod dependency_graph_tests;
+pub mod schedule_tests;
+pub mod tag_graph_tests;
+pub mod project_tree_tests;
+pub mod time_report_tests;
+pub mod estimate_report_tests;
+pub mod work_queue_tests;
+pub mod comment_thread_tests;
@@ -0,0 +1,108 @@
+use project_tracker_core::HasId;
+use project_tracker_core::builders::{project_builder::ProjectBuilder, task_builder::TaskBuilder};
+use project_tracker_core::graph::project_tree::{Progress, ProjectTree};
+use project_tracker_core::models::{
+    project::Project,
+    schedulable::{Schedulable, SchedulableItem, SchedulableItemStatus},
+    status_transition::StatusAction,
+    task::Task,
+};
+
+fn task(name: &str, status: SchedulableItemStatus) -> Task {
+    TaskBuilder::new().with_name(name).with_status(status).build()
+}
+
+fn project(name: &str, children: Vec<SchedulableItem>) -> Project {
+    ProjectBuilder::new().with_name(name).with_children(children).build()
+}
+
+/// root -> [done, open, canceled, sub -> [sub_done, sub_open]]
+fn sample_tree() -> (ProjectTree, Project, Project) {
+    let done = task("done", SchedulableItemStatus::Completed);
+    let open = task("open", SchedulableItemStatus::InProgress);
+    let canceled = task("canceled", SchedulableItemStatus::Canceled);
+    let sub_done = task("sub done", SchedulableItemStatus::Archived);
+    let sub_open = task("sub open", SchedulableItemStatus::NotStarted);
+    let sub = project(
+        "sub",
+        vec![SchedulableItem::Task(sub_done.id()), SchedulableItem::Task(sub_open.id())],
+    );
+    let root = project(
+        "root",
+        vec![
+            SchedulableItem::Task(done.id()),
+            SchedulableItem::Task(open.id()),
+            SchedulableItem::Task(canceled.id()),
+            SchedulableItem::Project(sub.id()),
+        ],
+    );
+    let tree = ProjectTree::from_items(
+        vec![root.clone(), sub.clone()],
+        vec![done, open, canceled, sub_done, sub_open],
+    );
+    (tree, root, sub)
+}
+
+#[test]
+fn progress_counts_leaves_through_child_projects() {
+    let (tree, root, sub) = sample_tree();
+
+    let progress = tree.progress(&SchedulableItem::Project(root.id())).unwrap();
+    assert_eq!(progress, Progress { finished: 2, total: 4 });
+    assert_eq!(progress.percent(), 50);
+
+    let progress = tree.progress(&SchedulableItem::Project(sub.id())).unwrap();
+    assert_eq!(progress.percent(), 50);
+}
+
+#[test]
+fn childless_items_are_measured_by_their_own_status() {
+    let mut finished = project("finished", vec![]);
+    finished.transition(StatusAction::Archive, None).unwrap();
+    let empty = project("empty", vec![]);
+    let tree = ProjectTree::from_items(vec![finished.clone(), empty.clone()], vec![]);
+
+    assert_eq!(tree.progress(&SchedulableItem::Project(finished.id())).unwrap().percent(), 100);
+    assert_eq!(tree.progress(&SchedulableItem::Project(empty.id())).unwrap().percent(), 0);
+    assert_eq!(tree.progress(&SchedulableItem::Project(project("unknown", vec![]).id())), None);
+}
+
+#[test]
+fn subtasks_are_part_of_the_tree() {
+    let child = task("child", SchedulableItemStatus::Completed);
+    let parent = TaskBuilder::new().with_name("parent").with_children(vec![child.id()]).build();
+    let tree = ProjectTree::from_items(vec![], vec![parent.clone(), child]);
+
+    assert_eq!(tree.progress(&SchedulableItem::Task(parent.id())).unwrap().percent(), 100);
+    assert!(tree.open_children(&SchedulableItem::Task(parent.id())).is_empty());
+}
+
+#[test]
+fn open_children_skip_finished_and_canceled_ones() {
+    let (tree, root, sub) = sample_tree();
+
+    let open = tree.open_children(&SchedulableItem::Project(root.id()));
+    assert_eq!(open.len(), 2);
+    assert!(open.contains(&SchedulableItem::Project(sub.id())));
+}
+
+#[test]
+fn ancestor_projects_walk_up_nearest_first() {
+    let leaf = task("leaf", SchedulableItemStatus::NotStarted);
+    let parent_task = TaskBuilder::new().with_name("parent task").with_children(vec![leaf.id()]).build();
+    let sub = project("sub", vec![SchedulableItem::Task(parent_task.id())]);
+    let root = project("root", vec![SchedulableItem::Project(sub.id())]);
+    let tree = ProjectTree::from_items(vec![root.clone(), sub.clone()], vec![leaf.clone(), parent_task]);
+
+    assert_eq!(tree.ancestor_projects(&SchedulableItem::Task(leaf.id())), vec![sub.id(), root.id()]);
+    assert!(tree.ancestor_projects(&SchedulableItem::Project(root.id())).is_empty());
+}
+
+#[test]
+fn start_action_jumps_to_in_progress() {
+    assert_eq!(
+        SchedulableItemStatus::NotStarted.after(StatusAction::Start),
+        Ok(SchedulableItemStatus::InProgress)
+    );
+    assert!(SchedulableItemStatus::InReview.after(StatusAction::Start).is_err());
+}
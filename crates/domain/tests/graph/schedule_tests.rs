@@ -0,0 +1,88 @@
+use chrono::{DateTime, TimeDelta, TimeZone, Utc};
+use project_tracker_core::HasId;
+use project_tracker_core::builders::task_builder::TaskBuilder;
+use project_tracker_core::graph::{dependency_graph::DependencyGraphError, schedule::Schedule};
+use project_tracker_core::id::Id;
+use project_tracker_core::models::task::Task;
+
+fn day(n: i64) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2030, 1, 1, 0, 0, 0).unwrap() + TimeDelta::days(n)
+}
+
+fn task(name: &str, start: i64, due: i64, dependencies: Vec<Id<Task>>) -> Task {
+    TaskBuilder::new()
+        .with_name(name)
+        .with_start_date(Some(day(start)))
+        .with_due_date(Some(day(due)))
+        .with_dependencies(dependencies)
+        .build()
+}
+
+/// design (0-2) -> build (2-7) -> ship (7-8), with docs (2-4) also after design
+fn sample_tasks() -> (Task, Task, Task, Task) {
+    let design = task("design", 0, 2, vec![]);
+    let build = task("build", 2, 7, vec![design.id()]);
+    let ship = task("ship", 7, 8, vec![build.id()]);
+    let docs = task("docs", 2, 4, vec![design.id()]);
+    (design, build, ship, docs)
+}
+
+#[test]
+fn forward_pass_computes_earliest_dates() {
+    let (design, build, ship, docs) = sample_tasks();
+    let schedule = Schedule::compute(vec![ship.clone(), docs.clone(), build.clone(), design.clone()], day(0)).unwrap();
+
+    let build_forecast = schedule.get(&build.id()).unwrap();
+    assert_eq!(build_forecast.earliest_start, day(2));
+    assert_eq!(build_forecast.earliest_finish, day(7));
+    assert_eq!(schedule.finish(), day(8));
+    assert_eq!(schedule.items()[0].id, design.id());
+}
+
+#[test]
+fn critical_path_and_slack() {
+    let (design, build, ship, docs) = sample_tasks();
+    let schedule = Schedule::compute(vec![design.clone(), build.clone(), ship.clone(), docs.clone()], day(0)).unwrap();
+
+    assert_eq!(schedule.critical_path(), vec![design.id(), build.id(), ship.id()]);
+
+    let docs_forecast = schedule.get(&docs.id()).unwrap();
+    assert!(!docs_forecast.critical);
+    assert_eq!(docs_forecast.slack, TimeDelta::days(4));
+    assert_eq!(docs_forecast.latest_finish, day(8));
+    assert_eq!(docs_forecast.latest_start, day(6));
+}
+
+#[test]
+fn impossible_due_dates_are_flagged() {
+    let slow = task("slow", 0, 10, vec![]);
+    let rushed = task("rushed", 3, 5, vec![slow.id()]);
+    let schedule = Schedule::compute(vec![slow.clone(), rushed.clone()], day(0)).unwrap();
+
+    assert_eq!(schedule.impossible_due_dates(), vec![rushed.id()]);
+    let rushed_forecast = schedule.get(&rushed.id()).unwrap();
+    assert_eq!(rushed_forecast.earliest_start, day(10));
+    assert_eq!(rushed_forecast.earliest_finish, day(12));
+}
+
+#[test]
+fn undated_items_start_at_origin() {
+    let milestone = TaskBuilder::new().with_name("kick-off").build();
+    let schedule = Schedule::compute(vec![milestone.clone()], day(3)).unwrap();
+
+    let forecast = schedule.get(&milestone.id()).unwrap();
+    assert_eq!(forecast.earliest_start, day(3));
+    assert_eq!(forecast.earliest_finish, day(3));
+    assert!(forecast.critical);
+}
+
+#[test]
+fn cycles_prevent_scheduling() {
+    let first = task("first", 0, 1, vec![]);
+    let second = task("second", 1, 2, vec![first.id()]);
+    let mut first = first;
+    first.add_dependency(second.id());
+
+    let result = Schedule::compute(vec![first, second], day(0));
+    assert!(matches!(result, Err(DependencyGraphError::Cycle(_))));
+}
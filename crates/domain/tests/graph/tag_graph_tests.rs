@@ -0,0 +1,107 @@
+use project_tracker_core::HasId;
+use project_tracker_core::builders::{project_builder::ProjectBuilder, tag_builder::TagBuilder};
+use project_tracker_core::graph::tag_graph::{TagGraph, TagGraphError};
+use project_tracker_core::models::tag::Tag;
+
+fn named_tag(name: &str) -> Tag {
+    TagBuilder::new().with_name(name).build()
+}
+
+/// hobbies <- programming <- rust, with a separate cooking tag under hobbies
+fn sample_taxonomy() -> (TagGraph, Tag, Tag, Tag, Tag) {
+    let hobbies = named_tag("hobbies");
+    let programming = TagBuilder::new().with_name("programming").with_parents(vec![hobbies.id()]).build();
+    let rust = TagBuilder::new().with_name("rust").with_parents(vec![programming.id()]).build();
+    let cooking = TagBuilder::new().with_name("cooking").with_parents(vec![hobbies.id()]).build();
+    let graph = TagGraph::from_tags(vec![hobbies.clone(), programming.clone(), rust.clone(), cooking.clone()]).unwrap();
+    (graph, hobbies, programming, rust, cooking)
+}
+
+#[test]
+fn ancestors_are_listed_nearest_first() {
+    let (graph, hobbies, programming, rust, _) = sample_taxonomy();
+    assert_eq!(graph.ancestors(&rust.id()), vec![programming.id(), hobbies.id()]);
+    assert!(graph.ancestors(&hobbies.id()).is_empty());
+}
+
+#[test]
+fn descendants_cover_every_branch() {
+    let (graph, hobbies, programming, rust, cooking) = sample_taxonomy();
+    let descendants = graph.descendants(&hobbies.id());
+    assert_eq!(descendants.len(), 3);
+    assert!(descendants.contains(&programming.id()));
+    assert!(descendants.contains(&rust.id()));
+    assert!(descendants.contains(&cooking.id()));
+    assert_eq!(graph.descendants(&programming.id()), vec![rust.id()]);
+}
+
+#[test]
+fn add_parent_rejects_self_parent() {
+    let (mut graph, hobbies, _, _, _) = sample_taxonomy();
+    let result = graph.add_parent(&hobbies.id(), hobbies.id());
+    assert_eq!(result, Err(TagGraphError::SelfParent(hobbies.id().to_string())));
+}
+
+#[test]
+fn add_parent_rejects_cycles() {
+    let (mut graph, hobbies, programming, rust, _) = sample_taxonomy();
+    let result = graph.add_parent(&hobbies.id(), rust.id());
+    assert_eq!(
+        result,
+        Err(TagGraphError::Cycle(vec![
+            hobbies.id().to_string(),
+            rust.id().to_string(),
+            programming.id().to_string(),
+            hobbies.id().to_string(),
+        ]))
+    );
+    assert!(graph.ancestors(&hobbies.id()).is_empty());
+}
+
+#[test]
+fn add_parent_accepts_new_branches() {
+    let (mut graph, _, _, rust, cooking) = sample_taxonomy();
+    assert!(graph.add_parent(&rust.id(), cooking.id()).is_ok());
+    assert!(graph.ancestors(&rust.id()).contains(&cooking.id()));
+    assert_eq!(graph.get(&rust.id()).unwrap().parents().len(), 2);
+}
+
+#[test]
+fn add_parent_requires_known_tag() {
+    let (mut graph, hobbies, _, _, _) = sample_taxonomy();
+    let stranger = named_tag("stranger");
+    let result = graph.add_parent(&stranger.id(), hobbies.id());
+    assert_eq!(result, Err(TagGraphError::UnknownTag(stranger.id().to_string())));
+}
+
+#[test]
+fn from_tags_detects_existing_cycles() {
+    let first = named_tag("first");
+    let second = TagBuilder::new().with_name("second").with_parents(vec![first.id()]).build();
+    let mut first = first;
+    first.add_parent(second.id());
+    assert!(matches!(TagGraph::from_tags(vec![first, second]), Err(TagGraphError::Cycle(_))));
+}
+
+#[test]
+fn insert_rejects_cycles() {
+    let (mut graph, hobbies, _, rust, _) = sample_taxonomy();
+    let looping = TagBuilder::new().with_id(hobbies.id()).with_name("hobbies").with_parents(vec![rust.id()]).build();
+    assert!(graph.insert(looping).is_err());
+    assert!(graph.insert(named_tag("standalone")).is_ok());
+}
+
+#[test]
+fn filter_matches_tag_or_descendants() {
+    let (graph, hobbies, programming, rust, cooking) = sample_taxonomy();
+    let rust_project = ProjectBuilder::new().with_name("Rust").with_tags(vec![rust.id()]).build();
+    let cooking_project = ProjectBuilder::new().with_name("Cooking").with_tags(vec![cooking.id()]).build();
+    let untagged = ProjectBuilder::new().with_name("Untagged").build();
+
+    assert!(graph.is_tagged_with(&rust_project, &programming.id()));
+    assert!(!graph.is_tagged_with(&cooking_project, &programming.id()));
+
+    let projects = vec![rust_project.clone(), cooking_project.clone(), untagged];
+    assert_eq!(graph.filter_tagged(projects.clone(), &programming.id()), vec![rust_project.clone()]);
+    assert_eq!(graph.filter_tagged(projects, &hobbies.id()), vec![rust_project, cooking_project]);
+}
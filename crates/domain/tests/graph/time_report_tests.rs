@@ -0,0 +1,101 @@
+use chrono::{DateTime, Duration, NaiveDate, TimeZone, Utc};
+use project_tracker_core::HasId;
+use project_tracker_core::builders::{
+    project_builder::ProjectBuilder, task_builder::TaskBuilder, time_entry_builder::TimeEntryBuilder,
+};
+use project_tracker_core::graph::{project_tree::ProjectTree, time_report::{TimeReport, week_start}};
+use project_tracker_core::id::Id;
+use project_tracker_core::models::{
+    person::Person,
+    schedulable::SchedulableItem,
+    tag::Tag,
+    time_entry::TimeEntry,
+};
+
+fn at(day: u32, hour: u32) -> DateTime<Utc> {
+    // 2025-03-03 is a Monday
+    Utc.with_ymd_and_hms(2025, 3, day, hour, 0, 0).unwrap()
+}
+
+fn entry(person_id: &Id<Person>, item: &SchedulableItem, start: DateTime<Utc>, minutes: i64) -> TimeEntry {
+    TimeEntryBuilder::new()
+        .with_person_id(person_id.clone())
+        .with_item(item.clone())
+        .with_start(start)
+        .with_end(Some(start + Duration::minutes(minutes)))
+        .build()
+}
+
+#[test]
+fn totals_per_item_person_and_recursively() {
+    let ada = Id::<Person>::new();
+    let bob = Id::<Person>::new();
+    let subtask = TaskBuilder::new().with_name("subtask").build();
+    let task = TaskBuilder::new().with_name("task").with_children(vec![subtask.id()]).build();
+    let sub = ProjectBuilder::new().with_name("sub").with_children(vec![SchedulableItem::Task(task.id())]).build();
+    let root = ProjectBuilder::new().with_name("root").with_children(vec![SchedulableItem::Project(sub.id())]).build();
+    let tree = ProjectTree::from_items(vec![root.clone(), sub.clone()], vec![task.clone(), subtask.clone()]);
+
+    let root_item = SchedulableItem::Project(root.id());
+    let task_item = SchedulableItem::Task(task.id());
+    let subtask_item = SchedulableItem::Task(subtask.id());
+    let report = TimeReport::new(
+        vec![
+            entry(&ada, &root_item, at(3, 9), 30),
+            entry(&ada, &task_item, at(4, 9), 60),
+            entry(&bob, &subtask_item, at(4, 13), 45),
+        ],
+        Utc::now(),
+    );
+
+    assert_eq!(report.total(), Duration::minutes(135));
+    assert_eq!(report.total_for_item(&root_item), Duration::minutes(30));
+    assert_eq!(report.total_for_person(&ada), Duration::minutes(90));
+    assert_eq!(report.per_person()[&bob], Duration::minutes(45));
+    assert_eq!(report.per_item()[&task_item], Duration::minutes(60));
+    assert_eq!(report.total_with_descendants(&tree, &root_item), Duration::minutes(135));
+    assert_eq!(report.total_with_descendants(&tree, &task_item), Duration::minutes(105));
+}
+
+#[test]
+fn totals_per_tag_and_week() {
+    let person = Id::<Person>::new();
+    let rust = Id::<Tag>::new();
+    let hobby = Id::<Tag>::new();
+    let task = TaskBuilder::new().with_tags(vec![rust.clone(), hobby.clone()]).build();
+    let project = ProjectBuilder::new().with_tags(vec![hobby.clone()]).build();
+    let tree = ProjectTree::from_items(vec![project.clone()], vec![task.clone()]);
+    let task_item = SchedulableItem::Task(task.id());
+    let project_item = SchedulableItem::Project(project.id());
+
+    let report = TimeReport::new(
+        vec![
+            entry(&person, &task_item, at(5, 10), 60),
+            entry(&person, &project_item, at(9, 10), 15),
+            entry(&person, &project_item, at(10, 10), 20),
+        ],
+        Utc::now(),
+    );
+
+    let per_tag = report.per_tag(&tree);
+    assert_eq!(per_tag[&rust], Duration::minutes(60));
+    assert_eq!(per_tag[&hobby], Duration::minutes(95));
+
+    let per_week: Vec<_> = report.per_week().into_iter().collect();
+    assert_eq!(
+        per_week,
+        vec![
+            (NaiveDate::from_ymd_opt(2025, 3, 3).unwrap(), Duration::minutes(75)),
+            (NaiveDate::from_ymd_opt(2025, 3, 10).unwrap(), Duration::minutes(20)),
+        ]
+    );
+    assert_eq!(week_start(at(9, 23)), NaiveDate::from_ymd_opt(2025, 3, 3).unwrap());
+}
+
+#[test]
+fn running_timers_count_up_to_now() {
+    let now = at(3, 12);
+    let running = TimeEntry::start_timer(Id::<Person>::new(), SchedulableItem::Project(Id::new()), at(3, 10));
+    let report = TimeReport::new(vec![running], now);
+    assert_eq!(report.total(), Duration::hours(2));
+}
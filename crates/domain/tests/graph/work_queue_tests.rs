@@ -0,0 +1,116 @@
+use chrono::{Duration, Utc};
+use project_tracker_core::HasId;
+use project_tracker_core::builders::{project_builder::ProjectBuilder, task_builder::TaskBuilder};
+use project_tracker_core::graph::work_queue::{WorkQueue, priority_weight, urgency};
+use project_tracker_core::models::schedulable::{Priority, SchedulableItem, SchedulableItemStatus};
+
+#[test]
+fn higher_priority_comes_first() {
+    let low = TaskBuilder::new().with_name("Low").with_priority(Priority::Low).build();
+    let critical = TaskBuilder::new().with_name("Critical").with_priority(Priority::Critical).build();
+    let medium = TaskBuilder::new().with_name("Medium").build();
+
+    let queue = WorkQueue::new(vec![], vec![low.clone(), critical.clone(), medium.clone()], Utc::now());
+
+    let order: Vec<SchedulableItem> = queue.entries().iter().map(|entry| entry.item.clone()).collect();
+    assert_eq!(
+        order,
+        vec![
+            SchedulableItem::Task(critical.id()),
+            SchedulableItem::Task(medium.id()),
+            SchedulableItem::Task(low.id()),
+        ]
+    );
+    assert_eq!(queue.next().unwrap().item, SchedulableItem::Task(critical.id()));
+}
+
+#[test]
+fn closer_due_date_raises_the_rank() {
+    let now = Utc::now();
+    let later = TaskBuilder::new()
+        .with_name("Later")
+        .with_priority(Priority::High)
+        .with_due_date(Some(now + Duration::days(90)))
+        .build();
+    let overdue = TaskBuilder::new()
+        .with_name("Overdue")
+        .with_due_date(Some(now - Duration::days(1)))
+        .build();
+    let tomorrow = TaskBuilder::new()
+        .with_name("Tomorrow")
+        .with_due_date(Some(now + Duration::hours(12)))
+        .build();
+
+    let queue = WorkQueue::new(vec![], vec![later.clone(), tomorrow.clone(), overdue.clone()], now);
+
+    let names: Vec<&str> = queue.entries().iter().map(|entry| entry.name.as_str()).collect();
+    assert_eq!(names, vec!["Overdue", "Tomorrow", "Later"]);
+    assert_eq!(queue.entries()[0].score, 6);
+    assert_eq!(queue.entries()[2].score, 4);
+}
+
+#[test]
+fn blocked_items_go_after_ready_ones() {
+    let dependency = TaskBuilder::new().with_name("Prepare").with_priority(Priority::Low).build();
+    let blocked = TaskBuilder::new()
+        .with_name("Ship")
+        .with_priority(Priority::Critical)
+        .with_dependencies(vec![dependency.id()])
+        .build();
+
+    let queue = WorkQueue::new(vec![], vec![blocked.clone(), dependency.clone()], Utc::now());
+
+    assert_eq!(queue.len(), 2);
+    assert_eq!(queue.next().unwrap().item, SchedulableItem::Task(dependency.id()));
+    let last = &queue.entries()[1];
+    assert!(!last.is_ready());
+    assert_eq!(last.blockers, vec![SchedulableItem::Task(dependency.id())]);
+    assert_eq!(queue.ready().count(), 1);
+}
+
+#[test]
+fn finished_dependencies_do_not_block() {
+    let done = TaskBuilder::new().with_status(SchedulableItemStatus::Completed).build();
+    let task = TaskBuilder::new().with_dependencies(vec![done.id()]).build();
+    let project = ProjectBuilder::new().with_status(SchedulableItemStatus::Canceled).build();
+
+    let queue = WorkQueue::new(vec![project], vec![done, task.clone()], Utc::now());
+
+    assert_eq!(queue.len(), 1);
+    assert_eq!(queue.next().unwrap().item, SchedulableItem::Task(task.id()));
+}
+
+#[test]
+fn canceled_dependencies_do_not_block() {
+    let dropped = TaskBuilder::new().with_status(SchedulableItemStatus::Canceled).build();
+    let task = TaskBuilder::new().with_dependencies(vec![dropped.id()]).build();
+
+    let queue = WorkQueue::new(vec![], vec![dropped, task.clone()], Utc::now());
+
+    assert_eq!(queue.len(), 1);
+    assert!(queue.next().unwrap().is_ready());
+    assert_eq!(queue.ready().count(), 1);
+}
+
+#[test]
+fn projects_are_ranked_with_tasks() {
+    let project = ProjectBuilder::new().with_name("Launch").with_priority(Priority::High).build();
+    let task = TaskBuilder::new().with_name("Tidy up").build();
+
+    let queue = WorkQueue::new(vec![project.clone()], vec![task], Utc::now());
+
+    assert_eq!(queue.next().unwrap().item, SchedulableItem::Project(project.id()));
+    assert!(WorkQueue::new(vec![], vec![], Utc::now()).is_empty());
+}
+
+#[test]
+fn scores_follow_priority_and_urgency() {
+    let now = Utc::now();
+    assert_eq!(priority_weight(Priority::Low), 0);
+    assert_eq!(priority_weight(Priority::Critical), 6);
+    assert_eq!(urgency(None, now), 0);
+    assert_eq!(urgency(Some(now - Duration::minutes(1)), now), 4);
+    assert_eq!(urgency(Some(now + Duration::days(5)), now), 2);
+    assert_eq!(urgency(Some(now + Duration::days(20)), now), 1);
+    assert_eq!(urgency(Some(now + Duration::days(31)), now), 0);
+}
@@ -0,0 +1,57 @@
+use chrono::Utc;
+use project_tracker_core::HasId;
+use project_tracker_core::builders::task_builder::TaskBuilder;
+use project_tracker_core::factories::{person_factory::sample_person, tag_factory::basic_tag};
+use project_tracker_core::models::{
+    audit::{AuditEntry, Auditable},
+    schedulable::{Priority, Schedulable},
+    tag::Tag,
+};
+
+#[test]
+fn creation_records_every_set_field() {
+    let task = TaskBuilder::new().with_name("Write docs").build();
+    let at = Utc::now();
+
+    let entries = AuditEntry::changes(None, Some(&task), None, at);
+
+    let fields: Vec<&str> = entries.iter().map(AuditEntry::field).collect();
+    assert_eq!(fields, vec!["name", "priority", "status"]);
+    assert!(entries.iter().all(|entry| entry.old_value().is_none()));
+    assert!(entries.iter().all(|entry| entry.entity_id() == task.id().to_string()));
+    assert_eq!(entries[0].new_value(), Some("Write docs"));
+    assert!(entries[0].id().to_string().starts_with("audit-"));
+}
+
+#[test]
+fn update_records_only_changed_fields() {
+    let before = TaskBuilder::new().with_name("Write docs").build();
+    let mut after = before.clone();
+    after.rename("Write the user guide");
+    after.set_priority(Priority::High);
+    after.add_tag(basic_tag().id());
+    let actor = sample_person().id();
+
+    let entries = AuditEntry::changes(Some(&before), Some(&after), Some(actor.clone()), Utc::now());
+
+    assert_eq!(entries.len(), 3);
+    assert_eq!(entries[0].field(), "name");
+    assert_eq!(entries[0].old_value(), Some("Write docs"));
+    assert_eq!(entries[0].new_value(), Some("Write the user guide"));
+    assert_eq!(entries[1].field(), "priority");
+    assert_eq!(entries[2].field(), "tags");
+    assert_eq!(entries[2].old_value(), None);
+    assert!(entries.iter().all(|entry| entry.actor() == Some(&actor)));
+    assert!(AuditEntry::changes(Some(&after), Some(&after), None, Utc::now()).is_empty());
+}
+
+#[test]
+fn deletion_clears_every_field() {
+    let tag = basic_tag();
+
+    let entries = AuditEntry::changes(Some(&tag), None, None, Utc::now());
+
+    assert_eq!(entries.len(), tag.audit_fields().len());
+    assert!(entries.iter().all(|entry| entry.new_value().is_none()));
+    assert!(AuditEntry::changes::<Tag>(None, None, None, Utc::now()).is_empty());
+}
@@ -0,0 +1,97 @@
+use project_tracker_core::factories::{project_factory::*, task_factory::*};
+use project_tracker_core::models::command::{CommandError, CommandHistory, SchedulableCommand};
+use project_tracker_core::models::schedulable::{Priority, Schedulable};
+
+#[test]
+fn undo_restores_removed_tags() {
+    let mut project = sample_project_with_tags();
+    let tags = project.tags();
+    let mut history = CommandHistory::new();
+
+    assert!(history.execute(&mut project, SchedulableCommand::RemoveAllTags));
+    assert!(!project.has_tags());
+
+    assert_eq!(history.undo(&mut project), Ok(SchedulableCommand::RemoveAllTags));
+    assert_eq!(project.tags(), tags);
+    assert!(!history.can_undo());
+    assert!(history.can_redo());
+}
+
+#[test]
+fn undo_restores_removed_children() {
+    let original = sample_project_with_child_projects_and_tasks();
+    let mut project = original.clone();
+    let mut history = CommandHistory::new();
+
+    history.execute(&mut project, SchedulableCommand::RemoveAllChildren);
+    assert!(!project.has_children());
+
+    history.undo(&mut project).unwrap();
+    assert!(project == original);
+}
+
+#[test]
+fn redo_replays_undone_commands_in_order() {
+    let original = sample_task();
+    let mut task = original.clone();
+    let mut history = CommandHistory::new();
+
+    history.execute(&mut task, SchedulableCommand::Rename("Renamed".into()));
+    history.execute(&mut task, SchedulableCommand::SetPriority(Priority::Critical));
+    history.execute(&mut task, SchedulableCommand::SetDescription("Updated".into()));
+    let done = task.clone();
+
+    for _ in 0..3 {
+        history.undo(&mut task).unwrap();
+    }
+    assert!(task == original);
+    assert_eq!(history.undo(&mut task), Err(CommandError::NothingToUndo));
+
+    assert_eq!(history.redo(&mut task), Ok(SchedulableCommand::Rename("Renamed".into())));
+    assert_eq!(task.name(), "Renamed");
+    history.redo(&mut task).unwrap();
+    history.redo(&mut task).unwrap();
+    assert!(task == done);
+    assert_eq!(history.redo(&mut task), Err(CommandError::NothingToRedo));
+}
+
+#[test]
+fn new_command_clears_redo_stack() {
+    let mut task = sample_task();
+    let mut history = CommandHistory::new();
+
+    history.execute(&mut task, SchedulableCommand::Rename("First".into()));
+    history.undo(&mut task).unwrap();
+    history.execute(&mut task, SchedulableCommand::Rename("Second".into()));
+
+    assert!(!history.can_redo());
+    assert_eq!(task.name(), "Second");
+    assert_eq!(
+        history.undo_stack().collect::<Vec<_>>(),
+        vec![&SchedulableCommand::Rename("Second".into())]
+    );
+}
+
+#[test]
+fn commands_that_change_nothing_are_not_recorded() {
+    let mut task = sample_task();
+    let mut history = CommandHistory::new();
+
+    let name = task.name().to_string();
+    assert!(!history.execute(&mut task, SchedulableCommand::Rename(name)));
+    assert!(!history.execute(&mut task, SchedulableCommand::RemoveAllChildren));
+    assert!(!history.can_undo());
+}
+
+#[test]
+fn undo_refuses_to_drop_outside_changes() {
+    let mut task = sample_task();
+    let mut history = CommandHistory::new();
+
+    history.execute(&mut task, SchedulableCommand::SetPriority(Priority::Critical));
+    task.rename("Changed elsewhere");
+
+    assert_eq!(history.undo(&mut task), Err(CommandError::Diverged));
+    assert_eq!(task.name(), "Changed elsewhere");
+    assert!(history.can_undo());
+}
@@ -0,0 +1,56 @@
+use chrono::{Duration, Utc};
+use project_tracker_core::HasId;
+use project_tracker_core::factories::comment_factory::*;
+use project_tracker_core::id::Id;
+use project_tracker_core::models::{comment::CommentError, person::Person};
+
+#[test]
+fn create_comment_id() {
+    let comment = sample_comment();
+    assert!(comment.id().to_string().starts_with("comment-"));
+    assert!(!comment.is_reply());
+    assert!(!comment.is_edited());
+    assert_eq!(comment.updated_at(), comment.created_at());
+    assert!(comment.is_valid());
+}
+
+#[test]
+fn edit_keeps_history() {
+    let mut comment = sample_comment();
+    let original = comment.body().to_string();
+    let edited_at = comment.created_at() + Duration::minutes(5);
+
+    comment.edit("Parser handles nested lists and tables now", edited_at).unwrap();
+
+    assert_eq!(comment.body(), "Parser handles nested lists and tables now");
+    assert!(comment.is_edited());
+    assert_eq!(comment.edits().len(), 1);
+    assert_eq!(comment.edits()[0].body, original);
+    assert_eq!(comment.updated_at(), edited_at);
+}
+
+#[test]
+fn invalid_edits_are_rejected() {
+    let mut comment = sample_comment();
+    let body = comment.body().to_string();
+    let created_at = comment.created_at();
+
+    assert_eq!(comment.edit("   ", Utc::now()).unwrap_err(), CommentError::EmptyBody);
+    assert_eq!(comment.edit(&body, Utc::now()).unwrap_err(), CommentError::Unchanged);
+    assert_eq!(
+        comment.edit("Too early", created_at - Duration::minutes(1)).unwrap_err(),
+        CommentError::EditedBeforeCreated { created_at, edited_at: created_at - Duration::minutes(1) }
+    );
+    assert!(!comment.is_edited());
+}
+
+#[test]
+fn reply_stays_on_the_same_item() {
+    let comment = sample_comment();
+    let reply = comment.reply(Id::<Person>::new(), "Nice!", Utc::now());
+
+    assert!(reply.is_reply());
+    assert_eq!(reply.parent_id(), Some(&comment.id()));
+    assert_eq!(reply.item(), comment.item());
+    assert!(!basic_comment().body().is_empty());
+}
@@ -0,0 +1,37 @@
+use chrono::Duration;
+use project_tracker_core::builders::{project_builder::ProjectBuilder, task_builder::TaskBuilder};
+use project_tracker_core::models::estimate::{Estimate, EstimateError, EstimateTotal};
+
+#[test]
+fn parse_and_display_estimates() {
+    assert_eq!("8h".parse::<Estimate>(), Ok(Estimate::Hours(8)));
+    assert_eq!(" 5 pt".parse::<Estimate>(), Ok(Estimate::Points(5)));
+    assert_eq!(Estimate::Hours(12).to_string(), "12h");
+    assert_eq!(Estimate::Points(3).to_string(), "3pt");
+    assert!(matches!("three".parse::<Estimate>(), Err(EstimateError::InvalidEstimate(_))));
+    assert!("-2h".parse::<Estimate>().is_err());
+}
+
+#[test]
+fn hour_estimates_have_a_duration() {
+    assert_eq!(Estimate::Hours(2).duration(), Some(Duration::hours(2)));
+    assert_eq!(Estimate::Points(2).duration(), None);
+
+    let mut total = EstimateTotal::from(Estimate::Hours(2));
+    total.add(Estimate::Points(5));
+    total.add(Estimate::Hours(1));
+    assert_eq!(total, EstimateTotal { hours: 3, points: 5 });
+    assert_eq!(total.duration(), Duration::hours(3));
+}
+
+#[test]
+fn estimates_on_projects_and_tasks() {
+    let mut project = ProjectBuilder::new().with_estimate(Some(Estimate::Points(8))).build();
+    assert_eq!(project.estimate(), Some(Estimate::Points(8)));
+    project.clear_estimate();
+    assert!(!project.has_estimate());
+
+    let mut task = TaskBuilder::new().build();
+    task.set_estimate(Estimate::Hours(4));
+    assert_eq!(task.estimate(), Some(Estimate::Hours(4)));
+}
@@ -0,0 +1,64 @@
+use chrono::{Duration, Utc};
+use project_tracker_core::HasId;
+use project_tracker_core::builders::task_builder::TaskBuilder;
+use project_tracker_core::factories::milestone_factory::*;
+use project_tracker_core::graph::project_tree::ProjectTree;
+use project_tracker_core::models::{
+    milestone::MilestoneState,
+    schedulable::{SchedulableItem, SchedulableItemStatus},
+};
+
+#[test]
+fn create_milestone_id() {
+    let milestone = sample_milestone();
+    assert!(milestone.id().to_string().starts_with("milestone-"));
+    assert_eq!(milestone.items().len(), 2);
+}
+
+#[test]
+fn milestone_states() {
+    assert_eq!(sample_milestone().state(), MilestoneState::Pending);
+    assert_eq!(sample_reached_milestone().state(), MilestoneState::Reached);
+    assert_eq!(sample_missed_milestone().state(), MilestoneState::Missed);
+}
+
+#[test]
+fn reaching_after_target_date_counts_as_missed() {
+    let mut milestone = sample_missed_milestone();
+    milestone.mark_reached(Utc::now());
+    assert_eq!(milestone.state(), MilestoneState::Missed);
+
+    milestone.set_target_date(Utc::now() + Duration::days(1));
+    assert_eq!(milestone.state(), MilestoneState::Reached);
+
+    milestone.reopen();
+    assert_eq!(milestone.state(), MilestoneState::Pending);
+}
+
+#[test]
+fn link_and_unlink_items() {
+    let mut milestone = basic_milestone();
+    let item = SchedulableItem::Task(TaskBuilder::new().build().id());
+
+    milestone.link(item.clone());
+    milestone.link(item.clone());
+    assert_eq!(milestone.items(), vec![item.clone()]);
+
+    milestone.unlink(&item);
+    assert!(!milestone.has_item(&item));
+}
+
+#[test]
+fn open_items_leave_out_finished_and_unknown_ones() {
+    let open = TaskBuilder::new().with_status(SchedulableItemStatus::InProgress).build();
+    let done = TaskBuilder::new().with_status(SchedulableItemStatus::Completed).build();
+    let unknown = TaskBuilder::new().build();
+
+    let mut milestone = basic_milestone();
+    for task in [&open, &done, &unknown] {
+        milestone.link(SchedulableItem::Task(task.id()));
+    }
+    let tree = ProjectTree::from_items(vec![], vec![open.clone(), done]);
+
+    assert_eq!(tree.open_items(&milestone.items()), vec![SchedulableItem::Task(open.id())]);
+}
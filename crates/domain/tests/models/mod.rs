@@ -2,4 +2,12 @@ pub mod project_tests;
 pub mod schedulable_tests;
 pub mod task_tests;
 pub mod tag_tests;
-pub mod person_tests;
\ No newline at end of file
+pub mod person_tests;
+pub mod status_transition_tests;
+pub mod milestone_tests;
+pub mod recurrence_tests;
+pub mod time_entry_tests;
+pub mod estimate_tests;
+pub mod comment_tests;
+pub mod audit_tests;
+pub mod command_tests;
@@ -344,7 +344,7 @@ fn add_child_project_to_project() {
     assert!(project.has_children());
     assert!(project.children().len() == 1);
     assert!(project.project_children().len() == 1);
-    assert!(project.task_children().len() == 0);
+    assert!(project.task_children().is_empty());
 }
 
 #[test]
@@ -359,7 +359,7 @@ fn add_multiple_child_projects_to_project() {
     assert!(project.has_children());
     assert!(project.children().len() == 3);
     assert!(project.project_children().len() == 3);
-    assert!(project.task_children().len() == 0);
+    assert!(project.task_children().is_empty());
 }
 
 #[test]
@@ -396,7 +396,7 @@ fn remove_multiple_child_projects_from_project() {
     assert!(project.has_children());
     assert!(project.children().len() == 1);
     assert!(project.project_children().len() == 1);
-    assert!(project.task_children().len() == 0);
+    assert!(project.task_children().is_empty());
     assert!(project.has_child(&SchedulableItem::Project(child_project_2.id())));
 }
 
@@ -407,7 +407,7 @@ fn add_child_task_to_project() {
     project.add_child(SchedulableItem::Task(child_task.id()));
     assert!(project.has_children());
     assert!(project.children().len() == 1);
-    assert!(project.project_children().len() == 0);
+    assert!(project.project_children().is_empty());
     assert!(project.task_children().len() == 1);
 }
 
@@ -425,7 +425,7 @@ fn add_multiple_child_tasks_to_project() {
     project.add_children(children);
     assert!(project.has_children());
     assert!(project.children().len() == 3);
-    assert!(project.project_children().len() == 0);
+    assert!(project.project_children().is_empty());
     assert!(project.task_children().len() == 3);
 }
 
@@ -443,12 +443,12 @@ fn remove_child_task_from_project() {
     project.add_children(children);
     assert!(project.has_children());
     assert!(project.children().len() == 3);
-    assert!(project.project_children().len() == 0);
+    assert!(project.project_children().is_empty());
     assert!(project.task_children().len() == 3);
     project.remove_child(SchedulableItem::Task(child_task_2.id()));
     assert!(project.has_children());
     assert!(project.children().len() == 2);
-    assert!(project.project_children().len() == 0);
+    assert!(project.project_children().is_empty());
     assert!(project.task_children().len() == 2);
 }
 
@@ -470,12 +470,12 @@ fn remove_multiple_child_tasks_from_project() {
     project.add_children(children);
     assert!(project.has_children());
     assert!(project.children().len() == 3);
-    assert!(project.project_children().len() == 0);
+    assert!(project.project_children().is_empty());
     assert!(project.task_children().len() == 3);
     project.remove_children(children_to_remove);
     assert!(project.has_children());
     assert!(project.children().len() == 1);
-    assert!(project.project_children().len() == 0);
+    assert!(project.project_children().is_empty());
     assert!(project.task_children().len() == 1);
     assert!(project.task_children().contains(&child_task_2.id().clone()));
 }
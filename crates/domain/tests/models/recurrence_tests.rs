@@ -0,0 +1,126 @@
+use chrono::{Duration, TimeZone, Utc};
+use project_tracker_core::HasId;
+use project_tracker_core::builders::task_builder::TaskBuilder;
+use project_tracker_core::factories::task_factory::*;
+use project_tracker_core::models::{
+    recurrence::{Frequency, RecurrenceEnd, RecurrenceError, RecurrenceRule},
+    schedulable::{Schedulable, SchedulableItemStatus},
+};
+
+#[test]
+fn rules_round_trip_through_rrule_text() {
+    let rule = RecurrenceRule::weekly().with_interval(2).with_count(6);
+    assert_eq!(rule.to_string(), "FREQ=WEEKLY;INTERVAL=2;COUNT=6");
+    assert_eq!("FREQ=WEEKLY;INTERVAL=2;COUNT=6".parse::<RecurrenceRule>(), Ok(rule));
+
+    let until = Utc.with_ymd_and_hms(2026, 12, 31, 0, 0, 0).unwrap();
+    let rule: RecurrenceRule = "FREQ=DAILY;UNTIL=20261231T000000Z".parse().unwrap();
+    assert_eq!(rule.frequency(), Frequency::Daily);
+    assert_eq!(rule.interval(), 1);
+    assert_eq!(rule.end(), RecurrenceEnd::Until(until));
+}
+
+#[test]
+fn invalid_rules_are_rejected() {
+    assert_eq!(
+        "FREQ=HOURLY".parse::<RecurrenceRule>(),
+        Err(RecurrenceError::InvalidRule("FREQ=HOURLY".into()))
+    );
+    assert!("INTERVAL=2".parse::<RecurrenceRule>().is_err());
+    assert!("FREQ=DAILY;INTERVAL=0".parse::<RecurrenceRule>().is_err());
+}
+
+#[test]
+fn monthly_steps_clamp_to_the_end_of_the_month() {
+    let date = Utc.with_ymd_and_hms(2026, 1, 31, 9, 0, 0).unwrap();
+    let rule = RecurrenceRule::monthly();
+    assert_eq!(rule.occurrence_date(date, 1), Some(date));
+    assert_eq!(rule.occurrence_date(date, 2), Some(Utc.with_ymd_and_hms(2026, 2, 28, 9, 0, 0).unwrap()));
+    assert_eq!(rule.occurrence_date(date, 3), Some(Utc.with_ymd_and_hms(2026, 3, 31, 9, 0, 0).unwrap()));
+    assert_eq!(rule.occurrence_date(date, 0), None);
+}
+
+#[test]
+fn monthly_series_keep_their_day_after_a_short_month() {
+    let anchor = Utc.with_ymd_and_hms(2028, 1, 31, 9, 0, 0).unwrap();
+    let first = TaskBuilder::new()
+        .with_start_date(Some(anchor))
+        .with_due_date(Some(anchor + Duration::days(1)))
+        .with_recurrence(Some(RecurrenceRule::monthly()))
+        .build();
+
+    let second = first.next_occurrence().unwrap();
+    let third = second.next_occurrence().unwrap();
+    let fourth = third.next_occurrence().unwrap();
+
+    let starts: Vec<_> = [&second, &third, &fourth].iter().map(|task| task.start_date().unwrap()).collect();
+    assert_eq!(
+        starts,
+        vec![
+            Utc.with_ymd_and_hms(2028, 2, 29, 9, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2028, 3, 31, 9, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2028, 4, 30, 9, 0, 0).unwrap(),
+        ]
+    );
+    assert_eq!(third.due_date(), Some(Utc.with_ymd_and_hms(2028, 4, 1, 9, 0, 0).unwrap()));
+    assert_eq!(fourth.series_start_date(), Some(anchor));
+    assert_eq!(fourth.occurrence(), 4);
+}
+
+#[test]
+fn next_occurrence_shifts_dates_and_links_the_series() {
+    let mut task = sample_weekly_task();
+    task.promote();
+    let next = task.next_occurrence().unwrap();
+
+    assert_ne!(next.id(), task.id());
+    assert_eq!(next.name(), task.name());
+    assert_eq!(next.status(), SchedulableItemStatus::NotStarted);
+    assert_eq!(next.start_date().unwrap(), task.start_date().unwrap() + Duration::weeks(1));
+    assert_eq!(next.due_date().unwrap(), task.due_date().unwrap() + Duration::weeks(1));
+    assert_eq!(next.series_id(), task.id());
+    assert_eq!(next.occurrence(), 2);
+
+    let third = next.next_occurrence().unwrap();
+    assert_eq!(third.series_id(), task.id());
+    assert_eq!(third.occurrence(), 3);
+}
+
+#[test]
+fn series_end_after_count_or_until() {
+    let start = Utc::now();
+    let counted = TaskBuilder::new()
+        .with_start_date(Some(start))
+        .with_recurrence(Some(RecurrenceRule::daily().with_count(2)))
+        .build();
+    let second = counted.next_occurrence().unwrap();
+    assert!(second.next_occurrence().is_none());
+
+    let bounded = TaskBuilder::new()
+        .with_due_date(Some(start))
+        .with_recurrence(Some(RecurrenceRule::daily().with_until(start + Duration::hours(36))))
+        .build();
+    assert!(bounded.next_occurrence().is_some());
+    assert!(bounded.next_occurrence().unwrap().next_occurrence().is_none());
+}
+
+#[test]
+fn tasks_without_rule_or_dates_do_not_recur() {
+    assert!(sample_task().next_occurrence().is_none());
+
+    let undated = TaskBuilder::new().with_recurrence(Some(RecurrenceRule::daily())).build();
+    assert!(undated.is_recurring());
+    assert!(undated.next_occurrence().is_none());
+}
+
+#[test]
+fn next_occurrence_after_skips_missed_dates() {
+    let task = TaskBuilder::new()
+        .with_start_date(Some(Utc::now() - Duration::days(10)))
+        .with_recurrence(Some(RecurrenceRule::weekly()))
+        .build();
+
+    let next = task.next_occurrence_after(Utc::now()).unwrap();
+    assert!(next.start_date().unwrap() > Utc::now());
+    assert_eq!(next.occurrence(), 3);
+}
@@ -3,7 +3,11 @@ use project_tracker_core::{
     HasId,
     builders::{project_builder::ProjectBuilder, task_builder::TaskBuilder},
     factories::{person_factory::basic_person, tag_factory::basic_tag},
-    models::{project::Project, schedulable::Schedulable, task::Task},
+    models::{
+        project::Project,
+        schedulable::{Priority, Schedulable},
+        task::Task,
+    },
 };
 
 // Generic function that works with any Schedulable type
@@ -93,3 +97,18 @@ fn test_validation_behavior() {
     assert!(!task.is_valid_start_date(Some(future_date + Duration::days(1))));
 }
 
+
+#[test]
+fn test_priority_defaults_to_medium_and_can_change() {
+    let mut project = ProjectBuilder::new().build();
+    let mut task = TaskBuilder::new().with_priority(Priority::Low).build();
+    assert_eq!(project.priority(), Priority::Medium);
+    assert_eq!(task.priority(), Priority::Low);
+
+    project.set_priority(Priority::Critical);
+    task.set_priority(Priority::High);
+    assert_eq!(project.priority(), Priority::Critical);
+    assert_eq!(task.priority(), Priority::High);
+    assert!(Priority::Critical > Priority::High && Priority::Medium > Priority::Low);
+    assert_eq!(Priority::High.to_string(), "High");
+}
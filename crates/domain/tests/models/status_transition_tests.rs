@@ -0,0 +1,67 @@
+use project_tracker_core::HasId;
+use project_tracker_core::factories::{person_factory::basic_person, project_factory::*, task_factory::*};
+use project_tracker_core::models::schedulable::{Schedulable, SchedulableItem, SchedulableItemStatus};
+use project_tracker_core::models::status_transition::{StatusAction, TransitionError};
+
+#[test]
+fn promote_walks_the_workflow() {
+    let mut task = sample_task();
+    let expected = [
+        SchedulableItemStatus::Planned,
+        SchedulableItemStatus::InProgress,
+        SchedulableItemStatus::InReview,
+        SchedulableItemStatus::Completed,
+    ];
+    for status in expected {
+        let transition = task.transition(StatusAction::Promote, None).unwrap();
+        assert_eq!(transition.to(), status);
+        assert_eq!(task.status(), status);
+    }
+}
+
+#[test]
+fn transition_records_who_from_and_to() {
+    let mut project = sample_in_progress_project();
+    let person = basic_person();
+    let transition = project.transition(StatusAction::Promote, Some(person.id())).unwrap();
+
+    assert_eq!(transition.item(), &SchedulableItem::Project(project.id()));
+    assert_eq!(transition.from(), SchedulableItemStatus::InProgress);
+    assert_eq!(transition.to(), SchedulableItemStatus::InReview);
+    assert_eq!(transition.by(), Some(&person.id()));
+}
+
+#[test]
+fn promoting_archived_item_is_rejected() {
+    let mut project = sample_archived_project();
+    let result = project.transition(StatusAction::Promote, None);
+
+    assert_eq!(
+        result,
+        Err(TransitionError::IllegalTransition {
+            from: SchedulableItemStatus::Archived,
+            action: StatusAction::Promote,
+        })
+    );
+    assert_eq!(project.status(), SchedulableItemStatus::Archived);
+}
+
+#[test]
+fn demote_and_cancel_limits() {
+    let mut task = sample_task();
+    assert!(task.transition(StatusAction::Demote, None).is_err());
+
+    let mut completed = sample_completed_task();
+    assert!(completed.transition(StatusAction::Cancel, None).is_err());
+    assert!(completed.transition(StatusAction::Archive, None).is_ok());
+    assert!(completed.transition(StatusAction::Archive, None).is_err());
+
+    let mut canceled = sample_canceled_task();
+    assert!(canceled.transition(StatusAction::Cancel, None).is_err());
+}
+
+#[test]
+fn illegal_transition_message_names_the_move() {
+    let error = SchedulableItemStatus::Completed.after(StatusAction::Promote).unwrap_err();
+    assert_eq!(error.to_string(), "Cannot promote an item that is Completed");
+}
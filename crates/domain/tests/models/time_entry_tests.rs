@@ -0,0 +1,58 @@
+use chrono::{Duration, Utc};
+use project_tracker_core::HasId;
+use project_tracker_core::factories::time_entry_factory::*;
+use project_tracker_core::models::{
+    person::Person,
+    schedulable::SchedulableItem,
+    task::Task,
+    time_entry::{TimeEntry, TimeEntryError},
+};
+use project_tracker_core::id::Id;
+
+#[test]
+fn create_time_entry_id() {
+    let entry = sample_time_entry();
+    assert!(entry.id().to_string().starts_with("time_entry-"));
+    assert_eq!(entry.duration(), Some(Duration::minutes(90)));
+    assert_eq!(entry.note(), "Paired on the parser");
+}
+
+#[test]
+fn start_and_stop_timer() {
+    let start = Utc::now() - Duration::hours(1);
+    let item = SchedulableItem::Task(Id::<Task>::new());
+    let mut entry = TimeEntry::start_timer(Id::<Person>::new(), item.clone(), start);
+    assert!(entry.is_running());
+    assert_eq!(entry.item(), &item);
+    assert_eq!(entry.duration(), None);
+    assert_eq!(entry.duration_at(start + Duration::minutes(5)), Duration::minutes(5));
+
+    entry.stop_timer(start + Duration::minutes(45)).unwrap();
+    assert!(!entry.is_running());
+    assert_eq!(entry.duration(), Some(Duration::minutes(45)));
+    assert_eq!(entry.duration_at(Utc::now()), Duration::minutes(45));
+}
+
+#[test]
+fn stopping_twice_or_before_start_fails() {
+    let mut entry = sample_running_time_entry();
+    let before_start = entry.start() - Duration::minutes(1);
+    assert!(matches!(
+        entry.stop_timer(before_start),
+        Err(TimeEntryError::EndsBeforeStart { .. })
+    ));
+    assert!(entry.is_running());
+
+    entry.stop_timer(Utc::now()).unwrap();
+    assert_eq!(entry.stop_timer(Utc::now()), Err(TimeEntryError::AlreadyStopped));
+}
+
+#[test]
+fn set_and_clear_note() {
+    let mut entry = basic_time_entry();
+    assert!(!entry.has_note());
+    entry.set_note("Reading docs");
+    assert_eq!(entry.note(), "Reading docs");
+    entry.clear_note();
+    assert_eq!(entry.note(), "");
+}